@@ -0,0 +1,129 @@
+package principal
+
+import (
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/base64"
+	"encoding/json"
+	"errors"
+	"os"
+	"strings"
+	"time"
+
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+// Header — ключ метаданных, в котором сервисы передают подписанный токен
+const Header = "x-principal"
+
+// openService — проверки здоровья, которые делаются не от чьего-либо имени
+const openService = "/grpc.health.v1.Health/"
+
+const (
+	RoleAdmin   = "admin"
+	RoleClient  = "client"
+	RoleService = "service"
+)
+
+var errInvalidToken = errors.New("invalid principal token")
+
+// Principal — тот, от чьего имени сделан вызов, проверенный гейтвеем
+type Principal struct {
+	ID   string `json:"sub"`
+	Role string `json:"role"`
+	Exp  int64  `json:"exp"`
+}
+
+// IsPrivileged — админ или сам сервис
+func (p Principal) IsPrivileged() bool {
+	return p.Role == RoleAdmin || p.Role == RoleService
+}
+
+// CanAccess — может ли принципал работать с ресурсами клиента
+func (p Principal) CanAccess(clientID string) bool {
+	return p.IsPrivileged() || p.ID == clientID
+}
+
+type contextKey struct{}
+
+// FromContext возвращает принципал вызова, если он был передан
+func FromContext(ctx context.Context) (Principal, bool) {
+	p, ok := ctx.Value(contextKey{}).(Principal)
+	return p, ok
+}
+
+// CanAccess — есть ли у вызова принципал, которому доступны ресурсы клиента
+func CanAccess(ctx context.Context, clientID string) bool {
+	p, ok := FromContext(ctx)
+	return ok && p.CanAccess(clientID)
+}
+
+// UnaryServerInterceptor проверяет токен принципала и кладёт принципал в контекст.
+// Вызовы без токена или с невалидным токеном отклоняются, кроме проверок здоровья.
+// Без PRINCIPAL_TOKEN_SECRET сервис не запускается.
+func UnaryServerInterceptor() grpc.UnaryServerInterceptor {
+	secret := []byte(os.Getenv("PRINCIPAL_TOKEN_SECRET"))
+	if len(secret) == 0 {
+		panic("PRINCIPAL_TOKEN_SECRET is not set")
+	}
+
+	return func(ctx context.Context, req any, info *grpc.UnaryServerInfo, handler grpc.UnaryHandler) (any, error) {
+		if strings.HasPrefix(info.FullMethod, openService) {
+			return handler(ctx, req)
+		}
+
+		md, _ := metadata.FromIncomingContext(ctx)
+		tokens := md.Get(Header)
+		if len(tokens) == 0 {
+			return nil, status.Error(codes.Unauthenticated, "Missing principal token")
+		}
+
+		p, err := verify(tokens[0], secret)
+		if err != nil {
+			return nil, status.Error(codes.Unauthenticated, "Invalid principal token")
+		}
+
+		return handler(context.WithValue(ctx, contextKey{}, p), req)
+	}
+}
+
+// verify проверяет подпись HS256 и срок действия токена
+func verify(token string, secret []byte) (Principal, error) {
+	parts := strings.Split(token, ".")
+	if len(parts) != 3 {
+		return Principal{}, errInvalidToken
+	}
+
+	var header struct {
+		Alg string `json:"alg"`
+	}
+	rawHeader, err := base64.RawURLEncoding.DecodeString(parts[0])
+	if err != nil || json.Unmarshal(rawHeader, &header) != nil || header.Alg != "HS256" {
+		return Principal{}, errInvalidToken
+	}
+
+	signature, err := base64.RawURLEncoding.DecodeString(parts[2])
+	if err != nil {
+		return Principal{}, errInvalidToken
+	}
+	mac := hmac.New(sha256.New, secret)
+	mac.Write([]byte(parts[0] + "." + parts[1]))
+	if !hmac.Equal(signature, mac.Sum(nil)) {
+		return Principal{}, errInvalidToken
+	}
+
+	var p Principal
+	rawClaims, err := base64.RawURLEncoding.DecodeString(parts[1])
+	if err != nil || json.Unmarshal(rawClaims, &p) != nil || p.ID == "" {
+		return Principal{}, errInvalidToken
+	}
+	if time.Now().Unix() >= p.Exp {
+		return Principal{}, errInvalidToken
+	}
+
+	return p, nil
+}
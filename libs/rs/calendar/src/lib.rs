@@ -0,0 +1,234 @@
+use chrono::{DateTime, Utc};
+
+const PRODUCT_ID: &str = "-//drop-table-prod//coworking//RU";
+const MAX_LINE_OCTETS: usize = 75;
+
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventStatus {
+    Confirmed,
+    Cancelled,
+}
+
+/// A single RFC 5545 `VEVENT`.
+#[derive(Clone, Debug)]
+pub struct Event {
+    pub uid: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub summary: String,
+    pub location: String,
+    pub description: String,
+    pub status: EventStatus,
+}
+
+/// An RFC 5545 `VCALENDAR` published with the `PUBLISH` method.
+#[derive(Clone, Debug, Default)]
+pub struct Calendar {
+    pub name: Option<String>,
+    pub events: Vec<Event>,
+}
+impl Calendar {
+    pub fn new(name: Option<String>, events: Vec<Event>) -> Self {
+        Self { name, events }
+    }
+
+    /// Renders the calendar with CRLF line endings and long lines folded.
+    pub fn to_ics(&self, stamp: DateTime<Utc>) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:{PRODUCT_ID}"),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+        ];
+        if let Some(name) = &self.name {
+            lines.push(format!("X-WR-CALNAME:{}", escape(name)));
+        }
+
+        for event in &self.events {
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!("UID:{}", escape(&event.uid)),
+                format!("DTSTAMP:{}", format_datetime(stamp)),
+                format!("DTSTART:{}", format_datetime(event.starts_at)),
+                format!("DTEND:{}", format_datetime(event.ends_at)),
+                format!("SUMMARY:{}", escape(&event.summary)),
+                format!("LOCATION:{}", escape(&event.location)),
+                format!("DESCRIPTION:{}", escape(&event.description)),
+                format!(
+                    "STATUS:{}",
+                    match event.status {
+                        EventStatus::Confirmed => "CONFIRMED",
+                        EventStatus::Cancelled => "CANCELLED",
+                    }
+                ),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        lines
+            .iter()
+            .map(|line| fold(line))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+}
+
+fn format_datetime(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Splits the content line into lines of at most 75 octets, never inside a character.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut length = 0;
+    for char in line.chars() {
+        if length + char.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(char);
+        length += char.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// Physical lines of the folded content line, without the CRLF.
+    fn lines(folded: &str) -> Vec<&str> {
+        folded
+            .strip_suffix("\r\n")
+            .expect("no trailing CRLF")
+            .split("\r\n")
+            .collect()
+    }
+
+    fn unfold(folded: &str) -> String {
+        folded.trim_end_matches("\r\n").replace("\r\n ", "")
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape("Room 1; floor 2"), "Room 1\\; floor 2");
+        assert_eq!(escape("Moscow, Arbat"), "Moscow\\, Arbat");
+        assert_eq!(escape("first\nsecond"), "first\\nsecond");
+        assert_eq!(escape("first\r\nsecond"), "first\\nsecond");
+        assert_eq!(escape("C:\\desk"), "C:\\\\desk");
+    }
+
+    #[test]
+    fn escapes_backslashes_before_the_rest() {
+        assert_eq!(escape("\\;"), "\\\\\\;");
+        assert_eq!(escape("\\n"), "\\\\n");
+    }
+
+    #[test]
+    fn keeps_plain_text() {
+        assert_eq!(escape("Переговорная: 10:00"), "Переговорная: 10:00");
+        assert_eq!(escape(""), "");
+    }
+
+    #[test]
+    fn keeps_short_lines_whole() {
+        assert_eq!(fold("SUMMARY:Desk"), "SUMMARY:Desk\r\n");
+
+        let line = "X".repeat(MAX_LINE_OCTETS);
+        assert_eq!(fold(&line), format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn folds_at_75_octets() {
+        let line = "X".repeat(MAX_LINE_OCTETS + 1);
+        let folded = fold(&line);
+
+        assert_eq!(lines(&folded), [&line[..MAX_LINE_OCTETS], " X"]);
+    }
+
+    #[test]
+    fn folds_continuation_lines_with_the_leading_space() {
+        let line = "X".repeat(3 * MAX_LINE_OCTETS);
+        let folded = fold(&line);
+
+        assert_eq!(lines(&folded).len(), 4);
+        assert!(lines(&folded)
+            .iter()
+            .all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert_eq!(unfold(&folded), line);
+    }
+
+    #[test]
+    fn never_splits_multibyte_characters() {
+        // the two-octet character would end at octet 76
+        let line = format!("{}Ж", "X".repeat(MAX_LINE_OCTETS - 1));
+        assert_eq!(
+            lines(&fold(&line)),
+            [&"X".repeat(MAX_LINE_OCTETS - 1)[..], " Ж"]
+        );
+
+        // the two-octet character ends exactly at octet 75
+        let line = format!("{}Ж", "X".repeat(MAX_LINE_OCTETS - 2));
+        assert_eq!(lines(&fold(&line)), [line.as_str()]);
+
+        for character in ["Ж", "€", "🪑"] {
+            for padding in 0..4 {
+                let line = format!("{}{}", "X".repeat(padding), character.repeat(60));
+                let folded = fold(&line);
+
+                assert!(lines(&folded)
+                    .iter()
+                    .all(|line| line.len() <= MAX_LINE_OCTETS));
+                assert_eq!(unfold(&folded), line);
+            }
+        }
+    }
+
+    #[test]
+    fn renders_escaped_and_folded_events() {
+        let starts_at = Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap();
+        let calendar = Calendar::new(
+            Some("Бронирования".to_string()),
+            vec![Event {
+                uid: "reservation-1@coworking".to_string(),
+                starts_at,
+                ends_at: starts_at + chrono::TimeDelta::hours(2),
+                summary: "Место A1, этаж 2".to_string(),
+                location: "Москва; Арбат".to_string(),
+                description: "Вход со двора.\nВозьмите пропуск на ресепшене, он понадобится \
+                    для прохода на этаж"
+                    .to_string(),
+                status: EventStatus::Confirmed,
+            }],
+        );
+
+        let ics = calendar.to_ics(starts_at);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("X-WR-CALNAME:Бронирования\r\n"));
+        assert!(ics.contains("DTSTART:20250303T100000Z\r\n"));
+        assert!(ics.contains("SUMMARY:Место A1\\, этаж 2\r\n"));
+        assert!(ics.contains("LOCATION:Москва\\; Арбат\r\n"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert!(ics.replace("\r\n ", "").contains(
+            "DESCRIPTION:Вход со двора.\\nВозьмите пропуск на ресепшене\\, он понадобится \
+                для прохода на этаж\r\n"
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+use std::{collections::HashMap, env, fs, sync::LazyLock};
+
+use toml::{Table, Value};
+
+/// Variable with the path to the config file, which is optional.
+pub const PATH_VARIABLE: &str = "CONFIG_FILE";
+
+pub struct File {
+    pub values: HashMap<String, String>,
+    pub errors: Vec<String>,
+}
+
+static FILE: LazyLock<File> = LazyLock::new(read);
+
+pub fn get() -> &'static File {
+    &FILE
+}
+
+fn read() -> File {
+    match env::var(PATH_VARIABLE) {
+        Ok(path) => read_from(&path),
+        Err(..) => File {
+            values: HashMap::new(),
+            errors: Vec::new(),
+        },
+    }
+}
+
+fn read_from(path: &str) -> File {
+    let mut file = File {
+        values: HashMap::new(),
+        errors: Vec::new(),
+    };
+
+    let table = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read the config file `{path}`: {err}"))
+        .and_then(|content| {
+            content
+                .parse::<Table>()
+                .map_err(|err| format!("Failed to parse the config file `{path}`: {err}"))
+        });
+
+    match table {
+        Ok(table) => flatten("", table, &mut file),
+        Err(err) => file.errors.push(err),
+    }
+
+    file
+}
+
+/// Turns the nested tables into variable names, `[grpc] request_timeout` is `GRPC_REQUEST_TIMEOUT`.
+fn flatten(prefix: &str, table: Table, file: &mut File) {
+    for (key, value) in table {
+        let key = key.to_uppercase().replace('-', "_");
+        let name = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}_{key}")
+        };
+
+        match value {
+            Value::Table(table) => flatten(&name, table, file),
+            Value::Array(items) => {
+                match items.into_iter().map(scalar).collect::<Option<Vec<_>>>() {
+                    Some(items) => {
+                        file.values.insert(name, items.join(","));
+                    }
+                    None => file.errors.push(format!(
+                        "`{name}` in the config file can only be a list of plain values"
+                    )),
+                }
+            }
+            value => {
+                file.values.insert(name, scalar(value).unwrap_or_default());
+            }
+        }
+    }
+}
+
+fn scalar(value: Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value),
+        Value::Integer(value) => Some(value.to_string()),
+        Value::Float(value) => Some(value.to_string()),
+        Value::Boolean(value) => Some(value.to_string()),
+        Value::Datetime(value) => Some(value.to_string()),
+        Value::Array(..) | Value::Table(..) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flattened(content: &str) -> File {
+        let mut file = File {
+            values: HashMap::new(),
+            errors: Vec::new(),
+        };
+        flatten("", content.parse().unwrap(), &mut file);
+        file
+    }
+
+    fn get<'a>(file: &'a File, name: &str) -> Option<&'a str> {
+        file.values.get(name).map(String::as_str)
+    }
+
+    #[test]
+    fn joins_table_names_into_variable_names() {
+        let file = flattened(
+            r#"
+            service_address = "0.0.0.0:8080"
+
+            [grpc]
+            request-timeout = "5s"
+
+            [grpc.breaker]
+            threshold = 5
+            "#,
+        );
+
+        assert_eq!(get(&file, "SERVICE_ADDRESS"), Some("0.0.0.0:8080"));
+        assert_eq!(get(&file, "GRPC_REQUEST_TIMEOUT"), Some("5s"));
+        assert_eq!(get(&file, "GRPC_BREAKER_THRESHOLD"), Some("5"));
+        assert_eq!(file.values.len(), 3);
+        assert!(file.errors.is_empty());
+    }
+
+    #[test]
+    fn writes_plain_values_like_the_env() {
+        let file = flattened(
+            r#"
+            retries = 2
+            multiplier = 1.25
+            enabled = true
+            since = 2025-03-03
+            "#,
+        );
+
+        assert_eq!(get(&file, "RETRIES"), Some("2"));
+        assert_eq!(get(&file, "MULTIPLIER"), Some("1.25"));
+        assert_eq!(get(&file, "ENABLED"), Some("true"));
+        assert_eq!(get(&file, "SINCE"), Some("2025-03-03"));
+    }
+
+    #[test]
+    fn joins_lists_with_commas() {
+        let file = flattened(
+            r#"
+            promo_codes = ["WELCOME10:10", "HALF:50"]
+            ports = [80, 443]
+            empty = []
+            "#,
+        );
+
+        assert_eq!(get(&file, "PROMO_CODES"), Some("WELCOME10:10,HALF:50"));
+        assert_eq!(get(&file, "PORTS"), Some("80,443"));
+        assert_eq!(get(&file, "EMPTY"), Some(""));
+    }
+
+    #[test]
+    fn rejects_nested_lists() {
+        let file = flattened(
+            r#"
+            [grpc]
+            nested = [[1, 2], [3]]
+            tables = [{ a = 1 }]
+            "#,
+        );
+
+        assert_eq!(file.values.len(), 0);
+        assert_eq!(
+            file.errors,
+            [
+                "`GRPC_NESTED` in the config file can only be a list of plain values",
+                "`GRPC_TABLES` in the config file can only be a list of plain values",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_malformed_files() {
+        let missing = read_from("/nonexistent/config.toml");
+        assert!(missing.errors[0].starts_with("Failed to read the config file"));
+
+        let path = env::temp_dir().join(format!("env-config-{}.toml", std::process::id()));
+        fs::write(&path, "[grpc\n").unwrap();
+        let malformed = read_from(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        assert!(malformed.errors[0].starts_with("Failed to parse the config file"));
+        assert!(malformed.values.is_empty());
+    }
+}
@@ -1,42 +1,209 @@
+//! Configuration of the services, declared with [`environment_variables!`].
+//!
+//! Every variable is looked up in the env first, then in the TOML file at `CONFIG_FILE`
+//! and falls back to its default. Variables declared without a default are required
+//! and can't be empty. Tables of the file are joined into the names,
+//! so `request_timeout` in the `[grpc]` table sets `GRPC_REQUEST_TIMEOUT`.
+//!
+//! Binaries call [`load`] first thing: it reports every invalid variable at once
+//! and dumps the resolved config when started with `--print-config`.
+
+use std::{env, fmt, process};
+
+mod file;
+mod value;
+
+pub use std::time::Duration;
+pub use url::Url;
+pub use value::{IntoValue, Secret, Value};
+
+/// Flag which makes [`load`] print the config and exit.
+pub const PRINT_CONFIG_FLAG: &str = "--print-config";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Source {
+    Default,
+    File,
+    Env,
+}
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Default => "default",
+            Self::File => "config file",
+            Self::Env => "env",
+        })
+    }
+}
+
+/// Variables declared by one [`environment_variables!`] invocation.
+#[derive(Clone, Copy)]
+pub struct Section {
+    pub name: &'static str,
+    pub variables: fn() -> Vec<Variable>,
+}
+
+pub struct Variable {
+    pub name: &'static str,
+    /// Shown value with its source, or the reason it's invalid.
+    pub value: Result<(String, Source), String>,
+}
+impl Variable {
+    pub fn new<T: Value>(
+        name: &'static str,
+        type_name: &'static str,
+        default: Option<impl FnOnce() -> T>,
+    ) -> Self {
+        Self {
+            name,
+            value: resolve(name, type_name, default).map(|(value, source)| (value.show(), source)),
+        }
+    }
+}
+
+pub fn resolve<T: Value>(
+    name: &str,
+    type_name: &str,
+    default: Option<impl FnOnce() -> T>,
+) -> Result<(T, Source), String> {
+    pick(
+        name,
+        type_name,
+        env::var(name).ok(),
+        file::get().values.get(name).cloned(),
+        default,
+    )
+}
+
+/// The env takes precedence over the config file, and both over the default.
+fn pick<T: Value>(
+    name: &str,
+    type_name: &str,
+    env: Option<String>,
+    file: Option<String>,
+    default: Option<impl FnOnce() -> T>,
+) -> Result<(T, Source), String> {
+    let (raw, source) = if let Some(raw) = env {
+        (raw, Source::Env)
+    } else if let Some(raw) = file {
+        (raw, Source::File)
+    } else {
+        return default
+            .map(|default| (default(), Source::Default))
+            .ok_or_else(|| format!("Variable `{name}` is required but isn't set"));
+    };
+
+    if default.is_none() && raw.trim().is_empty() {
+        return Err(format!(
+            "Variable `{name}` is required but is empty in the {source}"
+        ));
+    }
+
+    T::parse(&raw).map(|value| (value, source)).map_err(|err| {
+        format!("Invalid value of `{name}` from the {source}: expected `{type_name}`, {err}")
+    })
+}
+
+#[doc(hidden)]
+pub fn get<T: Value>(name: &str, type_name: &str, default: Option<impl FnOnce() -> T>) -> T {
+    let (value, source) = resolve(name, type_name, default).unwrap_or_else(|err| panic!("{err}"));
+
+    if source == Source::Default {
+        tracing::warn!(
+            "Variable `{name}` isn't set! Using default value `{}`",
+            value.show()
+        );
+    }
+
+    value
+}
+
+/// Checks the variables of all the sections, exiting with every error found if any is invalid.
+/// With `--print-config` prints the resolved values, secrets hidden, and exits.
+pub fn load(sections: &[Section]) {
+    let print = env::args().skip(1).any(|arg| arg == PRINT_CONFIG_FLAG);
+
+    let file = file::get();
+    let mut errors = file.errors.clone();
+    let mut known = Vec::new();
+    let mut output = String::new();
+
+    for section in sections {
+        output.push_str(&format!("# {}\n", section.name));
+
+        for variable in (section.variables)() {
+            known.push(variable.name);
+
+            match variable.value {
+                Ok((value, source)) => {
+                    output.push_str(&format!("{} = {value:?}  # {source}\n", variable.name));
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+        output.push('\n');
+    }
+
+    let mut unknown: Vec<_> = file
+        .values
+        .keys()
+        .filter(|name| !known.contains(&name.as_str()))
+        .collect();
+    unknown.sort();
+    errors.extend(
+        unknown
+            .into_iter()
+            .map(|name| format!("Unknown variable `{name}` in the config file")),
+    );
+
+    if !errors.is_empty() {
+        eprintln!("Invalid configuration:");
+        for err in errors {
+            eprintln!("  - {err}");
+        }
+        process::exit(1);
+    }
+
+    if print {
+        print!("{}", output.trim_end());
+        println!();
+        process::exit(0);
+    }
+}
+
 #[macro_export]
 macro_rules! environment_variables {
-    ( $( $name:ident: $type:tt = $default_value:expr ),* $(,)? ) => {
+    ( $( $name:ident: $type:ty $(= $default_value:expr)? ),* $(,)? ) => {
         pub mod config {
-            use std::{
-                sync::LazyLock,
-                env,
-                any::type_name,
-            };
-
-            fn get_variable_type<T>(_: &T) -> &'static str {
-                type_name::<T>().split("::").last().unwrap()
-            }
+            #[allow(unused_imports)]
+            use $crate::{Duration, Secret, Url};
+            use std::sync::LazyLock;
 
             $(
                 pub static $name: LazyLock<$type> = LazyLock::new(|| {
-                    let name = stringify!($name);
-
-                    if let Ok(value) = env::var(name) {
-                        if let Ok(value) = value.parse::<$type>() {
-                            value
-                        } else {
-                            panic!(
-                                "Invalid value type for the variable `{name}`! Expected type `{}`, got `{}`.",
-                                stringify!($type),
-                                get_variable_type(&value)
-                            )
-                        }
-                    } else {
-                        log::warn!(
-                            "Variable `{}` is missing in the env! Using default value `{}`",
-                            name,
-                            $default_value
-                        );
-                        <$type>::from($default_value)
-                    }
+                    $crate::get(
+                        stringify!($name),
+                        stringify!($type),
+                        $crate::default_value!($type $(, $default_value)?),
+                    )
                 });
             )*
 
+            pub const SECTION: $crate::Section = $crate::Section {
+                name: env!("CARGO_PKG_NAME"),
+                variables: || {
+                    vec![
+                        $(
+                            $crate::Variable::new::<$type>(
+                                stringify!($name),
+                                stringify!($type),
+                                $crate::default_value!($type $(, $default_value)?),
+                            ),
+                        )*
+                    ]
+                },
+            };
+
             pub fn init() {
                 $(
                     LazyLock::force(&$name);
@@ -45,3 +212,91 @@ macro_rules! environment_variables {
         }
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_value {
+    ( $type:ty ) => {
+        None::<fn() -> $type>
+    };
+    ( $type:ty, $default_value:expr ) => {
+        Some(|| $crate::IntoValue::<$type>::into_value($default_value))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NO_DEFAULT: Option<fn() -> u32> = None;
+
+    fn value(raw: &str) -> Option<String> {
+        Some(raw.to_string())
+    }
+
+    #[test]
+    fn takes_env_over_file_over_default() {
+        let default = || Some(|| 1u32);
+
+        assert_eq!(
+            pick("PORT", "u32", value("3"), value("2"), default()),
+            Ok((3, Source::Env))
+        );
+        assert_eq!(
+            pick("PORT", "u32", None, value("2"), default()),
+            Ok((2, Source::File))
+        );
+        assert_eq!(
+            pick("PORT", "u32", None, None, default()),
+            Ok((1, Source::Default))
+        );
+    }
+
+    #[test]
+    fn takes_empty_values_of_optional_variables() {
+        let default = Some(|| "fallback".to_string());
+
+        assert_eq!(
+            pick("NAME", "String", value(""), None, default),
+            Ok((String::new(), Source::Env))
+        );
+    }
+
+    #[test]
+    fn requires_variables_without_default() {
+        assert_eq!(
+            pick("PORT", "u32", None, None, NO_DEFAULT),
+            Err("Variable `PORT` is required but isn't set".to_string())
+        );
+        assert_eq!(
+            pick("PORT", "u32", None, value("7"), NO_DEFAULT),
+            Ok((7, Source::File))
+        );
+    }
+
+    #[test]
+    fn rejects_empty_required_variables() {
+        assert_eq!(
+            pick("KEY", "Secret", value(" "), None, None::<fn() -> Secret>),
+            Err("Variable `KEY` is required but is empty in the env".to_string())
+        );
+        assert_eq!(
+            pick("KEY", "Secret", None, value(""), None::<fn() -> Secret>),
+            Err("Variable `KEY` is required but is empty in the config file".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_invalid_values_with_their_source() {
+        let err = pick("PORT", "u32", value("eighty"), None, NO_DEFAULT).unwrap_err();
+
+        assert!(err.starts_with("Invalid value of `PORT` from the env: expected `u32`"));
+    }
+
+    #[test]
+    fn doesnt_fall_back_on_invalid_values() {
+        let result = pick("PORT", "u32", None, value("-1"), Some(|| 1u32));
+
+        assert!(result.unwrap_err().contains("from the config file"));
+    }
+}
@@ -0,0 +1,197 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use url::Url;
+
+/// Type of a variable, parsed from the env or the config file.
+pub trait Value: Sized {
+    fn parse(raw: &str) -> Result<Self, String>;
+
+    /// How the value is shown in the logs and by `--print-config`, with the secrets hidden.
+    fn show(&self) -> String;
+}
+
+macro_rules! from_str_values {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl Value for $type {
+                fn parse(raw: &str) -> Result<Self, String> {
+                    raw.trim()
+                        .parse()
+                        .map_err(|err: <$type as FromStr>::Err| err.to_string())
+                }
+
+                fn show(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+from_str_values!(bool, i32, i64, u16, u32, u64, usize, f32, f64);
+
+impl Value for String {
+    fn parse(raw: &str) -> Result<Self, String> {
+        Ok(raw.to_string())
+    }
+
+    fn show(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Written like `250ms`, `30s`, `5m` or `1h 30m`.
+impl Value for Duration {
+    fn parse(raw: &str) -> Result<Self, String> {
+        humantime::parse_duration(raw.trim()).map_err(|err| err.to_string())
+    }
+
+    fn show(&self) -> String {
+        humantime::format_duration(*self).to_string()
+    }
+}
+
+impl Value for Url {
+    fn parse(raw: &str) -> Result<Self, String> {
+        Url::parse(raw.trim()).map_err(|err| err.to_string())
+    }
+
+    fn show(&self) -> String {
+        let mut url = self.clone();
+        if url.password().is_some() {
+            let _ = url.set_password(Some("redacted"));
+        }
+        url.to_string()
+    }
+}
+
+/// Comma-separated, an empty value is an empty list.
+impl<T: Value> Value for Vec<T> {
+    fn parse(raw: &str) -> Result<Self, String> {
+        if raw.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        raw.split(',').map(|item| T::parse(item.trim())).collect()
+    }
+
+    fn show(&self) -> String {
+        self.iter().map(Value::show).collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Value which never gets into the logs, it has to be taken out with [`Secret::expose`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+impl Secret {
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(..)")
+    }
+}
+impl Value for Secret {
+    fn parse(raw: &str) -> Result<Self, String> {
+        Ok(Self(raw.to_string()))
+    }
+
+    fn show(&self) -> String {
+        if self.0.is_empty() {
+            String::new()
+        } else {
+            "[redacted]".to_string()
+        }
+    }
+}
+
+/// Default of a variable, either the value itself or its text, which is parsed like the env.
+pub trait IntoValue<T> {
+    fn into_value(self) -> T;
+}
+impl<T: Value> IntoValue<T> for T {
+    fn into_value(self) -> T {
+        self
+    }
+}
+impl<T: Value> IntoValue<T> for &str {
+    fn into_value(self) -> T {
+        T::parse(self).unwrap_or_else(|err| panic!("Invalid default value `{self}`: {err}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_and_flags_around_whitespace() {
+        assert_eq!(u32::parse(" 8080 "), Ok(8080));
+        assert_eq!(f64::parse("1.25"), Ok(1.25));
+        assert_eq!(bool::parse("true\n"), Ok(true));
+        assert!(u32::parse("-1").is_err());
+        assert!(bool::parse("yes").is_err());
+    }
+
+    #[test]
+    fn keeps_strings_as_is() {
+        assert_eq!(String::parse(" padded "), Ok(" padded ".to_string()));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(Duration::parse("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(Duration::parse("1h 30m"), Ok(Duration::from_secs(90 * 60)));
+        assert_eq!(Duration::from_secs(90).show(), "1m 30s");
+        assert!(Duration::parse("5").is_err());
+    }
+
+    #[test]
+    fn parses_comma_separated_lists() {
+        assert_eq!(Vec::<u32>::parse("1, 2,3"), Ok(vec![1, 2, 3]));
+        assert_eq!(Vec::<String>::parse(" "), Ok(vec![]));
+        assert_eq!(
+            Vec::<Duration>::parse("1s,2m"),
+            Ok(vec![Duration::from_secs(1), Duration::from_secs(120)])
+        );
+        assert!(Vec::<u32>::parse("1,,2").is_err());
+        assert_eq!(vec![1u32, 2].show(), "1,2");
+    }
+
+    #[test]
+    fn redacts_passwords_of_urls() {
+        let url = Url::parse("postgres://user:hunter2@db:5432/client").unwrap();
+        let plain = Url::parse("http://otel-collector:4317").unwrap();
+
+        assert_eq!(url.show(), "postgres://user:redacted@db:5432/client");
+        assert_eq!(plain.show(), "http://otel-collector:4317/");
+        assert!(Url::parse("not a url").is_err());
+    }
+
+    #[test]
+    fn hides_secrets() {
+        let secret = Secret::parse("hunter2").unwrap();
+
+        assert_eq!(secret.expose(), "hunter2");
+        assert_eq!(secret.show(), "[redacted]");
+        assert_eq!(format!("{secret:?}"), "Secret(..)");
+        assert_eq!(Secret::parse("").unwrap().show(), "");
+        assert_eq!(vec![secret].show(), "[redacted]");
+    }
+
+    #[test]
+    fn parses_defaults_written_as_text() {
+        let timeout: Duration = IntoValue::into_value("5s");
+        let retries: u32 = IntoValue::into_value(2u32);
+
+        assert_eq!(timeout, Duration::from_secs(5));
+        assert_eq!(retries, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid default value `soon`")]
+    fn panics_on_invalid_defaults() {
+        let _: Duration = IntoValue::into_value("soon");
+    }
+}
@@ -0,0 +1,58 @@
+use std::str::FromStr;
+
+use env_config::environment_variables;
+use tracing::Subscriber;
+use tracing_subscriber::{registry::LookupSpan, EnvFilter, Layer};
+
+environment_variables! {
+    LOG_FORMAT: String = if cfg!(debug_assertions) { "pretty" } else { "json" },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LogFormat {
+    /// Human readable lines, for local runs.
+    Pretty,
+    /// One JSON object per line with the fields of the event and its spans, for log collectors.
+    Json,
+}
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(format!("Unknown log format `{other}`")),
+        }
+    }
+}
+
+/// Writes the events to stdout in the format set by `LOG_FORMAT`,
+/// filtered by `RUST_LOG` (`debug` in debug builds and `info` in release ones by default).
+pub fn layer<S>() -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let default_level = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "info"
+    };
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_level));
+
+    let format: LogFormat = config::LOG_FORMAT
+        .parse()
+        .unwrap_or_else(|err| panic!("{err}"));
+
+    match format {
+        LogFormat::Pretty => tracing_subscriber::fmt::layer().with_filter(filter).boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_current_span(false)
+            .with_span_list(true)
+            .with_filter(filter)
+            .boxed(),
+    }
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
-use log::info;
-use sqlx::{postgres::PgPoolOptions, PgPool};
+use sqlx::{postgres::PgPoolOptions, Connection, PgPool};
+use tracing::info;
 
 pub mod cargo;
 
@@ -23,11 +23,11 @@ macro_rules! migrate {
         use sqlx::{migrate::MigrateDatabase, Connection};
 
         if !sqlx::Postgres::database_exists($uri).await? {
-            log::info!("Creating database...");
+            tracing::info!("Creating database...");
             sqlx::Postgres::create_database($uri).await?;
         }
 
-        log::info!("Applying migrations...");
+        tracing::info!("Applying migrations...");
 
         let mut conn = sqlx::PgConnection::connect($uri).await?;
         sqlx::migrate!($path)
@@ -37,3 +37,7 @@ macro_rules! migrate {
             .expect("Error while running database migrations!");
     };
 }
+
+pub async fn ping(pool: PgPool) -> Result<(), sqlx::Error> {
+    pool.acquire().await?.ping().await
+}
@@ -0,0 +1,335 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::future::BoxFuture;
+use http::{HeaderValue, Request, Response};
+use http_body_util::{BodyExt, Full};
+use tonic::{
+    body::{boxed, BoxBody},
+    transport::{Channel, Endpoint},
+    Code, Status,
+};
+use tower::{Service, ServiceExt};
+
+use crate::{
+    config, make_url, metrics::response_code, principal, request_id, telemetry::inject_context, tls,
+};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Channel to another service, which passes the current trace context,
+/// the id of the client request and the principal along with every call.
+///
+/// Connects on the first call and reconnects on its own, so the dependencies may come up later.
+/// Every call gets a deadline, the calls marked as retryable are retried with a backoff when
+/// the service is unavailable, and after a series of failures the calls are failed right away for a while.
+/// Streaming calls have neither a default deadline nor retries, see [`ServiceChannel::streaming`].
+#[derive(Clone, Debug)]
+pub struct ServiceChannel {
+    name: &'static str,
+    inner: Channel,
+    breaker: Arc<Mutex<CircuitBreaker>>,
+    streaming: &'static [&'static str],
+    retryable: &'static [&'static str],
+}
+impl ServiceChannel {
+    pub fn lazy(name: &'static str, address: &str) -> Self {
+        let url = make_url(address);
+        let mut endpoint = Endpoint::from_shared(url.clone())
+            .unwrap_or_else(|_| panic!("Invalid address of the {name} service: `{address}`"))
+            .connect_timeout(*config::GRPC_CONNECT_TIMEOUT);
+        if let Some(tls) = tls::client_config().filter(|_| url.starts_with("https://")) {
+            endpoint = endpoint
+                .tls_config(tls)
+                .unwrap_or_else(|err| panic!("Invalid TLS config of the {name} service: {err}"));
+        }
+        let inner = endpoint.connect_lazy();
+
+        Self {
+            name,
+            inner,
+            breaker: Arc::default(),
+            streaming: &[],
+            retryable: &[],
+        }
+    }
+
+    /// Marks the methods, as `/package.Service/Method`, which stream their request or response.
+    /// They may take as long as the data takes to transfer and can't be replayed,
+    /// so they only get a deadline when the caller sets one and are never retried.
+    pub fn streaming(mut self, methods: &'static [&'static str]) -> Self {
+        self.streaming = methods;
+        self
+    }
+
+    /// Marks the methods, as `/package.Service/Method`, which only read and are safe to repeat.
+    /// Only they are retried when the service is unavailable, the rest are called once.
+    pub fn retryable(mut self, methods: &'static [&'static str]) -> Self {
+        self.retryable = methods;
+        self
+    }
+
+    async fn send(self, mut request: Request<BoxBody>) -> Result<Response<BoxBody>, BoxError> {
+        if !self.breaker.lock().unwrap().allows_call(Instant::now()) {
+            return Err(
+                Status::unavailable(format!("The {} service is unavailable", self.name)).into(),
+            );
+        }
+
+        let path = request.uri().path().to_string();
+        let is_streaming = self.streaming.contains(&path.as_str());
+
+        let timeout = (!is_streaming).then_some(*config::GRPC_REQUEST_TIMEOUT);
+        if let Some(timeout) = timeout.filter(|_| !request.headers().contains_key("grpc-timeout")) {
+            request.headers_mut().insert(
+                "grpc-timeout",
+                HeaderValue::try_from(format!("{}m", timeout.as_millis())).unwrap(),
+            );
+        }
+
+        let retries = self.retries(&path);
+
+        if retries == 0 {
+            let response = self.attempt(request, timeout).await;
+            self.record(&response);
+            return response;
+        }
+
+        // the body is kept to be sent again, which is fine for unary requests
+        let (parts, body) = request.into_parts();
+        let body = body.collect().await?.to_bytes();
+
+        let mut backoff = *config::GRPC_RETRY_BACKOFF;
+        for attempt in 0..=retries {
+            let mut request = Request::new(boxed(Full::new(body.clone())));
+            *request.method_mut() = parts.method.clone();
+            *request.uri_mut() = parts.uri.clone();
+            *request.version_mut() = parts.version;
+            *request.headers_mut() = parts.headers.clone();
+
+            let response = self.attempt(request, timeout).await;
+            self.record(&response);
+            if attempt == retries || call_code(&response) != Code::Unavailable {
+                return response;
+            }
+
+            tracing::debug!(
+                "The {} service is unavailable, retrying {path} in {backoff:?}",
+                self.name
+            );
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+
+        unreachable!()
+    }
+
+    fn retries(&self, path: &str) -> u32 {
+        if self.retryable.contains(&path) && !self.streaming.contains(&path) {
+            *config::GRPC_RETRIES
+        } else {
+            0
+        }
+    }
+
+    async fn attempt(
+        &self,
+        request: Request<BoxBody>,
+        timeout: Option<Duration>,
+    ) -> Result<Response<BoxBody>, BoxError> {
+        let mut inner = self.inner.clone();
+        let call = async { inner.ready().await?.call(request).await };
+
+        let response = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, call).await.map_err(|_| {
+                Status::deadline_exceeded(format!(
+                    "The {} service didn't answer in {timeout:?}",
+                    self.name
+                ))
+            })?,
+            None => call.await,
+        };
+
+        response.map_err(|err| {
+            tracing::debug!("Failed to reach the {} service: {err}", self.name);
+            Status::unavailable(format!("The {} service is unavailable", self.name)).into()
+        })
+    }
+
+    fn record(&self, response: &Result<Response<BoxBody>, BoxError>) {
+        let failed = matches!(
+            call_code(response),
+            Code::Unavailable | Code::DeadlineExceeded
+        );
+
+        let mut breaker = self.breaker.lock().unwrap();
+        if failed {
+            if breaker.record_failure(Instant::now()) {
+                tracing::warn!(
+                    "The {} service keeps failing, calls to it are paused",
+                    self.name
+                );
+            }
+        } else {
+            breaker.record_success();
+        }
+    }
+}
+impl Service<Request<BoxBody>> for ServiceChannel {
+    type Response = Response<BoxBody>;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // the inner channel is awaited on every call
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut request: Request<BoxBody>) -> Self::Future {
+        inject_context(request.headers_mut());
+        if let Some(value) = request_id::current().and_then(|id| HeaderValue::try_from(id).ok()) {
+            request.headers_mut().insert(request_id::HEADER, value);
+        }
+        if let Some(value) =
+            principal::current().and_then(|principal| HeaderValue::try_from(principal.mint()).ok())
+        {
+            request.headers_mut().insert(principal::HEADER, value);
+        }
+
+        Box::pin(self.clone().send(request))
+    }
+}
+
+/// Errors of the channel itself are reported as statuses by the timeouts and the breaker,
+/// any other error means the service couldn't be reached.
+fn call_code(response: &Result<Response<BoxBody>, BoxError>) -> Code {
+    match response {
+        Ok(..) => response_code(response),
+        Err(err) => err
+            .downcast_ref::<Status>()
+            .map_or(Code::Unavailable, Status::code),
+    }
+}
+
+#[derive(Default, Debug)]
+struct CircuitBreaker {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+impl CircuitBreaker {
+    /// Once the pause is over, calls are let through until one of them fails again.
+    fn allows_call(&self, now: Instant) -> bool {
+        self.open_until.is_none_or(|open_until| now >= open_until)
+    }
+
+    /// Returns whether the breaker has just opened.
+    fn record_failure(&mut self, now: Instant) -> bool {
+        self.failures += 1;
+
+        let threshold_reached = self.failures >= *config::GRPC_BREAKER_THRESHOLD;
+        let half_open = self.open_until.is_some();
+        if threshold_reached || half_open {
+            self.open_until = Some(now + *config::GRPC_BREAKER_COOLDOWN);
+        }
+
+        threshold_reached && !half_open
+    }
+
+    fn record_success(&mut self) {
+        self.failures = 0;
+        self.open_until = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn retries_only_listed_methods() {
+        let channel = ServiceChannel::lazy("reservation", "localhost:1")
+            .streaming(&["/reservation.Reservation/GetBySeat"])
+            .retryable(&[
+                "/reservation.Reservation/GetByID",
+                "/reservation.Reservation/GetBySeat",
+            ]);
+
+        assert_eq!(
+            channel.retries("/reservation.Reservation/GetByID"),
+            *config::GRPC_RETRIES
+        );
+        assert_eq!(channel.retries("/reservation.Reservation/CheckOut"), 0);
+        assert_eq!(channel.retries("/reservation.Reservation/GetBySeat"), 0);
+    }
+
+    fn opened(now: Instant) -> CircuitBreaker {
+        let mut breaker = CircuitBreaker::default();
+        for _ in 1..*config::GRPC_BREAKER_THRESHOLD {
+            assert!(!breaker.record_failure(now));
+        }
+        assert!(breaker.record_failure(now));
+        breaker
+    }
+
+    #[test]
+    fn stays_closed_below_threshold() {
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        for _ in 1..*config::GRPC_BREAKER_THRESHOLD {
+            breaker.record_failure(now);
+        }
+        assert!(breaker.allows_call(now));
+
+        // a success in between starts the count over
+        breaker.record_success();
+        breaker.record_failure(now);
+        assert!(breaker.allows_call(now));
+    }
+
+    #[test]
+    fn opens_at_threshold() {
+        let now = Instant::now();
+        let breaker = opened(now);
+
+        assert!(!breaker.allows_call(now));
+        assert!(!breaker.allows_call(now + *config::GRPC_BREAKER_COOLDOWN / 2));
+    }
+
+    #[test]
+    fn lets_calls_through_once_cooled_down() {
+        let now = Instant::now();
+        let breaker = opened(now);
+
+        assert!(breaker.allows_call(now + *config::GRPC_BREAKER_COOLDOWN));
+    }
+
+    #[test]
+    fn reopens_when_half_open_call_fails() {
+        let now = Instant::now();
+        let mut breaker = opened(now);
+        let half_open = now + *config::GRPC_BREAKER_COOLDOWN;
+
+        // already reported when it first opened
+        assert!(!breaker.record_failure(half_open));
+        assert!(!breaker.allows_call(half_open));
+        assert!(breaker.allows_call(half_open + *config::GRPC_BREAKER_COOLDOWN));
+    }
+
+    #[test]
+    fn closes_when_half_open_call_succeeds() {
+        let now = Instant::now();
+        let mut breaker = opened(now);
+
+        breaker.record_success();
+
+        assert!(breaker.allows_call(now));
+        for _ in 1..*config::GRPC_BREAKER_THRESHOLD {
+            assert!(!breaker.record_failure(now));
+        }
+        assert!(breaker.allows_call(now));
+    }
+}
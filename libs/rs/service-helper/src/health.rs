@@ -0,0 +1,114 @@
+use std::{fmt::Display, future::Future, time::Duration};
+
+use futures::{
+    future::{join_all, BoxFuture},
+    FutureExt,
+};
+use tonic::{server::NamedService, Code};
+use tonic_health::{
+    pb::{health_check_response, health_client::HealthClient, HealthCheckRequest},
+    server::HealthReporter,
+    ServingStatus,
+};
+
+use crate::{channel::ServiceChannel, shutdown::Shutdown};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+pub const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+type Check = Box<dyn Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+/// Everything the service can't serve requests without.
+#[derive(Default)]
+pub struct HealthChecks {
+    checks: Vec<(&'static str, Check)>,
+}
+impl HealthChecks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn check<F, Fut, E>(mut self, name: &'static str, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display,
+    {
+        self.checks.push((
+            name,
+            Box::new(move || {
+                check()
+                    .map(|result| result.map_err(|err| err.to_string()))
+                    .boxed()
+            }),
+        ));
+        self
+    }
+
+    pub fn service(self, name: &'static str, channel: ServiceChannel) -> Self {
+        self.check(name, move || check_service(channel.clone()))
+    }
+
+    /// Reports the service `S` as serving while all the checks pass, re-running them periodically.
+    /// Once the shutdown is requested, reports it as not serving for the traffic to move elsewhere.
+    pub async fn run<S: NamedService>(self, mut reporter: HealthReporter, shutdown: Shutdown) {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        let mut last_status = None;
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown.requested() => break,
+            }
+
+            let results = join_all(self.checks.iter().map(|(name, check)| async move {
+                let result = match tokio::time::timeout(CHECK_TIMEOUT, check()).await {
+                    Ok(result) => result,
+                    Err(..) => Err("timed out".to_string()),
+                };
+                (*name, result)
+            }))
+            .await;
+
+            let mut status = ServingStatus::Serving;
+            for (name, result) in results {
+                if let Err(err) = result {
+                    tracing::warn!("Health check `{name}` failed: {err}");
+                    status = ServingStatus::NotServing;
+                }
+            }
+
+            if last_status != Some(status) {
+                tracing::info!("Serving status changed to {status}");
+                last_status = Some(status);
+            }
+
+            reporter.set_service_status("", status).await;
+            reporter.set_service_status(S::NAME, status).await;
+        }
+
+        reporter
+            .set_service_status("", ServingStatus::NotServing)
+            .await;
+        reporter
+            .set_service_status(S::NAME, ServingStatus::NotServing)
+            .await;
+    }
+}
+
+/// Asks the service behind the channel about its health with `grpc.health.v1`.
+/// The services which don't implement it are considered healthy as long as they answer.
+pub async fn check_service(channel: ServiceChannel) -> Result<(), String> {
+    let response = HealthClient::new(channel)
+        .check(HealthCheckRequest::default())
+        .await;
+
+    match response {
+        Ok(response) => match response.into_inner().status() {
+            health_check_response::ServingStatus::Serving => Ok(()),
+            status => Err(format!("service is {}", status.as_str_name())),
+        },
+        Err(status) if status.code() == Code::Unimplemented => Ok(()),
+        Err(status) => Err(status.message().to_string()),
+    }
+}
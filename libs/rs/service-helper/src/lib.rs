@@ -1,5 +1,37 @@
+use env_config::environment_variables;
+
+pub mod channel;
+pub mod health;
+pub mod metrics;
+pub mod principal;
+pub mod request_id;
 pub mod response;
+pub mod shutdown;
+pub mod telemetry;
+pub mod tls;
+
+environment_variables! {
+    GRPC_CONNECT_TIMEOUT: Duration = "1s",
+    GRPC_REQUEST_TIMEOUT: Duration = "5s",
+    GRPC_RETRIES: u32 = 2u32,
+    GRPC_RETRY_BACKOFF: Duration = "100ms",
+    GRPC_BREAKER_THRESHOLD: u32 = 5u32,
+    GRPC_BREAKER_COOLDOWN: Duration = "10s",
+    SHUTDOWN_TIMEOUT: Duration = "25s",
+    TLS_CERT_PATH: String = "",
+    TLS_KEY_PATH: String = "",
+    TLS_CA_PATH: String = "",
+    PRINCIPAL_TOKEN_SECRET: Secret,
+    PRINCIPAL_TOKEN_TTL: Duration = "1m",
+}
 
+/// Keeps the scheme of the address if there is one, so the services which don't speak TLS
+/// can still be reached over plaintext with `http://`.
 pub fn make_url(address: &str) -> String {
-    format!("http://{address}")
+    if address.starts_with("http://") || address.starts_with("https://") {
+        return address.to_string();
+    }
+
+    let scheme = if tls::is_enabled() { "https" } else { "http" };
+    format!("{scheme}://{address}")
 }
@@ -0,0 +1,89 @@
+use std::{
+    net::SocketAddr,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use futures::future::BoxFuture;
+use http::{Request, Response};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use tonic::Code;
+use tower::{Layer, Service};
+
+const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Serves the metrics recorded with the `metrics` macros in the Prometheus format
+/// on a port separate from the gRPC one.
+pub fn install_exporter(address: &str) {
+    let address: SocketAddr = address.parse().expect("Invalid metrics address");
+
+    PrometheusBuilder::new()
+        .with_http_listener(address)
+        .set_buckets(LATENCY_BUCKETS)
+        .expect("Invalid latency buckets")
+        .install()
+        .expect("Failed to start the metrics exporter");
+}
+
+/// Records the count and the latency of gRPC requests by method and status code.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MetricsLayer;
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService { inner }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MetricsService<S> {
+    inner: S,
+}
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for MetricsService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let method = request.uri().path().to_string();
+        let started_at = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await;
+
+            let code = response_code(&response);
+            let labels = [("method", method), ("code", format!("{code:?}"))];
+
+            metrics::counter!("grpc_server_requests_total", &labels).increment(1);
+            metrics::histogram!("grpc_server_request_duration_seconds", &labels)
+                .record(started_at.elapsed().as_secs_f64());
+
+            response
+        })
+    }
+}
+
+pub(crate) fn response_code<B, E>(response: &Result<Response<B>, E>) -> Code {
+    // failed calls are answered with the status in headers,
+    // successful ones only send it in trailers
+    match response {
+        Ok(response) => response
+            .headers()
+            .get("grpc-status")
+            .map(|status| Code::from_bytes(status.as_bytes()))
+            .unwrap_or(Code::Ok),
+        Err(..) => Code::Unknown,
+    }
+}
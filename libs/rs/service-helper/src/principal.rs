@@ -0,0 +1,276 @@
+use std::{
+    future::Future,
+    task::{Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::future::{self, BoxFuture, FutureExt};
+use http::{Request, Response};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use tonic::{body::BoxBody, Status};
+use tower::{Layer, Service};
+
+use crate::config;
+
+/// Metadata key the signed principal token is passed between services in.
+pub const HEADER: &str = "x-principal";
+
+/// Probed by the orchestrator and the other services, neither acting on behalf of anyone.
+const OPEN_SERVICE: &str = "/grpc.health.v1.Health/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    Client,
+    /// One of the services, acting on its own rather than on behalf of a user.
+    Service,
+}
+
+/// Whoever a call is made on behalf of, as verified by the gateway.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Principal {
+    pub id: String,
+    pub role: Role,
+}
+impl Principal {
+    pub fn admin(id: impl ToString) -> Self {
+        Self {
+            id: id.to_string(),
+            role: Role::Admin,
+        }
+    }
+
+    pub fn client(id: impl ToString) -> Self {
+        Self {
+            id: id.to_string(),
+            role: Role::Client,
+        }
+    }
+
+    pub fn service(name: &str) -> Self {
+        Self {
+            id: name.to_string(),
+            role: Role::Service,
+        }
+    }
+
+    pub fn is_privileged(&self) -> bool {
+        matches!(self.role, Role::Admin | Role::Service)
+    }
+
+    /// Whether the principal may act on the resources of the client.
+    pub fn can_access(&self, client_id: &str) -> bool {
+        self.is_privileged() || self.id == client_id
+    }
+
+    /// Signs the principal into a short-lived token.
+    pub fn mint(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        self.mint_with(
+            config::PRINCIPAL_TOKEN_SECRET.expose().as_bytes(),
+            now,
+            config::PRINCIPAL_TOKEN_TTL.as_secs(),
+        )
+    }
+
+    pub fn verify(token: &str) -> Result<Self, jsonwebtoken::errors::Error> {
+        Self::verify_with(token, config::PRINCIPAL_TOKEN_SECRET.expose().as_bytes())
+    }
+
+    fn mint_with(&self, secret: &[u8], issued_at: u64, ttl: u64) -> String {
+        encode(
+            &Header::new(Algorithm::HS256),
+            &Claims {
+                sub: self.id.clone(),
+                role: self.role,
+                iat: issued_at,
+                exp: issued_at + ttl,
+            },
+            &EncodingKey::from_secret(secret),
+        )
+        .expect("principal token is always encodable")
+    }
+
+    fn verify_with(token: &str, secret: &[u8]) -> Result<Self, jsonwebtoken::errors::Error> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_required_spec_claims(&["exp", "sub"]);
+
+        let claims =
+            decode::<Claims>(token, &DecodingKey::from_secret(secret), &validation)?.claims;
+
+        Ok(Self {
+            id: claims.sub,
+            role: claims.role,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    role: Role,
+    iat: u64,
+    exp: u64,
+}
+
+tokio::task_local! {
+    static PRINCIPAL: Principal;
+}
+
+/// Runs the future on behalf of the principal, the calls made from it carry the principal along.
+pub async fn scope<F: Future>(principal: Principal, future: F) -> F::Output {
+    PRINCIPAL.scope(principal, future).await
+}
+
+/// Principal the current calls are made on behalf of, if any.
+pub fn current() -> Option<Principal> {
+    PRINCIPAL.try_with(Clone::clone).ok()
+}
+
+/// Principal of the call, put into the request extensions by [`PrincipalLayer`].
+pub fn of<T>(request: &tonic::Request<T>) -> Option<Principal> {
+    request.extensions().get::<Principal>().cloned()
+}
+
+/// Verifies the principal token of incoming calls and exposes the principal to the handlers
+/// through the request extensions, also passing it on to the calls they make.
+/// Calls without a token or with an invalid one are rejected, apart from the health checks.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct PrincipalLayer;
+impl<S> Layer<S> for PrincipalLayer {
+    type Service = PrincipalService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PrincipalService { inner }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PrincipalService<S> {
+    inner: S,
+}
+impl<S, ReqBody> Service<Request<ReqBody>> for PrincipalService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<BoxBody>>,
+    S::Error: Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        if request.uri().path().starts_with(OPEN_SERVICE) {
+            return self.inner.call(request).boxed();
+        }
+
+        let Some(token) = request.headers().get(HEADER) else {
+            tracing::warn!(
+                "Rejected call to {} without a principal",
+                request.uri().path()
+            );
+            let status = Status::unauthenticated("Missing principal token");
+            return future::ok(status.into_http()).boxed();
+        };
+
+        let principal = match token.to_str() {
+            Ok(token) => Principal::verify(token).map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        let principal = match principal {
+            Ok(principal) => principal,
+            Err(err) => {
+                tracing::warn!("Rejected principal token: {err}");
+                let status = Status::unauthenticated("Invalid principal token");
+                return future::ok(status.into_http()).boxed();
+            }
+        };
+
+        request.extensions_mut().insert(principal.clone());
+        scope(principal, self.inner.call(request)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use tonic::{body::empty_body, Code};
+    use tower::{service_fn, ServiceExt};
+
+    use super::*;
+
+    const SECRET: &[u8] = b"secret";
+    const TTL: u64 = 60;
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    async fn call(path: &str) -> Code {
+        let inner =
+            service_fn(|_: Request<()>| async { Ok::<_, Infallible>(Response::new(empty_body())) });
+        let request = Request::builder().uri(path).body(()).unwrap();
+
+        let response = PrincipalLayer.layer(inner).oneshot(request).await.unwrap();
+        Status::from_header_map(response.headers()).map_or(Code::Ok, |status| status.code())
+    }
+
+    #[test]
+    fn verifies_minted_tokens() {
+        let principal = Principal::client("42");
+        let token = principal.mint_with(SECRET, now(), TTL);
+
+        assert_eq!(Principal::verify_with(&token, SECRET).unwrap(), principal);
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        // well past the leeway of the validation
+        let token = Principal::admin("42").mint_with(SECRET, now() - 10 * TTL, TTL);
+
+        assert!(Principal::verify_with(&token, SECRET).is_err());
+    }
+
+    #[test]
+    fn rejects_tokens_signed_with_another_secret() {
+        let token = Principal::admin("42").mint_with(b"another secret", now(), TTL);
+
+        assert!(Principal::verify_with(&token, SECRET).is_err());
+    }
+
+    #[test]
+    fn rejects_tokens_with_tampered_role() {
+        let token = Principal::client("42").mint_with(SECRET, now(), TTL);
+        let admin = Principal::admin("42").mint_with(SECRET, now(), TTL);
+
+        // the claims of the admin token along with the signature of the client one
+        let mut parts: Vec<_> = token.split('.').collect();
+        parts[1] = admin.split('.').nth(1).unwrap();
+
+        assert!(Principal::verify_with(&parts.join("."), SECRET).is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_calls_without_token() {
+        assert_eq!(call("/admin.Admin/Get").await, Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn lets_health_checks_through_without_token() {
+        assert_eq!(call("/grpc.health.v1.Health/Check").await, Code::Ok);
+    }
+}
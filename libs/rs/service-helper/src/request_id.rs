@@ -0,0 +1,18 @@
+use std::future::Future;
+
+/// Metadata key the id of the client request is passed between services in.
+pub const HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Runs the future on behalf of the client request with the given id.
+pub async fn scope<F: Future>(request_id: String, future: F) -> F::Output {
+    REQUEST_ID.scope(request_id, future).await
+}
+
+/// Id of the client request being served, if any.
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
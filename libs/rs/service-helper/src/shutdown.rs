@@ -0,0 +1,100 @@
+use std::future::Future;
+
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    time::Instant,
+};
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+
+use crate::config;
+
+/// Coordinates the graceful shutdown of a service.
+///
+/// Once SIGTERM or SIGINT arrives, the server stops accepting new requests
+/// and the background workers are asked to stop after their current step,
+/// so they leave their state consistent for the next start.
+#[derive(Clone, Debug, Default)]
+pub struct Shutdown {
+    token: CancellationToken,
+    workers: TaskTracker,
+}
+impl Shutdown {
+    /// Requests the shutdown on the first SIGTERM or SIGINT.
+    pub fn on_signal() -> Self {
+        let shutdown = Self::default();
+
+        let token = shutdown.token.clone();
+        tokio::spawn(async move {
+            let mut terminate =
+                signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+            let mut interrupt =
+                signal(SignalKind::interrupt()).expect("Failed to listen for SIGINT");
+
+            let signal = tokio::select! {
+                _ = terminate.recv() => "SIGTERM",
+                _ = interrupt.recv() => "SIGINT",
+            };
+            tracing::info!("Received {signal}, shutting down");
+            token.cancel();
+        });
+
+        shutdown
+    }
+
+    /// Resolves once the shutdown is requested, meant for `serve_with_shutdown`.
+    pub fn requested(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.token.clone().cancelled_owned()
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Spawns a background worker, which is waited for before the service exits.
+    /// It's expected to return soon after the shutdown is requested.
+    pub fn spawn_worker<F>(&self, worker: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.workers.spawn(worker);
+    }
+
+    /// Runs the server until it stops, giving it and the workers
+    /// `SHUTDOWN_TIMEOUT` to finish their work once the shutdown is requested.
+    pub async fn drain<F, E>(self, server: F) -> Result<(), E>
+    where
+        F: Future<Output = Result<(), E>>,
+    {
+        tokio::pin!(server);
+
+        let timeout = *config::SHUTDOWN_TIMEOUT;
+        let (result, deadline) = tokio::select! {
+            result = &mut server => (result, Instant::now() + timeout),
+            _ = self.token.cancelled() => {
+                let deadline = Instant::now() + timeout;
+                let result = tokio::time::timeout_at(deadline, &mut server)
+                    .await
+                    .unwrap_or_else(|_| {
+                        tracing::warn!("In-flight requests didn't finish in {timeout:?}, dropping them");
+                        Ok(())
+                    });
+                (result, deadline)
+            }
+        };
+
+        // the server may also stop on its own, the workers have to know either way
+        self.token.cancel();
+        self.workers.close();
+        if tokio::time::timeout_at(deadline, self.workers.wait())
+            .await
+            .is_err()
+        {
+            tracing::warn!(
+                "{} background workers didn't stop in {timeout:?}",
+                self.workers.len()
+            );
+        }
+
+        result
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt::Write as _,
+    io::Write as _,
+    task::Poll,
+    time::{Duration, SystemTime},
+};
+
+use futures::future::BoxFuture;
+use http::{HeaderMap, HeaderName, HeaderValue, Request, Response};
+use opentelemetry::{
+    global,
+    propagation::{Extractor, Injector},
+    trace::TracerProvider,
+    Context,
+};
+use opentelemetry_sdk::{
+    error::OTelSdkResult,
+    propagation::TraceContextPropagator,
+    runtime,
+    trace::{
+        span_processor_with_async_runtime::BatchSpanProcessor, SdkTracerProvider, SpanData,
+        SpanExporter,
+    },
+    Resource,
+};
+use tonic::Code;
+use tower::Service;
+use tracing::{field, level_filters::LevelFilter, Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer as _};
+
+use crate::{metrics::response_code, request_id};
+
+/// Flushes the spans that are not exported yet when dropped.
+#[must_use = "spans are only exported while the guard is alive"]
+pub struct TelemetryGuard {
+    provider: Option<SdkTracerProvider>,
+}
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(err) = provider.shutdown() {
+                eprintln!("Failed to flush spans: {err}");
+            }
+        }
+    }
+}
+
+/// Installs the `tracing` subscriber, which also receives the `log` records.
+///
+/// Logs are written as set up by [`logging::layer`]. Spans are exported as chosen by `OTEL_TRACES_EXPORTER`:
+/// `otlp` (configured by the standard `OTEL_EXPORTER_OTLP_*` variables),
+/// `stdout` or `none`. Release builds default to `otlp`, debug ones to `stdout`.
+pub fn init(service_name: &'static str) -> TelemetryGuard {
+    let default_exporter = if cfg!(debug_assertions) {
+        "stdout"
+    } else {
+        "otlp"
+    };
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let resource = Resource::builder().with_service_name(service_name).build();
+    let exporter =
+        std::env::var("OTEL_TRACES_EXPORTER").unwrap_or_else(|_| default_exporter.to_string());
+    let provider = match exporter.as_str() {
+        "otlp" => {
+            let exporter = opentelemetry_otlp::SpanExporter::builder()
+                .with_tonic()
+                .build()
+                .expect("Failed to create the OTLP exporter");
+            Some(
+                SdkTracerProvider::builder()
+                    .with_span_processor(
+                        BatchSpanProcessor::builder(exporter, runtime::TokioCurrentThread).build(),
+                    )
+                    .with_resource(resource)
+                    .build(),
+            )
+        }
+        "stdout" => Some(
+            SdkTracerProvider::builder()
+                .with_simple_exporter(StdoutSpanExporter)
+                .with_resource(resource)
+                .build(),
+        ),
+        "none" => None,
+        other => panic!("Unknown traces exporter: {other}"),
+    };
+
+    // the transport crates open a span for every connection and frame on debug level
+    let otel_layer = provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer(service_name))
+            .with_filter(LevelFilter::INFO)
+    });
+
+    tracing_subscriber::registry()
+        .with(logging::layer())
+        .with(otel_layer)
+        .init();
+
+    if let Some(provider) = &provider {
+        global::set_tracer_provider(provider.clone());
+    }
+
+    TelemetryGuard { provider }
+}
+
+/// Writes the context of the current span to the outgoing request headers.
+pub fn inject_context(headers: &mut HeaderMap) {
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(headers))
+    });
+}
+
+/// Reads the context of the caller's span from the incoming request headers.
+pub fn extract_context(headers: &HeaderMap) -> Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)))
+}
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
+    }
+}
+
+/// Prints finished spans one per line, for local runs without a collector.
+#[derive(Debug)]
+struct StdoutSpanExporter;
+impl SpanExporter for StdoutSpanExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, OTelSdkResult> {
+        let mut stdout = std::io::stdout().lock();
+        for span in batch {
+            let duration = span
+                .end_time
+                .duration_since(span.start_time)
+                .unwrap_or(Duration::ZERO);
+            let started_at = span
+                .start_time
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO);
+
+            let mut attributes = String::new();
+            for attribute in &span.attributes {
+                let _ = write!(attributes, " {}={}", attribute.key, attribute.value);
+            }
+
+            let _ = writeln!(
+                stdout,
+                "span {} trace_id={} span_id={} parent_id={} kind={:?} start={}.{:06} duration_ms={:.3} status={:?}{attributes}",
+                span.name,
+                span.span_context.trace_id(),
+                span.span_context.span_id(),
+                span.parent_span_id,
+                span.span_kind,
+                started_at.as_secs(),
+                started_at.subsec_micros(),
+                duration.as_secs_f64() * 1000.0,
+                span.status,
+            );
+        }
+
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+/// Opens a span for every gRPC request, continuing the trace of the caller
+/// and keeping the id of the client request it was made for.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TraceLayer;
+impl<S> tower::Layer<S> for TraceLayer {
+    type Service = TraceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceService { inner }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TraceService<S> {
+    inner: S,
+}
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for TraceService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut std::task::Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let request_id = request
+            .headers()
+            .get(request_id::HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let path = request.uri().path().trim_start_matches('/');
+        let (service, method) = path.split_once('/').unwrap_or((path, ""));
+        let span = tracing::info_span!(
+            "grpc request",
+            otel.name = path,
+            otel.kind = "server",
+            otel.status_code = field::Empty,
+            rpc.system = "grpc",
+            rpc.service = service,
+            rpc.method = method,
+            rpc.grpc.status_code = field::Empty,
+            request_id = request_id.as_deref(),
+        );
+        span.set_parent(extract_context(request.headers()));
+
+        let response = span.in_scope(|| self.inner.call(request));
+
+        let response = async move {
+            let response = response.await;
+
+            let code = response_code(&response);
+            let span = Span::current();
+            span.record("rpc.grpc.status_code", code as i32);
+            if code != Code::Ok {
+                span.record("otel.status_code", "ERROR");
+            }
+
+            response
+        }
+        .instrument(span);
+
+        // the calls made while serving the request carry its id further
+        match request_id {
+            Some(request_id) => Box::pin(request_id::scope(request_id, response)),
+            None => Box::pin(response),
+        }
+    }
+}
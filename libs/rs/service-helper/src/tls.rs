@@ -0,0 +1,358 @@
+//! Optional TLS of the traffic between the services.
+//!
+//! Setting `TLS_CERT_PATH` and `TLS_KEY_PATH` makes the servers speak TLS and the clients connect
+//! over it, verifying the servers against the system roots. Setting `TLS_CA_PATH` on top turns
+//! on mTLS: the clients present the certificate and verify the servers against the CA, and the
+//! servers only accept callers with a certificate issued by it.
+//! The caller is identified by the common name of its certificate.
+
+use std::{
+    collections::HashMap,
+    fs,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use futures::future::{self, BoxFuture, FutureExt};
+use http::{Request, Response};
+use simple_asn1::{oid, ASN1Block};
+use tonic::{
+    body::BoxBody,
+    transport::{
+        server::{TcpConnectInfo, TlsConnectInfo},
+        Certificate, ClientTlsConfig, Identity, Server, ServerTlsConfig,
+    },
+    Status,
+};
+use tower::{Layer, Service};
+
+use crate::config;
+
+pub fn is_enabled() -> bool {
+    !config::TLS_CERT_PATH.is_empty()
+}
+
+pub fn is_mutual() -> bool {
+    is_enabled() && !config::TLS_CA_PATH.is_empty()
+}
+
+/// `Server::builder()`, serving TLS when it's configured.
+pub fn server() -> Result<Server, tonic::transport::Error> {
+    let server = Server::builder();
+    if !is_enabled() {
+        return Ok(server);
+    }
+
+    let mut tls = ServerTlsConfig::new().identity(identity());
+    if is_mutual() {
+        tls = tls.client_ca_root(ca_certificate());
+    }
+
+    server.tls_config(tls)
+}
+
+/// Settings of the connections to the other services, `None` if they're plaintext.
+pub fn client_config() -> Option<ClientTlsConfig> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let tls = ClientTlsConfig::new();
+    Some(if is_mutual() {
+        tls.ca_certificate(ca_certificate()).identity(identity())
+    } else {
+        tls.with_native_roots()
+    })
+}
+
+fn identity() -> Identity {
+    Identity::from_pem(
+        read(&config::TLS_CERT_PATH, "certificate"),
+        read(&config::TLS_KEY_PATH, "key"),
+    )
+}
+
+fn ca_certificate() -> Certificate {
+    Certificate::from_pem(read(&config::TLS_CA_PATH, "CA certificate"))
+}
+
+fn read(path: &str, what: &str) -> Vec<u8> {
+    fs::read(path).unwrap_or_else(|err| panic!("Failed to read the TLS {what} `{path}`: {err}"))
+}
+
+/// Identity of the service the request came from, taken from its certificate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Caller(pub String);
+
+/// Restricts the methods, as `/package.Service/Method`, to the listed callers. Methods missing
+/// from the allowlist are rejected, apart from the health and reflection services.
+/// Only takes effect with mTLS, since otherwise there is no identity to check.
+#[derive(Clone, Default, Debug)]
+pub struct CallerAllowlist {
+    methods: HashMap<&'static str, &'static [&'static str]>,
+}
+impl CallerAllowlist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allow(mut self, method: &'static str, callers: &'static [&'static str]) -> Self {
+        self.methods.insert(method, callers);
+        self
+    }
+}
+impl<S> Layer<S> for CallerAllowlist {
+    type Service = CallerAllowlistService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CallerAllowlistService {
+            inner,
+            methods: Arc::new(self.methods.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CallerAllowlistService<S> {
+    inner: S,
+    methods: Arc<HashMap<&'static str, &'static [&'static str]>>,
+}
+impl<S, ReqBody> Service<Request<ReqBody>> for CallerAllowlistService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<BoxBody>>,
+    S::Error: Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        if !is_mutual() {
+            return self.inner.call(request).boxed();
+        }
+
+        let caller = request
+            .extensions()
+            .get::<TlsConnectInfo<TcpConnectInfo>>()
+            .and_then(TlsConnectInfo::peer_certs)
+            .and_then(|certs| certs.first().and_then(|cert| common_name(cert)));
+
+        let method = request.uri().path();
+        if !is_allowed(&self.methods, method, caller.as_deref()) {
+            tracing::warn!(
+                "Rejected call of {method} by {}",
+                caller.as_deref().unwrap_or("an unidentified caller")
+            );
+            let status = Status::permission_denied(format!("Calling {method} isn't allowed"));
+            return future::ok(status.into_http()).boxed();
+        }
+
+        if let Some(caller) = caller {
+            request.extensions_mut().insert(Caller(caller));
+        }
+
+        self.inner.call(request).boxed()
+    }
+}
+
+/// Services open to any caller with a valid certificate, for probes and debugging.
+const OPEN_SERVICES: &[&str] = &[
+    "/grpc.health.v1.Health/",
+    "/grpc.reflection.v1.ServerReflection/",
+];
+
+fn is_allowed(
+    methods: &HashMap<&'static str, &'static [&'static str]>,
+    method: &str,
+    caller: Option<&str>,
+) -> bool {
+    match (methods.get(method), caller) {
+        (Some(callers), Some(caller)) => callers.contains(&caller),
+        (Some(..), None) => false,
+        (None, ..) => OPEN_SERVICES
+            .iter()
+            .any(|service| method.starts_with(service)),
+    }
+}
+
+/// Common name of the certificate's subject.
+fn common_name(certificate: &[u8]) -> Option<String> {
+    let blocks = simple_asn1::from_der(certificate).ok()?;
+    let Some(ASN1Block::Sequence(_, certificate)) = blocks.first() else {
+        return None;
+    };
+    let Some(ASN1Block::Sequence(_, tbs_certificate)) = certificate.first() else {
+        return None;
+    };
+
+    // serial number, signature algorithm, issuer, validity and then the subject,
+    // skipping the explicitly tagged version
+    let subject = tbs_certificate
+        .iter()
+        .filter(|block| !matches!(block, ASN1Block::Explicit(..)))
+        .nth(4)?;
+    let ASN1Block::Sequence(_, names) = subject else {
+        return None;
+    };
+
+    let common_name = oid!(2, 5, 4, 3);
+    names.iter().find_map(|name| {
+        let ASN1Block::Set(_, attributes) = name else {
+            return None;
+        };
+        attributes.iter().find_map(|attribute| {
+            let ASN1Block::Sequence(_, pair) = attribute else {
+                return None;
+            };
+            match pair.as_slice() {
+                [ASN1Block::ObjectIdentifier(_, oid), ASN1Block::UTF8String(_, value)
+                | ASN1Block::PrintableString(_, value)
+                | ASN1Block::IA5String(_, value)]
+                    if *oid == common_name =>
+                {
+                    Some(value.clone())
+                }
+                _ => None,
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The certificates in `testdata` are issued by a throwaway CA with `openssl`.
+    fn der(pem: &[u8]) -> Vec<u8> {
+        rustls_pemfile::certs(&mut &pem[..])
+            .next()
+            .expect("no certificate in the PEM")
+            .unwrap()
+            .to_vec()
+    }
+
+    #[test]
+    fn reads_common_name_of_the_subject() {
+        // the issuer has a common name too, "Coworking Test CA"
+        let certificate = der(include_bytes!("../testdata/gateway.pem"));
+
+        assert_eq!(common_name(&certificate).as_deref(), Some("gateway"));
+    }
+
+    #[test]
+    fn reads_common_name_of_self_signed_certificates() {
+        let certificate = der(include_bytes!("../testdata/ed25519.pem"));
+
+        assert_eq!(common_name(&certificate).as_deref(), Some("analytics"));
+    }
+
+    #[test]
+    fn reads_common_names_in_any_string_type() {
+        let printable = der(include_bytes!("../testdata/printable.pem"));
+        let unicode = der(include_bytes!("../testdata/unicode.pem"));
+
+        assert_eq!(common_name(&printable).as_deref(), Some("notification"));
+        assert_eq!(common_name(&unicode).as_deref(), Some("шлюз"));
+    }
+
+    #[test]
+    fn reads_common_name_from_multivalued_names() {
+        let certificate = der(include_bytes!("../testdata/multivalued.pem"));
+
+        assert_eq!(common_name(&certificate).as_deref(), Some("billing"));
+    }
+
+    #[test]
+    fn reads_nothing_without_common_name() {
+        let certificate = der(include_bytes!("../testdata/nameless.pem"));
+
+        assert_eq!(common_name(&certificate), None);
+    }
+
+    #[test]
+    fn reads_nothing_from_garbage() {
+        let mut truncated = der(include_bytes!("../testdata/gateway.pem"));
+        truncated.truncate(100);
+
+        assert_eq!(common_name(&truncated), None);
+        assert_eq!(common_name(b"not a certificate"), None);
+        assert_eq!(common_name(&[]), None);
+    }
+
+    #[test]
+    fn restricts_listed_methods_to_their_callers() {
+        let allowlist = CallerAllowlist::new().allow("/admin.Admin/Delete", &["gateway"]);
+
+        assert!(is_allowed(
+            &allowlist.methods,
+            "/admin.Admin/Delete",
+            Some("gateway")
+        ));
+        assert!(!is_allowed(
+            &allowlist.methods,
+            "/admin.Admin/Delete",
+            Some("billing")
+        ));
+        assert!(!is_allowed(&allowlist.methods, "/admin.Admin/Delete", None));
+    }
+
+    #[test]
+    fn rejects_unlisted_methods() {
+        let allowlist = CallerAllowlist::new().allow("/admin.Admin/Delete", &["gateway"]);
+
+        assert!(!is_allowed(
+            &allowlist.methods,
+            "/admin.Admin/Get",
+            Some("gateway")
+        ));
+        assert!(!is_allowed(&allowlist.methods, "/admin.Admin/Get", None));
+        assert!(!is_allowed(
+            &CallerAllowlist::new().methods,
+            "/admin.Admin/Delete",
+            Some("gateway")
+        ));
+    }
+
+    #[test]
+    fn restricts_methods_by_their_full_path() {
+        let allowlist = CallerAllowlist::new().allow("/admin.Admin/Delete", &["gateway"]);
+
+        assert!(!is_allowed(
+            &allowlist.methods,
+            "/client.Client/Delete",
+            Some("gateway")
+        ));
+        assert!(!is_allowed(&allowlist.methods, "Delete", Some("gateway")));
+        assert!(!is_allowed(
+            &allowlist.methods,
+            "/admin.Admin/Delete/",
+            Some("gateway")
+        ));
+    }
+
+    #[test]
+    fn keeps_health_and_reflection_open() {
+        let allowlist = CallerAllowlist::new();
+
+        assert!(is_allowed(
+            &allowlist.methods,
+            "/grpc.health.v1.Health/Check",
+            Some("billing")
+        ));
+        assert!(is_allowed(
+            &allowlist.methods,
+            "/grpc.reflection.v1.ServerReflection/ServerReflectionInfo",
+            None
+        ));
+        assert!(!is_allowed(
+            &allowlist.methods,
+            "/grpc.health.v1.HealthCheck/Check",
+            Some("billing")
+        ));
+    }
+}
@@ -23,6 +23,7 @@ const (
 	Image_UploadImage_FullMethodName = "/image.Image/UploadImage"
 	Image_GetImage_FullMethodName    = "/image.Image/GetImage"
 	Image_DeleteImage_FullMethodName = "/image.Image/DeleteImage"
+	Image_ListImages_FullMethodName  = "/image.Image/ListImages"
 )
 
 // ImageClient is the client API for Image service.
@@ -32,6 +33,7 @@ type ImageClient interface {
 	UploadImage(ctx context.Context, opts ...grpc.CallOption) (grpc.ClientStreamingClient[UploadImageRequest, UploadImageResponse], error)
 	GetImage(ctx context.Context, in *GetImageRequest, opts ...grpc.CallOption) (grpc.ServerStreamingClient[GetImageResponse], error)
 	DeleteImage(ctx context.Context, in *DeleteImageRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	ListImages(ctx context.Context, in *ListImagesRequest, opts ...grpc.CallOption) (*ListImagesResponse, error)
 }
 
 type imageClient struct {
@@ -84,6 +86,16 @@ func (c *imageClient) DeleteImage(ctx context.Context, in *DeleteImageRequest, o
 	return out, nil
 }
 
+func (c *imageClient) ListImages(ctx context.Context, in *ListImagesRequest, opts ...grpc.CallOption) (*ListImagesResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ListImagesResponse)
+	err := c.cc.Invoke(ctx, Image_ListImages_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // ImageServer is the server API for Image service.
 // All implementations must embed UnimplementedImageServer
 // for forward compatibility.
@@ -91,6 +103,7 @@ type ImageServer interface {
 	UploadImage(grpc.ClientStreamingServer[UploadImageRequest, UploadImageResponse]) error
 	GetImage(*GetImageRequest, grpc.ServerStreamingServer[GetImageResponse]) error
 	DeleteImage(context.Context, *DeleteImageRequest) (*emptypb.Empty, error)
+	ListImages(context.Context, *ListImagesRequest) (*ListImagesResponse, error)
 	mustEmbedUnimplementedImageServer()
 }
 
@@ -110,6 +123,9 @@ func (UnimplementedImageServer) GetImage(*GetImageRequest, grpc.ServerStreamingS
 func (UnimplementedImageServer) DeleteImage(context.Context, *DeleteImageRequest) (*emptypb.Empty, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeleteImage not implemented")
 }
+func (UnimplementedImageServer) ListImages(context.Context, *ListImagesRequest) (*ListImagesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListImages not implemented")
+}
 func (UnimplementedImageServer) mustEmbedUnimplementedImageServer() {}
 func (UnimplementedImageServer) testEmbeddedByValue()               {}
 
@@ -167,6 +183,24 @@ func _Image_DeleteImage_Handler(srv interface{}, ctx context.Context, dec func(i
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Image_ListImages_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListImagesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ImageServer).ListImages(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Image_ListImages_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ImageServer).ListImages(ctx, req.(*ListImagesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // Image_ServiceDesc is the grpc.ServiceDesc for Image service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -178,6 +212,10 @@ var Image_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "DeleteImage",
 			Handler:    _Image_DeleteImage_Handler,
 		},
+		{
+			MethodName: "ListImages",
+			Handler:    _Image_ListImages_Handler,
+		},
 	},
 	Streams: []grpc.StreamDesc{
 		{
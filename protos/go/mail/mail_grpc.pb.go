@@ -20,101 +20,101 @@ import (
 const _ = grpc.SupportPackageIsVersion9
 
 const (
-	MailService_Send_FullMethodName = "/mail.MailService/Send"
+	Mail_Send_FullMethodName = "/mail.Mail/Send"
 )
 
-// MailServiceClient is the client API for MailService service.
+// MailClient is the client API for Mail service.
 //
 // For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
-type MailServiceClient interface {
+type MailClient interface {
 	Send(ctx context.Context, in *SendRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 }
 
-type mailServiceClient struct {
+type mailClient struct {
 	cc grpc.ClientConnInterface
 }
 
-func NewMailServiceClient(cc grpc.ClientConnInterface) MailServiceClient {
-	return &mailServiceClient{cc}
+func NewMailClient(cc grpc.ClientConnInterface) MailClient {
+	return &mailClient{cc}
 }
 
-func (c *mailServiceClient) Send(ctx context.Context, in *SendRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+func (c *mailClient) Send(ctx context.Context, in *SendRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(emptypb.Empty)
-	err := c.cc.Invoke(ctx, MailService_Send_FullMethodName, in, out, cOpts...)
+	err := c.cc.Invoke(ctx, Mail_Send_FullMethodName, in, out, cOpts...)
 	if err != nil {
 		return nil, err
 	}
 	return out, nil
 }
 
-// MailServiceServer is the server API for MailService service.
-// All implementations must embed UnimplementedMailServiceServer
+// MailServer is the server API for Mail service.
+// All implementations must embed UnimplementedMailServer
 // for forward compatibility.
-type MailServiceServer interface {
+type MailServer interface {
 	Send(context.Context, *SendRequest) (*emptypb.Empty, error)
-	mustEmbedUnimplementedMailServiceServer()
+	mustEmbedUnimplementedMailServer()
 }
 
-// UnimplementedMailServiceServer must be embedded to have
+// UnimplementedMailServer must be embedded to have
 // forward compatible implementations.
 //
 // NOTE: this should be embedded by value instead of pointer to avoid a nil
 // pointer dereference when methods are called.
-type UnimplementedMailServiceServer struct{}
+type UnimplementedMailServer struct{}
 
-func (UnimplementedMailServiceServer) Send(context.Context, *SendRequest) (*emptypb.Empty, error) {
+func (UnimplementedMailServer) Send(context.Context, *SendRequest) (*emptypb.Empty, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Send not implemented")
 }
-func (UnimplementedMailServiceServer) mustEmbedUnimplementedMailServiceServer() {}
-func (UnimplementedMailServiceServer) testEmbeddedByValue()                     {}
+func (UnimplementedMailServer) mustEmbedUnimplementedMailServer() {}
+func (UnimplementedMailServer) testEmbeddedByValue()              {}
 
-// UnsafeMailServiceServer may be embedded to opt out of forward compatibility for this service.
-// Use of this interface is not recommended, as added methods to MailServiceServer will
+// UnsafeMailServer may be embedded to opt out of forward compatibility for this service.
+// Use of this interface is not recommended, as added methods to MailServer will
 // result in compilation errors.
-type UnsafeMailServiceServer interface {
-	mustEmbedUnimplementedMailServiceServer()
+type UnsafeMailServer interface {
+	mustEmbedUnimplementedMailServer()
 }
 
-func RegisterMailServiceServer(s grpc.ServiceRegistrar, srv MailServiceServer) {
-	// If the following call pancis, it indicates UnimplementedMailServiceServer was
+func RegisterMailServer(s grpc.ServiceRegistrar, srv MailServer) {
+	// If the following call pancis, it indicates UnimplementedMailServer was
 	// embedded by pointer and is nil.  This will cause panics if an
 	// unimplemented method is ever invoked, so we test this at initialization
 	// time to prevent it from happening at runtime later due to I/O.
 	if t, ok := srv.(interface{ testEmbeddedByValue() }); ok {
 		t.testEmbeddedByValue()
 	}
-	s.RegisterService(&MailService_ServiceDesc, srv)
+	s.RegisterService(&Mail_ServiceDesc, srv)
 }
 
-func _MailService_Send_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+func _Mail_Send_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(SendRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(MailServiceServer).Send(ctx, in)
+		return srv.(MailServer).Send(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: MailService_Send_FullMethodName,
+		FullMethod: Mail_Send_FullMethodName,
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(MailServiceServer).Send(ctx, req.(*SendRequest))
+		return srv.(MailServer).Send(ctx, req.(*SendRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-// MailService_ServiceDesc is the grpc.ServiceDesc for MailService service.
+// Mail_ServiceDesc is the grpc.ServiceDesc for Mail service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
-var MailService_ServiceDesc = grpc.ServiceDesc{
-	ServiceName: "mail.MailService",
-	HandlerType: (*MailServiceServer)(nil),
+var Mail_ServiceDesc = grpc.ServiceDesc{
+	ServiceName: "mail.Mail",
+	HandlerType: (*MailServer)(nil),
 	Methods: []grpc.MethodDesc{
 		{
 			MethodName: "Send",
-			Handler:    _MailService_Send_Handler,
+			Handler:    _Mail_Send_Handler,
 		},
 	},
 	Streams:  []grpc.StreamDesc{},
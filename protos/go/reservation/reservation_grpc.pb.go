@@ -25,10 +25,13 @@ const (
 	Reservation_GetBySeat_FullMethodName   = "/reservation.Reservation/GetBySeat"
 	Reservation_GetByClient_FullMethodName = "/reservation.Reservation/GetByClient"
 	Reservation_Get_FullMethodName         = "/reservation.Reservation/Get"
+	Reservation_GetNoShows_FullMethodName  = "/reservation.Reservation/GetNoShows"
 	Reservation_Update_FullMethodName      = "/reservation.Reservation/Update"
 	Reservation_Cancel_FullMethodName      = "/reservation.Reservation/Cancel"
 	Reservation_Visit_FullMethodName       = "/reservation.Reservation/Visit"
 	Reservation_Delete_FullMethodName      = "/reservation.Reservation/Delete"
+	Reservation_CheckOut_FullMethodName    = "/reservation.Reservation/CheckOut"
+	Reservation_Extend_FullMethodName      = "/reservation.Reservation/Extend"
 )
 
 // ReservationClient is the client API for Reservation service.
@@ -40,10 +43,13 @@ type ReservationClient interface {
 	GetBySeat(ctx context.Context, in *GetBySeatRequest, opts ...grpc.CallOption) (*ReservationsResponse, error)
 	GetByClient(ctx context.Context, in *GetByClientRequest, opts ...grpc.CallOption) (*ReservationsResponse, error)
 	Get(ctx context.Context, in *GetRequest, opts ...grpc.CallOption) (*ReservationsResponse, error)
+	GetNoShows(ctx context.Context, in *GetNoShowsRequest, opts ...grpc.CallOption) (*ReservationsResponse, error)
 	Update(ctx context.Context, in *UpdateRequest, opts ...grpc.CallOption) (*ReservationResponse, error)
 	Cancel(ctx context.Context, in *DeleteRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	Visit(ctx context.Context, in *DeleteRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	Delete(ctx context.Context, in *DeleteRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	CheckOut(ctx context.Context, in *DeleteRequest, opts ...grpc.CallOption) (*ReservationResponse, error)
+	Extend(ctx context.Context, in *ExtendRequest, opts ...grpc.CallOption) (*ReservationResponse, error)
 }
 
 type reservationClient struct {
@@ -104,6 +110,16 @@ func (c *reservationClient) Get(ctx context.Context, in *GetRequest, opts ...grp
 	return out, nil
 }
 
+func (c *reservationClient) GetNoShows(ctx context.Context, in *GetNoShowsRequest, opts ...grpc.CallOption) (*ReservationsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ReservationsResponse)
+	err := c.cc.Invoke(ctx, Reservation_GetNoShows_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *reservationClient) Update(ctx context.Context, in *UpdateRequest, opts ...grpc.CallOption) (*ReservationResponse, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(ReservationResponse)
@@ -144,6 +160,26 @@ func (c *reservationClient) Delete(ctx context.Context, in *DeleteRequest, opts
 	return out, nil
 }
 
+func (c *reservationClient) CheckOut(ctx context.Context, in *DeleteRequest, opts ...grpc.CallOption) (*ReservationResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ReservationResponse)
+	err := c.cc.Invoke(ctx, Reservation_CheckOut_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *reservationClient) Extend(ctx context.Context, in *ExtendRequest, opts ...grpc.CallOption) (*ReservationResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ReservationResponse)
+	err := c.cc.Invoke(ctx, Reservation_Extend_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // ReservationServer is the server API for Reservation service.
 // All implementations must embed UnimplementedReservationServer
 // for forward compatibility.
@@ -153,10 +189,13 @@ type ReservationServer interface {
 	GetBySeat(context.Context, *GetBySeatRequest) (*ReservationsResponse, error)
 	GetByClient(context.Context, *GetByClientRequest) (*ReservationsResponse, error)
 	Get(context.Context, *GetRequest) (*ReservationsResponse, error)
+	GetNoShows(context.Context, *GetNoShowsRequest) (*ReservationsResponse, error)
 	Update(context.Context, *UpdateRequest) (*ReservationResponse, error)
 	Cancel(context.Context, *DeleteRequest) (*emptypb.Empty, error)
 	Visit(context.Context, *DeleteRequest) (*emptypb.Empty, error)
 	Delete(context.Context, *DeleteRequest) (*emptypb.Empty, error)
+	CheckOut(context.Context, *DeleteRequest) (*ReservationResponse, error)
+	Extend(context.Context, *ExtendRequest) (*ReservationResponse, error)
 	mustEmbedUnimplementedReservationServer()
 }
 
@@ -182,6 +221,9 @@ func (UnimplementedReservationServer) GetByClient(context.Context, *GetByClientR
 func (UnimplementedReservationServer) Get(context.Context, *GetRequest) (*ReservationsResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Get not implemented")
 }
+func (UnimplementedReservationServer) GetNoShows(context.Context, *GetNoShowsRequest) (*ReservationsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetNoShows not implemented")
+}
 func (UnimplementedReservationServer) Update(context.Context, *UpdateRequest) (*ReservationResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Update not implemented")
 }
@@ -194,6 +236,12 @@ func (UnimplementedReservationServer) Visit(context.Context, *DeleteRequest) (*e
 func (UnimplementedReservationServer) Delete(context.Context, *DeleteRequest) (*emptypb.Empty, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Delete not implemented")
 }
+func (UnimplementedReservationServer) CheckOut(context.Context, *DeleteRequest) (*ReservationResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CheckOut not implemented")
+}
+func (UnimplementedReservationServer) Extend(context.Context, *ExtendRequest) (*ReservationResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Extend not implemented")
+}
 func (UnimplementedReservationServer) mustEmbedUnimplementedReservationServer() {}
 func (UnimplementedReservationServer) testEmbeddedByValue()                     {}
 
@@ -305,6 +353,24 @@ func _Reservation_Get_Handler(srv interface{}, ctx context.Context, dec func(int
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Reservation_GetNoShows_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetNoShowsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ReservationServer).GetNoShows(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Reservation_GetNoShows_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ReservationServer).GetNoShows(ctx, req.(*GetNoShowsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Reservation_Update_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(UpdateRequest)
 	if err := dec(in); err != nil {
@@ -377,6 +443,42 @@ func _Reservation_Delete_Handler(srv interface{}, ctx context.Context, dec func(
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Reservation_CheckOut_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ReservationServer).CheckOut(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Reservation_CheckOut_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ReservationServer).CheckOut(ctx, req.(*DeleteRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Reservation_Extend_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ExtendRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ReservationServer).Extend(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Reservation_Extend_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ReservationServer).Extend(ctx, req.(*ExtendRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // Reservation_ServiceDesc is the grpc.ServiceDesc for Reservation service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -404,6 +506,10 @@ var Reservation_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Get",
 			Handler:    _Reservation_Get_Handler,
 		},
+		{
+			MethodName: "GetNoShows",
+			Handler:    _Reservation_GetNoShows_Handler,
+		},
 		{
 			MethodName: "Update",
 			Handler:    _Reservation_Update_Handler,
@@ -420,6 +526,14 @@ var Reservation_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Delete",
 			Handler:    _Reservation_Delete_Handler,
 		},
+		{
+			MethodName: "CheckOut",
+			Handler:    _Reservation_CheckOut_Handler,
+		},
+		{
+			MethodName: "Extend",
+			Handler:    _Reservation_Extend_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "reservation.proto",
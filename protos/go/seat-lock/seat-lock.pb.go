@@ -1,6 +1,6 @@
 // Code generated by protoc-gen-go. DO NOT EDIT.
 // versions:
-// 	protoc-gen-go v1.33.0
+// 	protoc-gen-go v1.36.5
 // 	protoc        v5.29.3
 // source: seat-lock.proto
 
@@ -12,6 +12,7 @@ import (
 	emptypb "google.golang.org/protobuf/types/known/emptypb"
 	reflect "reflect"
 	sync "sync"
+	unsafe "unsafe"
 )
 
 const (
@@ -22,21 +23,19 @@ const (
 )
 
 type SetLockRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	UserId        *string                `protobuf:"bytes,1,req,name=user_id,json=userId" json:"user_id,omitempty"`
+	SeatIndex     *string                `protobuf:"bytes,2,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	TtlSeconds    *uint64                `protobuf:"varint,3,opt,name=ttl_seconds,json=ttlSeconds" json:"ttl_seconds,omitempty"`
 	unknownFields protoimpl.UnknownFields
-
-	UserId    *string `protobuf:"bytes,1,req,name=user_id,json=userId" json:"user_id,omitempty"`
-	SeatIndex *string `protobuf:"bytes,2,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	sizeCache     protoimpl.SizeCache
 }
 
 func (x *SetLockRequest) Reset() {
 	*x = SetLockRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_seat_lock_proto_msgTypes[0]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
-	}
+	mi := &file_seat_lock_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
 func (x *SetLockRequest) String() string {
@@ -47,7 +46,7 @@ func (*SetLockRequest) ProtoMessage() {}
 
 func (x *SetLockRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_seat_lock_proto_msgTypes[0]
-	if protoimpl.UnsafeEnabled && x != nil {
+	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
@@ -76,21 +75,197 @@ func (x *SetLockRequest) GetSeatIndex() string {
 	return ""
 }
 
-type GetLockByUserIdRequest struct {
-	state         protoimpl.MessageState
+func (x *SetLockRequest) GetTtlSeconds() uint64 {
+	if x != nil && x.TtlSeconds != nil {
+		return *x.TtlSeconds
+	}
+	return 0
+}
+
+type ReleaseLockRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	UserId        *string                `protobuf:"bytes,1,req,name=user_id,json=userId" json:"user_id,omitempty"`
+	SeatIndex     *string                `protobuf:"bytes,2,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ReleaseLockRequest) Reset() {
+	*x = ReleaseLockRequest{}
+	mi := &file_seat_lock_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ReleaseLockRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReleaseLockRequest) ProtoMessage() {}
+
+func (x *ReleaseLockRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_seat_lock_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReleaseLockRequest.ProtoReflect.Descriptor instead.
+func (*ReleaseLockRequest) Descriptor() ([]byte, []int) {
+	return file_seat_lock_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *ReleaseLockRequest) GetUserId() string {
+	if x != nil && x.UserId != nil {
+		return *x.UserId
+	}
+	return ""
+}
+
+func (x *ReleaseLockRequest) GetSeatIndex() string {
+	if x != nil && x.SeatIndex != nil {
+		return *x.SeatIndex
+	}
+	return ""
+}
+
+type BookingLockRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	UserId        *string                `protobuf:"bytes,1,req,name=user_id,json=userId" json:"user_id,omitempty"`
+	SeatIndex     *string                `protobuf:"bytes,2,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	LockId        *string                `protobuf:"bytes,3,req,name=lock_id,json=lockId" json:"lock_id,omitempty"`
+	TtlSeconds    *uint64                `protobuf:"varint,4,opt,name=ttl_seconds,json=ttlSeconds" json:"ttl_seconds,omitempty"`
 	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
 
-	UserId *string `protobuf:"bytes,1,req,name=user_id,json=userId" json:"user_id,omitempty"`
+func (x *BookingLockRequest) Reset() {
+	*x = BookingLockRequest{}
+	mi := &file_seat_lock_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
-func (x *GetLockByUserIdRequest) Reset() {
-	*x = GetLockByUserIdRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_seat_lock_proto_msgTypes[1]
+func (x *BookingLockRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BookingLockRequest) ProtoMessage() {}
+
+func (x *BookingLockRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_seat_lock_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BookingLockRequest.ProtoReflect.Descriptor instead.
+func (*BookingLockRequest) Descriptor() ([]byte, []int) {
+	return file_seat_lock_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *BookingLockRequest) GetUserId() string {
+	if x != nil && x.UserId != nil {
+		return *x.UserId
+	}
+	return ""
+}
+
+func (x *BookingLockRequest) GetSeatIndex() string {
+	if x != nil && x.SeatIndex != nil {
+		return *x.SeatIndex
+	}
+	return ""
+}
+
+func (x *BookingLockRequest) GetLockId() string {
+	if x != nil && x.LockId != nil {
+		return *x.LockId
+	}
+	return ""
+}
+
+func (x *BookingLockRequest) GetTtlSeconds() uint64 {
+	if x != nil && x.TtlSeconds != nil {
+		return *x.TtlSeconds
+	}
+	return 0
+}
+
+type ReleaseBookingLockRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SeatIndex     *string                `protobuf:"bytes,1,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	LockId        *string                `protobuf:"bytes,2,req,name=lock_id,json=lockId" json:"lock_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ReleaseBookingLockRequest) Reset() {
+	*x = ReleaseBookingLockRequest{}
+	mi := &file_seat_lock_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ReleaseBookingLockRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReleaseBookingLockRequest) ProtoMessage() {}
+
+func (x *ReleaseBookingLockRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_seat_lock_proto_msgTypes[3]
+	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReleaseBookingLockRequest.ProtoReflect.Descriptor instead.
+func (*ReleaseBookingLockRequest) Descriptor() ([]byte, []int) {
+	return file_seat_lock_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *ReleaseBookingLockRequest) GetSeatIndex() string {
+	if x != nil && x.SeatIndex != nil {
+		return *x.SeatIndex
+	}
+	return ""
+}
+
+func (x *ReleaseBookingLockRequest) GetLockId() string {
+	if x != nil && x.LockId != nil {
+		return *x.LockId
+	}
+	return ""
+}
+
+type GetLockByUserIdRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	UserId        *string                `protobuf:"bytes,1,req,name=user_id,json=userId" json:"user_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetLockByUserIdRequest) Reset() {
+	*x = GetLockByUserIdRequest{}
+	mi := &file_seat_lock_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
 func (x *GetLockByUserIdRequest) String() string {
@@ -100,8 +275,8 @@ func (x *GetLockByUserIdRequest) String() string {
 func (*GetLockByUserIdRequest) ProtoMessage() {}
 
 func (x *GetLockByUserIdRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_seat_lock_proto_msgTypes[1]
-	if protoimpl.UnsafeEnabled && x != nil {
+	mi := &file_seat_lock_proto_msgTypes[4]
+	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
@@ -113,7 +288,7 @@ func (x *GetLockByUserIdRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetLockByUserIdRequest.ProtoReflect.Descriptor instead.
 func (*GetLockByUserIdRequest) Descriptor() ([]byte, []int) {
-	return file_seat_lock_proto_rawDescGZIP(), []int{1}
+	return file_seat_lock_proto_rawDescGZIP(), []int{4}
 }
 
 func (x *GetLockByUserIdRequest) GetUserId() string {
@@ -124,20 +299,17 @@ func (x *GetLockByUserIdRequest) GetUserId() string {
 }
 
 type GetLockBySeatIndexRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	SeatIndex     *string                `protobuf:"bytes,1,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
 	unknownFields protoimpl.UnknownFields
-
-	SeatIndex *string `protobuf:"bytes,1,req,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	sizeCache     protoimpl.SizeCache
 }
 
 func (x *GetLockBySeatIndexRequest) Reset() {
 	*x = GetLockBySeatIndexRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_seat_lock_proto_msgTypes[2]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
-	}
+	mi := &file_seat_lock_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
 func (x *GetLockBySeatIndexRequest) String() string {
@@ -147,8 +319,8 @@ func (x *GetLockBySeatIndexRequest) String() string {
 func (*GetLockBySeatIndexRequest) ProtoMessage() {}
 
 func (x *GetLockBySeatIndexRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_seat_lock_proto_msgTypes[2]
-	if protoimpl.UnsafeEnabled && x != nil {
+	mi := &file_seat_lock_proto_msgTypes[5]
+	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
@@ -160,7 +332,7 @@ func (x *GetLockBySeatIndexRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetLockBySeatIndexRequest.ProtoReflect.Descriptor instead.
 func (*GetLockBySeatIndexRequest) Descriptor() ([]byte, []int) {
-	return file_seat_lock_proto_rawDescGZIP(), []int{2}
+	return file_seat_lock_proto_rawDescGZIP(), []int{5}
 }
 
 func (x *GetLockBySeatIndexRequest) GetSeatIndex() string {
@@ -171,20 +343,20 @@ func (x *GetLockBySeatIndexRequest) GetSeatIndex() string {
 }
 
 type LockResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Status        *bool                  `protobuf:"varint,1,req,name=status" json:"status,omitempty"`
+	UserId        *string                `protobuf:"bytes,2,opt,name=user_id,json=userId" json:"user_id,omitempty"`
+	SeatIndex     *string                `protobuf:"bytes,3,opt,name=seat_index,json=seatIndex" json:"seat_index,omitempty"`
+	TtlSeconds    *uint64                `protobuf:"varint,4,opt,name=ttl_seconds,json=ttlSeconds" json:"ttl_seconds,omitempty"`
 	unknownFields protoimpl.UnknownFields
-
-	Status *bool `protobuf:"varint,1,req,name=status" json:"status,omitempty"`
+	sizeCache     protoimpl.SizeCache
 }
 
 func (x *LockResponse) Reset() {
 	*x = LockResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_seat_lock_proto_msgTypes[3]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
-	}
+	mi := &file_seat_lock_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
 }
 
 func (x *LockResponse) String() string {
@@ -194,8 +366,8 @@ func (x *LockResponse) String() string {
 func (*LockResponse) ProtoMessage() {}
 
 func (x *LockResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_seat_lock_proto_msgTypes[3]
-	if protoimpl.UnsafeEnabled && x != nil {
+	mi := &file_seat_lock_proto_msgTypes[6]
+	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
@@ -207,7 +379,7 @@ func (x *LockResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use LockResponse.ProtoReflect.Descriptor instead.
 func (*LockResponse) Descriptor() ([]byte, []int) {
-	return file_seat_lock_proto_rawDescGZIP(), []int{3}
+	return file_seat_lock_proto_rawDescGZIP(), []int{6}
 }
 
 func (x *LockResponse) GetStatus() bool {
@@ -217,73 +389,143 @@ func (x *LockResponse) GetStatus() bool {
 	return false
 }
 
+func (x *LockResponse) GetUserId() string {
+	if x != nil && x.UserId != nil {
+		return *x.UserId
+	}
+	return ""
+}
+
+func (x *LockResponse) GetSeatIndex() string {
+	if x != nil && x.SeatIndex != nil {
+		return *x.SeatIndex
+	}
+	return ""
+}
+
+func (x *LockResponse) GetTtlSeconds() uint64 {
+	if x != nil && x.TtlSeconds != nil {
+		return *x.TtlSeconds
+	}
+	return 0
+}
+
 var File_seat_lock_proto protoreflect.FileDescriptor
 
-var file_seat_lock_proto_rawDesc = []byte{
+var file_seat_lock_proto_rawDesc = string([]byte{
 	0x0a, 0x0f, 0x73, 0x65, 0x61, 0x74, 0x2d, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x70, 0x72, 0x6f, 0x74,
 	0x6f, 0x12, 0x08, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x1a, 0x1b, 0x67, 0x6f, 0x6f,
 	0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x65, 0x6d, 0x70,
-	0x74, 0x79, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x48, 0x0a, 0x0e, 0x53, 0x65, 0x74, 0x4c,
+	0x74, 0x79, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x69, 0x0a, 0x0e, 0x53, 0x65, 0x74, 0x4c,
 	0x6f, 0x63, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x75, 0x73,
 	0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x75, 0x73, 0x65,
 	0x72, 0x49, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65,
 	0x78, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64,
-	0x65, 0x78, 0x22, 0x31, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x55,
-	0x73, 0x65, 0x72, 0x49, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07,
-	0x75, 0x73, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x75,
-	0x73, 0x65, 0x72, 0x49, 0x64, 0x22, 0x3a, 0x0a, 0x19, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b,
-	0x42, 0x79, 0x53, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78,
-	0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65,
-	0x78, 0x22, 0x26, 0x0a, 0x0c, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28,
-	0x08, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x32, 0xe7, 0x01, 0x0a, 0x08, 0x53, 0x65,
-	0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x12, 0x3b, 0x0a, 0x07, 0x53, 0x65, 0x74, 0x4c, 0x6f, 0x63,
-	0x6b, 0x12, 0x18, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x53, 0x65, 0x74,
-	0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x67, 0x6f,
-	0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x45, 0x6d,
-	0x70, 0x74, 0x79, 0x12, 0x4b, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79,
-	0x55, 0x73, 0x65, 0x72, 0x49, 0x44, 0x12, 0x20, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63,
-	0x6b, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x55, 0x73, 0x65, 0x72, 0x49,
-	0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c,
-	0x6f, 0x63, 0x6b, 0x2e, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x51, 0x0a, 0x12, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x53, 0x65, 0x61,
-	0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x23, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63,
-	0x6b, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x53, 0x65, 0x61, 0x74, 0x49,
-	0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x65,
-	0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x42, 0x03, 0x5a, 0x01, 0x2f,
-}
+	0x65, 0x78, 0x12, 0x1f, 0x0a, 0x0b, 0x74, 0x74, 0x6c, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64,
+	0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x74, 0x74, 0x6c, 0x53, 0x65, 0x63, 0x6f,
+	0x6e, 0x64, 0x73, 0x22, 0x4c, 0x0a, 0x12, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x4c, 0x6f,
+	0x63, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x75, 0x73, 0x65,
+	0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x75, 0x73, 0x65, 0x72,
+	0x49, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78,
+	0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65,
+	0x78, 0x22, 0x86, 0x01, 0x0a, 0x12, 0x42, 0x6f, 0x6f, 0x6b, 0x69, 0x6e, 0x67, 0x4c, 0x6f, 0x63,
+	0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x75, 0x73, 0x65, 0x72,
+	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x75, 0x73, 0x65, 0x72, 0x49,
+	0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18,
+	0x02, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78,
+	0x12, 0x17, 0x0a, 0x07, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28,
+	0x09, 0x52, 0x06, 0x6c, 0x6f, 0x63, 0x6b, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x74, 0x74, 0x6c,
+	0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a,
+	0x74, 0x74, 0x6c, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x22, 0x53, 0x0a, 0x19, 0x52, 0x65,
+	0x6c, 0x65, 0x61, 0x73, 0x65, 0x42, 0x6f, 0x6f, 0x6b, 0x69, 0x6e, 0x67, 0x4c, 0x6f, 0x63, 0x6b,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f,
+	0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61,
+	0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x17, 0x0a, 0x07, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x69,
+	0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x6c, 0x6f, 0x63, 0x6b, 0x49, 0x64, 0x22,
+	0x31, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x55, 0x73, 0x65, 0x72,
+	0x49, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x75, 0x73, 0x65,
+	0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x52, 0x06, 0x75, 0x73, 0x65, 0x72,
+	0x49, 0x64, 0x22, 0x3a, 0x0a, 0x19, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x53,
+	0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x01, 0x20,
+	0x02, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0x7f,
+	0x0a, 0x0c, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x16,
+	0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x08, 0x52, 0x06,
+	0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x69,
+	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x75, 0x73, 0x65, 0x72, 0x49, 0x64, 0x12,
+	0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x61, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1f,
+	0x0a, 0x0b, 0x74, 0x74, 0x6c, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18, 0x04, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x0a, 0x74, 0x74, 0x6c, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x32,
+	0xc7, 0x03, 0x0a, 0x08, 0x53, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x12, 0x3b, 0x0a, 0x07,
+	0x53, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x12, 0x18, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f,
+	0x63, 0x6b, 0x2e, 0x53, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x16, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x4c, 0x6f, 0x63,
+	0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x43, 0x0a, 0x0b, 0x52, 0x65, 0x6c,
+	0x65, 0x61, 0x73, 0x65, 0x4c, 0x6f, 0x63, 0x6b, 0x12, 0x1c, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c,
+	0x6f, 0x63, 0x6b, 0x2e, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x4c, 0x6f, 0x63, 0x6b, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x12, 0x4b,
+	0x0a, 0x0f, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x55, 0x73, 0x65, 0x72, 0x49,
+	0x44, 0x12, 0x20, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x47, 0x65, 0x74,
+	0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x55, 0x73, 0x65, 0x72, 0x49, 0x64, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x4c,
+	0x6f, 0x63, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x51, 0x0a, 0x12, 0x47,
+	0x65, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x53, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65,
+	0x78, 0x12, 0x23, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x47, 0x65, 0x74,
+	0x4c, 0x6f, 0x63, 0x6b, 0x42, 0x79, 0x53, 0x65, 0x61, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63,
+	0x6b, 0x2e, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46,
+	0x0a, 0x0e, 0x4c, 0x6f, 0x63, 0x6b, 0x46, 0x6f, 0x72, 0x42, 0x6f, 0x6f, 0x6b, 0x69, 0x6e, 0x67,
+	0x12, 0x1c, 0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x42, 0x6f, 0x6f, 0x6b,
+	0x69, 0x6e, 0x67, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16,
+	0x2e, 0x73, 0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x51, 0x0a, 0x12, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73,
+	0x65, 0x42, 0x6f, 0x6f, 0x6b, 0x69, 0x6e, 0x67, 0x4c, 0x6f, 0x63, 0x6b, 0x12, 0x23, 0x2e, 0x73,
+	0x65, 0x61, 0x74, 0x4c, 0x6f, 0x63, 0x6b, 0x2e, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x42,
+	0x6f, 0x6f, 0x6b, 0x69, 0x6e, 0x67, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x16, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x62, 0x75, 0x66, 0x2e, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x42, 0x03, 0x5a, 0x01, 0x2f,
+})
 
 var (
 	file_seat_lock_proto_rawDescOnce sync.Once
-	file_seat_lock_proto_rawDescData = file_seat_lock_proto_rawDesc
+	file_seat_lock_proto_rawDescData []byte
 )
 
 func file_seat_lock_proto_rawDescGZIP() []byte {
 	file_seat_lock_proto_rawDescOnce.Do(func() {
-		file_seat_lock_proto_rawDescData = protoimpl.X.CompressGZIP(file_seat_lock_proto_rawDescData)
+		file_seat_lock_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_seat_lock_proto_rawDesc), len(file_seat_lock_proto_rawDesc)))
 	})
 	return file_seat_lock_proto_rawDescData
 }
 
-var file_seat_lock_proto_msgTypes = make([]protoimpl.MessageInfo, 4)
-var file_seat_lock_proto_goTypes = []interface{}{
+var file_seat_lock_proto_msgTypes = make([]protoimpl.MessageInfo, 7)
+var file_seat_lock_proto_goTypes = []any{
 	(*SetLockRequest)(nil),            // 0: seatLock.SetLockRequest
-	(*GetLockByUserIdRequest)(nil),    // 1: seatLock.GetLockByUserIdRequest
-	(*GetLockBySeatIndexRequest)(nil), // 2: seatLock.GetLockBySeatIndexRequest
-	(*LockResponse)(nil),              // 3: seatLock.LockResponse
-	(*emptypb.Empty)(nil),             // 4: google.protobuf.Empty
+	(*ReleaseLockRequest)(nil),        // 1: seatLock.ReleaseLockRequest
+	(*BookingLockRequest)(nil),        // 2: seatLock.BookingLockRequest
+	(*ReleaseBookingLockRequest)(nil), // 3: seatLock.ReleaseBookingLockRequest
+	(*GetLockByUserIdRequest)(nil),    // 4: seatLock.GetLockByUserIdRequest
+	(*GetLockBySeatIndexRequest)(nil), // 5: seatLock.GetLockBySeatIndexRequest
+	(*LockResponse)(nil),              // 6: seatLock.LockResponse
+	(*emptypb.Empty)(nil),             // 7: google.protobuf.Empty
 }
 var file_seat_lock_proto_depIdxs = []int32{
 	0, // 0: seatLock.SeatLock.SetLock:input_type -> seatLock.SetLockRequest
-	1, // 1: seatLock.SeatLock.GetLockByUserID:input_type -> seatLock.GetLockByUserIdRequest
-	2, // 2: seatLock.SeatLock.GetLockBySeatIndex:input_type -> seatLock.GetLockBySeatIndexRequest
-	4, // 3: seatLock.SeatLock.SetLock:output_type -> google.protobuf.Empty
-	3, // 4: seatLock.SeatLock.GetLockByUserID:output_type -> seatLock.LockResponse
-	3, // 5: seatLock.SeatLock.GetLockBySeatIndex:output_type -> seatLock.LockResponse
-	3, // [3:6] is the sub-list for method output_type
-	0, // [0:3] is the sub-list for method input_type
+	1, // 1: seatLock.SeatLock.ReleaseLock:input_type -> seatLock.ReleaseLockRequest
+	4, // 2: seatLock.SeatLock.GetLockByUserID:input_type -> seatLock.GetLockByUserIdRequest
+	5, // 3: seatLock.SeatLock.GetLockBySeatIndex:input_type -> seatLock.GetLockBySeatIndexRequest
+	2, // 4: seatLock.SeatLock.LockForBooking:input_type -> seatLock.BookingLockRequest
+	3, // 5: seatLock.SeatLock.ReleaseBookingLock:input_type -> seatLock.ReleaseBookingLockRequest
+	6, // 6: seatLock.SeatLock.SetLock:output_type -> seatLock.LockResponse
+	7, // 7: seatLock.SeatLock.ReleaseLock:output_type -> google.protobuf.Empty
+	6, // 8: seatLock.SeatLock.GetLockByUserID:output_type -> seatLock.LockResponse
+	6, // 9: seatLock.SeatLock.GetLockBySeatIndex:output_type -> seatLock.LockResponse
+	6, // 10: seatLock.SeatLock.LockForBooking:output_type -> seatLock.LockResponse
+	7, // 11: seatLock.SeatLock.ReleaseBookingLock:output_type -> google.protobuf.Empty
+	6, // [6:12] is the sub-list for method output_type
+	0, // [0:6] is the sub-list for method input_type
 	0, // [0:0] is the sub-list for extension type_name
 	0, // [0:0] is the sub-list for extension extendee
 	0, // [0:0] is the sub-list for field type_name
@@ -294,63 +536,13 @@ func file_seat_lock_proto_init() {
 	if File_seat_lock_proto != nil {
 		return
 	}
-	if !protoimpl.UnsafeEnabled {
-		file_seat_lock_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SetLockRequest); i {
-			case 0:
-				return &v.state
-			case 1:
-				return &v.sizeCache
-			case 2:
-				return &v.unknownFields
-			default:
-				return nil
-			}
-		}
-		file_seat_lock_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetLockByUserIdRequest); i {
-			case 0:
-				return &v.state
-			case 1:
-				return &v.sizeCache
-			case 2:
-				return &v.unknownFields
-			default:
-				return nil
-			}
-		}
-		file_seat_lock_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetLockBySeatIndexRequest); i {
-			case 0:
-				return &v.state
-			case 1:
-				return &v.sizeCache
-			case 2:
-				return &v.unknownFields
-			default:
-				return nil
-			}
-		}
-		file_seat_lock_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*LockResponse); i {
-			case 0:
-				return &v.state
-			case 1:
-				return &v.sizeCache
-			case 2:
-				return &v.unknownFields
-			default:
-				return nil
-			}
-		}
-	}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
-			RawDescriptor: file_seat_lock_proto_rawDesc,
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_seat_lock_proto_rawDesc), len(file_seat_lock_proto_rawDesc)),
 			NumEnums:      0,
-			NumMessages:   4,
+			NumMessages:   7,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -359,7 +551,6 @@ func file_seat_lock_proto_init() {
 		MessageInfos:      file_seat_lock_proto_msgTypes,
 	}.Build()
 	File_seat_lock_proto = out.File
-	file_seat_lock_proto_rawDesc = nil
 	file_seat_lock_proto_goTypes = nil
 	file_seat_lock_proto_depIdxs = nil
 }
@@ -21,17 +21,23 @@ const _ = grpc.SupportPackageIsVersion9
 
 const (
 	SeatLock_SetLock_FullMethodName            = "/seatLock.SeatLock/SetLock"
+	SeatLock_ReleaseLock_FullMethodName        = "/seatLock.SeatLock/ReleaseLock"
 	SeatLock_GetLockByUserID_FullMethodName    = "/seatLock.SeatLock/GetLockByUserID"
 	SeatLock_GetLockBySeatIndex_FullMethodName = "/seatLock.SeatLock/GetLockBySeatIndex"
+	SeatLock_LockForBooking_FullMethodName     = "/seatLock.SeatLock/LockForBooking"
+	SeatLock_ReleaseBookingLock_FullMethodName = "/seatLock.SeatLock/ReleaseBookingLock"
 )
 
 // SeatLockClient is the client API for SeatLock service.
 //
 // For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
 type SeatLockClient interface {
-	SetLock(ctx context.Context, in *SetLockRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
+	SetLock(ctx context.Context, in *SetLockRequest, opts ...grpc.CallOption) (*LockResponse, error)
+	ReleaseLock(ctx context.Context, in *ReleaseLockRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 	GetLockByUserID(ctx context.Context, in *GetLockByUserIdRequest, opts ...grpc.CallOption) (*LockResponse, error)
 	GetLockBySeatIndex(ctx context.Context, in *GetLockBySeatIndexRequest, opts ...grpc.CallOption) (*LockResponse, error)
+	LockForBooking(ctx context.Context, in *BookingLockRequest, opts ...grpc.CallOption) (*LockResponse, error)
+	ReleaseBookingLock(ctx context.Context, in *ReleaseBookingLockRequest, opts ...grpc.CallOption) (*emptypb.Empty, error)
 }
 
 type seatLockClient struct {
@@ -42,9 +48,9 @@ func NewSeatLockClient(cc grpc.ClientConnInterface) SeatLockClient {
 	return &seatLockClient{cc}
 }
 
-func (c *seatLockClient) SetLock(ctx context.Context, in *SetLockRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+func (c *seatLockClient) SetLock(ctx context.Context, in *SetLockRequest, opts ...grpc.CallOption) (*LockResponse, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
-	out := new(emptypb.Empty)
+	out := new(LockResponse)
 	err := c.cc.Invoke(ctx, SeatLock_SetLock_FullMethodName, in, out, cOpts...)
 	if err != nil {
 		return nil, err
@@ -52,6 +58,16 @@ func (c *seatLockClient) SetLock(ctx context.Context, in *SetLockRequest, opts .
 	return out, nil
 }
 
+func (c *seatLockClient) ReleaseLock(ctx context.Context, in *ReleaseLockRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, SeatLock_ReleaseLock_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *seatLockClient) GetLockByUserID(ctx context.Context, in *GetLockByUserIdRequest, opts ...grpc.CallOption) (*LockResponse, error) {
 	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
 	out := new(LockResponse)
@@ -72,13 +88,36 @@ func (c *seatLockClient) GetLockBySeatIndex(ctx context.Context, in *GetLockBySe
 	return out, nil
 }
 
+func (c *seatLockClient) LockForBooking(ctx context.Context, in *BookingLockRequest, opts ...grpc.CallOption) (*LockResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(LockResponse)
+	err := c.cc.Invoke(ctx, SeatLock_LockForBooking_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *seatLockClient) ReleaseBookingLock(ctx context.Context, in *ReleaseBookingLockRequest, opts ...grpc.CallOption) (*emptypb.Empty, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(emptypb.Empty)
+	err := c.cc.Invoke(ctx, SeatLock_ReleaseBookingLock_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // SeatLockServer is the server API for SeatLock service.
 // All implementations must embed UnimplementedSeatLockServer
 // for forward compatibility.
 type SeatLockServer interface {
-	SetLock(context.Context, *SetLockRequest) (*emptypb.Empty, error)
+	SetLock(context.Context, *SetLockRequest) (*LockResponse, error)
+	ReleaseLock(context.Context, *ReleaseLockRequest) (*emptypb.Empty, error)
 	GetLockByUserID(context.Context, *GetLockByUserIdRequest) (*LockResponse, error)
 	GetLockBySeatIndex(context.Context, *GetLockBySeatIndexRequest) (*LockResponse, error)
+	LockForBooking(context.Context, *BookingLockRequest) (*LockResponse, error)
+	ReleaseBookingLock(context.Context, *ReleaseBookingLockRequest) (*emptypb.Empty, error)
 	mustEmbedUnimplementedSeatLockServer()
 }
 
@@ -89,15 +128,24 @@ type SeatLockServer interface {
 // pointer dereference when methods are called.
 type UnimplementedSeatLockServer struct{}
 
-func (UnimplementedSeatLockServer) SetLock(context.Context, *SetLockRequest) (*emptypb.Empty, error) {
+func (UnimplementedSeatLockServer) SetLock(context.Context, *SetLockRequest) (*LockResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method SetLock not implemented")
 }
+func (UnimplementedSeatLockServer) ReleaseLock(context.Context, *ReleaseLockRequest) (*emptypb.Empty, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ReleaseLock not implemented")
+}
 func (UnimplementedSeatLockServer) GetLockByUserID(context.Context, *GetLockByUserIdRequest) (*LockResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetLockByUserID not implemented")
 }
 func (UnimplementedSeatLockServer) GetLockBySeatIndex(context.Context, *GetLockBySeatIndexRequest) (*LockResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetLockBySeatIndex not implemented")
 }
+func (UnimplementedSeatLockServer) LockForBooking(context.Context, *BookingLockRequest) (*LockResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method LockForBooking not implemented")
+}
+func (UnimplementedSeatLockServer) ReleaseBookingLock(context.Context, *ReleaseBookingLockRequest) (*emptypb.Empty, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ReleaseBookingLock not implemented")
+}
 func (UnimplementedSeatLockServer) mustEmbedUnimplementedSeatLockServer() {}
 func (UnimplementedSeatLockServer) testEmbeddedByValue()                  {}
 
@@ -137,6 +185,24 @@ func _SeatLock_SetLock_Handler(srv interface{}, ctx context.Context, dec func(in
 	return interceptor(ctx, in, info, handler)
 }
 
+func _SeatLock_ReleaseLock_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ReleaseLockRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SeatLockServer).ReleaseLock(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SeatLock_ReleaseLock_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SeatLockServer).ReleaseLock(ctx, req.(*ReleaseLockRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _SeatLock_GetLockByUserID_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(GetLockByUserIdRequest)
 	if err := dec(in); err != nil {
@@ -173,6 +239,42 @@ func _SeatLock_GetLockBySeatIndex_Handler(srv interface{}, ctx context.Context,
 	return interceptor(ctx, in, info, handler)
 }
 
+func _SeatLock_LockForBooking_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BookingLockRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SeatLockServer).LockForBooking(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SeatLock_LockForBooking_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SeatLockServer).LockForBooking(ctx, req.(*BookingLockRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _SeatLock_ReleaseBookingLock_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ReleaseBookingLockRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SeatLockServer).ReleaseBookingLock(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: SeatLock_ReleaseBookingLock_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SeatLockServer).ReleaseBookingLock(ctx, req.(*ReleaseBookingLockRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // SeatLock_ServiceDesc is the grpc.ServiceDesc for SeatLock service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -184,6 +286,10 @@ var SeatLock_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "SetLock",
 			Handler:    _SeatLock_SetLock_Handler,
 		},
+		{
+			MethodName: "ReleaseLock",
+			Handler:    _SeatLock_ReleaseLock_Handler,
+		},
 		{
 			MethodName: "GetLockByUserID",
 			Handler:    _SeatLock_GetLockByUserID_Handler,
@@ -192,6 +298,14 @@ var SeatLock_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GetLockBySeatIndex",
 			Handler:    _SeatLock_GetLockBySeatIndex_Handler,
 		},
+		{
+			MethodName: "LockForBooking",
+			Handler:    _SeatLock_LockForBooking_Handler,
+		},
+		{
+			MethodName: "ReleaseBookingLock",
+			Handler:    _SeatLock_ReleaseBookingLock_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "seat-lock.proto",
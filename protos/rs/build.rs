@@ -1,18 +1,23 @@
-use std::error::Error;
+use std::{env, error::Error, path::PathBuf};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let protos_dir = "../";
     let protos_src_dir = format!("{protos_dir}/src");
     let protos_rs_dir = format!("{protos_dir}/rs/src");
+    let descriptor_set_path = PathBuf::from(env::var("OUT_DIR")?).join("descriptor.bin");
 
     tonic_build::configure()
         .out_dir(protos_rs_dir)
+        .file_descriptor_set_path(descriptor_set_path)
         .compile_protos(
             &[
                 format!("{protos_src_dir}/admin.proto"),
+                format!("{protos_src_dir}/analytics.proto"),
+                format!("{protos_src_dir}/billing.proto"),
                 format!("{protos_src_dir}/client.proto"),
                 format!("{protos_src_dir}/coworking.proto"),
                 format!("{protos_src_dir}/image.proto"),
+                format!("{protos_src_dir}/image-worker.proto"),
                 format!("{protos_src_dir}/mail.proto"),
                 format!("{protos_src_dir}/notification.proto"),
                 format!("{protos_src_dir}/reservation.proto"),
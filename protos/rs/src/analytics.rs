@@ -0,0 +1,695 @@
+// This file is @generated by prost-build.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PeriodRequest {
+    #[prost(message, required, tag = "1")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "2")]
+    pub ends_at: ::prost_types::Timestamp,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OccupancyRequest {
+    #[prost(message, required, tag = "1")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "2")]
+    pub ends_at: ::prost_types::Timestamp,
+    #[prost(enumeration = "Granularity", required, tag = "3")]
+    pub granularity: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OccupancyPoint {
+    #[prost(message, required, tag = "1")]
+    pub bucket: ::prost_types::Timestamp,
+    #[prost(string, required, tag = "2")]
+    pub seat_type: ::prost::alloc::string::String,
+    #[prost(uint32, required, tag = "3")]
+    pub seats: u32,
+    #[prost(double, required, tag = "4")]
+    pub capacity_hours: f64,
+    #[prost(double, required, tag = "5")]
+    pub booked_hours: f64,
+    #[prost(double, required, tag = "6")]
+    pub occupied_hours: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OccupancyResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub points: ::prost::alloc::vec::Vec<OccupancyPoint>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeatmapRequest {
+    #[prost(message, required, tag = "1")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "2")]
+    pub ends_at: ::prost_types::Timestamp,
+    #[prost(string, optional, tag = "3")]
+    pub seat_type: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HeatmapCell {
+    #[prost(uint32, required, tag = "1")]
+    pub weekday: u32,
+    #[prost(uint32, required, tag = "2")]
+    pub hour: u32,
+    #[prost(double, required, tag = "3")]
+    pub capacity_hours: f64,
+    #[prost(double, required, tag = "4")]
+    pub booked_hours: f64,
+    #[prost(double, required, tag = "5")]
+    pub occupied_hours: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeatmapResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub cells: ::prost::alloc::vec::Vec<HeatmapCell>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeatTypeRates {
+    #[prost(string, required, tag = "1")]
+    pub seat_type: ::prost::alloc::string::String,
+    #[prost(uint32, required, tag = "2")]
+    pub reservations: u32,
+    #[prost(uint32, required, tag = "3")]
+    pub cancellations: u32,
+    #[prost(uint32, required, tag = "4")]
+    pub no_shows: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RatesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub rates: ::prost::alloc::vec::Vec<SeatTypeRates>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevenuePoint {
+    #[prost(message, required, tag = "1")]
+    pub day: ::prost_types::Timestamp,
+    #[prost(string, required, tag = "2")]
+    pub seat_type: ::prost::alloc::string::String,
+    #[prost(double, required, tag = "3")]
+    pub revenue: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevenueResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub points: ::prost::alloc::vec::Vec<RevenuePoint>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Granularity {
+    Hour = 1,
+    Day = 2,
+}
+impl Granularity {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Hour => "HOUR",
+            Self::Day => "DAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "HOUR" => Some(Self::Hour),
+            "DAY" => Some(Self::Day),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod analytics_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct AnalyticsClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl AnalyticsClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> AnalyticsClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AnalyticsClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            AnalyticsClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn get_occupancy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::OccupancyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OccupancyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/analytics.Analytics/GetOccupancy",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("analytics.Analytics", "GetOccupancy"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_heatmap(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HeatmapRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::HeatmapResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/analytics.Analytics/GetHeatmap",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("analytics.Analytics", "GetHeatmap"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_rates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PeriodRequest>,
+        ) -> std::result::Result<tonic::Response<super::RatesResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/analytics.Analytics/GetRates",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("analytics.Analytics", "GetRates"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_revenue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PeriodRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RevenueResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/analytics.Analytics/GetRevenue",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("analytics.Analytics", "GetRevenue"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn rebuild(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PeriodRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/analytics.Analytics/Rebuild",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("analytics.Analytics", "Rebuild"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod analytics_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with AnalyticsServer.
+    #[async_trait]
+    pub trait Analytics: std::marker::Send + std::marker::Sync + 'static {
+        async fn get_occupancy(
+            &self,
+            request: tonic::Request<super::OccupancyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OccupancyResponse>,
+            tonic::Status,
+        >;
+        async fn get_heatmap(
+            &self,
+            request: tonic::Request<super::HeatmapRequest>,
+        ) -> std::result::Result<tonic::Response<super::HeatmapResponse>, tonic::Status>;
+        async fn get_rates(
+            &self,
+            request: tonic::Request<super::PeriodRequest>,
+        ) -> std::result::Result<tonic::Response<super::RatesResponse>, tonic::Status>;
+        async fn get_revenue(
+            &self,
+            request: tonic::Request<super::PeriodRequest>,
+        ) -> std::result::Result<tonic::Response<super::RevenueResponse>, tonic::Status>;
+        async fn rebuild(
+            &self,
+            request: tonic::Request<super::PeriodRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct AnalyticsServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> AnalyticsServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for AnalyticsServer<T>
+    where
+        T: Analytics,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/analytics.Analytics/GetOccupancy" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOccupancySvc<T: Analytics>(pub Arc<T>);
+                    impl<
+                        T: Analytics,
+                    > tonic::server::UnaryService<super::OccupancyRequest>
+                    for GetOccupancySvc<T> {
+                        type Response = super::OccupancyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::OccupancyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Analytics>::get_occupancy(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetOccupancySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/analytics.Analytics/GetHeatmap" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetHeatmapSvc<T: Analytics>(pub Arc<T>);
+                    impl<T: Analytics> tonic::server::UnaryService<super::HeatmapRequest>
+                    for GetHeatmapSvc<T> {
+                        type Response = super::HeatmapResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HeatmapRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Analytics>::get_heatmap(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetHeatmapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/analytics.Analytics/GetRates" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRatesSvc<T: Analytics>(pub Arc<T>);
+                    impl<T: Analytics> tonic::server::UnaryService<super::PeriodRequest>
+                    for GetRatesSvc<T> {
+                        type Response = super::RatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PeriodRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Analytics>::get_rates(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetRatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/analytics.Analytics/GetRevenue" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRevenueSvc<T: Analytics>(pub Arc<T>);
+                    impl<T: Analytics> tonic::server::UnaryService<super::PeriodRequest>
+                    for GetRevenueSvc<T> {
+                        type Response = super::RevenueResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PeriodRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Analytics>::get_revenue(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetRevenueSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/analytics.Analytics/Rebuild" => {
+                    #[allow(non_camel_case_types)]
+                    struct RebuildSvc<T: Analytics>(pub Arc<T>);
+                    impl<T: Analytics> tonic::server::UnaryService<super::PeriodRequest>
+                    for RebuildSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PeriodRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Analytics>::rebuild(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RebuildSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for AnalyticsServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "analytics.Analytics";
+    impl<T> tonic::server::NamedService for AnalyticsServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
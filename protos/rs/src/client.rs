@@ -65,7 +65,7 @@ pub struct ClientRequest {
     #[prost(string, required, tag = "1")]
     pub id: ::prost::alloc::string::String,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientsRequest {
     #[prost(uint32, required, tag = "1")]
     pub limit: u32,
@@ -77,6 +77,17 @@ pub struct ClientsRequest {
     pub month: ::core::option::Option<u32>,
     #[prost(uint64, optional, tag = "5")]
     pub year: ::core::option::Option<u64>,
+    #[prost(string, repeated, tag = "6")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListClientsRequest {
+    #[prost(uint32, required, tag = "1")]
+    pub limit: u32,
+    #[prost(uint64, required, tag = "2")]
+    pub offset: u64,
+    #[prost(bool, optional, tag = "3")]
+    pub send_notifications: ::core::option::Option<bool>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientsResponse {
@@ -102,6 +113,18 @@ pub struct EditRequest {
     #[prost(bool, optional, tag = "8")]
     pub verified: ::core::option::Option<bool>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalendarTokenRequest {
+    #[prost(string, required, tag = "1")]
+    pub token: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalendarTokenResponse {
+    #[prost(string, required, tag = "1")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(message, required, tag = "2")]
+    pub created_at: ::prost_types::Timestamp,
+}
 /// Generated client implementations.
 pub mod client_client {
     #![allow(
@@ -312,6 +335,27 @@ pub mod client_client {
             req.extensions_mut().insert(GrpcMethod::new("client.Client", "GetMultiple"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListClientsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ClientsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/client.Client/GetAll");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("client.Client", "GetAll"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn edit(
             &mut self,
             request: impl tonic::IntoRequest<super::EditRequest>,
@@ -348,6 +392,72 @@ pub mod client_client {
             req.extensions_mut().insert(GrpcMethod::new("client.Client", "Delete"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn issue_calendar_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ClientRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CalendarTokenResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/client.Client/IssueCalendarToken",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("client.Client", "IssueCalendarToken"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revoke_calendar_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ClientRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/client.Client/RevokeCalendarToken",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("client.Client", "RevokeCalendarToken"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_by_calendar_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CalendarTokenRequest>,
+        ) -> std::result::Result<tonic::Response<super::ClientResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/client.Client/GetByCalendarToken",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("client.Client", "GetByCalendarToken"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -387,6 +497,10 @@ pub mod client_server {
             &self,
             request: tonic::Request<super::ClientsRequest>,
         ) -> std::result::Result<tonic::Response<super::ClientsResponse>, tonic::Status>;
+        async fn get_all(
+            &self,
+            request: tonic::Request<super::ListClientsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ClientsResponse>, tonic::Status>;
         async fn edit(
             &self,
             request: tonic::Request<super::EditRequest>,
@@ -395,6 +509,21 @@ pub mod client_server {
             &self,
             request: tonic::Request<super::ClientRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn issue_calendar_token(
+            &self,
+            request: tonic::Request<super::ClientRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CalendarTokenResponse>,
+            tonic::Status,
+        >;
+        async fn revoke_calendar_token(
+            &self,
+            request: tonic::Request<super::ClientRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn get_by_calendar_token(
+            &self,
+            request: tonic::Request<super::CalendarTokenRequest>,
+        ) -> std::result::Result<tonic::Response<super::ClientResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct ClientServer<T> {
@@ -734,6 +863,51 @@ pub mod client_server {
                     };
                     Box::pin(fut)
                 }
+                "/client.Client/GetAll" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAllSvc<T: Client>(pub Arc<T>);
+                    impl<
+                        T: Client,
+                    > tonic::server::UnaryService<super::ListClientsRequest>
+                    for GetAllSvc<T> {
+                        type Response = super::ClientsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListClientsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Client>::get_all(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAllSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/client.Client/Edit" => {
                     #[allow(non_camel_case_types)]
                     struct EditSvc<T: Client>(pub Arc<T>);
@@ -820,6 +994,137 @@ pub mod client_server {
                     };
                     Box::pin(fut)
                 }
+                "/client.Client/IssueCalendarToken" => {
+                    #[allow(non_camel_case_types)]
+                    struct IssueCalendarTokenSvc<T: Client>(pub Arc<T>);
+                    impl<T: Client> tonic::server::UnaryService<super::ClientRequest>
+                    for IssueCalendarTokenSvc<T> {
+                        type Response = super::CalendarTokenResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ClientRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Client>::issue_calendar_token(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = IssueCalendarTokenSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/client.Client/RevokeCalendarToken" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeCalendarTokenSvc<T: Client>(pub Arc<T>);
+                    impl<T: Client> tonic::server::UnaryService<super::ClientRequest>
+                    for RevokeCalendarTokenSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ClientRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Client>::revoke_calendar_token(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevokeCalendarTokenSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/client.Client/GetByCalendarToken" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetByCalendarTokenSvc<T: Client>(pub Arc<T>);
+                    impl<
+                        T: Client,
+                    > tonic::server::UnaryService<super::CalendarTokenRequest>
+                    for GetByCalendarTokenSvc<T> {
+                        type Response = super::ClientResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CalendarTokenRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Client>::get_by_calendar_token(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetByCalendarTokenSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
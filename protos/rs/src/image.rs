@@ -27,6 +27,8 @@ pub struct Metadata {
     pub content_type: ::prost::alloc::string::String,
     #[prost(enumeration = "ImageType", required, tag = "3")]
     pub image_type: i32,
+    #[prost(enumeration = "ImageVariant", optional, tag = "4")]
+    pub variant: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetImageRequest {
@@ -34,11 +36,15 @@ pub struct GetImageRequest {
     pub id: ::prost::alloc::string::String,
     #[prost(enumeration = "ImageType", required, tag = "2")]
     pub image_type: i32,
+    #[prost(enumeration = "ImageVariant", optional, tag = "3")]
+    pub variant: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetImageResponse {
     #[prost(bytes = "vec", required, tag = "1")]
     pub content: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, optional, tag = "2")]
+    pub content_type: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteImageRequest {
@@ -47,6 +53,18 @@ pub struct DeleteImageRequest {
     #[prost(enumeration = "ImageType", required, tag = "2")]
     pub image_type: i32,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListImagesRequest {
+    #[prost(string, required, tag = "1")]
+    pub prefix: ::prost::alloc::string::String,
+    #[prost(enumeration = "ImageType", required, tag = "2")]
+    pub image_type: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListImagesResponse {
+    #[prost(string, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ImageType {
@@ -79,6 +97,35 @@ impl ImageType {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ImageVariant {
+    Original = 1,
+    Medium = 2,
+    Thumbnail = 3,
+}
+impl ImageVariant {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Original => "ORIGINAL",
+            Self::Medium => "MEDIUM",
+            Self::Thumbnail => "THUMBNAIL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORIGINAL" => Some(Self::Original),
+            "MEDIUM" => Some(Self::Medium),
+            "THUMBNAIL" => Some(Self::Thumbnail),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod image_client {
     #![allow(
@@ -232,6 +279,27 @@ pub mod image_client {
             req.extensions_mut().insert(GrpcMethod::new("image.Image", "DeleteImage"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_images(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListImagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListImagesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/image.Image/ListImages");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("image.Image", "ListImages"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -268,6 +336,13 @@ pub mod image_server {
             &self,
             request: tonic::Request<super::DeleteImageRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn list_images(
+            &self,
+            request: tonic::Request<super::ListImagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListImagesResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ImageServer<T> {
@@ -481,6 +556,49 @@ pub mod image_server {
                     };
                     Box::pin(fut)
                 }
+                "/image.Image/ListImages" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListImagesSvc<T: Image>(pub Arc<T>);
+                    impl<T: Image> tonic::server::UnaryService<super::ListImagesRequest>
+                    for ListImagesSvc<T> {
+                        type Response = super::ListImagesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListImagesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Image>::list_images(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListImagesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
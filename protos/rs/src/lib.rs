@@ -1,8 +1,15 @@
 pub mod admin;
+pub mod analytics;
+pub mod billing;
 pub mod client;
 pub mod coworking;
 pub mod image;
+pub mod image_worker;
 pub mod mail;
 pub mod notification;
 pub mod reservation;
 pub mod seat_lock;
+
+/// Encoded `FileDescriptorSet` of all the protos along with their imports,
+/// for gRPC reflection and tools which need the schema at runtime.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/descriptor.bin"));
@@ -8,6 +8,188 @@ pub struct ScheduleRequest {
     #[prost(message, required, tag = "3")]
     pub send_at: ::prost_types::Timestamp,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WaitlistEntryResponse {
+    #[prost(string, required, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub seat_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub seat_type: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "5")]
+    pub seat_capacity: ::core::option::Option<u64>,
+    #[prost(message, required, tag = "6")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "7")]
+    pub ends_at: ::prost_types::Timestamp,
+    #[prost(string, required, tag = "8")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "9")]
+    pub offered_seat_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "10")]
+    pub hold_expires_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WaitlistEntriesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<WaitlistEntryResponse>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JoinWaitlistRequest {
+    #[prost(string, required, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub seat_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub seat_type: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "4")]
+    pub seat_capacity: ::core::option::Option<u64>,
+    #[prost(message, required, tag = "5")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "6")]
+    pub ends_at: ::prost_types::Timestamp,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetWaitlistRequest {
+    #[prost(string, required, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(uint32, required, tag = "2")]
+    pub limit: u32,
+    #[prost(uint64, required, tag = "3")]
+    pub offset: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LeaveWaitlistRequest {
+    #[prost(string, required, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub client_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HoldRequest {
+    #[prost(string, required, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub seat_id: ::prost::alloc::string::String,
+    #[prost(message, required, tag = "3")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "4")]
+    pub ends_at: ::prost_types::Timestamp,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseSlotRequest {
+    #[prost(string, required, tag = "1")]
+    pub seat_id: ::prost::alloc::string::String,
+    #[prost(message, required, tag = "2")]
+    pub starts_at: ::prost_types::Timestamp,
+    #[prost(message, required, tag = "3")]
+    pub ends_at: ::prost_types::Timestamp,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckStrikesRequest {
+    #[prost(string, required, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateAnnouncementRequest {
+    #[prost(string, required, tag = "1")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub html: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub reply_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "5")]
+    pub attachments: ::prost::alloc::vec::Vec<super::mail::Attachment>,
+    #[prost(string, required, tag = "6")]
+    pub audience: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "7")]
+    pub day: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "8")]
+    pub month: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag = "9")]
+    pub year: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnnouncementRequest {
+    #[prost(string, required, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnnouncementResponse {
+    #[prost(string, required, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3")]
+    pub audience: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "4")]
+    pub day: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "5")]
+    pub month: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag = "6")]
+    pub year: ::core::option::Option<u64>,
+    #[prost(string, required, tag = "7")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(message, required, tag = "8")]
+    pub created_at: ::prost_types::Timestamp,
+    #[prost(message, optional, tag = "9")]
+    pub completed_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, required, tag = "10")]
+    pub total: u64,
+    #[prost(uint64, required, tag = "11")]
+    pub pending: u64,
+    #[prost(uint64, required, tag = "12")]
+    pub sent: u64,
+    #[prost(uint64, required, tag = "13")]
+    pub failed: u64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetAnnouncementsRequest {
+    #[prost(uint32, required, tag = "1")]
+    pub limit: u32,
+    #[prost(uint64, required, tag = "2")]
+    pub offset: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnnouncementsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub announcements: ::prost::alloc::vec::Vec<AnnouncementResponse>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAnnouncementRecipientsRequest {
+    #[prost(string, required, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(uint32, required, tag = "2")]
+    pub limit: u32,
+    #[prost(uint64, required, tag = "3")]
+    pub offset: u64,
+    #[prost(string, optional, tag = "4")]
+    pub status: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnnouncementRecipientResponse {
+    #[prost(string, required, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(uint32, required, tag = "4")]
+    pub attempts: u32,
+    #[prost(string, optional, tag = "5")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub sent_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnnouncementRecipientsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub recipients: ::prost::alloc::vec::Vec<AnnouncementRecipientResponse>,
+}
 /// Generated client implementations.
 pub mod notification_client {
     #![allow(
@@ -120,6 +302,264 @@ pub mod notification_client {
                 .insert(GrpcMethod::new("notification.Notification", "Schedule"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn join_waitlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JoinWaitlistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WaitlistEntryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/JoinWaitlist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "JoinWaitlist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_waitlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetWaitlistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WaitlistEntriesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/GetWaitlist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "GetWaitlist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn leave_waitlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LeaveWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/LeaveWaitlist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "LeaveWaitlist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn check_hold(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HoldRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/CheckHold",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "CheckHold"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn fulfill_hold(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HoldRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/FulfillHold",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "FulfillHold"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn release_slot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReleaseSlotRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/ReleaseSlot",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "ReleaseSlot"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn check_strikes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckStrikesRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/CheckStrikes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "CheckStrikes"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_announcement(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateAnnouncementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/CreateAnnouncement",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("notification.Notification", "CreateAnnouncement"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_announcement(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AnnouncementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/GetAnnouncement",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "GetAnnouncement"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_announcements(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAnnouncementsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/GetAnnouncements",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("notification.Notification", "GetAnnouncements"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_announcement_recipients(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAnnouncementRecipientsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementRecipientsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/GetAnnouncementRecipients",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "notification.Notification",
+                        "GetAnnouncementRecipients",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -139,6 +579,68 @@ pub mod notification_server {
             &self,
             request: tonic::Request<super::ScheduleRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn join_waitlist(
+            &self,
+            request: tonic::Request<super::JoinWaitlistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WaitlistEntryResponse>,
+            tonic::Status,
+        >;
+        async fn get_waitlist(
+            &self,
+            request: tonic::Request<super::GetWaitlistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WaitlistEntriesResponse>,
+            tonic::Status,
+        >;
+        async fn leave_waitlist(
+            &self,
+            request: tonic::Request<super::LeaveWaitlistRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn check_hold(
+            &self,
+            request: tonic::Request<super::HoldRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn fulfill_hold(
+            &self,
+            request: tonic::Request<super::HoldRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn release_slot(
+            &self,
+            request: tonic::Request<super::ReleaseSlotRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn check_strikes(
+            &self,
+            request: tonic::Request<super::CheckStrikesRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn create_announcement(
+            &self,
+            request: tonic::Request<super::CreateAnnouncementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementResponse>,
+            tonic::Status,
+        >;
+        async fn get_announcement(
+            &self,
+            request: tonic::Request<super::AnnouncementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementResponse>,
+            tonic::Status,
+        >;
+        async fn get_announcements(
+            &self,
+            request: tonic::Request<super::GetAnnouncementsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementsResponse>,
+            tonic::Status,
+        >;
+        async fn get_announcement_recipients(
+            &self,
+            request: tonic::Request<super::GetAnnouncementRecipientsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AnnouncementRecipientsResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct NotificationServer<T> {
@@ -261,6 +763,506 @@ pub mod notification_server {
                     };
                     Box::pin(fut)
                 }
+                "/notification.Notification/JoinWaitlist" => {
+                    #[allow(non_camel_case_types)]
+                    struct JoinWaitlistSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::JoinWaitlistRequest>
+                    for JoinWaitlistSvc<T> {
+                        type Response = super::WaitlistEntryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::JoinWaitlistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::join_waitlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = JoinWaitlistSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/GetWaitlist" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetWaitlistSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::GetWaitlistRequest>
+                    for GetWaitlistSvc<T> {
+                        type Response = super::WaitlistEntriesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetWaitlistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::get_waitlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetWaitlistSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/LeaveWaitlist" => {
+                    #[allow(non_camel_case_types)]
+                    struct LeaveWaitlistSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::LeaveWaitlistRequest>
+                    for LeaveWaitlistSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LeaveWaitlistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::leave_waitlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LeaveWaitlistSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/CheckHold" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckHoldSvc<T: Notification>(pub Arc<T>);
+                    impl<T: Notification> tonic::server::UnaryService<super::HoldRequest>
+                    for CheckHoldSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HoldRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::check_hold(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CheckHoldSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/FulfillHold" => {
+                    #[allow(non_camel_case_types)]
+                    struct FulfillHoldSvc<T: Notification>(pub Arc<T>);
+                    impl<T: Notification> tonic::server::UnaryService<super::HoldRequest>
+                    for FulfillHoldSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HoldRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::fulfill_hold(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FulfillHoldSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/ReleaseSlot" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReleaseSlotSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::ReleaseSlotRequest>
+                    for ReleaseSlotSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReleaseSlotRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::release_slot(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReleaseSlotSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/CheckStrikes" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckStrikesSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::CheckStrikesRequest>
+                    for CheckStrikesSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckStrikesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::check_strikes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CheckStrikesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/CreateAnnouncement" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateAnnouncementSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::CreateAnnouncementRequest>
+                    for CreateAnnouncementSvc<T> {
+                        type Response = super::AnnouncementResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateAnnouncementRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::create_announcement(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateAnnouncementSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/GetAnnouncement" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAnnouncementSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::AnnouncementRequest>
+                    for GetAnnouncementSvc<T> {
+                        type Response = super::AnnouncementResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AnnouncementRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::get_announcement(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAnnouncementSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/GetAnnouncements" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAnnouncementsSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<super::GetAnnouncementsRequest>
+                    for GetAnnouncementsSvc<T> {
+                        type Response = super::AnnouncementsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAnnouncementsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::get_announcements(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAnnouncementsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/GetAnnouncementRecipients" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAnnouncementRecipientsSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::UnaryService<
+                        super::GetAnnouncementRecipientsRequest,
+                    > for GetAnnouncementRecipientsSvc<T> {
+                        type Response = super::AnnouncementRecipientsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetAnnouncementRecipientsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::get_announcement_recipients(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAnnouncementRecipientsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
@@ -15,44 +15,42 @@ pub struct ReservationResponse {
     pub is_canceled: bool,
     #[prost(bool, required, tag = "7")]
     pub is_visited: bool,
+    #[prost(double, optional, tag = "8")]
+    pub price: ::core::option::Option<f64>,
+    #[prost(message, optional, tag = "9")]
+    pub checked_in_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "10")]
+    pub checked_out_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "11")]
+    pub canceled_at: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateRequest {
     #[prost(string, required, tag = "1")]
     pub client_id: ::prost::alloc::string::String,
-    #[prost(bool, required, tag = "2")]
-    pub is_admin: bool,
     #[prost(string, required, tag = "3")]
     pub seat_id: ::prost::alloc::string::String,
     #[prost(message, required, tag = "4")]
     pub ends_at: ::prost_types::Timestamp,
     #[prost(message, required, tag = "5")]
     pub starts_at: ::prost_types::Timestamp,
+    #[prost(double, optional, tag = "6")]
+    pub price: ::core::option::Option<f64>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetByIdRequest {
     #[prost(string, required, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    #[prost(string, required, tag = "2")]
-    pub client_id: ::prost::alloc::string::String,
-    #[prost(bool, required, tag = "3")]
-    pub is_admin: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBySeatRequest {
     #[prost(string, required, tag = "1")]
     pub seat_id: ::prost::alloc::string::String,
-    #[prost(string, required, tag = "2")]
-    pub client_id: ::prost::alloc::string::String,
-    #[prost(bool, required, tag = "3")]
-    pub is_admin: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetByClientRequest {
     #[prost(string, required, tag = "1")]
     pub client_id: ::prost::alloc::string::String,
-    #[prost(bool, required, tag = "2")]
-    pub is_admin: bool,
     #[prost(uint32, required, tag = "3")]
     pub limit: u32,
     #[prost(uint64, required, tag = "4")]
@@ -80,19 +78,11 @@ pub struct GetRequest {
 pub struct DeleteRequest {
     #[prost(string, required, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    #[prost(string, required, tag = "2")]
-    pub client_id: ::prost::alloc::string::String,
-    #[prost(bool, required, tag = "3")]
-    pub is_admin: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateRequest {
     #[prost(string, required, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    #[prost(string, required, tag = "2")]
-    pub client_id: ::prost::alloc::string::String,
-    #[prost(bool, required, tag = "3")]
-    pub is_admin: bool,
     #[prost(string, optional, tag = "4")]
     pub seat_id: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(message, optional, tag = "5")]
@@ -103,6 +93,24 @@ pub struct UpdateRequest {
     pub is_canceled: ::core::option::Option<bool>,
     #[prost(bool, optional, tag = "8")]
     pub is_visited: ::core::option::Option<bool>,
+    #[prost(double, optional, tag = "9")]
+    pub price: ::core::option::Option<f64>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetNoShowsRequest {
+    #[prost(message, required, tag = "1")]
+    pub started_before: ::prost_types::Timestamp,
+    #[prost(uint32, required, tag = "2")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendRequest {
+    #[prost(string, required, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, required, tag = "4")]
+    pub ends_at: ::prost_types::Timestamp,
+    #[prost(double, optional, tag = "5")]
+    pub price: ::core::option::Option<f64>,
 }
 /// Generated client implementations.
 pub mod reservation_client {
@@ -339,6 +347,30 @@ pub mod reservation_client {
                 .insert(GrpcMethod::new("reservation.Reservation", "GetVisited"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_no_shows(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNoShowsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReservationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.Reservation/GetNoShows",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.Reservation", "GetNoShows"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn update(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateRequest>,
@@ -426,6 +458,54 @@ pub mod reservation_client {
                 .insert(GrpcMethod::new("reservation.Reservation", "Delete"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn check_out(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReservationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.Reservation/CheckOut",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.Reservation", "CheckOut"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn extend(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExtendRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReservationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.Reservation/Extend",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.Reservation", "Extend"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -483,6 +563,13 @@ pub mod reservation_server {
             tonic::Response<super::ReservationsResponse>,
             tonic::Status,
         >;
+        async fn get_no_shows(
+            &self,
+            request: tonic::Request<super::GetNoShowsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReservationsResponse>,
+            tonic::Status,
+        >;
         async fn update(
             &self,
             request: tonic::Request<super::UpdateRequest>,
@@ -502,6 +589,20 @@ pub mod reservation_server {
             &self,
             request: tonic::Request<super::DeleteRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
+        async fn check_out(
+            &self,
+            request: tonic::Request<super::DeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReservationResponse>,
+            tonic::Status,
+        >;
+        async fn extend(
+            &self,
+            request: tonic::Request<super::ExtendRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReservationResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ReservationServer<T> {
@@ -845,6 +946,51 @@ pub mod reservation_server {
                     };
                     Box::pin(fut)
                 }
+                "/reservation.Reservation/GetNoShows" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNoShowsSvc<T: Reservation>(pub Arc<T>);
+                    impl<
+                        T: Reservation,
+                    > tonic::server::UnaryService<super::GetNoShowsRequest>
+                    for GetNoShowsSvc<T> {
+                        type Response = super::ReservationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNoShowsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Reservation>::get_no_shows(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetNoShowsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/reservation.Reservation/Update" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateSvc<T: Reservation>(pub Arc<T>);
@@ -1024,6 +1170,96 @@ pub mod reservation_server {
                     };
                     Box::pin(fut)
                 }
+                "/reservation.Reservation/CheckOut" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckOutSvc<T: Reservation>(pub Arc<T>);
+                    impl<
+                        T: Reservation,
+                    > tonic::server::UnaryService<super::DeleteRequest>
+                    for CheckOutSvc<T> {
+                        type Response = super::ReservationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Reservation>::check_out(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CheckOutSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.Reservation/Extend" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExtendSvc<T: Reservation>(pub Arc<T>);
+                    impl<
+                        T: Reservation,
+                    > tonic::server::UnaryService<super::ExtendRequest>
+                    for ExtendSvc<T> {
+                        type Response = super::ReservationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExtendRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Reservation>::extend(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExtendSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
@@ -5,6 +5,33 @@ pub struct SetLockRequest {
     pub user_id: ::prost::alloc::string::String,
     #[prost(string, required, tag = "2")]
     pub seat_index: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "3")]
+    pub ttl_seconds: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseLockRequest {
+    #[prost(string, required, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub seat_index: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BookingLockRequest {
+    #[prost(string, required, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub seat_index: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3")]
+    pub lock_id: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "4")]
+    pub ttl_seconds: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseBookingLockRequest {
+    #[prost(string, required, tag = "1")]
+    pub seat_index: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2")]
+    pub lock_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLockByUserIdRequest {
@@ -16,10 +43,16 @@ pub struct GetLockBySeatIndexRequest {
     #[prost(string, required, tag = "1")]
     pub seat_index: ::prost::alloc::string::String,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LockResponse {
     #[prost(bool, required, tag = "1")]
     pub status: bool,
+    #[prost(string, optional, tag = "2")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub seat_index: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "4")]
+    pub ttl_seconds: ::core::option::Option<u64>,
 }
 /// Generated client implementations.
 pub mod seat_lock_client {
@@ -115,7 +148,7 @@ pub mod seat_lock_client {
         pub async fn set_lock(
             &mut self,
             request: impl tonic::IntoRequest<super::SetLockRequest>,
-        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+        ) -> std::result::Result<tonic::Response<super::LockResponse>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -132,6 +165,27 @@ pub mod seat_lock_client {
             req.extensions_mut().insert(GrpcMethod::new("seatLock.SeatLock", "SetLock"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn release_lock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReleaseLockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/seatLock.SeatLock/ReleaseLock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("seatLock.SeatLock", "ReleaseLock"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_lock_by_user_id(
             &mut self,
             request: impl tonic::IntoRequest<super::GetLockByUserIdRequest>,
@@ -174,6 +228,48 @@ pub mod seat_lock_client {
                 .insert(GrpcMethod::new("seatLock.SeatLock", "GetLockBySeatIndex"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn lock_for_booking(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BookingLockRequest>,
+        ) -> std::result::Result<tonic::Response<super::LockResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/seatLock.SeatLock/LockForBooking",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("seatLock.SeatLock", "LockForBooking"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn release_booking_lock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReleaseBookingLockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/seatLock.SeatLock/ReleaseBookingLock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("seatLock.SeatLock", "ReleaseBookingLock"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -192,6 +288,10 @@ pub mod seat_lock_server {
         async fn set_lock(
             &self,
             request: tonic::Request<super::SetLockRequest>,
+        ) -> std::result::Result<tonic::Response<super::LockResponse>, tonic::Status>;
+        async fn release_lock(
+            &self,
+            request: tonic::Request<super::ReleaseLockRequest>,
         ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
         async fn get_lock_by_user_id(
             &self,
@@ -201,6 +301,14 @@ pub mod seat_lock_server {
             &self,
             request: tonic::Request<super::GetLockBySeatIndexRequest>,
         ) -> std::result::Result<tonic::Response<super::LockResponse>, tonic::Status>;
+        async fn lock_for_booking(
+            &self,
+            request: tonic::Request<super::BookingLockRequest>,
+        ) -> std::result::Result<tonic::Response<super::LockResponse>, tonic::Status>;
+        async fn release_booking_lock(
+            &self,
+            request: tonic::Request<super::ReleaseBookingLockRequest>,
+        ) -> std::result::Result<tonic::Response<()>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct SeatLockServer<T> {
@@ -283,7 +391,7 @@ pub mod seat_lock_server {
                     struct SetLockSvc<T: SeatLock>(pub Arc<T>);
                     impl<T: SeatLock> tonic::server::UnaryService<super::SetLockRequest>
                     for SetLockSvc<T> {
-                        type Response = ();
+                        type Response = super::LockResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -321,6 +429,51 @@ pub mod seat_lock_server {
                     };
                     Box::pin(fut)
                 }
+                "/seatLock.SeatLock/ReleaseLock" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReleaseLockSvc<T: SeatLock>(pub Arc<T>);
+                    impl<
+                        T: SeatLock,
+                    > tonic::server::UnaryService<super::ReleaseLockRequest>
+                    for ReleaseLockSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReleaseLockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SeatLock>::release_lock(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReleaseLockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/seatLock.SeatLock/GetLockByUserID" => {
                     #[allow(non_camel_case_types)]
                     struct GetLockByUserIDSvc<T: SeatLock>(pub Arc<T>);
@@ -412,6 +565,96 @@ pub mod seat_lock_server {
                     };
                     Box::pin(fut)
                 }
+                "/seatLock.SeatLock/LockForBooking" => {
+                    #[allow(non_camel_case_types)]
+                    struct LockForBookingSvc<T: SeatLock>(pub Arc<T>);
+                    impl<
+                        T: SeatLock,
+                    > tonic::server::UnaryService<super::BookingLockRequest>
+                    for LockForBookingSvc<T> {
+                        type Response = super::LockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BookingLockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SeatLock>::lock_for_booking(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LockForBookingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/seatLock.SeatLock/ReleaseBookingLock" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReleaseBookingLockSvc<T: SeatLock>(pub Arc<T>);
+                    impl<
+                        T: SeatLock,
+                    > tonic::server::UnaryService<super::ReleaseBookingLockRequest>
+                    for ReleaseBookingLockSvc<T> {
+                        type Response = ();
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReleaseBookingLockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SeatLock>::release_booking_lock(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReleaseBookingLockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
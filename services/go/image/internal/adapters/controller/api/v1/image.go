@@ -11,6 +11,7 @@ import (
 	"gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/dto"
 	imageServ "gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/service"
 	"gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/utils/dotenv"
+	"gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/utils/pointers"
 	"google.golang.org/grpc"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
@@ -19,9 +20,10 @@ import (
 )
 
 type imageService interface {
-	Upload(ctx context.Context, req *dto.ImageDTO) (*image.UploadImageResponse, error)
-	GetImage(ctx context.Context, req *image.GetImageRequest) (*image.GetImageResponse, error)
+	Upload(ctx context.Context, req *dto.ImageDTO, content io.Reader) (*image.UploadImageResponse, error)
+	GetImage(ctx context.Context, req *image.GetImageRequest) (*dto.ImageObject, error)
 	DeleteImage(ctx context.Context, req *image.DeleteImageRequest) (*empty.Empty, error)
+	ListImages(ctx context.Context, req *image.ListImagesRequest) (*image.ListImagesResponse, error)
 }
 
 type imageHandler struct {
@@ -29,6 +31,13 @@ type imageHandler struct {
 	imageService imageService
 }
 
+type uploadResult struct {
+	response *image.UploadImageResponse
+	err      error
+}
+
+const chunkSize = 32 * 1024 // Можно регулировать
+
 func NewImageHandler(app *app.App) *imageHandler {
 	return &imageHandler{
 		imageService: imageServ.NewImageService(minio.NewImageStorage(
@@ -41,30 +50,57 @@ func NewImageHandler(app *app.App) *imageHandler {
 	}
 }
 
+// UploadImage pipes the received chunks straight into the storage, so the image is never held in memory whole.
+// The first message must carry the metadata.
 func (h *imageHandler) UploadImage(stream grpc.ClientStreamingServer[image.UploadImageRequest, image.UploadImageResponse]) error {
-	var imageDTO dto.ImageDTO
+	first, err := stream.Recv()
+	if err != nil {
+		return status.Error(codes.Internal, err.Error())
+	}
+	metadata := first.GetMetadata()
+	if metadata == nil {
+		return status.Error(codes.InvalidArgument, "the first message must contain the image metadata")
+	}
+	imageDTO := dto.ImageDTO{
+		ID:          metadata.GetId(),
+		ContentType: metadata.GetContentType(),
+		ImageType:   metadata.GetImageType(),
+		Variant:     metadata.GetVariant(),
+	}
+
+	reader, writer := io.Pipe()
+	result := make(chan uploadResult, 1)
+	go func() {
+		resp, errUpload := h.imageService.Upload(stream.Context(), &imageDTO, reader)
+		// unblocks the writer if the storage gave up before reading everything
+		_ = reader.CloseWithError(errUpload)
+		result <- uploadResult{response: resp, err: errUpload}
+	}()
+
 	for {
 		data, err := stream.Recv()
 		if err == io.EOF {
-			resp, errUpload := h.imageService.Upload(stream.Context(), &imageDTO)
-			if errUpload != nil {
-				return status.Error(codes.Internal, errUpload.Error())
-			}
-			return stream.SendAndClose(resp)
+			_ = writer.Close()
+			break
 		}
 		if err != nil {
+			// the client went away mid-upload: abort instead of storing a truncated image
+			_ = writer.CloseWithError(err)
+			<-result
 			return status.Error(codes.Internal, err.Error())
 		}
 
-		switch data.Data.(type) {
-		case *image.UploadImageRequest_Metadata:
-			imageDTO.ID = *data.Data.(*image.UploadImageRequest_Metadata).Metadata.Id
-			imageDTO.ContentType = *data.Data.(*image.UploadImageRequest_Metadata).Metadata.ContentType
-			imageDTO.ImageType = *data.Data.(*image.UploadImageRequest_Metadata).Metadata.ImageType
-		case *image.UploadImageRequest_Content:
-			imageDTO.Content = append(imageDTO.Content, data.Data.(*image.UploadImageRequest_Content).Content...)
+		if _, err := writer.Write(data.GetContent()); err != nil {
+			// the upload itself failed, its error is reported below
+			break
 		}
 	}
+
+	res := <-result
+	if res.err != nil {
+		return status.Error(codes.Internal, res.err.Error())
+	}
+	return stream.SendAndClose(res.response)
 }
 
 func (h *imageHandler) GetImage(request *image.GetImageRequest, respStream grpc.ServerStreamingServer[image.GetImageResponse]) error {
@@ -75,38 +111,55 @@ func (h *imageHandler) GetImage(request *image.GetImageRequest, respStream grpc.
 		}
 		return status.Error(codes.Internal, err.Error())
 	}
+	defer func(content io.ReadCloser) {
+		_ = content.Close()
+	}(res.Content)
 
-	const chunkSize = 4096 // Можно регулировать
-	content := res.Content
-	totalBytes := len(content)
-
-	for offset := 0; offset < totalBytes; offset += chunkSize {
-		end := offset + chunkSize
-		if end > totalBytes {
-			end = totalBytes
+	// the content type goes along with the first chunk
+	contentType := pointers.String(res.ContentType)
+	for {
+		// a fresh buffer per chunk: grpc may still hold on to the previous message
+		buffer := make([]byte, chunkSize)
+		n, readErr := res.Content.Read(buffer)
+		if n > 0 {
+			if sendErr := respStream.Send(&image.GetImageResponse{
+				Content:     buffer[:n],
+				ContentType: contentType,
+			}); sendErr != nil {
+				return sendErr
+			}
+			contentType = nil
 		}
-
-		chunk := content[offset:end]
-
-		if sendErr := respStream.Send(&image.GetImageResponse{
-			Content: chunk,
-		}); sendErr != nil {
-			return sendErr
+		if readErr == io.EOF {
+			return nil
+		}
+		if readErr != nil {
+			return status.Error(codes.Internal, readErr.Error())
 		}
 	}
-
-	return nil
 }
 
 func (h *imageHandler) DeleteImage(ctx context.Context, request *image.DeleteImageRequest) (*emptypb.Empty, error) {
 	emptyMessage, err := h.imageService.DeleteImage(ctx, request)
 	if err != nil {
-		return nil, err
+		if errors.Is(err, errorz.NotFound) {
+			return nil, status.Error(codes.NotFound, err.Error())
+		}
+		return nil, status.Error(codes.Internal, err.Error())
 	}
 
 	return emptyMessage, nil
 }
 
+func (h *imageHandler) ListImages(ctx context.Context, request *image.ListImagesRequest) (*image.ListImagesResponse, error) {
+	response, err := h.imageService.ListImages(ctx, request)
+	if err != nil {
+		return nil, status.Error(codes.Internal, err.Error())
+	}
+
+	return response, nil
+}
+
 func (h *imageHandler) Setup(gRPCServer *grpc.Server) {
 	image.RegisterImageServer(gRPCServer, h)
 }
@@ -1,7 +1,6 @@
 package minio
 
 import (
-	"bytes"
 	"context"
 	"fmt"
 	"github.com/golang/protobuf/ptypes/empty"
@@ -11,6 +10,7 @@ import (
 	"gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/dto"
 	"gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/utils/pointers"
 	"io"
+	"strings"
 )
 
 type imageStorage struct {
@@ -31,24 +31,53 @@ func NewImageStorage(client *minio.Client, coworkingBucket, seatBucket, featureB
 	}
 }
 
-// TODO secure routes for avatars
-
-func (s *imageStorage) Upload(ctx context.Context, req *dto.ImageDTO) (*image.UploadImageResponse, error) {
-	var bucket string
-	switch req.ImageType {
+func (s *imageStorage) bucket(imageType image.ImageType) string {
+	switch imageType {
 	case image.ImageType_AVATAR:
-		bucket = s.avatarBucket
+		return s.avatarBucket
 	case image.ImageType_COWORKING:
-		bucket = s.coworkingBucket
+		return s.coworkingBucket
 	case image.ImageType_SEAT:
-		bucket = s.seatBucket
+		return s.seatBucket
 	case image.ImageType_FEATURE:
-		bucket = s.featureBucket
+		return s.featureBucket
+	}
+	return ""
+}
+
+func filenamePrefix(imageType image.ImageType) string {
+	return fmt.Sprintf("%s-", imageType.String())
+}
+
+// variantSuffix keeps the original under the plain id and the processed variants next to it.
+func variantSuffix(variant image.ImageVariant) string {
+	if variant == image.ImageVariant_ORIGINAL {
+		return ""
 	}
+	return "." + strings.ToLower(variant.String())
+}
 
-	filename := fmt.Sprintf("%s-%s", req.ImageType.String(), req.ID)
+func objectName(imageType image.ImageType, id string, variant image.ImageVariant) string {
+	return filenamePrefix(imageType) + id + variantSuffix(variant)
+}
 
-	_, err := s.client.PutObject(ctx, bucket, filename, bytes.NewReader(req.Content), -1, minio.PutObjectOptions{
+func isVariant(key string) bool {
+	for value := range image.ImageVariant_name {
+		variant := image.ImageVariant(value)
+		if variant != image.ImageVariant_ORIGINAL && strings.HasSuffix(key, variantSuffix(variant)) {
+			return true
+		}
+	}
+	return false
+}
+
+// TODO secure routes for avatars
+
+func (s *imageStorage) Upload(ctx context.Context, req *dto.ImageDTO, content io.Reader) (*image.UploadImageResponse, error) {
+	filename := objectName(req.ImageType, req.ID, req.Variant)
+
+	// size -1 makes minio stream the content in parts instead of reading it whole
+	_, err := s.client.PutObject(ctx, s.bucket(req.ImageType), filename, content, -1, minio.PutObjectOptions{
 		ContentType: req.ContentType,
 	})
 
@@ -59,69 +88,66 @@ func (s *imageStorage) Upload(ctx context.Context, req *dto.ImageDTO) (*image.Up
 	return &image.UploadImageResponse{Filename: pointers.String(filename)}, nil
 }
 
-func (s *imageStorage) GetImage(ctx context.Context, req *image.GetImageRequest) (*image.GetImageResponse, error) {
-	var bucket string
-	switch *req.ImageType {
-	case image.ImageType_AVATAR:
-		bucket = s.avatarBucket
-	case image.ImageType_COWORKING:
-		bucket = s.coworkingBucket
-	case image.ImageType_SEAT:
-		bucket = s.seatBucket
-	case image.ImageType_FEATURE:
-		bucket = s.featureBucket
-	}
-
-	filename := fmt.Sprintf("%s-%s", req.ImageType.String(), *req.Id)
+func (s *imageStorage) GetImage(ctx context.Context, req *image.GetImageRequest) (*dto.ImageObject, error) {
+	bucket := s.bucket(req.GetImageType())
+	filename := objectName(req.GetImageType(), req.GetId(), req.GetVariant())
 
-	_, err := s.client.StatObject(ctx, bucket, filename, minio.StatObjectOptions{})
+	info, err := s.client.StatObject(ctx, bucket, filename, minio.StatObjectOptions{})
 	if err != nil {
 		return nil, errorz.NotFound
 	}
 
 	object, err := s.client.GetObject(ctx, bucket, filename, minio.GetObjectOptions{})
-	defer func(object *minio.Object) {
-		_ = object.Close()
-	}(object)
-
-	if err != nil {
-		return nil, err
-	}
-
-	buffer, err := io.ReadAll(object)
 	if err != nil {
 		return nil, err
 	}
 
-	return &image.GetImageResponse{Content: buffer}, nil
+	return &dto.ImageObject{
+		ContentType: info.ContentType,
+		Size:        info.Size,
+		Content:     object,
+	}, nil
 }
 
 // TODO secure routes
 
 func (s *imageStorage) DeleteImage(ctx context.Context, req *image.DeleteImageRequest) (*empty.Empty, error) {
-	var bucket string
-	switch *req.ImageType {
-	case image.ImageType_AVATAR:
-		bucket = s.avatarBucket
-	case image.ImageType_COWORKING:
-		bucket = s.coworkingBucket
-	case image.ImageType_SEAT:
-		bucket = s.seatBucket
-	case image.ImageType_FEATURE:
-		bucket = s.featureBucket
-	}
-
-	filename := fmt.Sprintf("%s-%s", req.ImageType.String(), *req.Id)
+	bucket := s.bucket(req.GetImageType())
+	original := objectName(req.GetImageType(), req.GetId(), image.ImageVariant_ORIGINAL)
 
-	_, err := s.client.StatObject(ctx, bucket, filename, minio.StatObjectOptions{})
+	_, err := s.client.StatObject(ctx, bucket, original, minio.StatObjectOptions{})
 	if err != nil {
 		return nil, errorz.NotFound
 	}
 
-	err = s.client.RemoveObject(ctx, bucket, filename, minio.RemoveObjectOptions{})
-	if err != nil {
-		return nil, err
+	// removing a missing object is not an error, so images stored before processing existed are fine
+	for value := range image.ImageVariant_name {
+		variant := objectName(req.GetImageType(), req.GetId(), image.ImageVariant(value))
+		err = s.client.RemoveObject(ctx, bucket, variant, minio.RemoveObjectOptions{})
+		if err != nil {
+			return nil, err
+		}
 	}
 
 	return &empty.Empty{}, nil
 }
+
+func (s *imageStorage) ListImages(ctx context.Context, req *image.ListImagesRequest) (*image.ListImagesResponse, error) {
+	prefix := filenamePrefix(req.GetImageType())
+
+	ids := make([]string, 0)
+	for object := range s.client.ListObjects(ctx, s.bucket(req.GetImageType()), minio.ListObjectsOptions{
+		Prefix:    prefix + req.GetPrefix(),
+		Recursive: true,
+	}) {
+		if object.Err != nil {
+			return nil, object.Err
+		}
+		if isVariant(object.Key) {
+			continue
+		}
+		ids = append(ids, strings.TrimPrefix(object.Key, prefix))
+	}
+
+	return &image.ListImagesResponse{Ids: ids}, nil
+}
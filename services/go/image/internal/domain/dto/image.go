@@ -1,10 +1,20 @@
 package dto
 
-import image "gitlab.com/drop-table-prod/backend/protos/go/image"
+import (
+	image "gitlab.com/drop-table-prod/backend/protos/go/image"
+	"io"
+)
 
 type ImageDTO struct {
 	ID          string
 	ContentType string
 	ImageType   image.ImageType
-	Content     []byte
+	Variant     image.ImageVariant
+}
+
+// ImageObject is a stored image opened for reading.
+type ImageObject struct {
+	ContentType string
+	Size        int64
+	Content     io.ReadCloser
 }
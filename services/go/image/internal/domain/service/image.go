@@ -5,12 +5,14 @@ import (
 	"github.com/golang/protobuf/ptypes/empty"
 	image "gitlab.com/drop-table-prod/backend/protos/go/image"
 	"gitlab.com/drop-table-prod/backend/services/go/image/internal/domain/dto"
+	"io"
 )
 
 type ImageStorage interface {
-	Upload(ctx context.Context, req *dto.ImageDTO) (*image.UploadImageResponse, error)
-	GetImage(ctx context.Context, req *image.GetImageRequest) (*image.GetImageResponse, error)
+	Upload(ctx context.Context, req *dto.ImageDTO, content io.Reader) (*image.UploadImageResponse, error)
+	GetImage(ctx context.Context, req *image.GetImageRequest) (*dto.ImageObject, error)
 	DeleteImage(ctx context.Context, req *image.DeleteImageRequest) (*empty.Empty, error)
+	ListImages(ctx context.Context, req *image.ListImagesRequest) (*image.ListImagesResponse, error)
 }
 
 type imageService struct {
@@ -23,14 +25,18 @@ func NewImageService(storage ImageStorage) *imageService {
 	}
 }
 
-func (s *imageService) Upload(ctx context.Context, req *dto.ImageDTO) (*image.UploadImageResponse, error) {
-	return s.storage.Upload(ctx, req)
+func (s *imageService) Upload(ctx context.Context, req *dto.ImageDTO, content io.Reader) (*image.UploadImageResponse, error) {
+	return s.storage.Upload(ctx, req, content)
 }
 
-func (s *imageService) GetImage(ctx context.Context, req *image.GetImageRequest) (*image.GetImageResponse, error) {
+func (s *imageService) GetImage(ctx context.Context, req *image.GetImageRequest) (*dto.ImageObject, error) {
 	return s.storage.GetImage(ctx, req)
 }
 
 func (s *imageService) DeleteImage(ctx context.Context, req *image.DeleteImageRequest) (*empty.Empty, error) {
 	return s.storage.DeleteImage(ctx, req)
 }
+
+func (s *imageService) ListImages(ctx context.Context, req *image.ListImagesRequest) (*image.ListImagesResponse, error) {
+	return s.storage.ListImages(ctx, req)
+}
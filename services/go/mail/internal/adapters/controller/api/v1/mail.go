@@ -2,6 +2,7 @@ package v1
 
 import (
 	"context"
+	"errors"
 	mail "gitlab.com/drop-table-prod/backend/protos/go/mail"
 	"gitlab.com/drop-table-prod/backend/services/go/mail/cmd/app"
 	mailServ "gitlab.com/drop-table-prod/backend/services/go/mail/internal/domain/service"
@@ -12,11 +13,11 @@ import (
 )
 
 type MailService interface {
-	SendEmail(to []string, topic, body string) error
+	SendEmail(message mailServ.Message) error
 }
 
 type mailHandler struct {
-	mail.UnimplementedMailServiceServer
+	mail.UnimplementedMailServer
 	mailservice MailService
 }
 
@@ -26,13 +27,44 @@ func NewMailHandler(app *app.App) *mailHandler {
 	}
 }
 
-func (h *mailHandler) SendMail(ctx context.Context, req *mail.SendRequest) (*emptypb.Empty, error) {
-	if err := h.mailservice.SendEmail(req.To, *req.Subject, *req.Body); err != nil {
+func (h *mailHandler) Send(ctx context.Context, req *mail.SendRequest) (*emptypb.Empty, error) {
+	attachments := make([]mailServ.Attachment, 0, len(req.Attachments))
+	for _, attachment := range req.Attachments {
+		attachments = append(attachments, mailServ.Attachment{
+			Filename:    attachment.GetFilename(),
+			ContentType: attachment.GetContentType(),
+			Content:     attachment.GetContent(),
+		})
+	}
+
+	headers := make([]mailServ.Header, 0, len(req.Headers))
+	for _, header := range req.Headers {
+		headers = append(headers, mailServ.Header{
+			Name:  header.GetName(),
+			Value: header.GetValue(),
+		})
+	}
+
+	err := h.mailservice.SendEmail(mailServ.Message{
+		To:          req.To,
+		Cc:          req.Cc,
+		Bcc:         req.Bcc,
+		ReplyTo:     req.GetReplyTo(),
+		Subject:     req.GetSubject(),
+		Text:        req.GetBody(),
+		HTML:        req.GetHtml(),
+		Headers:     headers,
+		Attachments: attachments,
+	})
+	if errors.Is(err, mailServ.ErrInvalidMessage) {
+		return nil, status.Error(codes.InvalidArgument, err.Error())
+	}
+	if err != nil {
 		return nil, status.Error(codes.Internal, err.Error())
 	}
 	return &emptypb.Empty{}, nil
 }
 
 func (h *mailHandler) Setup(gRPCServer *grpc.Server) {
-	mail.RegisterMailServiceServer(gRPCServer, h)
+	mail.RegisterMailServer(gRPCServer, h)
 }
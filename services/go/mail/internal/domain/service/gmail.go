@@ -1,11 +1,67 @@
 package service
 
 import (
+	"bytes"
+	"encoding/base64"
+	"errors"
 	"fmt"
 	"gitlab.com/drop-table-prod/backend/services/go/mail/internal/domain/utils/dotenv"
+	"io"
+	"mime"
+	"mime/multipart"
+	"net/mail"
 	"net/smtp"
+	"net/textproto"
+	"strings"
+	"time"
 )
 
+// base64LineLength ограничивает длину строк в base64-частях письма (RFC 2045)
+const base64LineLength = 76
+
+// ErrInvalidMessage возвращается, если письмо нельзя отправить в таком виде
+var ErrInvalidMessage = errors.New("invalid message")
+
+// reservedHeaders задаются самим сервисом и не могут быть переопределены
+var reservedHeaders = map[string]bool{
+	"From":                      true,
+	"To":                        true,
+	"Cc":                        true,
+	"Bcc":                       true,
+	"Reply-To":                  true,
+	"Subject":                   true,
+	"Date":                      true,
+	"Mime-Version":              true,
+	"Content-Type":              true,
+	"Content-Transfer-Encoding": true,
+}
+
+// Attachment представляет файл, прикрепленный к письму
+type Attachment struct {
+	Filename    string
+	ContentType string
+	Content     []byte
+}
+
+// Header представляет дополнительный заголовок письма
+type Header struct {
+	Name  string
+	Value string
+}
+
+// Message представляет письмо с текстовой и HTML-версиями и вложениями
+type Message struct {
+	To          []string
+	Cc          []string
+	Bcc         []string
+	ReplyTo     string
+	Subject     string
+	Text        string
+	HTML        string
+	Headers     []Header
+	Attachments []Attachment
+}
+
 // mailService представляет сервис для отправки писем
 type mailService struct {
 	from     string
@@ -24,16 +80,35 @@ func NewMailService(from, password, smtpHost, smtpPort string) mailService {
 	}
 }
 
-// SendEmail отправляет письмо на указанный адрес
-func (s mailService) SendEmail(to []string, topic, body string) error {
+// SendEmail отправляет письмо всем получателям, включая копии и скрытые копии
+func (s mailService) SendEmail(message Message) error {
+	recipients := append(append(append([]string{}, message.To...), message.Cc...), message.Bcc...)
+	if len(recipients) == 0 {
+		return fmt.Errorf("%w: no recipients", ErrInvalidMessage)
+	}
+	for _, address := range append(recipients, message.ReplyTo) {
+		if address == "" {
+			continue
+		}
+		if _, err := mail.ParseAddress(address); err != nil {
+			return fmt.Errorf("%w: bad address %q", ErrInvalidMessage, address)
+		}
+	}
+
 	// Формируем сообщение с правильными заголовками и телом
-	message := fmt.Sprintf("To: %s\r\nSubject: %s\r\n\r\n%s", to[0], topic, body)
+	data, err := s.buildMessage(message)
+	if err != nil {
+		return err
+	}
 
-	// Аутентификация
-	auth := smtp.PlainAuth("", s.from, s.password, s.smtpHost)
+	// Аутентификация; локальные SMTP-заглушки работают без пароля
+	var auth smtp.Auth
+	if s.password != "" {
+		auth = smtp.PlainAuth("", s.from, s.password, s.smtpHost)
+	}
 
 	// Отправка письма
-	err := smtp.SendMail(s.smtpHost+":"+s.smtpPort, auth, s.from, to, []byte(message))
+	err = smtp.SendMail(s.smtpHost+":"+s.smtpPort, auth, s.from, recipients, data)
 	if err != nil {
 		return fmt.Errorf("failed to send email: %w", err)
 	}
@@ -41,6 +116,162 @@ func (s mailService) SendEmail(to []string, topic, body string) error {
 	return nil
 }
 
+// buildMessage собирает письмо: text/plain и text/html объединяются в multipart/alternative,
+// а при наличии вложений все оборачивается в multipart/mixed
+func (s mailService) buildMessage(message Message) ([]byte, error) {
+	var buf bytes.Buffer
+
+	header := textproto.MIMEHeader{}
+	header.Set("From", s.from)
+	if len(message.To) > 0 {
+		header.Set("To", strings.Join(message.To, ", "))
+	}
+	if len(message.Cc) > 0 {
+		header.Set("Cc", strings.Join(message.Cc, ", "))
+	}
+	if message.ReplyTo != "" {
+		header.Set("Reply-To", message.ReplyTo)
+	}
+	header.Set("Subject", mime.BEncoding.Encode("UTF-8", message.Subject))
+	header.Set("Date", time.Now().Format(time.RFC1123Z))
+	header.Set("MIME-Version", "1.0")
+	for _, h := range message.Headers {
+		name := textproto.CanonicalMIMEHeaderKey(h.Name)
+		if name == "" || reservedHeaders[name] || strings.ContainsAny(name+h.Value, "\r\n") {
+			return nil, fmt.Errorf("%w: header %q is not allowed", ErrInvalidMessage, h.Name)
+		}
+		header.Add(name, mime.QEncoding.Encode("UTF-8", h.Value))
+	}
+
+	bodyHeader, body, err := buildBody(message)
+	if err != nil {
+		return nil, err
+	}
+
+	if len(message.Attachments) == 0 {
+		for name, values := range bodyHeader {
+			header[name] = values
+		}
+		writeHeader(&buf, header)
+		buf.Write(body)
+		return buf.Bytes(), nil
+	}
+
+	writer := multipart.NewWriter(&buf)
+	header.Set("Content-Type", mime.FormatMediaType("multipart/mixed", map[string]string{"boundary": writer.Boundary()}))
+	writeHeader(&buf, header)
+
+	part, err := writer.CreatePart(bodyHeader)
+	if err != nil {
+		return nil, err
+	}
+	if _, err := part.Write(body); err != nil {
+		return nil, err
+	}
+
+	for _, attachment := range message.Attachments {
+		contentType := attachment.ContentType
+		if contentType == "" {
+			contentType = "application/octet-stream"
+		}
+
+		header := textproto.MIMEHeader{}
+		header.Set("Content-Type", withParam(contentType, "name", attachment.Filename))
+		header.Set("Content-Disposition", mime.FormatMediaType("attachment", map[string]string{"filename": attachment.Filename}))
+		header.Set("Content-Transfer-Encoding", "base64")
+		part, err := writer.CreatePart(header)
+		if err != nil {
+			return nil, err
+		}
+		if err := writeBase64(part, attachment.Content); err != nil {
+			return nil, err
+		}
+	}
+
+	if err := writer.Close(); err != nil {
+		return nil, err
+	}
+
+	return buf.Bytes(), nil
+}
+
+// buildBody возвращает заголовки и тело письма: текст, HTML или обе версии
+func buildBody(message Message) (textproto.MIMEHeader, []byte, error) {
+	var buf bytes.Buffer
+	header := textproto.MIMEHeader{}
+
+	if message.HTML == "" || message.Text == "" {
+		contentType, content := "text/plain; charset=UTF-8", message.Text
+		if message.HTML != "" {
+			contentType, content = "text/html; charset=UTF-8", message.HTML
+		}
+		header.Set("Content-Type", contentType)
+		header.Set("Content-Transfer-Encoding", "base64")
+		if err := writeBase64(&buf, []byte(content)); err != nil {
+			return nil, nil, err
+		}
+		return header, buf.Bytes(), nil
+	}
+
+	writer := multipart.NewWriter(&buf)
+	header.Set("Content-Type", mime.FormatMediaType("multipart/alternative", map[string]string{"boundary": writer.Boundary()}))
+
+	// Текстовая версия идет первой: клиенты показывают последнюю поддерживаемую
+	for _, alternative := range []struct{ contentType, content string }{
+		{"text/plain; charset=UTF-8", message.Text},
+		{"text/html; charset=UTF-8", message.HTML},
+	} {
+		partHeader := textproto.MIMEHeader{}
+		partHeader.Set("Content-Type", alternative.contentType)
+		partHeader.Set("Content-Transfer-Encoding", "base64")
+		part, err := writer.CreatePart(partHeader)
+		if err != nil {
+			return nil, nil, err
+		}
+		if err := writeBase64(part, []byte(alternative.content)); err != nil {
+			return nil, nil, err
+		}
+	}
+	if err := writer.Close(); err != nil {
+		return nil, nil, err
+	}
+
+	return header, buf.Bytes(), nil
+}
+
+// writeHeader записывает заголовки и отделяющую их от тела пустую строку
+func writeHeader(w io.Writer, header textproto.MIMEHeader) {
+	for name, values := range header {
+		for _, value := range values {
+			fmt.Fprintf(w, "%s: %s\r\n", name, value)
+		}
+	}
+	fmt.Fprintf(w, "\r\n")
+}
+
+// writeBase64 записывает содержимое в base64 с переносом строк
+func writeBase64(w io.Writer, content []byte) error {
+	encoded := base64.StdEncoding.EncodeToString(content)
+	for len(encoded) > base64LineLength {
+		if _, err := fmt.Fprintf(w, "%s\r\n", encoded[:base64LineLength]); err != nil {
+			return err
+		}
+		encoded = encoded[base64LineLength:]
+	}
+	_, err := fmt.Fprintf(w, "%s\r\n", encoded)
+	return err
+}
+
+// withParam добавляет параметр к типу содержимого, сохраняя уже указанные
+func withParam(contentType, key, value string) string {
+	mediaType, params, err := mime.ParseMediaType(contentType)
+	if err != nil {
+		return mime.FormatMediaType("application/octet-stream", map[string]string{key: value})
+	}
+	params[key] = value
+	return mime.FormatMediaType(mediaType, params)
+}
+
 func NewMailServiceFromEnv() mailService {
 	from := dotenv.GetEnv("MAIL_EMAIL", "")
 	password := dotenv.GetEnv("MAIL_PASSWORD", "")
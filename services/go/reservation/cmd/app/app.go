@@ -4,6 +4,7 @@ import (
 	"context"
 	"fmt"
 	"gitlab.com/drop-table-prod/backend/libs/go/compression"
+	"gitlab.com/drop-table-prod/backend/libs/go/principal"
 	"net"
 
 	"github.com/grpc-ecosystem/go-grpc-middleware/v2/interceptors/logging"
@@ -65,6 +66,7 @@ func New(config *config.Config) *App {
 		grpc.ChainUnaryInterceptor(
 			recovery.UnaryServerInterceptor(recoveryOpts...),
 			logging.UnaryServerInterceptor(InterceptorLogger(logger.Log.SugaredLogger), loggingOpts...),
+			principal.UnaryServerInterceptor(),
 		),
 	)
 
@@ -3,10 +3,12 @@ package v1
 import (
 	"context"
 	"gitlab.com/drop-table-prod/backend/libs/go/errorz"
+	"gitlab.com/drop-table-prod/backend/libs/go/principal"
 	coworking "gitlab.com/drop-table-prod/backend/protos/go/coworking"
 	reservation "gitlab.com/drop-table-prod/backend/protos/go/reservation"
 	"gitlab.com/drop-table-prod/backend/services/go/reservation/cmd/app"
 	"gitlab.com/drop-table-prod/backend/services/go/reservation/internal/adapters/database/postgres"
+	"gitlab.com/drop-table-prod/backend/services/go/reservation/internal/adapters/logger"
 	"gitlab.com/drop-table-prod/backend/services/go/reservation/internal/domain/entity"
 	"gitlab.com/drop-table-prod/backend/services/go/reservation/internal/domain/service"
 	coworkingUtils "gitlab.com/drop-table-prod/backend/services/go/reservation/internal/domain/utils/coworking"
@@ -28,9 +30,13 @@ type ReservationService interface {
 	GetBySeat(ctx context.Context, seatID string) ([]entity.Reservation, error)
 	GetByDate(ctx context.Context, date time.Time) ([]entity.Reservation, error)
 	GetByVisitByDate(ctx context.Context, date time.Time) ([]entity.Reservation, error)
+	GetNoShows(ctx context.Context, startedBefore time.Time, limit int) ([]entity.Reservation, error)
 	Update(ctx context.Context, user *entity.Reservation) (*entity.Reservation, error)
 	Cancel(ctx context.Context, id string) error
 	Visit(ctx context.Context, id string) error
+	CheckOut(ctx context.Context, id string) (*entity.Reservation, error)
+	CheckOutExpired(ctx context.Context) (int64, error)
+	Extend(ctx context.Context, id string, endsAt time.Time, price *float64) (*entity.Reservation, error)
 	Delete(ctx context.Context, id string) error
 	Exists(ctx context.Context, id string) bool
 }
@@ -57,6 +63,10 @@ func (h *reservationHandler) Create(ctx context.Context, req *reservation.Create
 	var err error
 	var reservationEntity *entity.Reservation
 	var cond bool
+	if !principal.CanAccess(ctx, *req.ClientId) {
+		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
+	}
+
 	cond, err = coworkingUtils.IsSeatExist(ctx, *req.SeatId, h.GRPCClient)
 	if err != nil {
 		return nil, status.Error(codes.Internal, errorz.Forbidden.Error())
@@ -69,13 +79,17 @@ func (h *reservationHandler) Create(ctx context.Context, req *reservation.Create
 		return nil, err
 	}
 	return &reservation.ReservationResponse{
-		Id:         &reservationEntity.ID,
-		ClientId:   &reservationEntity.ClientID,
-		SeatId:     &reservationEntity.SeatID,
-		StartsAt:   timestamppb.New(reservationEntity.EndsAt),
-		EndsAt:     timestamppb.New(reservationEntity.EndsAt),
-		IsCanceled: &reservationEntity.IsCanceled,
-		IsVisited:  &reservationEntity.IsVisited,
+		Id:           &reservationEntity.ID,
+		ClientId:     &reservationEntity.ClientID,
+		SeatId:       &reservationEntity.SeatID,
+		StartsAt:     timestamppb.New(reservationEntity.StartsAt),
+		EndsAt:       timestamppb.New(reservationEntity.EndsAt),
+		IsCanceled:   &reservationEntity.IsCanceled,
+		IsVisited:    &reservationEntity.IsVisited,
+		Price:        reservationEntity.Price,
+		CheckedInAt:  optionalTimestamp(reservationEntity.CheckedInAt),
+		CheckedOutAt: optionalTimestamp(reservationEntity.CheckedOutAt),
+		CanceledAt:   optionalTimestamp(reservationEntity.CanceledAt),
 	}, nil
 }
 
@@ -86,19 +100,23 @@ func (h *reservationHandler) GetByID(ctx context.Context, req *reservation.GetBy
 		return nil, err
 	}
 
-	if !*req.IsAdmin && reservationEntity.ClientID != *req.ClientId {
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
 		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
 
 	}
 
 	return &reservation.ReservationResponse{
-		Id:         &reservationEntity.ID,
-		ClientId:   &reservationEntity.ClientID,
-		SeatId:     &reservationEntity.SeatID,
-		StartsAt:   timestamppb.New(reservationEntity.StartsAt),
-		EndsAt:     timestamppb.New(reservationEntity.EndsAt),
-		IsCanceled: &reservationEntity.IsCanceled,
-		IsVisited:  &reservationEntity.IsVisited,
+		Id:           &reservationEntity.ID,
+		ClientId:     &reservationEntity.ClientID,
+		SeatId:       &reservationEntity.SeatID,
+		StartsAt:     timestamppb.New(reservationEntity.StartsAt),
+		EndsAt:       timestamppb.New(reservationEntity.EndsAt),
+		IsCanceled:   &reservationEntity.IsCanceled,
+		IsVisited:    &reservationEntity.IsVisited,
+		Price:        reservationEntity.Price,
+		CheckedInAt:  optionalTimestamp(reservationEntity.CheckedInAt),
+		CheckedOutAt: optionalTimestamp(reservationEntity.CheckedOutAt),
+		CanceledAt:   optionalTimestamp(reservationEntity.CanceledAt),
 	}, nil
 }
 
@@ -118,13 +136,13 @@ func (h *reservationHandler) Update(ctx context.Context, req *reservation.Update
 		return nil, err
 	}
 
-	if !*req.IsAdmin && reservationEntity.ClientID != *req.ClientId {
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
 		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
 	}
 
 	reservEntity := entity.Reservation{
 		ID:       *req.Id,
-		ClientID: *req.ClientId,
+		ClientID: reservationEntity.ClientID,
 	}
 	if req.SeatId != nil {
 		reservEntity.SeatID = *req.SeatId
@@ -151,19 +169,40 @@ func (h *reservationHandler) Update(ctx context.Context, req *reservation.Update
 	} else {
 		reservEntity.IsVisited = reservationEntity.IsVisited
 	}
+	// the price is only requoted when the reservation is moved or resized
+	if req.Price != nil {
+		reservEntity.Price = req.Price
+	} else {
+		reservEntity.Price = reservationEntity.Price
+	}
+	reservEntity.CheckedInAt = reservationEntity.CheckedInAt
+	reservEntity.CheckedOutAt = reservationEntity.CheckedOutAt
+	reservEntity.CanceledAt = reservationEntity.CanceledAt
+	if reservEntity.IsCanceled && reservEntity.CanceledAt == nil {
+		now := time.Now()
+		reservEntity.CanceledAt = &now
+	}
+	if reservEntity.IsVisited && reservEntity.CheckedInAt == nil {
+		now := time.Now()
+		reservEntity.CheckedInAt = &now
+	}
 
 	if reservationEntity, err = h.reservationService.Update(ctx, &reservEntity); err != nil {
 		return nil, err
 	}
 
 	return &reservation.ReservationResponse{
-		Id:         &reservationEntity.ID,
-		ClientId:   &reservationEntity.ClientID,
-		SeatId:     &reservationEntity.SeatID,
-		StartsAt:   timestamppb.New(reservationEntity.StartsAt),
-		EndsAt:     timestamppb.New(reservationEntity.EndsAt),
-		IsCanceled: &reservationEntity.IsCanceled,
-		IsVisited:  &reservationEntity.IsVisited,
+		Id:           &reservationEntity.ID,
+		ClientId:     &reservationEntity.ClientID,
+		SeatId:       &reservationEntity.SeatID,
+		StartsAt:     timestamppb.New(reservationEntity.StartsAt),
+		EndsAt:       timestamppb.New(reservationEntity.EndsAt),
+		IsCanceled:   &reservationEntity.IsCanceled,
+		IsVisited:    &reservationEntity.IsVisited,
+		Price:        reservationEntity.Price,
+		CheckedInAt:  optionalTimestamp(reservationEntity.CheckedInAt),
+		CheckedOutAt: optionalTimestamp(reservationEntity.CheckedOutAt),
+		CanceledAt:   optionalTimestamp(reservationEntity.CanceledAt),
 	}, nil
 }
 
@@ -174,7 +213,7 @@ func (h *reservationHandler) Delete(ctx context.Context, req *reservation.Delete
 		return nil, err
 	}
 
-	if !*req.IsAdmin && reservationEntity.ClientID != *req.ClientId {
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
 		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
 	}
 
@@ -192,7 +231,7 @@ func (h *reservationHandler) Cancel(ctx context.Context, req *reservation.Delete
 		return nil, err
 	}
 
-	if !*req.IsAdmin && reservationEntity.ClientID != *req.ClientId {
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
 		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
 	}
 
@@ -210,7 +249,7 @@ func (h *reservationHandler) Visit(ctx context.Context, req *reservation.DeleteR
 		return nil, err
 	}
 
-	if !*req.IsAdmin && reservationEntity.ClientID != *req.ClientId {
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
 		return nil, status.Error(codes.Internal, errorz.Forbidden.Error())
 	}
 
@@ -221,9 +260,73 @@ func (h *reservationHandler) Visit(ctx context.Context, req *reservation.DeleteR
 	return nil, nil
 }
 
+func (h *reservationHandler) CheckOut(ctx context.Context, req *reservation.DeleteRequest) (*reservation.ReservationResponse, error) {
+	var err error
+	var reservationEntity *entity.Reservation
+	if reservationEntity, err = h.reservationService.GetByID(ctx, *req.Id); err != nil {
+		return nil, err
+	}
+
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
+		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
+	}
+
+	if reservationEntity, err = h.reservationService.CheckOut(ctx, *req.Id); err != nil {
+		return nil, err
+	}
+
+	return &reservation.ReservationResponse{
+		Id:           &reservationEntity.ID,
+		ClientId:     &reservationEntity.ClientID,
+		SeatId:       &reservationEntity.SeatID,
+		StartsAt:     timestamppb.New(reservationEntity.StartsAt),
+		EndsAt:       timestamppb.New(reservationEntity.EndsAt),
+		IsCanceled:   &reservationEntity.IsCanceled,
+		IsVisited:    &reservationEntity.IsVisited,
+		Price:        reservationEntity.Price,
+		CheckedInAt:  optionalTimestamp(reservationEntity.CheckedInAt),
+		CheckedOutAt: optionalTimestamp(reservationEntity.CheckedOutAt),
+		CanceledAt:   optionalTimestamp(reservationEntity.CanceledAt),
+	}, nil
+}
+
+func (h *reservationHandler) Extend(ctx context.Context, req *reservation.ExtendRequest) (*reservation.ReservationResponse, error) {
+	var err error
+	var reservationEntity *entity.Reservation
+	if reservationEntity, err = h.reservationService.GetByID(ctx, *req.Id); err != nil {
+		return nil, err
+	}
+
+	if !principal.CanAccess(ctx, reservationEntity.ClientID) {
+		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
+	}
+
+	if reservationEntity, err = h.reservationService.Extend(ctx, *req.Id, req.EndsAt.AsTime(), req.Price); err != nil {
+		return nil, err
+	}
+
+	return &reservation.ReservationResponse{
+		Id:           &reservationEntity.ID,
+		ClientId:     &reservationEntity.ClientID,
+		SeatId:       &reservationEntity.SeatID,
+		StartsAt:     timestamppb.New(reservationEntity.StartsAt),
+		EndsAt:       timestamppb.New(reservationEntity.EndsAt),
+		IsCanceled:   &reservationEntity.IsCanceled,
+		IsVisited:    &reservationEntity.IsVisited,
+		Price:        reservationEntity.Price,
+		CheckedInAt:  optionalTimestamp(reservationEntity.CheckedInAt),
+		CheckedOutAt: optionalTimestamp(reservationEntity.CheckedOutAt),
+		CanceledAt:   optionalTimestamp(reservationEntity.CanceledAt),
+	}, nil
+}
+
 func (h *reservationHandler) GetByClient(ctx context.Context, req *reservation.GetByClientRequest) (*reservation.ReservationsResponse, error) {
 	var err error
 	var reservEntities []entity.Reservation
+	if !principal.CanAccess(ctx, *req.ClientId) {
+		return nil, status.Error(codes.PermissionDenied, errorz.Forbidden.Error())
+	}
+
 	if reservEntities, err = h.reservationService.GetAllByClient(ctx, *req.ClientId, int(*req.Limit), int(*req.Offset)); err != nil {
 		return nil, err
 	}
@@ -232,12 +335,16 @@ func (h *reservationHandler) GetByClient(ctx context.Context, req *reservation.G
 	for _, reservEntity := range reservEntities {
 
 		response = append(response, &reservation.ReservationResponse{
-			Id:         &reservEntity.ID,
-			ClientId:   &reservEntity.ClientID,
-			SeatId:     &reservEntity.SeatID,
-			StartsAt:   timestamppb.New(reservEntity.StartsAt),
-			EndsAt:     timestamppb.New(reservEntity.EndsAt),
-			IsCanceled: &reservEntity.IsCanceled,
+			Id:           &reservEntity.ID,
+			ClientId:     &reservEntity.ClientID,
+			SeatId:       &reservEntity.SeatID,
+			StartsAt:     timestamppb.New(reservEntity.StartsAt),
+			EndsAt:       timestamppb.New(reservEntity.EndsAt),
+			IsCanceled:   &reservEntity.IsCanceled,
+			Price:        reservEntity.Price,
+			CheckedInAt:  optionalTimestamp(reservEntity.CheckedInAt),
+			CheckedOutAt: optionalTimestamp(reservEntity.CheckedOutAt),
+			CanceledAt:   optionalTimestamp(reservEntity.CanceledAt),
 		})
 	}
 	return &reservation.ReservationsResponse{
@@ -255,13 +362,17 @@ func (h *reservationHandler) GetBySeat(ctx context.Context, req *reservation.Get
 	var response []*reservation.ReservationResponse
 	for _, reservEntity := range reservEntities {
 		response = append(response, &reservation.ReservationResponse{
-			Id:         &reservEntity.ID,
-			ClientId:   &reservEntity.ClientID,
-			SeatId:     &reservEntity.SeatID,
-			StartsAt:   timestamppb.New(reservEntity.StartsAt),
-			EndsAt:     timestamppb.New(reservEntity.EndsAt),
-			IsCanceled: &reservEntity.IsCanceled,
-			IsVisited:  &reservEntity.IsVisited,
+			Id:           &reservEntity.ID,
+			ClientId:     &reservEntity.ClientID,
+			SeatId:       &reservEntity.SeatID,
+			StartsAt:     timestamppb.New(reservEntity.StartsAt),
+			EndsAt:       timestamppb.New(reservEntity.EndsAt),
+			IsCanceled:   &reservEntity.IsCanceled,
+			IsVisited:    &reservEntity.IsVisited,
+			Price:        reservEntity.Price,
+			CheckedInAt:  optionalTimestamp(reservEntity.CheckedInAt),
+			CheckedOutAt: optionalTimestamp(reservEntity.CheckedOutAt),
+			CanceledAt:   optionalTimestamp(reservEntity.CanceledAt),
 		})
 	}
 	return &reservation.ReservationsResponse{
@@ -299,13 +410,17 @@ func (h *reservationHandler) Get(ctx context.Context, req *reservation.GetReques
 	var response []*reservation.ReservationResponse
 	for _, reservEntity := range reservEntities {
 		response = append(response, &reservation.ReservationResponse{
-			Id:         &reservEntity.ID,
-			ClientId:   &reservEntity.ClientID,
-			SeatId:     &reservEntity.SeatID,
-			StartsAt:   timestamppb.New(reservEntity.StartsAt),
-			EndsAt:     timestamppb.New(reservEntity.EndsAt),
-			IsCanceled: &reservEntity.IsCanceled,
-			IsVisited:  &reservEntity.IsVisited,
+			Id:           &reservEntity.ID,
+			ClientId:     &reservEntity.ClientID,
+			SeatId:       &reservEntity.SeatID,
+			StartsAt:     timestamppb.New(reservEntity.StartsAt),
+			EndsAt:       timestamppb.New(reservEntity.EndsAt),
+			IsCanceled:   &reservEntity.IsCanceled,
+			IsVisited:    &reservEntity.IsVisited,
+			Price:        reservEntity.Price,
+			CheckedInAt:  optionalTimestamp(reservEntity.CheckedInAt),
+			CheckedOutAt: optionalTimestamp(reservEntity.CheckedOutAt),
+			CanceledAt:   optionalTimestamp(reservEntity.CanceledAt),
 		})
 	}
 
@@ -335,13 +450,17 @@ func (h *reservationHandler) GetByVisitByDate(ctx context.Context, req *reservat
 	var response []*reservation.ReservationResponse
 	for _, reservEntity := range reservEntities {
 		response = append(response, &reservation.ReservationResponse{
-			Id:         &reservEntity.ID,
-			ClientId:   &reservEntity.ClientID,
-			SeatId:     &reservEntity.SeatID,
-			StartsAt:   timestamppb.New(reservEntity.StartsAt),
-			EndsAt:     timestamppb.New(reservEntity.EndsAt),
-			IsCanceled: &reservEntity.IsCanceled,
-			IsVisited:  &reservEntity.IsVisited,
+			Id:           &reservEntity.ID,
+			ClientId:     &reservEntity.ClientID,
+			SeatId:       &reservEntity.SeatID,
+			StartsAt:     timestamppb.New(reservEntity.StartsAt),
+			EndsAt:       timestamppb.New(reservEntity.EndsAt),
+			IsCanceled:   &reservEntity.IsCanceled,
+			IsVisited:    &reservEntity.IsVisited,
+			Price:        reservEntity.Price,
+			CheckedInAt:  optionalTimestamp(reservEntity.CheckedInAt),
+			CheckedOutAt: optionalTimestamp(reservEntity.CheckedOutAt),
+			CanceledAt:   optionalTimestamp(reservEntity.CanceledAt),
 		})
 	}
 
@@ -350,6 +469,59 @@ func (h *reservationHandler) GetByVisitByDate(ctx context.Context, req *reservat
 	}, nil
 }
 
+func (h *reservationHandler) GetNoShows(ctx context.Context, req *reservation.GetNoShowsRequest) (*reservation.ReservationsResponse, error) {
+	var err error
+	var reservEntities []entity.Reservation
+	if reservEntities, err = h.reservationService.GetNoShows(ctx, req.StartedBefore.AsTime(), int(*req.Limit)); err != nil {
+		return nil, err
+	}
+
+	var response []*reservation.ReservationResponse
+	for _, reservEntity := range reservEntities {
+		response = append(response, &reservation.ReservationResponse{
+			Id:           &reservEntity.ID,
+			ClientId:     &reservEntity.ClientID,
+			SeatId:       &reservEntity.SeatID,
+			StartsAt:     timestamppb.New(reservEntity.StartsAt),
+			EndsAt:       timestamppb.New(reservEntity.EndsAt),
+			IsCanceled:   &reservEntity.IsCanceled,
+			IsVisited:    &reservEntity.IsVisited,
+			Price:        reservEntity.Price,
+			CheckedInAt:  optionalTimestamp(reservEntity.CheckedInAt),
+			CheckedOutAt: optionalTimestamp(reservEntity.CheckedOutAt),
+			CanceledAt:   optionalTimestamp(reservEntity.CanceledAt),
+		})
+	}
+
+	return &reservation.ReservationsResponse{
+		Reservations: response,
+	}, nil
+}
+
+// RunAutoCheckOut periodically checks out the visited reservations that have already ended.
+func (h *reservationHandler) RunAutoCheckOut(interval time.Duration) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+
+	for range ticker.C {
+		count, err := h.reservationService.CheckOutExpired(context.Background())
+		if err != nil {
+			logger.Log.Errorf("Failed to check out expired reservations: %v", err)
+			continue
+		}
+		if count > 0 {
+			logger.Log.Infof("Checked out %d expired reservations", count)
+		}
+	}
+}
+
 func (h *reservationHandler) Setup(gRPCServer *grpc.Server) {
 	reservation.RegisterReservationServer(gRPCServer, h)
 }
+
+func optionalTimestamp(t *time.Time) *timestamppb.Timestamp {
+	if t == nil {
+		return nil
+	}
+	return timestamppb.New(*t)
+}
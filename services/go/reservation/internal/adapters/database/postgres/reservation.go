@@ -92,6 +92,30 @@ func (s *reservationStorage) GetByVisitByDate(ctx context.Context, visit bool, d
 	return reservations, err
 }
 
+// GetNoShows is a method that returns ongoing reservations which weren't visited although they started before startedBefore.
+func (s *reservationStorage) GetNoShows(ctx context.Context, startedBefore, now time.Time, limit int) ([]entity.Reservation, error) {
+	var reservations []entity.Reservation
+	err := s.db.WithContext(ctx).Model(&entity.Reservation{}).
+		Where("is_canceled = ?", false).
+		Where("is_visited = ?", false).
+		Where("starts_at <= ?", startedBefore).
+		Where("ends_at > ?", now).
+		Order("starts_at").
+		Limit(limit).Find(&reservations).Error
+	return reservations, err
+}
+
+// CheckOutExpired is a method that checks out every visited reservation which ended before now at its ends_at.
+func (s *reservationStorage) CheckOutExpired(ctx context.Context, now time.Time) (int64, error) {
+	result := s.db.WithContext(ctx).Model(&entity.Reservation{}).
+		Where("is_canceled = ?", false).
+		Where("is_visited = ?", true).
+		Where("checked_out_at IS NULL").
+		Where("ends_at <= ?", now).
+		Update("checked_out_at", gorm.Expr("ends_at"))
+	return result.RowsAffected, result.Error
+}
+
 // Update is a method to update an existing Client in database.
 func (s *reservationStorage) Update(ctx context.Context, reservation *entity.Reservation) (*entity.Reservation, error) {
 	err := s.db.WithContext(ctx).Model(&entity.Reservation{}).Where("id = ?", reservation.ID).Updates(&reservation).Error
@@ -125,3 +149,16 @@ func (s *reservationStorage) CheckPlace(ctx context.Context, seat string, start,
 
 	return err == gorm.ErrRecordNotFound
 }
+
+// IsFree is a method that checks that no other reservation of the seat overlaps with the given time range.
+func (s *reservationStorage) IsFree(ctx context.Context, seatID, excludeID string, start, end time.Time) bool {
+	var reservation entity.Reservation
+	err := s.db.WithContext(ctx).Model(&entity.Reservation{}).
+		Where("seat_id = ?", seatID).
+		Where("id <> ?", excludeID).
+		Where("is_canceled = ?", false).
+		Where("starts_at < ? AND ends_at > ?", end, start).
+		First(&reservation).Error
+
+	return err == gorm.ErrRecordNotFound
+}
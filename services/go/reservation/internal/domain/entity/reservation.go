@@ -15,4 +15,9 @@ type Reservation struct {
 	EndsAt     time.Time `json:"ends_at" gorm:"not null"`
 	IsCanceled bool      `json:"is_canceled" gorm:"not null,default:false"`
 	IsVisited  bool      `json:"is_visited" gorm:"not null,default:false"`
+	Price      *float64  `json:"price"`
+
+	CheckedInAt  *time.Time `json:"checked_in_at"`
+	CheckedOutAt *time.Time `json:"checked_out_at"`
+	CanceledAt   *time.Time `json:"canceled_at"`
 }
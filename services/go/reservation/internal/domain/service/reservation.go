@@ -20,10 +20,13 @@ type reservationStorage interface {
 	GetBySeat(ctx context.Context, seatID string) ([]entity.Reservation, error)
 	GetByDate(ctx context.Context, date time.Time) ([]entity.Reservation, error)
 	GetByVisitByDate(ctx context.Context, visit bool, date time.Time) ([]entity.Reservation, error)
+	GetNoShows(ctx context.Context, startedBefore, now time.Time, limit int) ([]entity.Reservation, error)
+	CheckOutExpired(ctx context.Context, now time.Time) (int64, error)
 	Update(ctx context.Context, reservation *entity.Reservation) (*entity.Reservation, error)
 	Delete(ctx context.Context, id string) error
 	Exists(ctx context.Context, id string) bool
 	CheckPlace(ctx context.Context, seat string, start, end time.Time) bool
+	IsFree(ctx context.Context, seatID, excludeID string, start, end time.Time) bool
 }
 
 type reservationService struct {
@@ -47,6 +50,7 @@ func (s *reservationService) Create(ctx context.Context, req reservation.CreateR
 		EndsAt:     req.EndsAt.AsTime(),
 		IsCanceled: false,
 		IsVisited:  false,
+		Price:      req.Price,
 	}
 
 	newRes, err := s.storage.Create(ctx, res)
@@ -119,6 +123,15 @@ func (s *reservationService) GetByVisitByDate(ctx context.Context, date time.Tim
 	return reservations, nil
 }
 
+func (s *reservationService) GetNoShows(ctx context.Context, startedBefore time.Time, limit int) ([]entity.Reservation, error) {
+	reservations, err := s.storage.GetNoShows(ctx, startedBefore, time.Now(), limit)
+	if err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to get no-show reservations: %v", err)
+	}
+
+	return reservations, nil
+}
+
 func (s *reservationService) Update(ctx context.Context, reservation *entity.Reservation) (*entity.Reservation, error) {
 	if !s.storage.Exists(ctx, reservation.ID) {
 		return nil, status.Errorf(codes.NotFound, "reservation not found")
@@ -139,7 +152,8 @@ func (s *reservationService) Cancel(ctx context.Context, id string) error {
 	if !s.storage.Exists(ctx, id) {
 		return status.Errorf(codes.NotFound, "reservation not found")
 	}
-	_, err := s.storage.Update(ctx, &entity.Reservation{ID: id, IsCanceled: true})
+	now := time.Now()
+	_, err := s.storage.Update(ctx, &entity.Reservation{ID: id, IsCanceled: true, CanceledAt: &now})
 	if err != nil {
 		return status.Errorf(codes.Internal, "failed to cancel reservation: %v", err)
 	}
@@ -150,13 +164,74 @@ func (s *reservationService) Visit(ctx context.Context, id string) error {
 	if !s.storage.Exists(ctx, id) {
 		return status.Errorf(codes.NotFound, "reservation not found")
 	}
-	_, err := s.storage.Update(ctx, &entity.Reservation{ID: id, IsVisited: true})
+	now := time.Now()
+	_, err := s.storage.Update(ctx, &entity.Reservation{ID: id, IsVisited: true, CheckedInAt: &now})
 	if err != nil {
 		return status.Errorf(codes.Internal, "failed to mark reservation as visited: %v", err)
 	}
 	return nil
 }
 
+func (s *reservationService) CheckOut(ctx context.Context, id string) (*entity.Reservation, error) {
+	res, err := s.GetByID(ctx, id)
+	if err != nil {
+		return nil, err
+	}
+	if res.IsCanceled {
+		return nil, status.Errorf(codes.InvalidArgument, "reservation is canceled")
+	}
+	if !res.IsVisited {
+		return nil, status.Errorf(codes.InvalidArgument, "reservation is not checked in")
+	}
+	if res.CheckedOutAt != nil {
+		return res, nil
+	}
+
+	now := time.Now()
+	res.CheckedOutAt = &now
+	if res, err = s.storage.Update(ctx, res); err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to check out reservation: %v", err)
+	}
+	return res, nil
+}
+
+func (s *reservationService) CheckOutExpired(ctx context.Context) (int64, error) {
+	count, err := s.storage.CheckOutExpired(ctx, time.Now())
+	if err != nil {
+		return 0, status.Errorf(codes.Internal, "failed to check out expired reservations: %v", err)
+	}
+	return count, nil
+}
+
+func (s *reservationService) Extend(ctx context.Context, id string, endsAt time.Time, price *float64) (*entity.Reservation, error) {
+	res, err := s.GetByID(ctx, id)
+	if err != nil {
+		return nil, err
+	}
+	if res.IsCanceled {
+		return nil, status.Errorf(codes.InvalidArgument, "reservation is canceled")
+	}
+	if res.CheckedOutAt != nil {
+		return nil, status.Errorf(codes.InvalidArgument, "reservation is already checked out")
+	}
+	if !endsAt.After(res.EndsAt) {
+		return nil, status.Errorf(codes.InvalidArgument, "new end time must be later than the current one")
+	}
+
+	if !s.storage.IsFree(ctx, res.SeatID, res.ID, res.EndsAt, endsAt) {
+		return nil, status.Errorf(codes.ResourceExhausted, "seat is already booked")
+	}
+
+	res.EndsAt = endsAt
+	if price != nil {
+		res.Price = price
+	}
+	if res, err = s.storage.Update(ctx, res); err != nil {
+		return nil, status.Errorf(codes.Internal, "failed to extend reservation: %v", err)
+	}
+	return res, nil
+}
+
 func (s *reservationService) Delete(ctx context.Context, id string) error {
 	if !s.storage.Exists(ctx, id) {
 		return status.Errorf(codes.NotFound, "reservation not found")
@@ -2,18 +2,24 @@ package v1
 
 import (
 	"context"
+	"errors"
 	lock "gitlab.com/drop-table-prod/backend/protos/go/seat-lock"
 	"gitlab.com/drop-table-prod/backend/services/go/seat-lock/cmd/app"
 	"gitlab.com/drop-table-prod/backend/services/go/seat-lock/internal/adapters/database/redis"
 	"gitlab.com/drop-table-prod/backend/services/go/seat-lock/internal/domain/service"
 	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
 	"google.golang.org/protobuf/types/known/emptypb"
 )
 
 type SeatLockService interface {
-	Lock(ctx context.Context, req *lock.SetLockRequest) error
+	Lock(ctx context.Context, req *lock.SetLockRequest) (*lock.LockResponse, error)
+	Release(ctx context.Context, req *lock.ReleaseLockRequest) error
 	GetLockByUserId(ctx context.Context, req *lock.GetLockByUserIdRequest) (*lock.LockResponse, error)
 	GetLockBySeatIndex(ctx context.Context, req *lock.GetLockBySeatIndexRequest) (*lock.LockResponse, error)
+	LockForBooking(ctx context.Context, req *lock.BookingLockRequest) (*lock.LockResponse, error)
+	ReleaseBookingLock(ctx context.Context, req *lock.ReleaseBookingLockRequest) error
 }
 
 type SeatLockHandler struct {
@@ -27,9 +33,40 @@ func NewSeatLockHandler(app *app.App) *SeatLockHandler {
 	}
 }
 
-func (h *SeatLockHandler) SetLock(ctx context.Context, req *lock.SetLockRequest) (*emptypb.Empty, error) {
-	err := h.service.Lock(ctx, req)
-	return &emptypb.Empty{}, err
+func (h *SeatLockHandler) SetLock(ctx context.Context, req *lock.SetLockRequest) (*lock.LockResponse, error) {
+	response, err := h.service.Lock(ctx, req)
+	if err != nil {
+		if errors.Is(err, redis.ErrSeatLocked) {
+			return nil, status.Error(codes.AlreadyExists, err.Error())
+		}
+		return nil, status.Error(codes.Internal, err.Error())
+	}
+	return response, nil
+}
+
+func (h *SeatLockHandler) ReleaseLock(ctx context.Context, req *lock.ReleaseLockRequest) (*emptypb.Empty, error) {
+	if err := h.service.Release(ctx, req); err != nil {
+		return nil, status.Error(codes.Internal, err.Error())
+	}
+	return &emptypb.Empty{}, nil
+}
+
+func (h *SeatLockHandler) LockForBooking(ctx context.Context, req *lock.BookingLockRequest) (*lock.LockResponse, error) {
+	response, err := h.service.LockForBooking(ctx, req)
+	if err != nil {
+		if errors.Is(err, redis.ErrSeatLocked) {
+			return nil, status.Error(codes.AlreadyExists, err.Error())
+		}
+		return nil, status.Error(codes.Internal, err.Error())
+	}
+	return response, nil
+}
+
+func (h *SeatLockHandler) ReleaseBookingLock(ctx context.Context, req *lock.ReleaseBookingLockRequest) (*emptypb.Empty, error) {
+	if err := h.service.ReleaseBookingLock(ctx, req); err != nil {
+		return nil, status.Error(codes.Internal, err.Error())
+	}
+	return &emptypb.Empty{}, nil
 }
 
 func (h *SeatLockHandler) GetLockByUserID(ctx context.Context, req *lock.GetLockByUserIdRequest) (*lock.LockResponse, error) {
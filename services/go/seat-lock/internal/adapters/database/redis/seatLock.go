@@ -2,11 +2,87 @@ package redis
 
 import (
 	"context"
+	"errors"
 	"github.com/redis/go-redis/v9"
 	lock "gitlab.com/drop-table-prod/backend/protos/go/seat-lock"
 	"time"
 )
 
+const (
+	seatKeyPrefix    = "seat-lock:seat:"
+	userKeyPrefix    = "seat-lock:user:"
+	bookingKeyPrefix = "seat-lock:booking:"
+)
+
+// ErrSeatLocked is returned when the seat is already held by another user.
+var ErrSeatLocked = errors.New("seat is locked by another user")
+
+// lockScript atomically takes (or extends) the seat lock for the user.
+// Extending never shortens an existing lock.
+// A user holds at most one seat at a time: the previous seat is released.
+// The seat can't be taken while another user is booking it.
+var lockScript = redis.NewScript(`
+local ttl = tonumber(ARGV[3])
+local owner = redis.call('GET', KEYS[1])
+if owner and owner ~= ARGV[1] then
+	return {0, owner, redis.call('PTTL', KEYS[1])}
+end
+local booker = redis.call('HGET', KEYS[3], 'user')
+if booker and booker ~= ARGV[1] then
+	return {0, booker, redis.call('PTTL', KEYS[3])}
+end
+if owner then
+	ttl = math.max(ttl, redis.call('PTTL', KEYS[1]))
+end
+local previous = redis.call('GET', KEYS[2])
+if previous and previous ~= ARGV[2] then
+	local previousKey = ARGV[4] .. previous
+	if redis.call('GET', previousKey) == ARGV[1] then
+		redis.call('DEL', previousKey)
+	end
+end
+redis.call('SET', KEYS[1], ARGV[1], 'PX', ttl)
+redis.call('SET', KEYS[2], ARGV[2], 'PX', ttl)
+return {1, ARGV[1], ttl}
+`)
+
+// releaseScript removes the lock only if it is still owned by the user.
+var releaseScript = redis.NewScript(`
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+	redis.call('DEL', KEYS[1])
+end
+if redis.call('GET', KEYS[2]) == ARGV[2] then
+	redis.call('DEL', KEYS[2])
+end
+return 1
+`)
+
+// bookingLockScript takes the seat for a single booking, under a key of its own,
+// so the holds of the user are left as they are.
+// The seat may be held by the user themselves, but not by anyone else,
+// and only one booking of the seat goes at a time.
+var bookingLockScript = redis.NewScript(`
+local owner = redis.call('GET', KEYS[1])
+if owner and owner ~= ARGV[1] then
+	return {0, owner, redis.call('PTTL', KEYS[1])}
+end
+local booker = redis.call('HGET', KEYS[2], 'user')
+if booker then
+	return {0, booker, redis.call('PTTL', KEYS[2])}
+end
+redis.call('HSET', KEYS[2], 'user', ARGV[1], 'lock', ARGV[2])
+redis.call('PEXPIRE', KEYS[2], ARGV[3])
+return {1, ARGV[1], tonumber(ARGV[3])}
+`)
+
+// releaseBookingScript removes the booking lock only if it was taken under the same lock id.
+var releaseBookingScript = redis.NewScript(`
+if redis.call('HGET', KEYS[1], 'lock') == ARGV[1] then
+	redis.call('DEL', KEYS[1])
+end
+return 1
+`)
+
 type seatLockStorage struct {
 	db *redis.Client
 }
@@ -17,32 +93,109 @@ func NewSeatLockStorage(client *redis.Client) *seatLockStorage {
 	}
 }
 
-func (s *seatLockStorage) Lock(ctx context.Context, req *lock.SetLockRequest) error {
-	err := s.db.Set(ctx, *req.UserId, *req.SeatIndex, 5*time.Minute).Err()
+func (s *seatLockStorage) Lock(ctx context.Context, userId, seatIndex string, ttl time.Duration) (*lock.LockResponse, error) {
+	result, err := lockScript.Run(
+		ctx,
+		s.db,
+		[]string{seatKeyPrefix + seatIndex, userKeyPrefix + userId, bookingKeyPrefix + seatIndex},
+		userId, seatIndex, ttl.Milliseconds(), seatKeyPrefix,
+	).Slice()
 	if err != nil {
-		return err
+		return nil, err
 	}
-	err = s.db.Set(ctx, *req.SeatIndex, *req.UserId, 5*time.Minute).Err()
+
+	owner := result[1].(string)
+	response := newLockResponse(owner, seatIndex, time.Duration(result[2].(int64))*time.Millisecond)
+	if result[0].(int64) == 0 {
+		return response, ErrSeatLocked
+	}
+	return response, nil
+}
+
+func (s *seatLockStorage) Release(ctx context.Context, userId, seatIndex string) error {
+	return releaseScript.Run(
+		ctx,
+		s.db,
+		[]string{seatKeyPrefix + seatIndex, userKeyPrefix + userId},
+		userId, seatIndex,
+	).Err()
+}
+
+func (s *seatLockStorage) LockForBooking(ctx context.Context, userId, seatIndex, lockId string, ttl time.Duration) (*lock.LockResponse, error) {
+	result, err := bookingLockScript.Run(
+		ctx,
+		s.db,
+		[]string{seatKeyPrefix + seatIndex, bookingKeyPrefix + seatIndex},
+		userId, lockId, ttl.Milliseconds(),
+	).Slice()
 	if err != nil {
-		return err
+		return nil, err
+	}
+
+	owner := result[1].(string)
+	response := newLockResponse(owner, seatIndex, time.Duration(result[2].(int64))*time.Millisecond)
+	if result[0].(int64) == 0 {
+		return response, ErrSeatLocked
 	}
-	return nil
+	return response, nil
 }
 
-func (s *seatLockStorage) GetLockByUserId(ctx context.Context, req *lock.GetLockByUserIdRequest) (*lock.LockResponse, error) {
-	value, err := s.db.Get(ctx, *req.UserId).Result()
+func (s *seatLockStorage) ReleaseBookingLock(ctx context.Context, seatIndex, lockId string) error {
+	return releaseBookingScript.Run(
+		ctx,
+		s.db,
+		[]string{bookingKeyPrefix + seatIndex},
+		lockId,
+	).Err()
+}
+
+func (s *seatLockStorage) GetLockByUserId(ctx context.Context, userId string) (*lock.LockResponse, error) {
+	key := userKeyPrefix + userId
+	seatIndex, err := s.db.Get(ctx, key).Result()
+	if errors.Is(err, redis.Nil) {
+		return newEmptyLockResponse(), nil
+	}
 	if err != nil {
-		return &lock.LockResponse{}, err
+		return nil, err
 	}
-	status := value == *req.UserId
-	return &lock.LockResponse{Status: &status}, nil
+	ttl, err := s.db.PTTL(ctx, key).Result()
+	if err != nil {
+		return nil, err
+	}
+	return newLockResponse(userId, seatIndex, ttl), nil
 }
 
-func (s *seatLockStorage) GetLockBySeatIndex(ctx context.Context, req *lock.GetLockBySeatIndexRequest) (*lock.LockResponse, error) {
-	value, err := s.db.Get(ctx, *req.SeatIndex).Result()
+func (s *seatLockStorage) GetLockBySeatIndex(ctx context.Context, seatIndex string) (*lock.LockResponse, error) {
+	key := seatKeyPrefix + seatIndex
+	userId, err := s.db.Get(ctx, key).Result()
+	if errors.Is(err, redis.Nil) {
+		return newEmptyLockResponse(), nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	ttl, err := s.db.PTTL(ctx, key).Result()
 	if err != nil {
-		return &lock.LockResponse{}, err
+		return nil, err
+	}
+	return newLockResponse(userId, seatIndex, ttl), nil
+}
+
+func newLockResponse(userId, seatIndex string, ttl time.Duration) *lock.LockResponse {
+	status := true
+	ttlSeconds := uint64(0)
+	if ttl > 0 {
+		ttlSeconds = uint64((ttl + time.Second - 1) / time.Second)
 	}
-	status := value == *req.SeatIndex
-	return &lock.LockResponse{Status: &status}, nil
+	return &lock.LockResponse{
+		Status:     &status,
+		UserId:     &userId,
+		SeatIndex:  &seatIndex,
+		TtlSeconds: &ttlSeconds,
+	}
+}
+
+func newEmptyLockResponse() *lock.LockResponse {
+	status := false
+	return &lock.LockResponse{Status: &status}
 }
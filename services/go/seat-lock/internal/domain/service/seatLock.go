@@ -3,12 +3,22 @@ package service
 import (
 	"context"
 	lock "gitlab.com/drop-table-prod/backend/protos/go/seat-lock"
+	"time"
 )
 
+// DefaultLockTTL is used when the caller does not request a specific TTL.
+const DefaultLockTTL = 5 * time.Minute
+
+// MaxLockTTL bounds the TTL a caller may request.
+const MaxLockTTL = 30 * time.Minute
+
 type SeatLockStorage interface {
-	Lock(ctx context.Context, req *lock.SetLockRequest) error
-	GetLockByUserId(ctx context.Context, req *lock.GetLockByUserIdRequest) (*lock.LockResponse, error)
-	GetLockBySeatIndex(ctx context.Context, req *lock.GetLockBySeatIndexRequest) (*lock.LockResponse, error)
+	Lock(ctx context.Context, userId, seatIndex string, ttl time.Duration) (*lock.LockResponse, error)
+	Release(ctx context.Context, userId, seatIndex string) error
+	GetLockByUserId(ctx context.Context, userId string) (*lock.LockResponse, error)
+	GetLockBySeatIndex(ctx context.Context, seatIndex string) (*lock.LockResponse, error)
+	LockForBooking(ctx context.Context, userId, seatIndex, lockId string, ttl time.Duration) (*lock.LockResponse, error)
+	ReleaseBookingLock(ctx context.Context, seatIndex, lockId string) error
 }
 
 type SeatLockService struct {
@@ -21,14 +31,34 @@ func NewSeatLockService(storage SeatLockStorage) *SeatLockService {
 	}
 }
 
-func (s *SeatLockService) Lock(ctx context.Context, req *lock.SetLockRequest) error {
-	return s.storage.Lock(ctx, req)
+func (s *SeatLockService) Lock(ctx context.Context, req *lock.SetLockRequest) (*lock.LockResponse, error) {
+	return s.storage.Lock(ctx, req.GetUserId(), req.GetSeatIndex(), lockTTL(req.TtlSeconds))
+}
+
+func (s *SeatLockService) LockForBooking(ctx context.Context, req *lock.BookingLockRequest) (*lock.LockResponse, error) {
+	return s.storage.LockForBooking(ctx, req.GetUserId(), req.GetSeatIndex(), req.GetLockId(), lockTTL(req.TtlSeconds))
+}
+
+func (s *SeatLockService) ReleaseBookingLock(ctx context.Context, req *lock.ReleaseBookingLockRequest) error {
+	return s.storage.ReleaseBookingLock(ctx, req.GetSeatIndex(), req.GetLockId())
+}
+
+func (s *SeatLockService) Release(ctx context.Context, req *lock.ReleaseLockRequest) error {
+	return s.storage.Release(ctx, req.GetUserId(), req.GetSeatIndex())
 }
 
 func (s *SeatLockService) GetLockByUserId(ctx context.Context, req *lock.GetLockByUserIdRequest) (*lock.LockResponse, error) {
-	return s.storage.GetLockByUserId(ctx, req)
+	return s.storage.GetLockByUserId(ctx, req.GetUserId())
 }
 
 func (s *SeatLockService) GetLockBySeatIndex(ctx context.Context, req *lock.GetLockBySeatIndexRequest) (*lock.LockResponse, error) {
-	return s.storage.GetLockBySeatIndex(ctx, req)
+	return s.storage.GetLockBySeatIndex(ctx, req.GetSeatIndex())
+}
+
+// lockTTL bounds the requested TTL, falling back to the default one
+func lockTTL(ttlSeconds *uint64) time.Duration {
+	if ttlSeconds != nil && *ttlSeconds > 0 {
+		return min(time.Duration(*ttlSeconds)*time.Second, MaxLockTTL)
+	}
+	return DefaultLockTTL
 }
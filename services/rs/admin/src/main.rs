@@ -1,37 +1,88 @@
 use admin::{config, service::AdminService};
-use env_logger::Env;
 use protos::admin::admin_server::AdminServer;
-use tonic::{codec::CompressionEncoding, transport::Server};
+use service_helper::{
+    health::HealthChecks,
+    metrics::{self, MetricsLayer},
+    principal::PrincipalLayer,
+    shutdown::Shutdown,
+    telemetry::{self, TraceLayer},
+    tls::{self, CallerAllowlist},
+};
+use tonic::codec::CompressionEncoding;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let default_log_level = if cfg!(debug_assertions) {
-        "debug"
-    } else {
-        "info"
-    };
-    env_logger::init_from_env(Env::default().default_filter_or(default_log_level));
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("admin");
     config::init();
 
-    postgres_helper::migrate!(&config::DATABASE_URL, "./db/migrations");
+    let shutdown = Shutdown::on_signal();
 
-    let pool = postgres_helper::connect(&config::DATABASE_URL)
+    metrics::install_exporter(&config::METRICS_ADDRESS);
+
+    postgres_helper::migrate!(config::DATABASE_URL.as_str(), "./db/migrations");
+
+    let pool = postgres_helper::connect(config::DATABASE_URL.as_str())
         .await
         .expect("Database connection failed");
 
-    let service = AdminServer::new(AdminService::new(pool))
+    let health_checks = HealthChecks::new().check("postgres", {
+        let pool = pool.clone();
+        move || postgres_helper::ping(pool.clone())
+    });
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    shutdown.spawn_worker(
+        health_checks.run::<AdminServer<AdminService>>(health_reporter, shutdown.clone()),
+    );
+
+    let service = AdminServer::new(AdminService::new(pool.clone()))
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    log::info!(
+    // off by default, the schema shouldn't be discoverable in production
+    let reflection_service = config::GRPC_REFLECTION
+        .then(|| {
+            tonic_reflection::server::Builder::configure()
+                .register_encoded_file_descriptor_set(protos::FILE_DESCRIPTOR_SET)
+                .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+                .with_service_name(protos::admin::admin_server::SERVICE_NAME)
+                .with_service_name(tonic_health::pb::health_server::SERVICE_NAME)
+                .build_v1()
+        })
+        .transpose()?;
+
+    tracing::info!(
         "Started admin service on {}",
         config::SERVICE_ADDRESS.as_str()
     );
 
-    Server::builder()
+    const GATEWAY: &[&str] = &["gateway"];
+    let callers = CallerAllowlist::new()
+        .allow("/admin.Admin/Register", GATEWAY)
+        .allow("/admin.Admin/Login", GATEWAY)
+        .allow("/admin.Admin/ValidateToken", GATEWAY)
+        .allow("/admin.Admin/ChangePassword", GATEWAY)
+        .allow("/admin.Admin/Get", GATEWAY)
+        .allow("/admin.Admin/Edit", GATEWAY)
+        .allow("/admin.Admin/Delete", GATEWAY);
+
+    let server = tls::server()?
+        .layer(MetricsLayer)
+        .layer(TraceLayer)
+        .layer(callers)
+        .layer(PrincipalLayer)
+        .add_service(health_service)
+        .add_optional_service(reflection_service)
         .add_service(service)
-        .serve(config::SERVICE_ADDRESS.parse()?)
-        .await?;
+        .serve_with_shutdown(config::SERVICE_ADDRESS.parse()?, shutdown.requested());
+    shutdown.drain(server).await?;
+
+    pool.close().await;
+    tracing::info!("Stopped admin service");
 
     Ok(())
 }
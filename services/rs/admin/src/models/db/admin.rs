@@ -13,7 +13,7 @@ pub struct DBAdmin {
 }
 impl DBAdmin {
     pub async fn insert(self, transaction: &mut Transaction<'_, Postgres>) -> Result<Self, Error> {
-        Ok(query_file_as!(
+        query_file_as!(
             Self,
             "db/queries/insert.sql",
             self.id,
@@ -21,25 +21,25 @@ impl DBAdmin {
             self.password_hash,
         )
         .fetch_one(&mut **transaction)
-        .await?)
+        .await
     }
 
     pub async fn get_by_id<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        Ok(query_file_as!(Self, "db/queries/get_by_id.sql", id)
+        query_file_as!(Self, "db/queries/get_by_id.sql", id)
             .fetch_optional(executor)
-            .await?)
+            .await
     }
 
     pub async fn get_by_email<'a, E>(email: &str, executor: E) -> Result<Option<Self>, Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        Ok(query_file_as!(Self, "db/queries/get_by_email.sql", email)
+        query_file_as!(Self, "db/queries/get_by_email.sql", email)
             .fetch_optional(executor)
-            .await?)
+            .await
     }
 
     pub async fn patch(
@@ -49,7 +49,7 @@ impl DBAdmin {
         last_password_change: Option<DateTime<Utc>>,
         transaction: &mut Transaction<'_, Postgres>,
     ) -> Result<Option<Self>, Error> {
-        Ok(query_file_as!(
+        query_file_as!(
             Self,
             "db/queries/patch.sql",
             id,
@@ -58,7 +58,7 @@ impl DBAdmin {
             last_password_change,
         )
         .fetch_optional(&mut **transaction)
-        .await?)
+        .await
     }
 
     pub async fn delete(
@@ -71,11 +71,11 @@ impl DBAdmin {
         Ok(())
     }
 }
-impl Into<AdminResponse> for DBAdmin {
-    fn into(self) -> AdminResponse {
-        AdminResponse {
-            id: self.id.to_string(),
-            email: self.email,
+impl From<DBAdmin> for AdminResponse {
+    fn from(value: DBAdmin) -> Self {
+        Self {
+            id: value.id.to_string(),
+            email: value.email,
         }
     }
 }
@@ -2,6 +2,7 @@ use argon2::{password_hash::SaltString, Argon2, PasswordHasher};
 use chrono::Utc;
 use protos::admin::{AuthResponse, ChangePasswordRequest};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -11,8 +12,13 @@ use super::error::ServiceError;
 
 pub async fn handle(
     req: ChangePasswordRequest,
+    principal: &Principal,
     pool: &PgPool,
 ) -> Result<AuthResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
     let id = Uuid::parse_str(&req.id).unwrap();
 
     let hasher = Argon2::default();
@@ -1,4 +1,5 @@
 use protos::admin::AdminRequest;
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -6,7 +7,15 @@ use crate::models::db::DBAdmin;
 
 use super::error::ServiceError;
 
-pub async fn handle(req: AdminRequest, pool: &PgPool) -> Result<(), ServiceError> {
+pub async fn handle(
+    req: AdminRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<(), ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
     let id = Uuid::parse_str(&req.id).unwrap();
 
     let mut transaction = pool.begin().await?;
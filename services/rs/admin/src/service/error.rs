@@ -17,21 +17,29 @@ pub enum ServiceError {
     #[error("Admin was not found")]
     NotFound,
 
+    #[error("The call isn't made on behalf of anyone")]
+    Unauthenticated,
+
+    #[error("Only admins may manage the admins")]
+    NotOwner,
+
     #[error("Admin with this email already exists")]
     AlreadyExists,
 }
 
-impl Into<Status> for ServiceError {
-    fn into(self) -> Status {
-        let code: Code = match self {
-            Self::Database(..) => Code::Internal,
-            Self::Hasher(..) => Code::Internal,
-            Self::InvalidCredentials => Code::Unauthenticated,
-            Self::InvalidToken => Code::Unauthenticated,
-            Self::NotFound => Code::NotFound,
-            Self::AlreadyExists => Code::AlreadyExists,
+impl From<ServiceError> for Status {
+    fn from(error: ServiceError) -> Self {
+        let code: Code = match error {
+            ServiceError::Database(..) => Code::Internal,
+            ServiceError::Hasher(..) => Code::Internal,
+            ServiceError::InvalidCredentials => Code::Unauthenticated,
+            ServiceError::InvalidToken => Code::Unauthenticated,
+            ServiceError::NotFound => Code::NotFound,
+            ServiceError::Unauthenticated => Code::Unauthenticated,
+            ServiceError::NotOwner => Code::PermissionDenied,
+            ServiceError::AlreadyExists => Code::AlreadyExists,
         };
 
-        Status::new(code, self.to_string())
+        Status::new(code, error.to_string())
     }
 }
@@ -1,12 +1,34 @@
 use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use protos::admin::{AuthResponse, LoginRequest};
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 
 use crate::{models::db::DBAdmin, utils::token};
 
 use super::error::ServiceError;
 
-pub async fn handle(req: LoginRequest, pool: &PgPool) -> Result<AuthResponse, ServiceError> {
+pub async fn handle(
+    req: LoginRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<AuthResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let response = login(req, pool).await;
+
+    let result = match &response {
+        Ok(..) => "success",
+        Err(ServiceError::InvalidCredentials) => "invalid_credentials",
+        Err(..) => "error",
+    };
+    metrics::counter!("admin_logins_total", "result" => result).increment(1);
+
+    response
+}
+
+async fn login(req: LoginRequest, pool: &PgPool) -> Result<AuthResponse, ServiceError> {
     let admin = match DBAdmin::get_by_email(&req.email, pool).await? {
         None => return Err(ServiceError::InvalidCredentials),
         Some(admin) => admin,
@@ -3,6 +3,7 @@ use protos::admin::{
     admin_server::Admin, AdminRequest, AdminResponse, AuthResponse, ChangePasswordRequest,
     EditRequest, LoginRequest, RegisterRequest, ValidateTokenRequest,
 };
+use service_helper::principal;
 use service_helper::response::ServiceResult;
 use sqlx::{Pool, Postgres};
 use tonic::{async_trait, Request, Response};
@@ -30,14 +31,16 @@ impl AdminService {
 #[async_trait]
 impl Admin for AdminService {
     async fn register(&self, request: Request<RegisterRequest>) -> ServiceResult<AuthResponse> {
-        register::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        register::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn login(&self, request: Request<LoginRequest>) -> ServiceResult<AuthResponse> {
-        login::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        login::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
@@ -47,7 +50,8 @@ impl Admin for AdminService {
         &self,
         request: Request<ValidateTokenRequest>,
     ) -> ServiceResult<AdminResponse> {
-        validate_token::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        validate_token::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
@@ -57,28 +61,32 @@ impl Admin for AdminService {
         &self,
         request: Request<ChangePasswordRequest>,
     ) -> ServiceResult<AuthResponse> {
-        change_password::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        change_password::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn get(&self, request: Request<AdminRequest>) -> ServiceResult<AdminResponse> {
-        get::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn edit(&self, request: Request<EditRequest>) -> ServiceResult<AdminResponse> {
-        edit::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        edit::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn delete(&self, request: Request<AdminRequest>) -> ServiceResult<()> {
-        delete::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        delete::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
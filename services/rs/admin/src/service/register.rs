@@ -2,6 +2,7 @@ use argon2::{password_hash::SaltString, Argon2, PasswordHasher};
 use chrono::Utc;
 use protos::admin::{AuthResponse, RegisterRequest};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -9,7 +10,15 @@ use crate::{models::db::DBAdmin, utils::token};
 
 use super::error::ServiceError;
 
-pub async fn handle(req: RegisterRequest, pool: &PgPool) -> Result<AuthResponse, ServiceError> {
+pub async fn handle(
+    req: RegisterRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<AuthResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
     if DBAdmin::get_by_email(&req.email, pool).await?.is_some() {
         return Err(ServiceError::AlreadyExists)?;
     }
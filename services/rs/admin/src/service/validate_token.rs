@@ -1,5 +1,6 @@
 use chrono::Utc;
 use protos::admin::{AdminResponse, ValidateTokenRequest};
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -9,8 +10,13 @@ use super::error::ServiceError;
 
 pub async fn handle(
     req: ValidateTokenRequest,
+    principal: &Principal,
     pool: &PgPool,
 ) -> Result<AdminResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
     let claims = match token::parse(req.token) {
         None => return Err(ServiceError::InvalidToken),
         Some(claims) => claims,
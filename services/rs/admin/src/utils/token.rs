@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 use crate::config;
 
-static TOKEN_LIFETIME: usize = 60 * 60 * 24 * 3 as usize;
+static TOKEN_LIFETIME: usize = 60 * 60 * 24 * 3;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -25,7 +25,7 @@ pub fn new(id: Uuid) -> String {
             iat: current_time,
             sub: id.to_string(),
         },
-        &EncodingKey::from_secret(config::JWT_SECRET.as_bytes()),
+        &EncodingKey::from_secret(config::JWT_SECRET.expose().as_bytes()),
     )
     .unwrap()
 }
@@ -33,7 +33,7 @@ pub fn new(id: Uuid) -> String {
 pub fn parse(token: String) -> Option<Claims> {
     match decode(
         &token,
-        &DecodingKey::from_secret(config::JWT_SECRET.as_bytes()),
+        &DecodingKey::from_secret(config::JWT_SECRET.expose().as_bytes()),
         &Validation::default(),
     ) {
         Err(..) => None,
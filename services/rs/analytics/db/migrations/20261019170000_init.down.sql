@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS daily_rollups;
+DROP TABLE IF EXISTS hourly_rollups;
\ No newline at end of file
@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS hourly_rollups
+(
+    hour             timestamptz      NOT NULL,
+    seat_type        text             NOT NULL,
+    seats            integer          NOT NULL,
+    booked_minutes   double precision NOT NULL,
+    occupied_minutes double precision NOT NULL,
+    revenue          double precision NOT NULL,
+    PRIMARY KEY (hour, seat_type)
+);
+
+CREATE TABLE IF NOT EXISTS daily_rollups
+(
+    day              date             NOT NULL,
+    seat_type        text             NOT NULL,
+    seats            integer          NOT NULL,
+    capacity_minutes double precision NOT NULL,
+    booked_minutes   double precision NOT NULL,
+    occupied_minutes double precision NOT NULL,
+    revenue          double precision NOT NULL,
+    reservations     integer          NOT NULL,
+    cancellations    integer          NOT NULL,
+    no_shows         integer          NOT NULL,
+    PRIMARY KEY (day, seat_type)
+);
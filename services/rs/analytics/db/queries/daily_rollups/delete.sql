@@ -0,0 +1,3 @@
+DELETE
+FROM daily_rollups
+WHERE day = $1
@@ -0,0 +1,5 @@
+SELECT *
+FROM daily_rollups
+WHERE day >= $1
+  AND day < $2
+ORDER BY day, seat_type
@@ -0,0 +1,9 @@
+SELECT seat_type,
+       SUM(reservations)::bigint  AS "reservations!",
+       SUM(cancellations)::bigint AS "cancellations!",
+       SUM(no_shows)::bigint      AS "no_shows!"
+FROM daily_rollups
+WHERE day >= $1
+  AND day < $2
+GROUP BY seat_type
+ORDER BY seat_type
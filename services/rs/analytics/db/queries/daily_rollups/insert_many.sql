@@ -0,0 +1,5 @@
+INSERT INTO daily_rollups (day, seat_type, seats, capacity_minutes, booked_minutes, occupied_minutes, revenue,
+                           reservations, cancellations, no_shows)
+SELECT *
+FROM UNNEST($1::date[], $2::text[], $3::integer[], $4::double precision[], $5::double precision[],
+            $6::double precision[], $7::double precision[], $8::integer[], $9::integer[], $10::integer[])
@@ -0,0 +1,4 @@
+DELETE
+FROM hourly_rollups
+WHERE hour >= $1
+  AND hour < $2
@@ -0,0 +1,11 @@
+SELECT EXTRACT(ISODOW FROM hour AT TIME ZONE 'UTC')::integer AS "weekday!",
+       EXTRACT(HOUR FROM hour AT TIME ZONE 'UTC')::integer   AS "hour!",
+       SUM(seats * 60)::double precision                    AS "capacity_minutes!",
+       SUM(booked_minutes)                                  AS "booked_minutes!",
+       SUM(occupied_minutes)                                AS "occupied_minutes!"
+FROM hourly_rollups
+WHERE hour >= $1
+  AND hour < $2
+  AND ($3::text IS NULL OR seat_type = $3)
+GROUP BY 1, 2
+ORDER BY 1, 2
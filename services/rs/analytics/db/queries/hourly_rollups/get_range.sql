@@ -0,0 +1,5 @@
+SELECT *
+FROM hourly_rollups
+WHERE hour >= $1
+  AND hour < $2
+ORDER BY hour, seat_type
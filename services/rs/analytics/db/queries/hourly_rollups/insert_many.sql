@@ -0,0 +1,4 @@
+INSERT INTO hourly_rollups (hour, seat_type, seats, booked_minutes, occupied_minutes, revenue)
+SELECT *
+FROM UNNEST($1::timestamptz[], $2::text[], $3::integer[], $4::double precision[], $5::double precision[],
+            $6::double precision[])
@@ -0,0 +1,103 @@
+use analytics::{
+    config,
+    service::{rollup, AnalyticsService},
+};
+use protos::{
+    analytics::analytics_server::AnalyticsServer, coworking::coworking_client::CoworkingClient,
+    reservation::reservation_client::ReservationClient,
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal, PrincipalLayer},
+    shutdown::Shutdown,
+    telemetry::{self, TraceLayer},
+    tls::{self, CallerAllowlist},
+};
+use tonic::codec::CompressionEncoding;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("analytics");
+    config::init();
+
+    let shutdown = Shutdown::on_signal();
+
+    postgres_helper::migrate!(config::DATABASE_URL.as_str(), "./db/migrations");
+
+    let pool = postgres_helper::connect(config::DATABASE_URL.as_str())
+        .await
+        .expect("Database connection failed");
+
+    let coworking_client = CoworkingClient::new(
+        ServiceChannel::lazy("coworking", &config::COWORKING_SERVICE_ADDRESS).retryable(&[
+            "/coworking.Coworking/GetByID",
+            "/coworking.Coworking/GetSeat",
+            "/coworking.Coworking/GetSeats",
+        ]),
+    )
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    let reservation_client = ReservationClient::new(
+        ServiceChannel::lazy("reservation", &config::RESERVATION_SERVICE_ADDRESS).retryable(&[
+            "/reservation.Reservation/GetByID",
+            "/reservation.Reservation/GetBySeat",
+            "/reservation.Reservation/GetByClient",
+            "/reservation.Reservation/Get",
+            "/reservation.Reservation/GetVisited",
+            "/reservation.Reservation/GetNoShows",
+        ]),
+    )
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    // the rollups are made on the service's own behalf
+    shutdown.spawn_worker(principal::scope(
+        Principal::service("analytics"),
+        rollup::run(
+            pool.clone(),
+            coworking_client.clone(),
+            reservation_client.clone(),
+            shutdown.clone(),
+        ),
+    ));
+
+    let service = AnalyticsServer::new(AnalyticsService::new(
+        pool.clone(),
+        coworking_client,
+        reservation_client,
+    ))
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    tracing::info!(
+        "Started analytics service on {}",
+        config::SERVICE_ADDRESS.as_str()
+    );
+
+    const GATEWAY: &[&str] = &["gateway"];
+    let callers = CallerAllowlist::new()
+        .allow("/analytics.Analytics/GetOccupancy", GATEWAY)
+        .allow("/analytics.Analytics/GetHeatmap", GATEWAY)
+        .allow("/analytics.Analytics/GetRates", GATEWAY)
+        .allow("/analytics.Analytics/GetRevenue", GATEWAY)
+        .allow("/analytics.Analytics/Rebuild", GATEWAY);
+
+    let server = tls::server()?
+        .layer(TraceLayer)
+        .layer(callers)
+        .layer(PrincipalLayer)
+        .add_service(service)
+        .serve_with_shutdown(config::SERVICE_ADDRESS.parse()?, shutdown.requested());
+    shutdown.drain(server).await?;
+
+    pool.close().await;
+    tracing::info!("Stopped analytics service");
+
+    Ok(())
+}
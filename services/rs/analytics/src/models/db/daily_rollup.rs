@@ -0,0 +1,141 @@
+use chrono::{NaiveDate, NaiveTime};
+use convertions::datetime_into_timestamp;
+use protos::analytics::{OccupancyPoint, RevenuePoint, SeatTypeRates};
+use sqlx::{query_file, query_file_as, Error, Executor, Postgres};
+
+#[derive(Clone, Debug)]
+pub struct DBDailyRollup {
+    pub day: NaiveDate,
+    pub seat_type: String,
+    pub seats: i32,
+    pub capacity_minutes: f64,
+    pub booked_minutes: f64,
+    pub occupied_minutes: f64,
+    pub revenue: f64,
+    pub reservations: i32,
+    pub cancellations: i32,
+    pub no_shows: i32,
+}
+impl DBDailyRollup {
+    pub async fn insert_many<'a, E>(rollups: &[Self], executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let days: Vec<NaiveDate> = rollups.iter().map(|rollup| rollup.day).collect();
+        let seat_types: Vec<String> = rollups.iter().map(|r| r.seat_type.clone()).collect();
+        let seats: Vec<i32> = rollups.iter().map(|rollup| rollup.seats).collect();
+        let capacity: Vec<f64> = rollups.iter().map(|r| r.capacity_minutes).collect();
+        let booked: Vec<f64> = rollups.iter().map(|r| r.booked_minutes).collect();
+        let occupied: Vec<f64> = rollups.iter().map(|r| r.occupied_minutes).collect();
+        let revenue: Vec<f64> = rollups.iter().map(|rollup| rollup.revenue).collect();
+        let reservations: Vec<i32> = rollups.iter().map(|r| r.reservations).collect();
+        let cancellations: Vec<i32> = rollups.iter().map(|r| r.cancellations).collect();
+        let no_shows: Vec<i32> = rollups.iter().map(|r| r.no_shows).collect();
+
+        query_file!(
+            "db/queries/daily_rollups/insert_many.sql",
+            &days,
+            &seat_types,
+            &seats,
+            &capacity,
+            &booked,
+            &occupied,
+            &revenue,
+            &reservations,
+            &cancellations,
+            &no_shows
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete<'a, E>(day: NaiveDate, executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!("db/queries/daily_rollups/delete.sql", day)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_range<'a, E>(
+        starts_on: NaiveDate,
+        ends_on: NaiveDate,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/daily_rollups/get_range.sql",
+            starts_on,
+            ends_on
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+impl From<DBDailyRollup> for OccupancyPoint {
+    fn from(value: DBDailyRollup) -> Self {
+        Self {
+            bucket: datetime_into_timestamp(value.day.and_time(NaiveTime::MIN).and_utc()),
+            seat_type: value.seat_type,
+            seats: value.seats as u32,
+            capacity_hours: value.capacity_minutes / 60.0,
+            booked_hours: value.booked_minutes / 60.0,
+            occupied_hours: value.occupied_minutes / 60.0,
+        }
+    }
+}
+impl From<DBDailyRollup> for RevenuePoint {
+    fn from(value: DBDailyRollup) -> Self {
+        Self {
+            day: datetime_into_timestamp(value.day.and_time(NaiveTime::MIN).and_utc()),
+            seat_type: value.seat_type,
+            revenue: value.revenue,
+        }
+    }
+}
+
+/// Reservation outcomes of a seat type summed up over a period.
+#[derive(Clone, Debug)]
+pub struct DBSeatTypeRates {
+    pub seat_type: String,
+    pub reservations: i64,
+    pub cancellations: i64,
+    pub no_shows: i64,
+}
+impl DBSeatTypeRates {
+    pub async fn get<'a, E>(
+        starts_on: NaiveDate,
+        ends_on: NaiveDate,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/daily_rollups/get_rates.sql",
+            starts_on,
+            ends_on
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+impl From<DBSeatTypeRates> for SeatTypeRates {
+    fn from(value: DBSeatTypeRates) -> Self {
+        Self {
+            seat_type: value.seat_type,
+            reservations: value.reservations as u32,
+            cancellations: value.cancellations as u32,
+            no_shows: value.no_shows as u32,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use convertions::datetime_into_timestamp;
+use protos::analytics::{HeatmapCell, OccupancyPoint};
+use sqlx::{query_file, query_file_as, Error, Executor, Postgres};
+
+#[derive(Clone, Debug)]
+pub struct DBHourlyRollup {
+    pub hour: DateTime<Utc>,
+    pub seat_type: String,
+    pub seats: i32,
+    pub booked_minutes: f64,
+    pub occupied_minutes: f64,
+    pub revenue: f64,
+}
+impl DBHourlyRollup {
+    pub async fn insert_many<'a, E>(rollups: &[Self], executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        let hours: Vec<DateTime<Utc>> = rollups.iter().map(|rollup| rollup.hour).collect();
+        let seat_types: Vec<String> = rollups.iter().map(|r| r.seat_type.clone()).collect();
+        let seats: Vec<i32> = rollups.iter().map(|rollup| rollup.seats).collect();
+        let booked: Vec<f64> = rollups.iter().map(|r| r.booked_minutes).collect();
+        let occupied: Vec<f64> = rollups.iter().map(|r| r.occupied_minutes).collect();
+        let revenue: Vec<f64> = rollups.iter().map(|rollup| rollup.revenue).collect();
+
+        query_file!(
+            "db/queries/hourly_rollups/insert_many.sql",
+            &hours,
+            &seat_types,
+            &seats,
+            &booked,
+            &occupied,
+            &revenue
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_range<'a, E>(
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!(
+            "db/queries/hourly_rollups/delete_range.sql",
+            starts_at,
+            ends_at
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_range<'a, E>(
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/hourly_rollups/get_range.sql",
+            starts_at,
+            ends_at
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+impl From<DBHourlyRollup> for OccupancyPoint {
+    fn from(value: DBHourlyRollup) -> Self {
+        Self {
+            bucket: datetime_into_timestamp(value.hour),
+            seat_type: value.seat_type,
+            seats: value.seats as u32,
+            capacity_hours: value.seats as f64,
+            booked_hours: value.booked_minutes / 60.0,
+            occupied_hours: value.occupied_minutes / 60.0,
+        }
+    }
+}
+
+/// Rollups of a weekday and hour summed up over a period.
+#[derive(Clone, Debug)]
+pub struct DBHeatmapCell {
+    pub weekday: i32,
+    pub hour: i32,
+    pub capacity_minutes: f64,
+    pub booked_minutes: f64,
+    pub occupied_minutes: f64,
+}
+impl DBHeatmapCell {
+    pub async fn get<'a, E>(
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        seat_type: Option<String>,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/hourly_rollups/get_heatmap.sql",
+            starts_at,
+            ends_at,
+            seat_type
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+impl From<DBHeatmapCell> for HeatmapCell {
+    fn from(value: DBHeatmapCell) -> Self {
+        Self {
+            weekday: value.weekday as u32,
+            hour: value.hour as u32,
+            capacity_hours: value.capacity_minutes / 60.0,
+            booked_hours: value.booked_minutes / 60.0,
+            occupied_hours: value.occupied_minutes / 60.0,
+        }
+    }
+}
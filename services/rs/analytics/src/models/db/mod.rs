@@ -0,0 +1,5 @@
+mod daily_rollup;
+mod hourly_rollup;
+
+pub use daily_rollup::{DBDailyRollup, DBSeatTypeRates};
+pub use hourly_rollup::{DBHeatmapCell, DBHourlyRollup};
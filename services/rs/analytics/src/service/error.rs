@@ -0,0 +1,31 @@
+use tonic::{Code, Status};
+
+use super::period::MAX_PERIOD_DAYS;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error("{0}")]
+    Service(#[from] Status),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Period must start before it ends and span at most {MAX_PERIOD_DAYS} days")]
+    InvalidPeriod,
+}
+
+impl From<ServiceError> for Status {
+    fn from(error: ServiceError) -> Self {
+        if let ServiceError::Service(status) = error {
+            return status;
+        }
+
+        let code: Code = match error {
+            ServiceError::Service(..) => unreachable!(),
+            ServiceError::Database(..) => Code::Internal,
+            ServiceError::InvalidPeriod => Code::InvalidArgument,
+        };
+
+        Status::new(code, error.to_string())
+    }
+}
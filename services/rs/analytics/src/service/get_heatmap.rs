@@ -0,0 +1,18 @@
+use protos::analytics::{HeatmapCell, HeatmapRequest, HeatmapResponse};
+use sqlx::PgPool;
+
+use crate::models::db::DBHeatmapCell;
+
+use super::{error::ServiceError, period::Period};
+
+pub async fn handle(req: HeatmapRequest, pool: &PgPool) -> Result<HeatmapResponse, ServiceError> {
+    let period = Period::parse(req.starts_at, req.ends_at).ok_or(ServiceError::InvalidPeriod)?;
+
+    let cells = DBHeatmapCell::get(period.starts_at, period.ends_at, req.seat_type, pool)
+        .await?
+        .into_iter()
+        .map(HeatmapCell::from)
+        .collect();
+
+    Ok(HeatmapResponse { cells })
+}
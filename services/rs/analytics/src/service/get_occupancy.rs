@@ -0,0 +1,28 @@
+use protos::analytics::{Granularity, OccupancyPoint, OccupancyRequest, OccupancyResponse};
+use sqlx::PgPool;
+
+use crate::models::db::{DBDailyRollup, DBHourlyRollup};
+
+use super::{error::ServiceError, period::Period};
+
+pub async fn handle(
+    req: OccupancyRequest,
+    pool: &PgPool,
+) -> Result<OccupancyResponse, ServiceError> {
+    let period = Period::parse(req.starts_at, req.ends_at).ok_or(ServiceError::InvalidPeriod)?;
+
+    let points: Vec<OccupancyPoint> = match req.granularity() {
+        Granularity::Hour => DBHourlyRollup::get_range(period.starts_at, period.ends_at, pool)
+            .await?
+            .into_iter()
+            .map(OccupancyPoint::from)
+            .collect(),
+        Granularity::Day => DBDailyRollup::get_range(period.starts_on(), period.ends_on(), pool)
+            .await?
+            .into_iter()
+            .map(OccupancyPoint::from)
+            .collect(),
+    };
+
+    Ok(OccupancyResponse { points })
+}
@@ -0,0 +1,18 @@
+use protos::analytics::{PeriodRequest, RatesResponse, SeatTypeRates};
+use sqlx::PgPool;
+
+use crate::models::db::DBSeatTypeRates;
+
+use super::{error::ServiceError, period::Period};
+
+pub async fn handle(req: PeriodRequest, pool: &PgPool) -> Result<RatesResponse, ServiceError> {
+    let period = Period::parse(req.starts_at, req.ends_at).ok_or(ServiceError::InvalidPeriod)?;
+
+    let rates = DBSeatTypeRates::get(period.starts_on(), period.ends_on(), pool)
+        .await?
+        .into_iter()
+        .map(SeatTypeRates::from)
+        .collect();
+
+    Ok(RatesResponse { rates })
+}
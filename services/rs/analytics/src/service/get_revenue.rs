@@ -0,0 +1,18 @@
+use protos::analytics::{PeriodRequest, RevenuePoint, RevenueResponse};
+use sqlx::PgPool;
+
+use crate::models::db::DBDailyRollup;
+
+use super::{error::ServiceError, period::Period};
+
+pub async fn handle(req: PeriodRequest, pool: &PgPool) -> Result<RevenueResponse, ServiceError> {
+    let period = Period::parse(req.starts_at, req.ends_at).ok_or(ServiceError::InvalidPeriod)?;
+
+    let points = DBDailyRollup::get_range(period.starts_on(), period.ends_on(), pool)
+        .await?
+        .into_iter()
+        .map(RevenuePoint::from)
+        .collect();
+
+    Ok(RevenueResponse { points })
+}
@@ -0,0 +1,90 @@
+use error::ServiceError;
+use protos::{
+    analytics::{
+        analytics_server::Analytics, HeatmapRequest, HeatmapResponse, OccupancyRequest,
+        OccupancyResponse, PeriodRequest, RatesResponse, RevenueResponse,
+    },
+    coworking::coworking_client::CoworkingClient,
+    reservation::reservation_client::ReservationClient,
+};
+use service_helper::channel::ServiceChannel;
+use service_helper::response::ServiceResult;
+use sqlx::{Pool, Postgres};
+use tonic::{async_trait, Request, Response};
+
+mod error;
+mod get_heatmap;
+mod get_occupancy;
+mod get_rates;
+mod get_revenue;
+mod period;
+mod rebuild;
+pub mod rollup;
+
+pub struct AnalyticsService {
+    pub postgres_pool: Pool<Postgres>,
+    pub coworking_client: CoworkingClient<ServiceChannel>,
+    pub reservation_client: ReservationClient<ServiceChannel>,
+}
+impl AnalyticsService {
+    pub fn new(
+        pool: Pool<Postgres>,
+        coworking_client: CoworkingClient<ServiceChannel>,
+        reservation_client: ReservationClient<ServiceChannel>,
+    ) -> Self {
+        Self {
+            postgres_pool: pool,
+            coworking_client,
+            reservation_client,
+        }
+    }
+}
+
+#[async_trait]
+impl Analytics for AnalyticsService {
+    async fn get_occupancy(
+        &self,
+        request: Request<OccupancyRequest>,
+    ) -> ServiceResult<OccupancyResponse> {
+        get_occupancy::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_heatmap(
+        &self,
+        request: Request<HeatmapRequest>,
+    ) -> ServiceResult<HeatmapResponse> {
+        get_heatmap::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_rates(&self, request: Request<PeriodRequest>) -> ServiceResult<RatesResponse> {
+        get_rates::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_revenue(&self, request: Request<PeriodRequest>) -> ServiceResult<RevenueResponse> {
+        get_revenue::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn rebuild(&self, request: Request<PeriodRequest>) -> ServiceResult<()> {
+        rebuild::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            &self.coworking_client,
+            &self.reservation_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use convertions::timestamp_into_datetime;
+use prost_types::Timestamp;
+
+pub const MAX_PERIOD_DAYS: i64 = 366;
+
+/// A period the rollups are queried or rebuilt for, `ends_at` is exclusive.
+#[derive(Clone, Copy, Debug)]
+pub struct Period {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+impl Period {
+    pub fn parse(starts_at: Timestamp, ends_at: Timestamp) -> Option<Self> {
+        let starts_at = timestamp_into_datetime(starts_at)?;
+        let ends_at = timestamp_into_datetime(ends_at)?;
+
+        if ends_at <= starts_at || ends_at - starts_at > TimeDelta::days(MAX_PERIOD_DAYS) {
+            return None;
+        }
+
+        Some(Self { starts_at, ends_at })
+    }
+
+    /// Days the period covers at least partially.
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let last_day = (self.ends_at - TimeDelta::nanoseconds(1)).date_naive();
+        self.starts_at
+            .date_naive()
+            .iter_days()
+            .take_while(move |day| *day <= last_day)
+    }
+
+    pub fn starts_on(&self) -> NaiveDate {
+        self.starts_at.date_naive()
+    }
+
+    /// The first day after the period, partially covered days are included.
+    pub fn ends_on(&self) -> NaiveDate {
+        self.days()
+            .last()
+            .unwrap_or(self.starts_on())
+            .succ_opt()
+            .unwrap()
+    }
+}
@@ -0,0 +1,19 @@
+use protos::{
+    analytics::PeriodRequest, coworking::coworking_client::CoworkingClient,
+    reservation::reservation_client::ReservationClient,
+};
+use service_helper::channel::ServiceChannel;
+use sqlx::PgPool;
+
+use super::{error::ServiceError, period::Period, rollup};
+
+pub async fn handle(
+    req: PeriodRequest,
+    pool: &PgPool,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    let period = Period::parse(req.starts_at, req.ends_at).ok_or(ServiceError::InvalidPeriod)?;
+
+    rollup::rebuild(period.days(), pool, coworking_client, reservation_client).await
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, Utc};
+use convertions::timestamp_into_datetime;
+use protos::{
+    coworking::{coworking_client::CoworkingClient, GetSeatsRequest},
+    reservation::{reservation_client::ReservationClient, GetRequest, ReservationResponse},
+};
+use service_helper::{channel::ServiceChannel, shutdown::Shutdown};
+use sqlx::PgPool;
+use tonic::Code;
+
+use crate::{
+    config,
+    models::db::{DBDailyRollup, DBHourlyRollup},
+};
+
+use super::error::ServiceError;
+
+const SEATS_PAGE_SIZE: u32 = 100;
+
+struct SeatInfo {
+    seat_type: String,
+    cost: f64,
+}
+
+/// Periodically rebuilds the rollups of the last few days, since check-outs,
+/// cancellations and no-shows keep changing them after the fact.
+pub async fn run(
+    pool: PgPool,
+    coworking_client: CoworkingClient<ServiceChannel>,
+    reservation_client: ReservationClient<ServiceChannel>,
+    shutdown: Shutdown,
+) {
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(*config::ROLLUP_INTERVAL_MINUTES * 60));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.requested() => break,
+        }
+
+        let today = Utc::now().date_naive();
+        let days = (0..=*config::ROLLUP_LOOKBACK_DAYS)
+            .rev()
+            .map(|days_ago| today - TimeDelta::days(days_ago));
+
+        if let Err(err) = rebuild(days, &pool, &coworking_client, &reservation_client).await {
+            tracing::error!("Failed to roll up recent reservations: {err}");
+        }
+    }
+}
+
+/// Recomputes the hourly and daily rollups of the days from scratch.
+pub async fn rebuild(
+    days: impl Iterator<Item = NaiveDate>,
+    pool: &PgPool,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    let seats = get_seats(coworking_client).await?;
+
+    for day in days {
+        let reservations = reservation_client
+            .clone()
+            .get(GetRequest {
+                limit: 0,
+                offset: 0,
+                day: Some(day.day()),
+                month: Some(day.month()),
+                year: Some(day.year() as u64),
+            })
+            .await?
+            .into_inner()
+            .reservations;
+
+        let (hourly, daily) = roll_up(day, &seats, &reservations, Utc::now());
+        let day_starts_at = day.and_time(NaiveTime::MIN).and_utc();
+
+        let mut transaction = pool.begin().await?;
+
+        DBHourlyRollup::delete_range(
+            day_starts_at,
+            day_starts_at + TimeDelta::days(1),
+            &mut *transaction,
+        )
+        .await?;
+        DBHourlyRollup::insert_many(&hourly, &mut *transaction).await?;
+
+        DBDailyRollup::delete(day, &mut *transaction).await?;
+        DBDailyRollup::insert_many(&daily, &mut *transaction).await?;
+
+        transaction.commit().await?;
+    }
+
+    Ok(())
+}
+
+async fn get_seats(
+    coworking_client: &CoworkingClient<ServiceChannel>,
+) -> Result<HashMap<String, SeatInfo>, ServiceError> {
+    let mut seats = HashMap::new();
+    let mut offset = 0;
+    loop {
+        let page = match coworking_client
+            .clone()
+            .get_seats(GetSeatsRequest {
+                limit: SEATS_PAGE_SIZE,
+                offset,
+            })
+            .await
+        {
+            Ok(response) => response.into_inner().seats,
+            Err(status) if status.code() == Code::NotFound => break,
+            Err(status) => return Err(status.into()),
+        };
+        let fetched = page.len();
+
+        seats.extend(page.into_iter().map(|seat| {
+            (
+                seat.id,
+                SeatInfo {
+                    seat_type: seat.r#type,
+                    cost: seat.cost,
+                },
+            )
+        }));
+
+        if fetched < SEATS_PAGE_SIZE as usize {
+            break;
+        }
+        offset += SEATS_PAGE_SIZE as u64;
+    }
+
+    Ok(seats)
+}
+
+/// Splits the reservations of the day into opening hours of every seat type.
+/// Reservations are counted on the day they start, the time they take on the day they overlap.
+fn roll_up(
+    day: NaiveDate,
+    seats: &HashMap<String, SeatInfo>,
+    reservations: &[ReservationResponse],
+    now: DateTime<Utc>,
+) -> (Vec<DBHourlyRollup>, Vec<DBDailyRollup>) {
+    let day_starts_at = day.and_time(NaiveTime::MIN).and_utc();
+    let hours: Vec<DateTime<Utc>> = (*config::OPENING_HOUR..*config::CLOSING_HOUR)
+        .map(|hour| day_starts_at + TimeDelta::hours(hour as i64))
+        .collect();
+
+    let mut seat_counts: BTreeMap<&str, i32> = BTreeMap::new();
+    for seat in seats.values() {
+        *seat_counts.entry(&seat.seat_type).or_default() += 1;
+    }
+    let type_indexes: HashMap<&str, usize> = seat_counts
+        .keys()
+        .enumerate()
+        .map(|(index, seat_type)| (*seat_type, index))
+        .collect();
+
+    let mut hourly: Vec<DBHourlyRollup> = hours
+        .iter()
+        .flat_map(|hour| {
+            seat_counts.iter().map(|(seat_type, seats)| DBHourlyRollup {
+                hour: *hour,
+                seat_type: seat_type.to_string(),
+                seats: *seats,
+                booked_minutes: 0.0,
+                occupied_minutes: 0.0,
+                revenue: 0.0,
+            })
+        })
+        .collect();
+    let mut daily: Vec<DBDailyRollup> = seat_counts
+        .iter()
+        .map(|(seat_type, seats)| DBDailyRollup {
+            day,
+            seat_type: seat_type.to_string(),
+            seats: *seats,
+            capacity_minutes: (*seats as usize * hours.len() * 60) as f64,
+            booked_minutes: 0.0,
+            occupied_minutes: 0.0,
+            revenue: 0.0,
+            reservations: 0,
+            cancellations: 0,
+            no_shows: 0,
+        })
+        .collect();
+
+    for reservation in reservations {
+        let Some(seat) = seats.get(&reservation.seat_id) else {
+            continue;
+        };
+        let type_index = type_indexes[seat.seat_type.as_str()];
+
+        let starts_at = timestamp_into_datetime(reservation.starts_at).unwrap();
+        let ends_at = timestamp_into_datetime(reservation.ends_at).unwrap();
+
+        if starts_at.date_naive() == day {
+            let rollup = &mut daily[type_index];
+            rollup.reservations += 1;
+            if is_no_show(reservation, starts_at, ends_at, now) {
+                rollup.no_shows += 1;
+            } else if reservation.is_canceled {
+                rollup.cancellations += 1;
+            }
+        }
+
+        if reservation.is_canceled {
+            continue;
+        }
+
+        let occupied = reservation
+            .checked_in_at
+            .and_then(timestamp_into_datetime)
+            .map(|checked_in_at| {
+                let checked_out_at = reservation
+                    .checked_out_at
+                    .and_then(timestamp_into_datetime)
+                    .unwrap_or(now.min(ends_at));
+                (checked_in_at, checked_out_at)
+            });
+
+        for (hour_index, hour) in hours.iter().enumerate() {
+            let hour_ends_at = *hour + TimeDelta::hours(1);
+            let rollup = &mut hourly[hour_index * seat_counts.len() + type_index];
+
+            let booked = overlap_minutes(starts_at, ends_at, *hour, hour_ends_at);
+            rollup.booked_minutes += booked;
+            rollup.revenue += booked / 60.0 * seat.cost;
+
+            if let Some((checked_in_at, checked_out_at)) = occupied {
+                rollup.occupied_minutes +=
+                    overlap_minutes(checked_in_at, checked_out_at, *hour, hour_ends_at);
+            }
+        }
+    }
+
+    for (index, rollup) in hourly.iter().enumerate() {
+        let daily = &mut daily[index % seat_counts.len()];
+        daily.booked_minutes += rollup.booked_minutes;
+        daily.occupied_minutes += rollup.occupied_minutes;
+        daily.revenue += rollup.revenue;
+    }
+
+    (hourly, daily)
+}
+
+/// A reservation nobody checked in to: either cancelled by the no-show sweep
+/// after it had started or already over without being cancelled at all.
+fn is_no_show(
+    reservation: &ReservationResponse,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> bool {
+    if reservation.is_visited {
+        return false;
+    }
+
+    match reservation.canceled_at.and_then(timestamp_into_datetime) {
+        Some(canceled_at) => reservation.is_canceled && canceled_at >= starts_at,
+        None => !reservation.is_canceled && ends_at <= now,
+    }
+}
+
+fn overlap_minutes(
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    bucket_starts_at: DateTime<Utc>,
+    bucket_ends_at: DateTime<Utc>,
+) -> f64 {
+    let overlap = ends_at.min(bucket_ends_at) - starts_at.max(bucket_starts_at);
+    overlap.num_seconds().max(0) as f64 / 60.0
+}
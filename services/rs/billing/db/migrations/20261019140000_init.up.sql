@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS invoices
+(
+    id               uuid             NOT NULL PRIMARY KEY,
+    client_id        uuid             NOT NULL,
+    kind             text             NOT NULL,
+    reservation_id   uuid,
+    period_starts_at timestamptz,
+    period_ends_at   timestamptz,
+    status           text             NOT NULL,
+    total            double precision NOT NULL,
+    currency         text             NOT NULL,
+    payment_provider text,
+    payment_id       text,
+    issued_at        timestamptz      NOT NULL,
+    paid_at          timestamptz,
+    refunded_at      timestamptz
+);
+
+CREATE INDEX IF NOT EXISTS invoices_client_id_idx ON invoices (client_id, issued_at);
+CREATE UNIQUE INDEX IF NOT EXISTS invoices_payment_id_idx ON invoices (payment_provider, payment_id);
+
+CREATE TABLE IF NOT EXISTS invoice_lines
+(
+    id             uuid             NOT NULL PRIMARY KEY,
+    invoice_id     uuid             NOT NULL REFERENCES invoices (id) ON DELETE CASCADE,
+    position       integer          NOT NULL,
+    reservation_id uuid,
+    description    text             NOT NULL,
+    hours          double precision NOT NULL,
+    amount         double precision NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS invoice_lines_invoice_id_idx ON invoice_lines (invoice_id, position);
+-- a reservation is billed at most once, either on its own or as a part of a monthly invoice
+CREATE UNIQUE INDEX IF NOT EXISTS invoice_lines_reservation_id_idx ON invoice_lines (reservation_id);
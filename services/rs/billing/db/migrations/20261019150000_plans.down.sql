@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS plan_usages;
+DROP TABLE IF EXISTS client_plans;
+DROP TABLE IF EXISTS plans;
\ No newline at end of file
@@ -0,0 +1,38 @@
+CREATE TABLE IF NOT EXISTS plans
+(
+    id             uuid             NOT NULL PRIMARY KEY,
+    name           text             NOT NULL,
+    description    text,
+    included_hours double precision NOT NULL,
+    -- empty means any seat type
+    seat_types     text[]           NOT NULL,
+    validity_days  integer          NOT NULL,
+    price          double precision NOT NULL,
+    is_active      boolean          NOT NULL,
+    created_at     timestamptz      NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS client_plans
+(
+    id         uuid             NOT NULL PRIMARY KEY,
+    client_id  uuid             NOT NULL,
+    plan_id    uuid             NOT NULL REFERENCES plans (id),
+    hours_left double precision NOT NULL,
+    starts_at  timestamptz      NOT NULL,
+    expires_at timestamptz      NOT NULL,
+    invoice_id uuid REFERENCES invoices (id)
+);
+
+CREATE INDEX IF NOT EXISTS client_plans_client_id_idx ON client_plans (client_id, expires_at);
+
+CREATE TABLE IF NOT EXISTS plan_usages
+(
+    id             uuid             NOT NULL PRIMARY KEY,
+    client_plan_id uuid             NOT NULL REFERENCES client_plans (id) ON DELETE CASCADE,
+    -- set once the reservation is created
+    reservation_id uuid,
+    hours          double precision NOT NULL,
+    created_at     timestamptz      NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS plan_usages_reservation_id_idx ON plan_usages (reservation_id);
@@ -0,0 +1,5 @@
+UPDATE client_plans
+SET hours_left = hours_left - $2
+WHERE id = $1
+  AND hours_left >= $2
+RETURNING *
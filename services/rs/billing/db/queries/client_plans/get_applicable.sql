@@ -0,0 +1,8 @@
+SELECT client_plans.*
+FROM client_plans
+         JOIN plans ON plans.id = client_plans.plan_id
+WHERE client_plans.client_id = $1
+  AND client_plans.starts_at <= $3
+  AND $3 < client_plans.expires_at
+  AND (cardinality(plans.seat_types) = 0 OR $2 = ANY (plans.seat_types))
+ORDER BY client_plans.expires_at
@@ -0,0 +1,5 @@
+SELECT *
+FROM client_plans
+WHERE client_id = $1
+  AND (expires_at > current_timestamp OR $2)
+ORDER BY expires_at DESC
@@ -0,0 +1,3 @@
+INSERT INTO client_plans (id, client_id, plan_id, hours_left, starts_at, expires_at, invoice_id)
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+RETURNING *
@@ -0,0 +1,4 @@
+UPDATE client_plans
+SET hours_left = hours_left + $2
+WHERE id = $1
+RETURNING *
@@ -0,0 +1,4 @@
+SELECT *
+FROM invoice_lines
+WHERE invoice_id = $1
+ORDER BY position
@@ -0,0 +1,3 @@
+SELECT reservation_id AS "reservation_id!"
+FROM invoice_lines
+WHERE reservation_id = ANY ($1)
@@ -0,0 +1,3 @@
+INSERT INTO invoice_lines (id, invoice_id, position, reservation_id, description, hours, amount)
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+RETURNING *
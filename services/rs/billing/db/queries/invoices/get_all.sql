@@ -0,0 +1,4 @@
+SELECT *
+FROM invoices
+ORDER BY issued_at DESC
+LIMIT $1 OFFSET $2
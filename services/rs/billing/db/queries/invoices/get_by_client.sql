@@ -0,0 +1,5 @@
+SELECT *
+FROM invoices
+WHERE client_id = $1
+ORDER BY issued_at DESC
+LIMIT $2 OFFSET $3
@@ -0,0 +1,3 @@
+SELECT *
+FROM invoices
+WHERE id = $1
@@ -0,0 +1,4 @@
+SELECT *
+FROM invoices
+WHERE payment_provider = $1
+  AND payment_id = $2
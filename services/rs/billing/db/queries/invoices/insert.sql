@@ -0,0 +1,4 @@
+INSERT INTO invoices (id, client_id, kind, reservation_id, period_starts_at, period_ends_at, status, total, currency,
+                      issued_at)
+VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7, $8, current_timestamp)
+RETURNING *
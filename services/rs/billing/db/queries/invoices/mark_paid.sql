@@ -0,0 +1,6 @@
+UPDATE invoices
+SET status  = 'paid',
+    paid_at = current_timestamp
+WHERE id = $1
+  AND status = 'pending'
+RETURNING *
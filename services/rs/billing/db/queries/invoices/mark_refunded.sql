@@ -0,0 +1,6 @@
+UPDATE invoices
+SET status      = 'refunded',
+    refunded_at = current_timestamp
+WHERE id = $1
+  AND status = 'paid'
+RETURNING *
@@ -0,0 +1,6 @@
+UPDATE invoices
+SET payment_provider = $2,
+    payment_id       = $3
+WHERE id = $1
+  AND status = 'pending'
+RETURNING *
@@ -0,0 +1,4 @@
+UPDATE plan_usages
+SET reservation_id = $2
+WHERE id = $1
+RETURNING *
@@ -0,0 +1,5 @@
+DELETE
+FROM plan_usages
+WHERE id = $1
+   OR reservation_id = $2
+RETURNING *
@@ -0,0 +1,3 @@
+INSERT INTO plan_usages (id, client_plan_id, hours, created_at)
+VALUES ($1, $2, $3, current_timestamp)
+RETURNING *
@@ -0,0 +1,5 @@
+SELECT *
+FROM plans
+WHERE is_active OR $1
+ORDER BY created_at
+LIMIT $2 OFFSET $3
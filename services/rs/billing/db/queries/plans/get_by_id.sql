@@ -0,0 +1,3 @@
+SELECT *
+FROM plans
+WHERE id = $1
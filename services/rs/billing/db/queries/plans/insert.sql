@@ -0,0 +1,3 @@
+INSERT INTO plans (id, name, description, included_hours, seat_types, validity_days, price, is_active, created_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, true, current_timestamp)
+RETURNING *
@@ -0,0 +1,7 @@
+UPDATE plans
+SET name        = COALESCE($2, name),
+    description = COALESCE($3, description),
+    price       = COALESCE($4, price),
+    is_active   = COALESCE($5, is_active)
+WHERE id = $1
+RETURNING *
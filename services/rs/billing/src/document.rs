@@ -0,0 +1,91 @@
+use std::fmt::Write;
+
+use protos::{client::ClientResponse, coworking::CoworkingResponse};
+
+use crate::models::db::{DBInvoice, DBInvoiceLine};
+
+pub const HTML_CONTENT_TYPE: &str = "text/html; charset=utf-8";
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em;color:#222}\
+table{border-collapse:collapse;width:100%}\
+th,td{border-bottom:1px solid #ccc;padding:.4em;text-align:left}\
+td.num,th.num{text-align:right}\
+.total{font-weight:bold}";
+
+/// Renders a standalone HTML invoice, which is printable to PDF from any browser.
+pub fn render_html(
+    invoice: &DBInvoice,
+    lines: &[DBInvoiceLine],
+    client: &ClientResponse,
+    coworking: &CoworkingResponse,
+) -> String {
+    let mut html = String::new();
+
+    let _ = write!(
+        html,
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+        <title>Invoice {id}</title><style>{STYLE}</style></head><body>\
+        <h1>Invoice {id}</h1>\
+        <p>{coworking_name}<br>{coworking_address}</p>\
+        <p>Billed to: {client_name}<br>{client_email}</p>\
+        <p>Issued: {issued_at} UTC<br>Status: {status}</p>",
+        id = invoice.id,
+        coworking_name = escape(&coworking.name),
+        coworking_address = escape(&coworking.address),
+        client_name = escape(&format!(
+            "{} {} {}",
+            client.meta.surname, client.meta.name, client.meta.patronymic
+        )),
+        client_email = escape(&client.meta.email),
+        issued_at = invoice.issued_at.format("%d.%m.%Y %H:%M"),
+        status = escape(&invoice.status),
+    );
+
+    if let (Some(starts_at), Some(ends_at)) = (invoice.period_starts_at, invoice.period_ends_at) {
+        let _ = write!(
+            html,
+            "<p>Period: {} - {}</p>",
+            starts_at.format("%d.%m.%Y"),
+            (ends_at - chrono::Duration::days(1)).format("%d.%m.%Y")
+        );
+    }
+
+    html.push_str(
+        "<table><thead><tr><th>#</th><th>Description</th>\
+        <th class=\"num\">Hours</th><th class=\"num\">Amount</th></tr></thead><tbody>",
+    );
+    for (number, line) in lines.iter().enumerate() {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td class=\"num\">{:.2}</td><td class=\"num\">{:.2}</td></tr>",
+            number + 1,
+            escape(&line.description),
+            line.hours,
+            line.amount
+        );
+    }
+    let _ = write!(
+        html,
+        "</tbody><tfoot><tr class=\"total\"><td colspan=\"3\">Total</td>\
+        <td class=\"num\">{:.2} {}</td></tr></tfoot></table></body></html>",
+        invoice.total,
+        escape(&invoice.currency)
+    );
+
+    html
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use billing::{config, provider, service::BillingService};
+use protos::{
+    billing::billing_server::BillingServer, client::client_client::ClientClient,
+    coworking::coworking_client::CoworkingClient,
+    reservation::reservation_client::ReservationClient,
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::PrincipalLayer,
+    shutdown::Shutdown,
+    telemetry::{self, TraceLayer},
+    tls::{self, CallerAllowlist},
+};
+use tonic::codec::CompressionEncoding;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("billing");
+    config::init();
+
+    let shutdown = Shutdown::on_signal();
+
+    postgres_helper::migrate!(config::DATABASE_URL.as_str(), "./db/migrations");
+
+    let pool = postgres_helper::connect(config::DATABASE_URL.as_str())
+        .await
+        .expect("Database connection failed");
+
+    let payment_provider = provider::from_config().unwrap_or_else(|err| panic!("{err}"));
+
+    let client_client = ClientClient::new(
+        ServiceChannel::lazy("client", &config::CLIENT_SERVICE_ADDRESS).retryable(&[
+            "/client.Client/ValidateToken",
+            "/client.Client/Get",
+            "/client.Client/GetMultiple",
+            "/client.Client/GetAll",
+            "/client.Client/GetByCalendarToken",
+        ]),
+    )
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    let coworking_client = CoworkingClient::new(
+        ServiceChannel::lazy("coworking", &config::COWORKING_SERVICE_ADDRESS).retryable(&[
+            "/coworking.Coworking/GetByID",
+            "/coworking.Coworking/GetSeat",
+            "/coworking.Coworking/GetSeats",
+        ]),
+    )
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    let reservation_client = ReservationClient::new(
+        ServiceChannel::lazy("reservation", &config::RESERVATION_SERVICE_ADDRESS).retryable(&[
+            "/reservation.Reservation/GetByID",
+            "/reservation.Reservation/GetBySeat",
+            "/reservation.Reservation/GetByClient",
+            "/reservation.Reservation/Get",
+            "/reservation.Reservation/GetVisited",
+            "/reservation.Reservation/GetNoShows",
+        ]),
+    )
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    let service = BillingServer::new(BillingService::new(
+        pool.clone(),
+        Arc::from(payment_provider),
+        client_client,
+        coworking_client,
+        reservation_client,
+    ))
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    tracing::info!(
+        "Started billing service on {}",
+        config::SERVICE_ADDRESS.as_str()
+    );
+
+    const GATEWAY: &[&str] = &["gateway"];
+    let callers = CallerAllowlist::new()
+        .allow("/billing.Billing/IssueReservationInvoice", GATEWAY)
+        .allow("/billing.Billing/IssueMonthlyInvoice", GATEWAY)
+        .allow("/billing.Billing/GetInvoice", GATEWAY)
+        .allow("/billing.Billing/GetInvoices", GATEWAY)
+        .allow("/billing.Billing/GetInvoiceDocument", GATEWAY)
+        .allow("/billing.Billing/Pay", GATEWAY)
+        .allow("/billing.Billing/Refund", GATEWAY)
+        .allow("/billing.Billing/HandlePaymentCallback", GATEWAY)
+        .allow("/billing.Billing/CreatePlan", GATEWAY)
+        .allow("/billing.Billing/GetPlan", GATEWAY)
+        .allow("/billing.Billing/GetPlans", GATEWAY)
+        .allow("/billing.Billing/UpdatePlan", GATEWAY)
+        .allow("/billing.Billing/DeletePlan", GATEWAY)
+        .allow("/billing.Billing/AssignPlan", GATEWAY)
+        .allow("/billing.Billing/GetClientPlans", GATEWAY)
+        .allow("/billing.Billing/DrawDown", GATEWAY)
+        .allow("/billing.Billing/AttachUsage", GATEWAY)
+        .allow("/billing.Billing/RestoreUsage", GATEWAY);
+
+    let server = tls::server()?
+        .layer(TraceLayer)
+        .layer(callers)
+        .layer(PrincipalLayer)
+        .add_service(service)
+        .serve_with_shutdown(config::SERVICE_ADDRESS.parse()?, shutdown.requested());
+    shutdown.drain(server).await?;
+
+    pool.close().await;
+    tracing::info!("Stopped billing service");
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use convertions::datetime_into_timestamp;
+use protos::billing::ClientPlanResponse;
+use sqlx::{query_file_as, Error, Executor, Postgres};
+use uuid::Uuid;
+
+use super::DBPlan;
+
+/// A plan bought by a client along with the hours left on it.
+#[derive(Clone, Debug)]
+pub struct DBClientPlan {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub plan_id: Uuid,
+    pub hours_left: f64,
+    pub starts_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub invoice_id: Option<Uuid>,
+}
+impl DBClientPlan {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/client_plans/insert.sql",
+            self.id,
+            self.client_id,
+            self.plan_id,
+            self.hours_left,
+            self.starts_at,
+            self.expires_at,
+            self.invoice_id
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_by_client<'a, E>(
+        client_id: Uuid,
+        include_expired: bool,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/client_plans/get_by_client.sql",
+            client_id,
+            include_expired
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Plans valid at `at` that cover the seat type, the ones expiring first come first.
+    pub async fn get_applicable<'a, E>(
+        client_id: Uuid,
+        seat_type: &str,
+        at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/client_plans/get_applicable.sql",
+            client_id,
+            seat_type,
+            at
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Takes the hours off the balance, `None` means there weren't enough hours left.
+    pub async fn draw_down<'a, E>(id: Uuid, hours: f64, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/client_plans/draw_down.sql", id, hours)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub async fn restore<'a, E>(id: Uuid, hours: f64, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/client_plans/restore.sql", id, hours)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub fn into_proto(self, plan: DBPlan) -> ClientPlanResponse {
+        ClientPlanResponse {
+            id: self.id.to_string(),
+            client_id: self.client_id.to_string(),
+            plan: plan.into(),
+            hours_left: self.hours_left,
+            starts_at: datetime_into_timestamp(self.starts_at),
+            expires_at: datetime_into_timestamp(self.expires_at),
+            invoice_id: self.invoice_id.map(|id| id.to_string()),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use chrono::{DateTime, Utc};
+use convertions::datetime_into_timestamp;
+use protos::billing::InvoiceResponse;
+use sqlx::{query_file_as, Error, Executor, Postgres};
+use uuid::Uuid;
+
+use super::DBInvoiceLine;
+
+#[derive(Clone, Debug)]
+pub struct DBInvoice {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub kind: String,
+    pub reservation_id: Option<Uuid>,
+    pub period_starts_at: Option<DateTime<Utc>>,
+    pub period_ends_at: Option<DateTime<Utc>>,
+    pub status: String,
+    pub total: f64,
+    pub currency: String,
+    pub payment_provider: Option<String>,
+    pub payment_id: Option<String>,
+    pub issued_at: DateTime<Utc>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub refunded_at: Option<DateTime<Utc>>,
+}
+impl DBInvoice {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/invoices/insert.sql",
+            self.id,
+            self.client_id,
+            self.kind,
+            self.reservation_id,
+            self.period_starts_at,
+            self.period_ends_at,
+            self.total,
+            self.currency
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_by_id<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/invoices/get_by_id.sql", id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub async fn get_by_client<'a, E>(
+        client_id: Uuid,
+        limit: i64,
+        offset: i64,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/invoices/get_by_client.sql",
+            client_id,
+            limit,
+            offset
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_all<'a, E>(limit: i64, offset: i64, executor: E) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/invoices/get_all.sql", limit, offset)
+            .fetch_all(executor)
+            .await
+    }
+
+    pub async fn get_by_payment<'a, E>(
+        provider: &str,
+        payment_id: &str,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/invoices/get_by_payment.sql",
+            provider,
+            payment_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Attaches a payment to a pending invoice, `None` means the invoice isn't pending anymore.
+    pub async fn set_payment<'a, E>(
+        id: Uuid,
+        provider: &str,
+        payment_id: &str,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/invoices/set_payment.sql",
+            id,
+            provider,
+            payment_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Marks a pending invoice as paid, `None` means the invoice isn't pending anymore.
+    pub async fn mark_paid<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/invoices/mark_paid.sql", id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    /// Marks a paid invoice as refunded, `None` means the invoice isn't paid.
+    pub async fn mark_refunded<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/invoices/mark_refunded.sql", id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub fn into_proto(self, lines: Vec<DBInvoiceLine>) -> InvoiceResponse {
+        InvoiceResponse {
+            id: self.id.to_string(),
+            client_id: self.client_id.to_string(),
+            kind: self.kind,
+            status: self.status,
+            total: self.total,
+            currency: self.currency,
+            lines: lines.into_iter().map(DBInvoiceLine::into).collect(),
+            reservation_id: self.reservation_id.map(|id| id.to_string()),
+            period_starts_at: self.period_starts_at.map(datetime_into_timestamp),
+            period_ends_at: self.period_ends_at.map(datetime_into_timestamp),
+            payment_id: self.payment_id,
+            issued_at: datetime_into_timestamp(self.issued_at),
+            paid_at: self.paid_at.map(datetime_into_timestamp),
+            refunded_at: self.refunded_at.map(datetime_into_timestamp),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use protos::billing::InvoiceLine;
+use sqlx::{query_file_as, query_file_scalar, Error, Executor, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DBInvoiceLine {
+    pub id: Uuid,
+    pub invoice_id: Uuid,
+    pub position: i32,
+    pub reservation_id: Option<Uuid>,
+    pub description: String,
+    pub hours: f64,
+    pub amount: f64,
+}
+impl DBInvoiceLine {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/invoice_lines/insert.sql",
+            self.id,
+            self.invoice_id,
+            self.position,
+            self.reservation_id,
+            self.description,
+            self.hours,
+            self.amount
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_by_invoice<'a, E>(invoice_id: Uuid, executor: E) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/invoice_lines/get_by_invoice.sql",
+            invoice_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Returns which of the reservations were already billed.
+    pub async fn get_invoiced_reservations<'a, E>(
+        reservation_ids: &[Uuid],
+        executor: E,
+    ) -> Result<Vec<Uuid>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_scalar!(
+            "db/queries/invoice_lines/get_invoiced_reservations.sql",
+            reservation_ids
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+impl From<DBInvoiceLine> for InvoiceLine {
+    fn from(value: DBInvoiceLine) -> Self {
+        Self {
+            description: value.description,
+            hours: value.hours,
+            amount: value.amount,
+            reservation_id: value.reservation_id.map(|id| id.to_string()),
+        }
+    }
+}
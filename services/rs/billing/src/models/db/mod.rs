@@ -0,0 +1,11 @@
+mod client_plan;
+mod invoice;
+mod invoice_line;
+mod plan;
+mod plan_usage;
+
+pub use client_plan::DBClientPlan;
+pub use invoice::DBInvoice;
+pub use invoice_line::DBInvoiceLine;
+pub use plan::DBPlan;
+pub use plan_usage::DBPlanUsage;
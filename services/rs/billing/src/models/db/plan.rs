@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use protos::billing::PlanResponse;
+use sqlx::{query_file_as, Error, Executor, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DBPlan {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub included_hours: f64,
+    pub seat_types: Vec<String>,
+    pub validity_days: i32,
+    pub price: f64,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+}
+impl DBPlan {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/plans/insert.sql",
+            self.id,
+            self.name,
+            self.description,
+            self.included_hours,
+            &self.seat_types,
+            self.validity_days,
+            self.price
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_by_id<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/plans/get_by_id.sql", id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub async fn get_all<'a, E>(
+        include_inactive: bool,
+        limit: i64,
+        offset: i64,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/plans/get_all.sql",
+            include_inactive,
+            limit,
+            offset
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn update<'a, E>(
+        id: Uuid,
+        name: Option<String>,
+        description: Option<String>,
+        price: Option<f64>,
+        is_active: Option<bool>,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/plans/update.sql",
+            id,
+            name,
+            description,
+            price,
+            is_active
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub fn covers(&self, seat_type: &str) -> bool {
+        self.seat_types.is_empty() || self.seat_types.iter().any(|t| t == seat_type)
+    }
+}
+impl From<DBPlan> for PlanResponse {
+    fn from(value: DBPlan) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            description: value.description,
+            included_hours: value.included_hours,
+            seat_types: value.seat_types,
+            validity_days: value.validity_days as u32,
+            price: value.price,
+            is_active: value.is_active,
+        }
+    }
+}
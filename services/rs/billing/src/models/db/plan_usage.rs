@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use sqlx::{query_file_as, Error, Executor, Postgres};
+use uuid::Uuid;
+
+/// Hours drawn from a client plan for a reservation.
+#[derive(Clone, Debug)]
+pub struct DBPlanUsage {
+    pub id: Uuid,
+    pub client_plan_id: Uuid,
+    pub reservation_id: Option<Uuid>,
+    pub hours: f64,
+    pub created_at: DateTime<Utc>,
+}
+impl DBPlanUsage {
+    pub async fn insert<'a, E>(
+        id: Uuid,
+        client_plan_id: Uuid,
+        hours: f64,
+        executor: E,
+    ) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/plan_usages/insert.sql",
+            id,
+            client_plan_id,
+            hours
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn attach<'a, E>(
+        id: Uuid,
+        reservation_id: Uuid,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/plan_usages/attach.sql",
+            id,
+            reservation_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Deletes the usage either by its id or by the reservation it was attached to.
+    pub async fn delete<'a, E>(
+        id: Option<Uuid>,
+        reservation_id: Option<Uuid>,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/plan_usages/delete.sql",
+            id,
+            reservation_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use uuid::Uuid;
+
+use super::{PaymentEvent, PaymentEventKind, PaymentProvider, ProviderError, ProviderPayment};
+
+/// Keeps the payments in memory and never charges anyone, so billing works offline.
+/// A payment is settled by posting `{"payment_id": "...", "event": "succeeded"}`
+/// (or `failed`, `refunded`) to the payment callback route.
+///
+/// When `FAKE_PROVIDER_SECRET` is set, the callbacks have to carry the hex-encoded
+/// HMAC-SHA256 of the body under it in the `X-Signature` header.
+/// The payments are lost on restart, along with the chance to settle them.
+pub struct FakeProvider {
+    secret: Option<Vec<u8>>,
+    payments: Mutex<HashMap<String, FakePayment>>,
+}
+
+#[derive(Debug)]
+struct FakePayment {
+    amount: f64,
+    refunded: bool,
+}
+
+#[derive(Deserialize)]
+struct FakeCallback {
+    payment_id: String,
+    event: FakeEvent,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FakeEvent {
+    Succeeded,
+    Failed,
+    Refunded,
+}
+
+impl FakeProvider {
+    /// The callbacks aren't checked for a signature if the secret is empty.
+    pub fn new(secret: &str) -> Self {
+        if secret.is_empty() {
+            tracing::warn!("`FAKE_PROVIDER_SECRET` isn't set, fake payment callbacks are unsigned");
+        }
+
+        Self {
+            secret: (!secret.is_empty()).then(|| secret.as_bytes().to_vec()),
+            payments: Mutex::default(),
+        }
+    }
+
+    fn mac(secret: &[u8]) -> Hmac<Sha256> {
+        Hmac::new_from_slice(secret).expect("HMAC accepts keys of any size")
+    }
+
+    /// Signature the callback with this payload has to carry, if the provider has a secret.
+    pub fn sign(&self, payload: &[u8]) -> Option<String> {
+        let mut mac = Self::mac(self.secret.as_ref()?);
+        mac.update(payload);
+        Some(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    fn verify(&self, payload: &[u8], signature: Option<&str>) -> Result<(), ProviderError> {
+        let Some(secret) = &self.secret else {
+            return Ok(());
+        };
+
+        let signature = signature
+            .and_then(|signature| hex::decode(signature.trim()).ok())
+            .ok_or(ProviderError::InvalidSignature)?;
+
+        let mut mac = Self::mac(secret);
+        mac.update(payload);
+        mac.verify_slice(&signature)
+            .map_err(|_| ProviderError::InvalidSignature)
+    }
+}
+
+#[async_trait]
+impl PaymentProvider for FakeProvider {
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+
+    async fn create_payment(
+        &self,
+        invoice_id: Uuid,
+        amount: f64,
+        currency: &str,
+    ) -> Result<ProviderPayment, ProviderError> {
+        let id = format!("fake-{}", Uuid::now_v7());
+        tracing::debug!(
+            "Created fake payment {id} of {amount} {currency} for invoice {invoice_id}"
+        );
+
+        self.payments.lock().unwrap().insert(
+            id.clone(),
+            FakePayment {
+                amount,
+                refunded: false,
+            },
+        );
+
+        Ok(ProviderPayment {
+            confirmation_url: Some(format!("fake://payments/{id}")),
+            id,
+        })
+    }
+
+    async fn refund(
+        &self,
+        payment_id: &str,
+        amount: f64,
+        currency: &str,
+    ) -> Result<(), ProviderError> {
+        let mut payments = self.payments.lock().unwrap();
+        let payment = payments
+            .get_mut(payment_id)
+            .ok_or_else(|| ProviderError::UnknownPayment(payment_id.to_string()))?;
+
+        if payment.refunded || amount > payment.amount {
+            return Err(ProviderError::Provider(format!(
+                "payment `{payment_id}` can't be refunded"
+            )));
+        }
+        payment.refunded = true;
+        tracing::debug!("Refunded {amount} {currency} of fake payment {payment_id}");
+
+        Ok(())
+    }
+
+    async fn parse_callback(
+        &self,
+        payload: &[u8],
+        signature: Option<&str>,
+    ) -> Result<PaymentEvent, ProviderError> {
+        self.verify(payload, signature)?;
+
+        let callback: FakeCallback = serde_json::from_slice(payload)
+            .map_err(|err| ProviderError::MalformedCallback(err.to_string()))?;
+
+        if !self
+            .payments
+            .lock()
+            .unwrap()
+            .contains_key(&callback.payment_id)
+        {
+            return Err(ProviderError::UnknownPayment(callback.payment_id));
+        }
+
+        Ok(PaymentEvent {
+            payment_id: callback.payment_id,
+            kind: match callback.event {
+                FakeEvent::Succeeded => PaymentEventKind::Succeeded,
+                FakeEvent::Failed => PaymentEventKind::Failed,
+                FakeEvent::Refunded => PaymentEventKind::Refunded,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn payment(provider: &FakeProvider) -> String {
+        provider
+            .create_payment(Uuid::now_v7(), 100.0, "RUB")
+            .await
+            .unwrap()
+            .id
+    }
+
+    fn callback(payment_id: &str, event: &str) -> Vec<u8> {
+        format!(r#"{{"payment_id": "{payment_id}", "event": "{event}"}}"#).into_bytes()
+    }
+
+    #[tokio::test]
+    async fn settles_created_payments() {
+        let provider = FakeProvider::new("");
+        let id = payment(&provider).await;
+
+        let event = provider
+            .parse_callback(&callback(&id, "succeeded"), None)
+            .await
+            .unwrap();
+
+        assert_eq!(event.payment_id, id);
+        assert_eq!(event.kind, PaymentEventKind::Succeeded);
+    }
+
+    #[tokio::test]
+    async fn rejects_callbacks_of_unknown_payments() {
+        let result = FakeProvider::new("")
+            .parse_callback(&callback("fake-1", "succeeded"), None)
+            .await;
+
+        assert!(matches!(result, Err(ProviderError::UnknownPayment(..))));
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_callbacks() {
+        let provider = FakeProvider::new("");
+        let id = payment(&provider).await;
+
+        let result = provider
+            .parse_callback(&callback(&id, "stolen"), None)
+            .await;
+
+        assert!(matches!(result, Err(ProviderError::MalformedCallback(..))));
+    }
+
+    #[tokio::test]
+    async fn refunds_payments_once() {
+        let provider = FakeProvider::new("");
+        let id = payment(&provider).await;
+
+        provider.refund(&id, 100.0, "RUB").await.unwrap();
+
+        assert!(provider.refund(&id, 100.0, "RUB").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn refunds_no_more_than_was_paid() {
+        let provider = FakeProvider::new("");
+        let id = payment(&provider).await;
+
+        assert!(provider.refund(&id, 150.0, "RUB").await.is_err());
+        assert!(matches!(
+            provider.refund("fake-1", 100.0, "RUB").await,
+            Err(ProviderError::UnknownPayment(..))
+        ));
+    }
+
+    #[tokio::test]
+    async fn accepts_signed_callbacks_with_a_secret() {
+        let provider = FakeProvider::new("test-secret");
+        let id = payment(&provider).await;
+        let payload = callback(&id, "refunded");
+        let signature = provider.sign(&payload).unwrap();
+
+        let event = provider
+            .parse_callback(&payload, Some(&signature))
+            .await
+            .unwrap();
+
+        assert_eq!(event.kind, PaymentEventKind::Refunded);
+    }
+
+    #[tokio::test]
+    async fn rejects_unsigned_callbacks_with_a_secret() {
+        let provider = FakeProvider::new("test-secret");
+        let id = payment(&provider).await;
+
+        let result = provider
+            .parse_callback(&callback(&id, "succeeded"), None)
+            .await;
+
+        assert!(matches!(result, Err(ProviderError::InvalidSignature)));
+    }
+
+    #[tokio::test]
+    async fn rejects_callbacks_signed_with_another_secret() {
+        let provider = FakeProvider::new("test-secret");
+        let id = payment(&provider).await;
+        let payload = callback(&id, "succeeded");
+        let signature = FakeProvider::new("other-secret").sign(&payload).unwrap();
+
+        let result = provider.parse_callback(&payload, Some(&signature)).await;
+
+        assert!(matches!(result, Err(ProviderError::InvalidSignature)));
+    }
+
+    #[tokio::test]
+    async fn rejects_tampered_payloads() {
+        let provider = FakeProvider::new("test-secret");
+        let id = payment(&provider).await;
+        let signature = provider.sign(&callback(&id, "failed")).unwrap();
+
+        let result = provider
+            .parse_callback(&callback(&id, "succeeded"), Some(&signature))
+            .await;
+
+        assert!(matches!(result, Err(ProviderError::InvalidSignature)));
+    }
+}
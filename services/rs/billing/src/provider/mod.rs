@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::config;
+
+mod fake;
+mod yookassa;
+
+pub use fake::FakeProvider;
+pub use yookassa::YooKassaProvider;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProviderError {
+    #[error("Malformed payment callback: {0}")]
+    MalformedCallback(String),
+
+    #[error("Payment callback signature is invalid")]
+    InvalidSignature,
+
+    #[error("Payment `{0}` is unknown to the provider")]
+    UnknownPayment(String),
+
+    #[error("Payment callback doesn't match the state of the payment")]
+    Unconfirmed,
+
+    #[error("Payment provider error: {0}")]
+    Provider(String),
+}
+
+#[derive(Debug)]
+pub struct ProviderPayment {
+    pub id: String,
+    pub confirmation_url: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PaymentEventKind {
+    Succeeded,
+    Failed,
+    Refunded,
+}
+
+#[derive(Debug)]
+pub struct PaymentEvent {
+    pub payment_id: String,
+    pub kind: PaymentEventKind,
+}
+
+/// A payment provider the invoices are paid through.
+/// Its callbacks reach [`PaymentProvider::parse_callback`] exactly as the gateway received them.
+#[async_trait]
+pub trait PaymentProvider: Send + Sync {
+    /// Stored along with the payment ids, so they don't clash between providers.
+    fn name(&self) -> &'static str;
+
+    async fn create_payment(
+        &self,
+        invoice_id: Uuid,
+        amount: f64,
+        currency: &str,
+    ) -> Result<ProviderPayment, ProviderError>;
+
+    async fn refund(
+        &self,
+        payment_id: &str,
+        amount: f64,
+        currency: &str,
+    ) -> Result<(), ProviderError>;
+
+    /// Has to make sure the callback comes from the provider, by its signature or by asking the provider.
+    async fn parse_callback(
+        &self,
+        payload: &[u8],
+        signature: Option<&str>,
+    ) -> Result<PaymentEvent, ProviderError>;
+}
+
+/// Builds the provider set by `PAYMENT_PROVIDER`.
+pub fn from_config() -> Result<Box<dyn PaymentProvider>, String> {
+    match config::PAYMENT_PROVIDER.as_str() {
+        "yookassa" => Ok(Box::new(YooKassaProvider::new(
+            config::YOOKASSA_API_URL.clone(),
+            &config::YOOKASSA_SHOP_ID,
+            config::YOOKASSA_SECRET_KEY.expose(),
+            &config::PAYMENT_RETURN_URL,
+        )?)),
+        "fake" => Ok(Box::new(FakeProvider::new(
+            config::FAKE_PROVIDER_SECRET.expose(),
+        ))),
+        other => Err(format!("Unknown payment provider `{other}`")),
+    }
+}
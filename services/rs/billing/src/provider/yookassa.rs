@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use url::Url;
+use uuid::Uuid;
+
+use super::{PaymentEvent, PaymentEventKind, PaymentProvider, ProviderError, ProviderPayment};
+
+const IDEMPOTENCE_KEY_HEADER: &str = "Idempotence-Key";
+
+/// Takes the payments through YooKassa and redirects the clients to its payment page.
+/// YooKassa doesn't sign its notifications, so each one is confirmed by fetching the payment it's about.
+pub struct YooKassaProvider {
+    client: Client,
+    api_url: Url,
+    shop_id: String,
+    secret_key: String,
+    return_url: String,
+}
+
+#[derive(Deserialize)]
+struct Amount {
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct Confirmation {
+    confirmation_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RemotePayment {
+    id: String,
+    status: String,
+    confirmation: Option<Confirmation>,
+    refunded_amount: Option<Amount>,
+}
+impl RemotePayment {
+    fn confirms(&self, kind: PaymentEventKind) -> bool {
+        match kind {
+            PaymentEventKind::Succeeded => self.status == "succeeded",
+            PaymentEventKind::Failed => self.status == "canceled",
+            PaymentEventKind::Refunded => self
+                .refunded_amount
+                .as_ref()
+                .and_then(|amount| amount.value.parse::<f64>().ok())
+                .is_some_and(|value| value > 0.0),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Notification {
+    event: String,
+    object: NotificationObject,
+}
+
+/// Either a payment or a refund, the latter refers to its payment by `payment_id`.
+#[derive(Deserialize)]
+struct NotificationObject {
+    id: String,
+    payment_id: Option<String>,
+}
+
+impl Notification {
+    fn parse(payload: &[u8]) -> Result<PaymentEvent, ProviderError> {
+        let notification: Notification = serde_json::from_slice(payload)
+            .map_err(|err| ProviderError::MalformedCallback(err.to_string()))?;
+
+        let (payment_id, kind) = match notification.event.as_str() {
+            "payment.succeeded" => (Some(notification.object.id), PaymentEventKind::Succeeded),
+            "payment.canceled" => (Some(notification.object.id), PaymentEventKind::Failed),
+            "refund.succeeded" => (notification.object.payment_id, PaymentEventKind::Refunded),
+            other => {
+                return Err(ProviderError::MalformedCallback(format!(
+                    "unsupported event `{other}`"
+                )))
+            }
+        };
+
+        Ok(PaymentEvent {
+            payment_id: payment_id.ok_or_else(|| {
+                ProviderError::MalformedCallback("refund has no `payment_id`".into())
+            })?,
+            kind,
+        })
+    }
+}
+
+impl YooKassaProvider {
+    pub fn new(
+        api_url: Url,
+        shop_id: &str,
+        secret_key: &str,
+        return_url: &str,
+    ) -> Result<Self, String> {
+        if shop_id.is_empty() || secret_key.is_empty() || return_url.is_empty() {
+            return Err(
+                "`YOOKASSA_SHOP_ID`, `YOOKASSA_SECRET_KEY` and `PAYMENT_RETURN_URL` \
+                have to be set to use YooKassa"
+                    .into(),
+            );
+        }
+
+        Ok(Self {
+            client: Client::new(),
+            api_url,
+            shop_id: shop_id.to_string(),
+            secret_key: secret_key.to_string(),
+            return_url: return_url.to_string(),
+        })
+    }
+
+    fn url(&self, path: &str) -> Result<Url, ProviderError> {
+        self.api_url
+            .join(path)
+            .map_err(|err| ProviderError::Provider(err.to_string()))
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        payment_id: &str,
+    ) -> Result<T, ProviderError> {
+        let response = request
+            .basic_auth(&self.shop_id, Some(&self.secret_key))
+            .send()
+            .await
+            .map_err(|err| ProviderError::Provider(err.to_string()))?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => Err(ProviderError::UnknownPayment(payment_id.to_string())),
+            status if !status.is_success() => Err(ProviderError::Provider(format!(
+                "YooKassa responded with {status}: {}",
+                response.text().await.unwrap_or_default()
+            ))),
+            _ => response
+                .json()
+                .await
+                .map_err(|err| ProviderError::Provider(err.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl PaymentProvider for YooKassaProvider {
+    fn name(&self) -> &'static str {
+        "yookassa"
+    }
+
+    async fn create_payment(
+        &self,
+        invoice_id: Uuid,
+        amount: f64,
+        currency: &str,
+    ) -> Result<ProviderPayment, ProviderError> {
+        let request = self
+            .client
+            .post(self.url("payments")?)
+            .header(IDEMPOTENCE_KEY_HEADER, Uuid::now_v7().to_string())
+            .json(&json!({
+                "amount": { "value": format!("{amount:.2}"), "currency": currency },
+                "capture": true,
+                "confirmation": { "type": "redirect", "return_url": self.return_url },
+                "description": format!("Invoice {invoice_id}"),
+                "metadata": { "invoice_id": invoice_id },
+            }));
+        let payment: RemotePayment = self.send(request, "").await?;
+
+        Ok(ProviderPayment {
+            confirmation_url: payment
+                .confirmation
+                .and_then(|confirmation| confirmation.confirmation_url),
+            id: payment.id,
+        })
+    }
+
+    async fn refund(
+        &self,
+        payment_id: &str,
+        amount: f64,
+        currency: &str,
+    ) -> Result<(), ProviderError> {
+        let request = self
+            .client
+            .post(self.url("refunds")?)
+            .header(IDEMPOTENCE_KEY_HEADER, Uuid::now_v7().to_string())
+            .json(&json!({
+                "payment_id": payment_id,
+                "amount": { "value": format!("{amount:.2}"), "currency": currency },
+            }));
+        self.send::<serde_json::Value>(request, payment_id).await?;
+
+        Ok(())
+    }
+
+    async fn parse_callback(
+        &self,
+        payload: &[u8],
+        _signature: Option<&str>,
+    ) -> Result<PaymentEvent, ProviderError> {
+        let event = Notification::parse(payload)?;
+
+        let request = self
+            .client
+            .get(self.url(&format!("payments/{}", event.payment_id))?);
+        let payment: RemotePayment = self.send(request, &event.payment_id).await?;
+
+        if !payment.confirms(event.kind) {
+            return Err(ProviderError::Unconfirmed);
+        }
+
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment(json: &str) -> RemotePayment {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn parses_payment_notifications() {
+        let event = Notification::parse(
+            br#"{"type": "notification", "event": "payment.succeeded",
+                "object": {"id": "2d8e6c0a", "status": "succeeded"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.payment_id, "2d8e6c0a");
+        assert_eq!(event.kind, PaymentEventKind::Succeeded);
+    }
+
+    #[test]
+    fn parses_refund_notifications_by_their_payment() {
+        let event = Notification::parse(
+            br#"{"type": "notification", "event": "refund.succeeded",
+                "object": {"id": "refund-1", "payment_id": "2d8e6c0a", "status": "succeeded"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.payment_id, "2d8e6c0a");
+        assert_eq!(event.kind, PaymentEventKind::Refunded);
+    }
+
+    #[test]
+    fn rejects_unsupported_events() {
+        let result = Notification::parse(
+            br#"{"type": "notification", "event": "payment.waiting_for_capture",
+                "object": {"id": "2d8e6c0a"}}"#,
+        );
+
+        assert!(matches!(result, Err(ProviderError::MalformedCallback(..))));
+    }
+
+    #[test]
+    fn confirms_only_matching_states() {
+        let pending = payment(r#"{"id": "1", "status": "pending"}"#);
+        let succeeded = payment(r#"{"id": "1", "status": "succeeded"}"#);
+        let refunded = payment(
+            r#"{"id": "1", "status": "succeeded", "refunded_amount": {"value": "10.00", "currency": "RUB"}}"#,
+        );
+
+        assert!(!pending.confirms(PaymentEventKind::Succeeded));
+        assert!(succeeded.confirms(PaymentEventKind::Succeeded));
+        assert!(!succeeded.confirms(PaymentEventKind::Failed));
+        assert!(!succeeded.confirms(PaymentEventKind::Refunded));
+        assert!(refunded.confirms(PaymentEventKind::Refunded));
+    }
+
+    #[test]
+    fn requires_credentials() {
+        let api_url = Url::parse("https://api.yookassa.ru/v3/").unwrap();
+
+        assert!(YooKassaProvider::new(api_url.clone(), "", "key", "https://example.com").is_err());
+        assert!(YooKassaProvider::new(api_url, "shop", "key", "https://example.com").is_ok());
+    }
+}
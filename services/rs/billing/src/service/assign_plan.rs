@@ -0,0 +1,71 @@
+use chrono::{TimeDelta, Utc};
+use protos::billing::{AssignPlanRequest, ClientPlanResponse};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBClientPlan, DBPlan};
+
+use super::{
+    error::ServiceError,
+    issue::{issue_in, NewInvoice, NewLine},
+};
+
+/// Sells the plan to the client. The hours are available right away,
+/// while a paid plan gets a pending invoice.
+pub async fn handle(
+    req: AssignPlanRequest,
+    pool: &PgPool,
+) -> Result<ClientPlanResponse, ServiceError> {
+    let client_id = Uuid::parse_str(&req.client_id).unwrap();
+    let plan_id = Uuid::parse_str(&req.plan_id).unwrap();
+
+    let plan = DBPlan::get_by_id(plan_id, pool)
+        .await?
+        .ok_or(ServiceError::PlanNotFound)?;
+    if !plan.is_active {
+        return Err(ServiceError::PlanInactive);
+    }
+
+    let starts_at = Utc::now();
+    let expires_at = starts_at + TimeDelta::days(plan.validity_days as i64);
+
+    let mut transaction = pool.begin().await?;
+
+    let invoice_id = if plan.price > 0.0 {
+        let (invoice, _) = issue_in(
+            NewInvoice {
+                client_id,
+                kind: "plan",
+                reservation_id: None,
+                period: Some((starts_at, expires_at)),
+                lines: vec![NewLine {
+                    reservation_id: None,
+                    description: format!("Plan \"{}\", {} days", plan.name, plan.validity_days),
+                    hours: plan.included_hours,
+                    amount: plan.price,
+                }],
+            },
+            &mut transaction,
+        )
+        .await?;
+        Some(invoice.id)
+    } else {
+        None
+    };
+
+    let client_plan = DBClientPlan {
+        id: Uuid::now_v7(),
+        client_id,
+        plan_id,
+        hours_left: plan.included_hours,
+        starts_at,
+        expires_at,
+        invoice_id,
+    }
+    .insert(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    Ok(client_plan.into_proto(plan))
+}
@@ -0,0 +1,18 @@
+use protos::billing::AttachUsageRequest;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBPlanUsage;
+
+use super::error::ServiceError;
+
+pub async fn handle(req: AttachUsageRequest, pool: &PgPool) -> Result<(), ServiceError> {
+    let id = Uuid::parse_str(&req.usage_id).unwrap();
+    let reservation_id = Uuid::parse_str(&req.reservation_id).unwrap();
+
+    DBPlanUsage::attach(id, reservation_id, pool)
+        .await?
+        .ok_or(ServiceError::UsageNotFound)?;
+
+    Ok(())
+}
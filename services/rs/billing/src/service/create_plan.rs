@@ -0,0 +1,25 @@
+use protos::billing::{CreatePlanRequest, PlanResponse};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBPlan;
+
+use super::error::ServiceError;
+
+pub async fn handle(req: CreatePlanRequest, pool: &PgPool) -> Result<PlanResponse, ServiceError> {
+    let plan = DBPlan {
+        id: Uuid::now_v7(),
+        name: req.name,
+        description: req.description,
+        included_hours: req.included_hours,
+        seat_types: req.seat_types,
+        validity_days: req.validity_days as i32,
+        price: req.price,
+        is_active: true,
+        created_at: chrono::Utc::now(),
+    }
+    .insert(pool)
+    .await?;
+
+    Ok(plan.into())
+}
@@ -0,0 +1,18 @@
+use protos::billing::PlanRequest;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBPlan;
+
+use super::error::ServiceError;
+
+/// Plans are only deactivated, so the clients who bought them keep their hours.
+pub async fn handle(req: PlanRequest, pool: &PgPool) -> Result<(), ServiceError> {
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    DBPlan::update(id, None, None, None, Some(false), pool)
+        .await?
+        .ok_or(ServiceError::PlanNotFound)?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use convertions::timestamp_into_datetime;
+use protos::{
+    billing::{DrawDownRequest, DrawDownResponse},
+    coworking::{coworking_client::CoworkingClient, SeatRequest},
+};
+use service_helper::channel::ServiceChannel;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBClientPlan, DBPlanUsage};
+
+use super::error::ServiceError;
+
+/// Takes the reservation hours off the first plan that covers them.
+/// Clients without a plan for the seat type pay per booking, so nothing is drawn down.
+pub async fn handle(
+    req: DrawDownRequest,
+    pool: &PgPool,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+) -> Result<DrawDownResponse, ServiceError> {
+    let client_id = Uuid::parse_str(&req.client_id).unwrap();
+    let starts_at = timestamp_into_datetime(req.starts_at).unwrap();
+    let ends_at = timestamp_into_datetime(req.ends_at).unwrap();
+    let hours = (ends_at - starts_at).num_seconds() as f64 / 3600.0;
+
+    let seat = coworking_client
+        .clone()
+        .get_seat(SeatRequest { id: req.seat_id })
+        .await?
+        .into_inner();
+
+    let applicable = DBClientPlan::get_applicable(client_id, &seat.r#type, starts_at, pool).await?;
+    if applicable.is_empty() {
+        return Ok(DrawDownResponse::default());
+    }
+
+    let mut transaction = pool.begin().await?;
+
+    for client_plan in applicable {
+        let Some(client_plan) =
+            DBClientPlan::draw_down(client_plan.id, hours, &mut *transaction).await?
+        else {
+            continue;
+        };
+
+        let usage =
+            DBPlanUsage::insert(Uuid::now_v7(), client_plan.id, hours, &mut *transaction).await?;
+
+        transaction.commit().await?;
+
+        return Ok(DrawDownResponse {
+            usage_id: Some(usage.id.to_string()),
+            client_plan_id: Some(client_plan.id.to_string()),
+            hours_left: Some(client_plan.hours_left),
+        });
+    }
+
+    Err(ServiceError::InsufficientBalance)
+}
@@ -0,0 +1,84 @@
+use tonic::{Code, Status};
+
+use crate::provider::ProviderError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error("{0}")]
+    Service(#[from] Status),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("{0}")]
+    Provider(#[from] ProviderError),
+
+    #[error("Invoice was not found")]
+    NotFound,
+
+    #[error("The call isn't made on behalf of anyone")]
+    Unauthenticated,
+
+    #[error("You're not allowed to access this invoice")]
+    NotOwner,
+
+    #[error("Cancelled reservations can't be invoiced")]
+    ReservationCancelled,
+
+    #[error("Reservation was already invoiced")]
+    AlreadyInvoiced,
+
+    #[error("There are no uninvoiced reservations in this period")]
+    NothingToInvoice,
+
+    #[error("Invalid billing period")]
+    InvalidPeriod,
+
+    #[error("Invoice is not pending payment")]
+    NotPending,
+
+    #[error("Only paid invoices can be refunded")]
+    NotPaid,
+
+    #[error("Plan was not found")]
+    PlanNotFound,
+
+    #[error("Plan is no longer available")]
+    PlanInactive,
+
+    #[error("Plan usage was not found")]
+    UsageNotFound,
+
+    #[error("Not enough hours left on the plan")]
+    InsufficientBalance,
+}
+
+impl From<ServiceError> for Status {
+    fn from(error: ServiceError) -> Self {
+        if let ServiceError::Service(status) = error {
+            return status;
+        }
+
+        let code: Code = match error {
+            ServiceError::Service(..) => unreachable!(),
+            ServiceError::Database(..) => Code::Internal,
+            ServiceError::Provider(ProviderError::Provider(..)) => Code::Internal,
+            ServiceError::Provider(..) => Code::InvalidArgument,
+            ServiceError::NotFound => Code::NotFound,
+            ServiceError::Unauthenticated => Code::Unauthenticated,
+            ServiceError::NotOwner => Code::PermissionDenied,
+            ServiceError::ReservationCancelled => Code::InvalidArgument,
+            ServiceError::AlreadyInvoiced => Code::AlreadyExists,
+            ServiceError::NothingToInvoice => Code::NotFound,
+            ServiceError::InvalidPeriod => Code::InvalidArgument,
+            ServiceError::NotPending => Code::AlreadyExists,
+            ServiceError::NotPaid => Code::InvalidArgument,
+            ServiceError::PlanNotFound => Code::NotFound,
+            ServiceError::PlanInactive => Code::InvalidArgument,
+            ServiceError::UsageNotFound => Code::NotFound,
+            ServiceError::InsufficientBalance => Code::ResourceExhausted,
+        };
+
+        Status::new(code, error.to_string())
+    }
+}
@@ -0,0 +1,34 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use protos::billing::{ClientPlansResponse, GetClientPlansRequest};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBClientPlan, DBPlan};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: GetClientPlansRequest,
+    pool: &PgPool,
+) -> Result<ClientPlansResponse, ServiceError> {
+    let client_id = Uuid::parse_str(&req.client_id).unwrap();
+
+    let client_plans = DBClientPlan::get_by_client(client_id, req.include_expired, pool).await?;
+
+    let mut plans = HashMap::new();
+    let mut response = Vec::with_capacity(client_plans.len());
+    for client_plan in client_plans {
+        if let Entry::Vacant(entry) = plans.entry(client_plan.plan_id) {
+            let plan = DBPlan::get_by_id(client_plan.plan_id, pool)
+                .await?
+                .ok_or(ServiceError::PlanNotFound)?;
+            entry.insert(plan);
+        }
+
+        let plan = plans[&client_plan.plan_id].clone();
+        response.push(client_plan.into_proto(plan));
+    }
+
+    Ok(ClientPlansResponse { plans: response })
+}
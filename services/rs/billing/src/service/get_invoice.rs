@@ -0,0 +1,38 @@
+use protos::billing::{GetInvoiceRequest, InvoiceResponse};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBInvoice, DBInvoiceLine};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: GetInvoiceRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<InvoiceResponse, ServiceError> {
+    let invoice = load(&req, principal, pool).await?;
+    let lines = DBInvoiceLine::get_by_invoice(invoice.id, pool).await?;
+
+    Ok(invoice.into_proto(lines))
+}
+
+/// Fetches the invoice, making sure that a client only gets their own invoices.
+pub async fn load(
+    req: &GetInvoiceRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<DBInvoice, ServiceError> {
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    let invoice = DBInvoice::get_by_id(id, pool)
+        .await?
+        .ok_or(ServiceError::NotFound)?;
+
+    if !principal.can_access(&invoice.client_id.to_string()) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    Ok(invoice)
+}
@@ -0,0 +1,47 @@
+use protos::{
+    billing::{GetInvoiceRequest, InvoiceDocumentResponse},
+    client::{client_client::ClientClient, ClientRequest},
+    coworking::{coworking_client::CoworkingClient, GetCoworkingByIdRequest},
+};
+use service_helper::{channel::ServiceChannel, principal::Principal};
+use sqlx::PgPool;
+
+use crate::{config, document, models::db::DBInvoiceLine};
+
+use super::{error::ServiceError, get_invoice::load};
+
+pub async fn handle(
+    req: GetInvoiceRequest,
+    principal: &Principal,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+) -> Result<InvoiceDocumentResponse, ServiceError> {
+    let invoice = load(&req, principal, pool).await?;
+    let lines = DBInvoiceLine::get_by_invoice(invoice.id, pool).await?;
+
+    let client = client_client
+        .clone()
+        .get(ClientRequest {
+            id: invoice.client_id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let coworking = coworking_client
+        .clone()
+        .get_by_id(GetCoworkingByIdRequest {
+            id: config::COWORKING_ID.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let filename = format!("invoice-{}.html", invoice.id);
+    let content = document::render_html(&invoice, &lines, &client, &coworking);
+
+    Ok(InvoiceDocumentResponse {
+        filename,
+        content_type: document::HTML_CONTENT_TYPE.to_string(),
+        content: content.into_bytes(),
+    })
+}
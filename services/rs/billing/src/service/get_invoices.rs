@@ -0,0 +1,30 @@
+use protos::billing::{GetInvoicesRequest, InvoicesResponse};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBInvoice, DBInvoiceLine};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: GetInvoicesRequest,
+    pool: &PgPool,
+) -> Result<InvoicesResponse, ServiceError> {
+    let (limit, offset) = (req.limit as i64, req.offset as i64);
+
+    let stored = match req.client_id {
+        Some(client_id) => {
+            let client_id = Uuid::parse_str(&client_id).unwrap();
+            DBInvoice::get_by_client(client_id, limit, offset, pool).await?
+        }
+        None => DBInvoice::get_all(limit, offset, pool).await?,
+    };
+
+    let mut invoices = Vec::with_capacity(stored.len());
+    for invoice in stored {
+        let lines = DBInvoiceLine::get_by_invoice(invoice.id, pool).await?;
+        invoices.push(invoice.into_proto(lines));
+    }
+
+    Ok(InvoicesResponse { invoices })
+}
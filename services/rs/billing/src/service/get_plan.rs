@@ -0,0 +1,16 @@
+use protos::billing::{PlanRequest, PlanResponse};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBPlan;
+
+use super::error::ServiceError;
+
+pub async fn handle(req: PlanRequest, pool: &PgPool) -> Result<PlanResponse, ServiceError> {
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    DBPlan::get_by_id(id, pool)
+        .await?
+        .map(DBPlan::into)
+        .ok_or(ServiceError::PlanNotFound)
+}
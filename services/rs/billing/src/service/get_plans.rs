@@ -0,0 +1,20 @@
+use protos::billing::{GetPlansRequest, PlansResponse};
+use sqlx::PgPool;
+
+use crate::models::db::DBPlan;
+
+use super::error::ServiceError;
+
+pub async fn handle(req: GetPlansRequest, pool: &PgPool) -> Result<PlansResponse, ServiceError> {
+    let plans = DBPlan::get_all(
+        req.include_inactive,
+        req.limit as i64,
+        req.offset as i64,
+        pool,
+    )
+    .await?;
+
+    Ok(PlansResponse {
+        plans: plans.into_iter().map(DBPlan::into).collect(),
+    })
+}
@@ -0,0 +1,42 @@
+use protos::billing::{InvoiceResponse, PaymentCallbackRequest};
+use sqlx::PgPool;
+
+use crate::{
+    models::db::{DBInvoice, DBInvoiceLine},
+    provider::{PaymentEventKind, PaymentProvider},
+};
+
+use super::error::ServiceError;
+
+/// Applies a provider's payment event to the invoice.
+/// Providers may deliver an event more than once, so repeated events leave the invoice as it is.
+pub async fn handle(
+    req: PaymentCallbackRequest,
+    pool: &PgPool,
+    provider: &dyn PaymentProvider,
+) -> Result<InvoiceResponse, ServiceError> {
+    let event = provider
+        .parse_callback(&req.payload, req.signature.as_deref())
+        .await?;
+
+    let invoice = DBInvoice::get_by_payment(provider.name(), &event.payment_id, pool)
+        .await?
+        .ok_or(ServiceError::NotFound)?;
+
+    let updated = match event.kind {
+        PaymentEventKind::Succeeded => DBInvoice::mark_paid(invoice.id, pool).await?,
+        PaymentEventKind::Refunded => DBInvoice::mark_refunded(invoice.id, pool).await?,
+        PaymentEventKind::Failed => {
+            tracing::warn!(
+                "Payment {} of invoice {} failed",
+                event.payment_id,
+                invoice.id
+            );
+            None
+        }
+    };
+
+    let lines = DBInvoiceLine::get_by_invoice(invoice.id, pool).await?;
+
+    Ok(updated.unwrap_or(invoice).into_proto(lines))
+}
@@ -0,0 +1,127 @@
+use convertions::timestamp_into_datetime;
+use protos::{billing::InvoiceResponse, coworking::SeatResponse, reservation::ReservationResponse};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    config,
+    models::db::{DBInvoice, DBInvoiceLine},
+};
+
+use super::error::ServiceError;
+
+pub struct NewLine {
+    pub reservation_id: Option<Uuid>,
+    pub description: String,
+    pub hours: f64,
+    pub amount: f64,
+}
+impl NewLine {
+    /// Bills the price fixed on the reservation. Reservations booked before pricing
+    /// existed have none, so they are billed at the seat's hourly cost.
+    pub fn from_reservation(reservation: &ReservationResponse, seat: &SeatResponse) -> Self {
+        let starts_at = timestamp_into_datetime(reservation.starts_at).unwrap();
+        let ends_at = timestamp_into_datetime(reservation.ends_at).unwrap();
+        let hours = (ends_at - starts_at).num_seconds() as f64 / 3600.0;
+
+        let amount = reservation.price.unwrap_or(seat.cost * hours);
+
+        Self {
+            reservation_id: Some(Uuid::parse_str(&reservation.id).unwrap()),
+            description: format!(
+                "Seat #{} ({}), {} - {} UTC",
+                seat.number,
+                seat.r#type,
+                starts_at.format("%d.%m.%Y %H:%M"),
+                ends_at.format("%H:%M")
+            ),
+            hours: round_to_cents(hours),
+            amount: round_to_cents(amount),
+        }
+    }
+}
+
+pub struct NewInvoice {
+    pub client_id: Uuid,
+    pub kind: &'static str,
+    pub reservation_id: Option<Uuid>,
+    pub period: Option<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)>,
+    pub lines: Vec<NewLine>,
+}
+
+/// Stores the invoice along with its lines in one transaction.
+pub async fn issue(invoice: NewInvoice, pool: &PgPool) -> Result<InvoiceResponse, ServiceError> {
+    let mut transaction = pool.begin().await?;
+
+    let (invoice, lines) = issue_in(invoice, &mut transaction).await?;
+
+    transaction.commit().await?;
+
+    Ok(invoice.into_proto(lines))
+}
+
+/// Stores the invoice as a part of a bigger transaction.
+pub async fn issue_in(
+    invoice: NewInvoice,
+    transaction: &mut Transaction<'_, Postgres>,
+) -> Result<(DBInvoice, Vec<DBInvoiceLine>), ServiceError> {
+    let reservation_ids: Vec<Uuid> = invoice
+        .lines
+        .iter()
+        .filter_map(|line| line.reservation_id)
+        .collect();
+    if !DBInvoiceLine::get_invoiced_reservations(&reservation_ids, &mut **transaction)
+        .await?
+        .is_empty()
+    {
+        return Err(ServiceError::AlreadyInvoiced);
+    }
+
+    let stored = DBInvoice {
+        id: Uuid::now_v7(),
+        client_id: invoice.client_id,
+        kind: invoice.kind.to_string(),
+        reservation_id: invoice.reservation_id,
+        period_starts_at: invoice.period.map(|(starts_at, _)| starts_at),
+        period_ends_at: invoice.period.map(|(_, ends_at)| ends_at),
+        status: "pending".to_string(),
+        total: round_to_cents(invoice.lines.iter().map(|line| line.amount).sum()),
+        currency: config::CURRENCY.to_string(),
+        payment_provider: None,
+        payment_id: None,
+        issued_at: chrono::Utc::now(),
+        paid_at: None,
+        refunded_at: None,
+    }
+    .insert(&mut **transaction)
+    .await?;
+
+    let mut lines = Vec::with_capacity(invoice.lines.len());
+    for (position, line) in invoice.lines.into_iter().enumerate() {
+        let line = DBInvoiceLine {
+            id: Uuid::now_v7(),
+            invoice_id: stored.id,
+            position: position as i32,
+            reservation_id: line.reservation_id,
+            description: line.description,
+            hours: line.hours,
+            amount: line.amount,
+        }
+        .insert(&mut **transaction)
+        .await
+        .map_err(|err| match err {
+            // another invoice got the reservation in the meantime
+            sqlx::Error::Database(err) if err.is_unique_violation() => {
+                ServiceError::AlreadyInvoiced
+            }
+            err => err.into(),
+        })?;
+        lines.push(line);
+    }
+
+    Ok((stored, lines))
+}
+
+pub fn round_to_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
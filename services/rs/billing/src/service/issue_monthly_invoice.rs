@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use chrono::{Months, NaiveDate, NaiveTime};
+use convertions::timestamp_into_datetime;
+use protos::{
+    billing::{InvoiceResponse, IssueMonthlyInvoiceRequest},
+    coworking::{coworking_client::CoworkingClient, SeatRequest},
+    reservation::{reservation_client::ReservationClient, GetByClientRequest},
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBInvoiceLine;
+
+use super::{
+    error::ServiceError,
+    issue::{issue, NewInvoice, NewLine},
+};
+
+const RESERVATIONS_PAGE_SIZE: u32 = 100;
+
+/// Bills every reservation of the client in the month that wasn't invoiced on its own.
+pub async fn handle(
+    req: IssueMonthlyInvoiceRequest,
+    pool: &PgPool,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+) -> Result<InvoiceResponse, ServiceError> {
+    let client_id = Uuid::parse_str(&req.client_id).unwrap();
+
+    let period_starts_at = i32::try_from(req.year)
+        .ok()
+        .and_then(|year| NaiveDate::from_ymd_opt(year, req.month, 1))
+        .ok_or(ServiceError::InvalidPeriod)?
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    let period_ends_at = period_starts_at
+        .checked_add_months(Months::new(1))
+        .ok_or(ServiceError::InvalidPeriod)?;
+
+    let mut reservations = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page = principal::scope(
+            Principal::service("billing"),
+            reservation_client
+                .clone()
+                .get_by_client(GetByClientRequest {
+                    client_id: req.client_id.clone(),
+                    limit: RESERVATIONS_PAGE_SIZE,
+                    offset,
+                }),
+        )
+        .await?
+        .into_inner()
+        .reservations;
+        let fetched = page.len();
+
+        reservations.extend(page.into_iter().filter(|reservation| {
+            let starts_at = timestamp_into_datetime(reservation.starts_at).unwrap();
+            !reservation.is_canceled && period_starts_at <= starts_at && starts_at < period_ends_at
+        }));
+
+        if fetched < RESERVATIONS_PAGE_SIZE as usize {
+            break;
+        }
+        offset += RESERVATIONS_PAGE_SIZE as u64;
+    }
+
+    let ids: Vec<Uuid> = reservations
+        .iter()
+        .map(|reservation| Uuid::parse_str(&reservation.id).unwrap())
+        .collect();
+    let invoiced = DBInvoiceLine::get_invoiced_reservations(&ids, pool).await?;
+    reservations
+        .retain(|reservation| !invoiced.contains(&Uuid::parse_str(&reservation.id).unwrap()));
+
+    if reservations.is_empty() {
+        return Err(ServiceError::NothingToInvoice);
+    }
+    reservations.sort_by_key(|reservation| reservation.starts_at.seconds);
+
+    let mut seats = HashMap::new();
+    let mut lines = Vec::with_capacity(reservations.len());
+    for reservation in &reservations {
+        if !seats.contains_key(&reservation.seat_id) {
+            let seat = coworking_client
+                .clone()
+                .get_seat(SeatRequest {
+                    id: reservation.seat_id.clone(),
+                })
+                .await?
+                .into_inner();
+            seats.insert(reservation.seat_id.clone(), seat);
+        }
+
+        lines.push(NewLine::from_reservation(
+            reservation,
+            &seats[&reservation.seat_id],
+        ));
+    }
+
+    issue(
+        NewInvoice {
+            client_id,
+            kind: "monthly",
+            reservation_id: None,
+            period: Some((period_starts_at, period_ends_at)),
+            lines,
+        },
+        pool,
+    )
+    .await
+}
@@ -0,0 +1,58 @@
+use protos::{
+    billing::{InvoiceResponse, IssueReservationInvoiceRequest},
+    coworking::{coworking_client::CoworkingClient, SeatRequest},
+    reservation::{reservation_client::ReservationClient, GetByIdRequest},
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::{
+    error::ServiceError,
+    issue::{issue, NewInvoice, NewLine},
+};
+
+pub async fn handle(
+    req: IssueReservationInvoiceRequest,
+    pool: &PgPool,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+) -> Result<InvoiceResponse, ServiceError> {
+    let reservation = principal::scope(
+        Principal::service("billing"),
+        reservation_client.clone().get_by_id(GetByIdRequest {
+            id: req.reservation_id,
+        }),
+    )
+    .await?
+    .into_inner();
+
+    if reservation.is_canceled {
+        return Err(ServiceError::ReservationCancelled);
+    }
+
+    let seat = coworking_client
+        .clone()
+        .get_seat(SeatRequest {
+            id: reservation.seat_id.clone(),
+        })
+        .await?
+        .into_inner();
+
+    let line = NewLine::from_reservation(&reservation, &seat);
+
+    issue(
+        NewInvoice {
+            client_id: Uuid::parse_str(&reservation.client_id).unwrap(),
+            kind: "reservation",
+            reservation_id: line.reservation_id,
+            period: None,
+            lines: vec![line],
+        },
+        pool,
+    )
+    .await
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use error::ServiceError;
+use protos::{
+    billing::{
+        billing_server::Billing, AssignPlanRequest, AttachUsageRequest, ClientPlanResponse,
+        ClientPlansResponse, CreatePlanRequest, DrawDownRequest, DrawDownResponse,
+        GetClientPlansRequest, GetInvoiceRequest, GetInvoicesRequest, GetPlansRequest,
+        InvoiceDocumentResponse, InvoiceResponse, InvoicesResponse, IssueMonthlyInvoiceRequest,
+        IssueReservationInvoiceRequest, PaymentCallbackRequest, PaymentResponse, PlanRequest,
+        PlanResponse, PlansResponse, RefundRequest, RestoreUsageRequest, UpdatePlanRequest,
+    },
+    client::client_client::ClientClient,
+    coworking::coworking_client::CoworkingClient,
+    reservation::reservation_client::ReservationClient,
+};
+use service_helper::channel::ServiceChannel;
+use service_helper::principal;
+use service_helper::response::ServiceResult;
+use sqlx::{Pool, Postgres};
+use tonic::{async_trait, Request, Response};
+
+use crate::provider::PaymentProvider;
+
+mod assign_plan;
+mod attach_usage;
+mod create_plan;
+mod delete_plan;
+mod draw_down;
+mod error;
+mod get_client_plans;
+mod get_invoice;
+mod get_invoice_document;
+mod get_invoices;
+mod get_plan;
+mod get_plans;
+mod handle_payment_callback;
+mod issue;
+mod issue_monthly_invoice;
+mod issue_reservation_invoice;
+mod pay;
+mod refund;
+mod restore_usage;
+mod update_plan;
+
+pub struct BillingService {
+    pub postgres_pool: Pool<Postgres>,
+    pub payment_provider: Arc<dyn PaymentProvider>,
+    pub client_client: ClientClient<ServiceChannel>,
+    pub coworking_client: CoworkingClient<ServiceChannel>,
+    pub reservation_client: ReservationClient<ServiceChannel>,
+}
+impl BillingService {
+    pub fn new(
+        pool: Pool<Postgres>,
+        payment_provider: Arc<dyn PaymentProvider>,
+        client_client: ClientClient<ServiceChannel>,
+        coworking_client: CoworkingClient<ServiceChannel>,
+        reservation_client: ReservationClient<ServiceChannel>,
+    ) -> Self {
+        Self {
+            postgres_pool: pool,
+            payment_provider,
+            client_client,
+            coworking_client,
+            reservation_client,
+        }
+    }
+}
+
+#[async_trait]
+impl Billing for BillingService {
+    async fn issue_reservation_invoice(
+        &self,
+        request: Request<IssueReservationInvoiceRequest>,
+    ) -> ServiceResult<InvoiceResponse> {
+        issue_reservation_invoice::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            &self.coworking_client,
+            &self.reservation_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn issue_monthly_invoice(
+        &self,
+        request: Request<IssueMonthlyInvoiceRequest>,
+    ) -> ServiceResult<InvoiceResponse> {
+        issue_monthly_invoice::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            &self.coworking_client,
+            &self.reservation_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn get_invoice(
+        &self,
+        request: Request<GetInvoiceRequest>,
+    ) -> ServiceResult<InvoiceResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_invoice::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_invoices(
+        &self,
+        request: Request<GetInvoicesRequest>,
+    ) -> ServiceResult<InvoicesResponse> {
+        get_invoices::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_invoice_document(
+        &self,
+        request: Request<GetInvoiceRequest>,
+    ) -> ServiceResult<InvoiceDocumentResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_invoice_document::handle(
+            request.into_inner(),
+            &principal,
+            &self.postgres_pool,
+            &self.client_client,
+            &self.coworking_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn pay(&self, request: Request<GetInvoiceRequest>) -> ServiceResult<PaymentResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        pay::handle(
+            request.into_inner(),
+            &principal,
+            &self.postgres_pool,
+            self.payment_provider.as_ref(),
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn refund(&self, request: Request<RefundRequest>) -> ServiceResult<InvoiceResponse> {
+        refund::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            self.payment_provider.as_ref(),
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn handle_payment_callback(
+        &self,
+        request: Request<PaymentCallbackRequest>,
+    ) -> ServiceResult<InvoiceResponse> {
+        handle_payment_callback::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            self.payment_provider.as_ref(),
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn create_plan(
+        &self,
+        request: Request<CreatePlanRequest>,
+    ) -> ServiceResult<PlanResponse> {
+        create_plan::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_plan(&self, request: Request<PlanRequest>) -> ServiceResult<PlanResponse> {
+        get_plan::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_plans(&self, request: Request<GetPlansRequest>) -> ServiceResult<PlansResponse> {
+        get_plans::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn update_plan(
+        &self,
+        request: Request<UpdatePlanRequest>,
+    ) -> ServiceResult<PlanResponse> {
+        update_plan::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn delete_plan(&self, request: Request<PlanRequest>) -> ServiceResult<()> {
+        delete_plan::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn assign_plan(
+        &self,
+        request: Request<AssignPlanRequest>,
+    ) -> ServiceResult<ClientPlanResponse> {
+        assign_plan::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_client_plans(
+        &self,
+        request: Request<GetClientPlansRequest>,
+    ) -> ServiceResult<ClientPlansResponse> {
+        get_client_plans::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn draw_down(
+        &self,
+        request: Request<DrawDownRequest>,
+    ) -> ServiceResult<DrawDownResponse> {
+        draw_down::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            &self.coworking_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn attach_usage(&self, request: Request<AttachUsageRequest>) -> ServiceResult<()> {
+        attach_usage::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn restore_usage(&self, request: Request<RestoreUsageRequest>) -> ServiceResult<()> {
+        restore_usage::handle(request.into_inner(), &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+}
@@ -0,0 +1,34 @@
+use protos::billing::{GetInvoiceRequest, PaymentResponse};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+
+use crate::{models::db::DBInvoice, provider::PaymentProvider};
+
+use super::{error::ServiceError, get_invoice::load};
+
+/// Starts a payment of the invoice. The invoice is marked as paid once the provider reports it.
+pub async fn handle(
+    req: GetInvoiceRequest,
+    principal: &Principal,
+    pool: &PgPool,
+    provider: &dyn PaymentProvider,
+) -> Result<PaymentResponse, ServiceError> {
+    let invoice = load(&req, principal, pool).await?;
+    if invoice.status != "pending" {
+        return Err(ServiceError::NotPending);
+    }
+
+    let payment = provider
+        .create_payment(invoice.id, invoice.total, &invoice.currency)
+        .await?;
+
+    DBInvoice::set_payment(invoice.id, provider.name(), &payment.id, pool)
+        .await?
+        .ok_or(ServiceError::NotPending)?;
+
+    Ok(PaymentResponse {
+        invoice_id: invoice.id.to_string(),
+        payment_id: payment.id,
+        confirmation_url: payment.confirmation_url,
+    })
+}
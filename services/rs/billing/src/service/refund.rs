@@ -0,0 +1,38 @@
+use protos::billing::{InvoiceResponse, RefundRequest};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    models::db::{DBInvoice, DBInvoiceLine},
+    provider::PaymentProvider,
+};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: RefundRequest,
+    pool: &PgPool,
+    provider: &dyn PaymentProvider,
+) -> Result<InvoiceResponse, ServiceError> {
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    let invoice = DBInvoice::get_by_id(id, pool)
+        .await?
+        .ok_or(ServiceError::NotFound)?;
+
+    let payment_id = match (invoice.status.as_str(), &invoice.payment_id) {
+        ("paid", Some(payment_id)) => payment_id,
+        _ => return Err(ServiceError::NotPaid),
+    };
+
+    provider
+        .refund(payment_id, invoice.total, &invoice.currency)
+        .await?;
+
+    let invoice = DBInvoice::mark_refunded(id, pool)
+        .await?
+        .ok_or(ServiceError::NotPaid)?;
+    let lines = DBInvoiceLine::get_by_invoice(id, pool).await?;
+
+    Ok(invoice.into_proto(lines))
+}
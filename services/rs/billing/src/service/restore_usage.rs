@@ -0,0 +1,24 @@
+use protos::billing::RestoreUsageRequest;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBClientPlan, DBPlanUsage};
+
+use super::error::ServiceError;
+
+/// Gives the drawn hours back to the plan. Reservations paid per booking have no usage,
+/// so there is nothing to restore for them.
+pub async fn handle(req: RestoreUsageRequest, pool: &PgPool) -> Result<(), ServiceError> {
+    let id = req.usage_id.map(|id| Uuid::parse_str(&id).unwrap());
+    let reservation_id = req.reservation_id.map(|id| Uuid::parse_str(&id).unwrap());
+
+    let mut transaction = pool.begin().await?;
+
+    if let Some(usage) = DBPlanUsage::delete(id, reservation_id, &mut *transaction).await? {
+        DBClientPlan::restore(usage.client_plan_id, usage.hours, &mut *transaction).await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
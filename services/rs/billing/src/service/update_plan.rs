@@ -0,0 +1,24 @@
+use protos::billing::{PlanResponse, UpdatePlanRequest};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBPlan;
+
+use super::error::ServiceError;
+
+/// Only the terms that don't affect the hours already sold can be changed.
+pub async fn handle(req: UpdatePlanRequest, pool: &PgPool) -> Result<PlanResponse, ServiceError> {
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    DBPlan::update(
+        id,
+        req.name,
+        req.description,
+        req.price,
+        req.is_active,
+        pool,
+    )
+    .await?
+    .map(DBPlan::into)
+    .ok_or(ServiceError::PlanNotFound)
+}
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS calendar_tokens
+(
+    client_id  uuid        NOT NULL PRIMARY KEY REFERENCES clients (id) ON DELETE CASCADE,
+    token      text        NOT NULL UNIQUE,
+    created_at timestamptz NOT NULL DEFAULT now()
+);
\ No newline at end of file
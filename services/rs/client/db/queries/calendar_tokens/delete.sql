@@ -0,0 +1,3 @@
+DELETE
+FROM calendar_tokens
+WHERE client_id = $1
\ No newline at end of file
@@ -0,0 +1,6 @@
+INSERT INTO calendar_tokens (client_id, token, created_at)
+VALUES ($1, $2, now())
+ON CONFLICT (client_id) DO UPDATE
+    SET token      = EXCLUDED.token,
+        created_at = EXCLUDED.created_at
+RETURNING *
\ No newline at end of file
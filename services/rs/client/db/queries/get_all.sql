@@ -0,0 +1,5 @@
+SELECT *
+FROM clients
+WHERE NOT deleted AND ($3::bool IS NULL OR send_notifications = $3)
+ORDER BY id
+LIMIT $1 OFFSET $2
\ No newline at end of file
@@ -0,0 +1,4 @@
+SELECT clients.*
+FROM clients
+    JOIN calendar_tokens ON calendar_tokens.client_id = clients.id
+WHERE calendar_tokens.token = $1 AND NOT clients.deleted
\ No newline at end of file
@@ -1,47 +1,114 @@
 use client::{config, service::ClientService};
-use env_logger::Env;
 use protos::{
     client::client_server::ClientServer, reservation::reservation_client::ReservationClient,
 };
-use service_helper::make_url;
-use tonic::{codec::CompressionEncoding, transport::Server};
+use service_helper::{
+    channel::ServiceChannel,
+    health::HealthChecks,
+    metrics::{self, MetricsLayer},
+    principal::PrincipalLayer,
+    shutdown::Shutdown,
+    telemetry::{self, TraceLayer},
+    tls::{self, CallerAllowlist},
+};
+use tonic::codec::CompressionEncoding;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let default_log_level = if cfg!(debug_assertions) {
-        "debug"
-    } else {
-        "info"
-    };
-    env_logger::init_from_env(Env::default().default_filter_or(default_log_level));
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("client");
     config::init();
 
-    postgres_helper::migrate!(&config::DATABASE_URL, "./db/migrations");
+    let shutdown = Shutdown::on_signal();
+
+    metrics::install_exporter(&config::METRICS_ADDRESS);
+
+    postgres_helper::migrate!(config::DATABASE_URL.as_str(), "./db/migrations");
 
-    let pool = postgres_helper::connect(&config::DATABASE_URL)
+    let pool = postgres_helper::connect(config::DATABASE_URL.as_str())
         .await
         .expect("Database connection failed");
 
-    let reservation_client =
-        ReservationClient::connect(make_url(&config::RESERVATION_SERVICE_ADDRESS))
-            .await
-            .expect("Failed to connect to the reservation service")
-            .send_compressed(CompressionEncoding::Zstd)
-            .accept_compressed(CompressionEncoding::Zstd);
+    let reservation_channel =
+        ServiceChannel::lazy("reservation", &config::RESERVATION_SERVICE_ADDRESS).retryable(&[
+            "/reservation.Reservation/GetByID",
+            "/reservation.Reservation/GetBySeat",
+            "/reservation.Reservation/GetByClient",
+            "/reservation.Reservation/Get",
+            "/reservation.Reservation/GetVisited",
+            "/reservation.Reservation/GetNoShows",
+        ]);
+    let reservation_client = ReservationClient::new(reservation_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
+
+    let health_checks = HealthChecks::new()
+        .check("postgres", {
+            let pool = pool.clone();
+            move || postgres_helper::ping(pool.clone())
+        })
+        .service("reservation", reservation_channel);
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    shutdown.spawn_worker(
+        health_checks.run::<ClientServer<ClientService>>(health_reporter, shutdown.clone()),
+    );
 
-    let service = ClientServer::new(ClientService::new(pool, reservation_client))
+    let service = ClientServer::new(ClientService::new(pool.clone(), reservation_client))
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    log::info!(
+    // off by default, the schema shouldn't be discoverable in production
+    let reflection_service = config::GRPC_REFLECTION
+        .then(|| {
+            tonic_reflection::server::Builder::configure()
+                .register_encoded_file_descriptor_set(protos::FILE_DESCRIPTOR_SET)
+                .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+                .with_service_name(protos::client::client_server::SERVICE_NAME)
+                .with_service_name(tonic_health::pb::health_server::SERVICE_NAME)
+                .build_v1()
+        })
+        .transpose()?;
+
+    tracing::info!(
         "Started client service on {}",
         config::SERVICE_ADDRESS.as_str()
     );
 
-    Server::builder()
+    const GATEWAY: &[&str] = &["gateway"];
+    const NOTIFICATION: &[&str] = &["notification"];
+    const GATEWAY_AND_NOTIFICATION: &[&str] = &["gateway", "notification"];
+    const GATEWAY_BILLING_AND_NOTIFICATION: &[&str] = &["gateway", "billing", "notification"];
+    let callers = CallerAllowlist::new()
+        .allow("/client.Client/Register", GATEWAY)
+        .allow("/client.Client/Login", GATEWAY)
+        .allow("/client.Client/ValidateToken", GATEWAY)
+        .allow("/client.Client/ChangePassword", GATEWAY)
+        .allow("/client.Client/Get", GATEWAY_BILLING_AND_NOTIFICATION)
+        .allow("/client.Client/GetMultiple", GATEWAY_AND_NOTIFICATION)
+        .allow("/client.Client/GetAll", NOTIFICATION)
+        .allow("/client.Client/Edit", GATEWAY)
+        .allow("/client.Client/Delete", GATEWAY)
+        .allow("/client.Client/IssueCalendarToken", GATEWAY)
+        .allow("/client.Client/RevokeCalendarToken", GATEWAY)
+        .allow("/client.Client/GetByCalendarToken", GATEWAY);
+
+    let server = tls::server()?
+        .layer(MetricsLayer)
+        .layer(TraceLayer)
+        .layer(callers)
+        .layer(PrincipalLayer)
+        .add_service(health_service)
+        .add_optional_service(reflection_service)
         .add_service(service)
-        .serve(config::SERVICE_ADDRESS.parse()?)
-        .await?;
+        .serve_with_shutdown(config::SERVICE_ADDRESS.parse()?, shutdown.requested());
+    shutdown.drain(server).await?;
+
+    pool.close().await;
+    tracing::info!("Stopped client service");
 
     Ok(())
 }
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use convertions::datetime_into_timestamp;
+use protos::client::CalendarTokenResponse;
+use sqlx::{query_file, query_file_as, Error, Executor, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DBCalendarToken {
+    pub client_id: Uuid,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+impl DBCalendarToken {
+    /// Issues the token, replacing the one the client had before.
+    pub async fn upsert<'a, E>(client_id: Uuid, token: &str, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/calendar_tokens/upsert.sql",
+            client_id,
+            token
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn delete<'a, E>(client_id: Uuid, executor: E) -> Result<bool, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!("db/queries/calendar_tokens/delete.sql", client_id)
+            .execute(executor)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+}
+
+impl From<DBCalendarToken> for CalendarTokenResponse {
+    fn from(token: DBCalendarToken) -> Self {
+        Self {
+            token: token.token,
+            created_at: datetime_into_timestamp(token.created_at),
+        }
+    }
+}
@@ -20,7 +20,7 @@ pub struct DBClient {
 }
 impl DBClient {
     pub async fn insert(self, transaction: &mut Transaction<'_, Postgres>) -> Result<Self, Error> {
-        Ok(query_file_as!(
+        query_file_as!(
             Self,
             "db/queries/insert.sql",
             self.id,
@@ -34,36 +34,69 @@ impl DBClient {
             self.verified
         )
         .fetch_one(&mut **transaction)
-        .await?)
+        .await
     }
 
     pub async fn get_by_id<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        Ok(query_file_as!(Self, "db/queries/get_by_id.sql", id)
+        query_file_as!(Self, "db/queries/get_by_id.sql", id)
             .fetch_optional(executor)
-            .await?)
+            .await
     }
 
     pub async fn get_by_email<'a, E>(email: &str, executor: E) -> Result<Option<Self>, Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        Ok(query_file_as!(Self, "db/queries/get_by_email.sql", email)
+        query_file_as!(Self, "db/queries/get_by_email.sql", email)
             .fetch_optional(executor)
-            .await?)
+            .await
+    }
+
+    pub async fn get_by_calendar_token<'a, E>(
+        token: &str,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/get_by_calendar_token.sql", token)
+            .fetch_optional(executor)
+            .await
     }
 
     pub async fn get_multiple<'a, E>(ids: Vec<Uuid>, executor: E) -> Result<Vec<Self>, Error>
     where
         E: Executor<'a, Database = Postgres>,
     {
-        Ok(query_file_as!(Self, "db/queries/get_multiple.sql", &ids)
+        query_file_as!(Self, "db/queries/get_multiple.sql", &ids)
             .fetch_all(executor)
-            .await?)
+            .await
+    }
+
+    pub async fn get_all<'a, E>(
+        limit: i64,
+        offset: i64,
+        send_notifications: Option<bool>,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/get_all.sql",
+            limit,
+            offset,
+            send_notifications
+        )
+        .fetch_all(executor)
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn patch(
         id: Uuid,
         name: Option<&str>,
@@ -77,7 +110,7 @@ impl DBClient {
         verified: Option<bool>,
         transaction: &mut Transaction<'_, Postgres>,
     ) -> Result<Option<Self>, Error> {
-        Ok(query_file_as!(
+        query_file_as!(
             Self,
             "db/queries/patch.sql",
             id,
@@ -92,7 +125,7 @@ impl DBClient {
             verified
         )
         .fetch_optional(&mut **transaction)
-        .await?)
+        .await
     }
 
     pub async fn delete(
@@ -105,20 +138,20 @@ impl DBClient {
         Ok(())
     }
 }
-impl Into<ClientResponse> for DBClient {
-    fn into(self) -> ClientResponse {
-        ClientResponse {
-            id: self.id.to_string(),
+impl From<DBClient> for ClientResponse {
+    fn from(value: DBClient) -> Self {
+        Self {
+            id: value.id.to_string(),
             meta: ClientMeta {
-                name: self.name,
-                surname: self.surname,
-                patronymic: self.patronymic,
-                email: self.email,
-                send_notifications: self.send_notifications,
-                is_internal: self.is_internal,
+                name: value.name,
+                surname: value.surname,
+                patronymic: value.patronymic,
+                email: value.email,
+                send_notifications: value.send_notifications,
+                is_internal: value.is_internal,
             },
-            last_password_cgange: datetime_into_timestamp(self.last_password_change),
-            verified: self.verified,
+            last_password_cgange: datetime_into_timestamp(value.last_password_change),
+            verified: value.verified,
         }
     }
 }
@@ -1,3 +1,5 @@
+mod calendar_token;
 mod client;
 
+pub use calendar_token::DBCalendarToken;
 pub use client::DBClient;
@@ -1,4 +1,5 @@
 use protos::client::{ClientResponse, EditRequest};
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -6,7 +7,15 @@ use crate::models::db::DBClient;
 
 use super::error::ServiceError;
 
-pub async fn handle(req: EditRequest, pool: &PgPool) -> Result<ClientResponse, ServiceError> {
+pub async fn handle(
+    req: EditRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<ClientResponse, ServiceError> {
+    if !principal.can_access(&req.id) {
+        return Err(ServiceError::NotOwner);
+    }
+
     let id = Uuid::parse_str(&req.id).unwrap();
 
     let mut transaction = pool.begin().await?;
@@ -17,29 +17,45 @@ pub enum ServiceError {
     #[error("Invalid token")]
     InvalidToken,
 
+    #[error("Invalid client id `{0}`")]
+    InvalidId(String),
+
     #[error("Client was not found")]
     NotFound,
 
+    #[error("The call isn't made on behalf of anyone")]
+    Unauthenticated,
+
+    #[error("You're not allowed to access this client")]
+    NotOwner,
+
     #[error("Client with this email already exists")]
     AlreadyExists,
+
+    #[error("Calendar feed was not issued")]
+    CalendarTokenNotFound,
 }
 
-impl Into<Status> for ServiceError {
-    fn into(self) -> Status {
-        if let Self::Service(status) = self {
+impl From<ServiceError> for Status {
+    fn from(error: ServiceError) -> Self {
+        if let ServiceError::Service(status) = error {
             return status;
         }
 
-        let code: Code = match self {
-            Self::Service(..) => unreachable!(),
-            Self::Database(..) => Code::Internal,
-            Self::Hasher(..) => Code::Internal,
-            Self::InvalidCredentials => Code::Unauthenticated,
-            Self::InvalidToken => Code::Unauthenticated,
-            Self::NotFound => Code::NotFound,
-            Self::AlreadyExists => Code::AlreadyExists,
+        let code: Code = match error {
+            ServiceError::Service(..) => unreachable!(),
+            ServiceError::Database(..) => Code::Internal,
+            ServiceError::Hasher(..) => Code::Internal,
+            ServiceError::InvalidCredentials => Code::Unauthenticated,
+            ServiceError::InvalidToken => Code::Unauthenticated,
+            ServiceError::InvalidId(..) => Code::InvalidArgument,
+            ServiceError::NotFound => Code::NotFound,
+            ServiceError::Unauthenticated => Code::Unauthenticated,
+            ServiceError::NotOwner => Code::PermissionDenied,
+            ServiceError::AlreadyExists => Code::AlreadyExists,
+            ServiceError::CalendarTokenNotFound => Code::NotFound,
         };
 
-        Status::new(code, self.to_string())
+        Status::new(code, error.to_string())
     }
 }
@@ -1,4 +1,5 @@
 use protos::client::{ClientRequest, ClientResponse};
+use service_helper::principal::Principal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -6,7 +7,15 @@ use crate::models::db::DBClient;
 
 use super::error::ServiceError;
 
-pub async fn handle(req: ClientRequest, pool: &PgPool) -> Result<ClientResponse, ServiceError> {
+pub async fn handle(
+    req: ClientRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<ClientResponse, ServiceError> {
+    if !principal.can_access(&req.id) {
+        return Err(ServiceError::NotOwner);
+    }
+
     let id = Uuid::parse_str(&req.id).unwrap();
 
     match DBClient::get_by_id(id, pool).await? {
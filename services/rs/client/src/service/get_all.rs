@@ -0,0 +1,31 @@
+use protos::client::{ClientsResponse, ListClientsRequest};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+
+use crate::models::db::DBClient;
+
+use super::error::ServiceError;
+
+/// Lists the clients page by page, optionally only those with the given notification preference.
+pub async fn handle(
+    req: ListClientsRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<ClientsResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let clients = DBClient::get_all(
+        req.limit as i64,
+        req.offset as i64,
+        req.send_notifications,
+        pool,
+    )
+    .await?
+    .into_iter()
+    .map(Into::into)
+    .collect();
+
+    Ok(ClientsResponse { clients })
+}
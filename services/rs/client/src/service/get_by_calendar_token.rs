@@ -0,0 +1,22 @@
+use protos::client::{CalendarTokenRequest, ClientResponse};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+
+use crate::models::db::DBClient;
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: CalendarTokenRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<ClientResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    match DBClient::get_by_calendar_token(&req.token, pool).await? {
+        None => Err(ServiceError::InvalidToken),
+        Some(client) => Ok(client.into()),
+    }
+}
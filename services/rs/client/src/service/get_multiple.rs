@@ -2,34 +2,49 @@ use protos::{
     client::{ClientResponse, ClientsRequest, ClientsResponse},
     reservation::{reservation_client::ReservationClient, GetRequest},
 };
+use service_helper::{channel::ServiceChannel, principal::Principal};
 use sqlx::PgPool;
-use tonic::transport::Channel;
 use uuid::Uuid;
 
 use crate::models::db::DBClient;
 
 use super::error::ServiceError;
 
+/// Returns the clients with the requested ids, or the clients
+/// who visited the coworking on the requested date if none are given.
 pub async fn handle(
     req: ClientsRequest,
+    principal: &Principal,
     pool: &PgPool,
-    reservation_client: &ReservationClient<Channel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
 ) -> Result<ClientsResponse, ServiceError> {
-    let ids = reservation_client
-        .clone()
-        .get_visited(GetRequest {
-            limit: req.limit,
-            offset: req.offset,
-            day: req.day,
-            month: req.month,
-            year: req.year,
-        })
-        .await?
-        .into_inner()
-        .reservations
-        .iter()
-        .map(|r| Uuid::parse_str(&r.client_id).unwrap())
-        .collect();
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let ids = if !req.ids.is_empty() {
+        req.ids
+            .iter()
+            .map(|id| Uuid::parse_str(id).map_err(|_| id))
+            .collect::<Result<_, _>>()
+            .map_err(|id| ServiceError::InvalidId(id.clone()))?
+    } else {
+        reservation_client
+            .clone()
+            .get_visited(GetRequest {
+                limit: req.limit,
+                offset: req.offset,
+                day: req.day,
+                month: req.month,
+                year: req.year,
+            })
+            .await?
+            .into_inner()
+            .reservations
+            .iter()
+            .map(|r| Uuid::parse_str(&r.client_id).unwrap())
+            .collect()
+    };
 
     let clients: Vec<ClientResponse> = DBClient::get_multiple(ids, pool)
         .await?
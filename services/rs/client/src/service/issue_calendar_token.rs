@@ -0,0 +1,38 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use protos::client::{CalendarTokenResponse, ClientRequest};
+use rand_chacha::{
+    rand_core::{RngCore, SeedableRng},
+    ChaCha20Rng,
+};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBCalendarToken, DBClient};
+
+use super::error::ServiceError;
+
+const TOKEN_BYTES: usize = 32;
+
+/// Issues a new calendar feed token, revoking the previous one.
+pub async fn handle(
+    req: ClientRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<CalendarTokenResponse, ServiceError> {
+    if !principal.can_access(&req.id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    if DBClient::get_by_id(id, pool).await?.is_none() {
+        return Err(ServiceError::NotFound);
+    }
+
+    let mut bytes = [0u8; TOKEN_BYTES];
+    ChaCha20Rng::from_entropy().fill_bytes(&mut bytes);
+    let token = URL_SAFE_NO_PAD.encode(bytes);
+
+    Ok(DBCalendarToken::upsert(id, &token, pool).await?.into())
+}
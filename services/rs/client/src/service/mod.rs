@@ -1,32 +1,41 @@
 use error::ServiceError;
 use protos::{
     client::{
-        client_server::Client, AuthResponse, ChangePasswordRequest, ClientRequest, ClientResponse,
-        ClientsRequest, ClientsResponse, EditRequest, LoginRequest, RegisterRequest,
-        ValidateTokenRequest,
+        client_server::Client, AuthResponse, CalendarTokenRequest, CalendarTokenResponse,
+        ChangePasswordRequest, ClientRequest, ClientResponse, ClientsRequest, ClientsResponse,
+        EditRequest, ListClientsRequest, LoginRequest, RegisterRequest, ValidateTokenRequest,
     },
     reservation::reservation_client::ReservationClient,
 };
+use service_helper::channel::ServiceChannel;
+use service_helper::principal;
 use service_helper::response::ServiceResult;
 use sqlx::{Pool, Postgres};
-use tonic::{async_trait, transport::Channel, Request, Response};
+use tonic::{async_trait, Request, Response};
 
 mod change_password;
 mod delete;
 mod edit;
 mod error;
 mod get;
+mod get_all;
+mod get_by_calendar_token;
 mod get_multiple;
+mod issue_calendar_token;
 mod login;
 mod register;
+mod revoke_calendar_token;
 mod validate_token;
 
 pub struct ClientService {
     pub postgres_pool: Pool<Postgres>,
-    pub reservation_client: ReservationClient<Channel>,
+    pub reservation_client: ReservationClient<ServiceChannel>,
 }
 impl ClientService {
-    pub fn new(pool: Pool<Postgres>, reservation_client: ReservationClient<Channel>) -> Self {
+    pub fn new(
+        pool: Pool<Postgres>,
+        reservation_client: ReservationClient<ServiceChannel>,
+    ) -> Self {
         Self {
             postgres_pool: pool,
             reservation_client,
@@ -37,14 +46,16 @@ impl ClientService {
 #[async_trait]
 impl Client for ClientService {
     async fn register(&self, request: Request<RegisterRequest>) -> ServiceResult<AuthResponse> {
-        register::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        register::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn login(&self, request: Request<LoginRequest>) -> ServiceResult<AuthResponse> {
-        login::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        login::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
@@ -54,7 +65,8 @@ impl Client for ClientService {
         &self,
         request: Request<ValidateTokenRequest>,
     ) -> ServiceResult<ClientResponse> {
-        validate_token::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        validate_token::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
@@ -64,14 +76,16 @@ impl Client for ClientService {
         &self,
         request: Request<ChangePasswordRequest>,
     ) -> ServiceResult<AuthResponse> {
-        change_password::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        change_password::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn get(&self, request: Request<ClientRequest>) -> ServiceResult<ClientResponse> {
-        get::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
@@ -81,8 +95,10 @@ impl Client for ClientService {
         &self,
         request: Request<ClientsRequest>,
     ) -> ServiceResult<ClientsResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
         get_multiple::handle(
             request.into_inner(),
+            &principal,
             &self.postgres_pool,
             &self.reservation_client,
         )
@@ -91,15 +107,58 @@ impl Client for ClientService {
         .map_err(ServiceError::into)
     }
 
+    async fn get_all(
+        &self,
+        request: Request<ListClientsRequest>,
+    ) -> ServiceResult<ClientsResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_all::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
     async fn edit(&self, request: Request<EditRequest>) -> ServiceResult<ClientResponse> {
-        edit::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        edit::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
     }
 
     async fn delete(&self, request: Request<ClientRequest>) -> ServiceResult<()> {
-        delete::handle(request.into_inner(), &self.postgres_pool)
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        delete::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn issue_calendar_token(
+        &self,
+        request: Request<ClientRequest>,
+    ) -> ServiceResult<CalendarTokenResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        issue_calendar_token::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn revoke_calendar_token(&self, request: Request<ClientRequest>) -> ServiceResult<()> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        revoke_calendar_token::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_by_calendar_token(
+        &self,
+        request: Request<CalendarTokenRequest>,
+    ) -> ServiceResult<ClientResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_by_calendar_token::handle(request.into_inner(), &principal, &self.postgres_pool)
             .await
             .map(Response::new)
             .map_err(ServiceError::into)
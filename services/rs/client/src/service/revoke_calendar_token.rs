@@ -0,0 +1,25 @@
+use protos::client::ClientRequest;
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBCalendarToken;
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: ClientRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<(), ServiceError> {
+    if !principal.can_access(&req.id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    match DBCalendarToken::delete(id, pool).await? {
+        false => Err(ServiceError::CalendarTokenNotFound),
+        true => Ok(()),
+    }
+}
@@ -17,7 +17,11 @@ use protos::{
     admin::{admin_client::AdminClient, ValidateTokenRequest as ValidateAdminTokenRequest},
     client::client_client::ClientClient,
 };
-use tonic::{transport::Channel, Request};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use tonic::Request;
 
 use super::AuthenticationError;
 
@@ -47,11 +51,19 @@ impl AuthEntity {
             AuthEntity::Admin(admin) => Ok(admin),
         }
     }
+
+    /// Principal the calls to the services are made on behalf of.
+    pub fn principal(&self) -> Principal {
+        match self {
+            AuthEntity::Admin(admin) => Principal::admin(admin.id),
+            AuthEntity::Client(client) => Principal::client(client.id),
+        }
+    }
 }
 
 pub async fn any_auth_middleware(
-    admin_client: Data<AdminClient<Channel>>,
-    client_client: Data<ClientClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, Error> {
@@ -59,8 +71,8 @@ pub async fn any_auth_middleware(
 }
 
 pub async fn client_auth_middleware(
-    admin_client: Data<AdminClient<Channel>>,
-    client_client: Data<ClientClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, Error> {
@@ -68,8 +80,8 @@ pub async fn client_auth_middleware(
 }
 
 pub async fn admin_auth_middleware(
-    admin_client: Data<AdminClient<Channel>>,
-    client_client: Data<ClientClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, Error> {
@@ -78,47 +90,47 @@ pub async fn admin_auth_middleware(
 
 pub async fn auth_middleware(
     access_level: TokenType,
-    admin_client: Data<AdminClient<Channel>>,
-    client_client: Data<ClientClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, Error> {
     let token = extract_auth_from_authorization_header(&req)?;
 
-    let token_type = match decode_header(&token).map(|h| h.kid.map(|k| k.clone())) {
+    // the token is validated before anyone is signed in, so on the gateway's own behalf
+
+    let (token_type, entity) = match decode_header(&token).map(|h| h.kid) {
         Ok(Some(token_type)) if &token_type == "admin" => {
-            let mut service_client = (&**admin_client).clone();
+            let mut service_client = (**admin_client).clone();
 
             let request = Request::new(ValidateAdminTokenRequest { token });
 
-            let admin: Admin = service_client
-                .validate_token(request)
-                .await
-                .map_err(ServiceError::from)?
-                .into_inner()
-                .into();
-
-            req.extensions_mut()
-                .insert(AuthEntity::Admin(admin.clone()));
+            let admin: Admin = principal::scope(
+                Principal::service("gateway"),
+                service_client.validate_token(request),
+            )
+            .await
+            .map_err(ServiceError::from)?
+            .into_inner()
+            .into();
 
-            TokenType::Admin
+            (TokenType::Admin, AuthEntity::Admin(admin))
         }
         Ok(Some(token_type)) if &token_type == "client" => {
-            let mut service_client = (&**client_client).clone();
+            let mut service_client = (**client_client).clone();
 
             let request = Request::new(ValidateClientTokenRequest { token });
 
-            let client: Client = service_client
-                .validate_token(request)
-                .await
-                .map_err(ServiceError::from)?
-                .into_inner()
-                .into();
+            let client: Client = principal::scope(
+                Principal::service("gateway"),
+                service_client.validate_token(request),
+            )
+            .await
+            .map_err(ServiceError::from)?
+            .into_inner()
+            .into();
 
-            req.extensions_mut()
-                .insert(AuthEntity::Client(client.clone()));
-
-            TokenType::Client
+            (TokenType::Client, AuthEntity::Client(client))
         }
         _ => return Err(AuthenticationError::InvalidCredentials)?,
     };
@@ -132,7 +144,10 @@ pub async fn auth_middleware(
         }
     }
 
-    next.call(req).await
+    let principal = entity.principal();
+    req.extensions_mut().insert(entity);
+
+    principal::scope(principal, next.call(req)).await
 }
 
 pub fn extract_auth_from_authorization_header(
@@ -141,7 +156,7 @@ pub fn extract_auth_from_authorization_header(
     let headers = req.headers();
     let token_val: Option<&HeaderValue> = headers.get(AUTHORIZATION);
     let token_val = token_val
-        .ok_or_else(|| AuthenticationError::NoAuthorizationHeader)?
+        .ok_or(AuthenticationError::NoAuthorizationHeader)?
         .to_str()
         .map_err(|_| AuthenticationError::InvalidCredentials)?;
 
@@ -39,10 +39,8 @@ impl ResponseError for AuthenticationError {
     }
 
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code()).json(ApiError {
-            error: self.error_name(),
-            description: self.to_string(),
-        })
+        HttpResponse::build(self.status_code())
+            .json(ApiError::new(self.error_name(), self.to_string()))
     }
 }
 
@@ -1,16 +1,19 @@
 use actix_web::web::{get, Data, JsonConfig, PathConfig};
+use actix_web_prom::PrometheusMetrics;
 use env_config::environment_variables;
-use log::info;
 use protos::{
-    admin::admin_client::AdminClient, client::client_client::ClientClient,
+    admin::admin_client::AdminClient, analytics::analytics_client::AnalyticsClient,
+    billing::billing_client::BillingClient, client::client_client::ClientClient,
     coworking::coworking_client::CoworkingClient, image::image_client::ImageClient,
-    mail::mail_client::MailClient, notification::notification_client::NotificationClient,
+    image_worker::image_worker_client::ImageWorkerClient,
+    notification::notification_client::NotificationClient,
     reservation::reservation_client::ReservationClient,
     seat_lock::seat_lock_client::SeatLockClient,
 };
-use service_helper::make_url;
-use tonic::{codec::CompressionEncoding, transport::Channel};
-use utils::openapi::Swagger;
+use service_helper::channel::ServiceChannel;
+use tonic::codec::CompressionEncoding;
+use tracing::info;
+use utils::{health::Dependencies, openapi::Swagger};
 use utoipa::openapi::OpenApi as OpenApiStruct;
 use utoipa::OpenApi;
 use utoipa_actix_web::service_config::ServiceConfig;
@@ -32,91 +35,188 @@ environment_variables! {
     COWORKING_SERVICE_ADDRESS: String = "localhost:50053",
     RESERVATION_SERVICE_ADDRESS: String = "localhost:50054",
     SEAT_LOCK_SERVICE_ADDRESS: String = "localhost:50055",
+    SEAT_LOCK_TTL_SECONDS: u64 = 30u64,
     // доп. фичи
     MAIL_SERVICE_ADDRESS: String = "localhost:50056",
     NOTIFICATION_SERVICE_ADDRESS: String = "localhost:50057",
-    IMAGE_SERVICE_ADDRESS: String = "localhost:50058"
+    IMAGE_SERVICE_ADDRESS: String = "localhost:50058",
+    IMAGE_WORKER_SERVICE_ADDRESS: String = "localhost:50059",
+    IMAGE_MAX_SIZE: u64 = 10u64 * 1024 * 1024,
+    BILLING_SERVICE_ADDRESS: String = "localhost:50060",
+    ANALYTICS_SERVICE_ADDRESS: String = "localhost:50061",
+    // ценообразование
+    PEAK_HOURS_START: u32 = 10u32,
+    PEAK_HOURS_END: u32 = 18u32,
+    PEAK_MULTIPLIER: f64 = 1.25f64,
+    OFF_PEAK_MULTIPLIER: f64 = 0.8f64,
+    INTERNAL_DISCOUNT_PERCENT: f64 = 20f64,
+    PROMO_CODES: Vec<String> = "",
+    // чек-ин
+    CHECKIN_SECRET: Secret,
+    CHECKIN_EARLY_MINUTES: i64 = 30i64,
+    CHECKIN_DEVICE_KEY: Secret,
 }
 
 #[derive(Clone)]
 pub struct SolutionConfig {
     pub openapi: OpenApiStruct,
+    pub metrics: PrometheusMetrics,
     pub coworking_id: Uuid,
-    pub admin: AdminClient<Channel>,
-    pub client: ClientClient<Channel>,
-    pub coworking: CoworkingClient<Channel>,
-    pub reservation: ReservationClient<Channel>,
-    // pub seat_lock: SeatLockClient<Channel>,
-    // pub mail: MailClient<Channel>,
-    pub notification: NotificationClient<Channel>,
-    // pub image: ImageClient<Channel>,
+    pub admin: AdminClient<ServiceChannel>,
+    pub client: ClientClient<ServiceChannel>,
+    pub coworking: CoworkingClient<ServiceChannel>,
+    pub reservation: ReservationClient<ServiceChannel>,
+    pub seat_lock: SeatLockClient<ServiceChannel>,
+    // pub mail: MailClient<ServiceChannel>,
+    pub notification: NotificationClient<ServiceChannel>,
+    pub image: ImageClient<ServiceChannel>,
+    pub image_worker: ImageWorkerClient<ServiceChannel>,
+    pub billing: BillingClient<ServiceChannel>,
+    pub analytics: AnalyticsClient<ServiceChannel>,
+    pub dependencies: Dependencies,
 }
 
 pub async fn app_setup() -> SolutionConfig {
     config::init();
     info!("Starting gateway on {}", config::SERVER_ADDRESS.as_str());
 
-    let admin_client = AdminClient::connect(make_url(&config::ADMIN_SERVICE_ADDRESS))
-        .await
-        .expect("Failed to connect to the admin service")
+    let admin_channel = ServiceChannel::lazy("admin", &config::ADMIN_SERVICE_ADDRESS)
+        .retryable(&["/admin.Admin/ValidateToken", "/admin.Admin/Get"]);
+    let admin_client = AdminClient::new(admin_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    let client_client = ClientClient::connect(make_url(&config::CLIENT_SERVICE_ADDRESS))
-        .await
-        .expect("Failed to connect to the client service")
+    let client_channel =
+        ServiceChannel::lazy("client", &config::CLIENT_SERVICE_ADDRESS).retryable(&[
+            "/client.Client/ValidateToken",
+            "/client.Client/Get",
+            "/client.Client/GetMultiple",
+            "/client.Client/GetAll",
+            "/client.Client/GetByCalendarToken",
+        ]);
+    let client_client = ClientClient::new(client_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    let coworking_client = CoworkingClient::connect(make_url(&config::COWORKING_SERVICE_ADDRESS))
-        .await
-        .expect("Failed to connect to the coworking service")
+    let coworking_channel = ServiceChannel::lazy("coworking", &config::COWORKING_SERVICE_ADDRESS)
+        .retryable(&[
+            "/coworking.Coworking/GetByID",
+            "/coworking.Coworking/GetSeat",
+            "/coworking.Coworking/GetSeats",
+        ]);
+    let coworking_client = CoworkingClient::new(coworking_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    let reservation_client =
-        ReservationClient::connect(make_url(&config::RESERVATION_SERVICE_ADDRESS))
-            .await
-            .expect("Failed to connect to the reservation service")
-            .send_compressed(CompressionEncoding::Zstd)
-            .accept_compressed(CompressionEncoding::Zstd);
+    let reservation_channel =
+        ServiceChannel::lazy("reservation", &config::RESERVATION_SERVICE_ADDRESS).retryable(&[
+            "/reservation.Reservation/GetByID",
+            "/reservation.Reservation/GetBySeat",
+            "/reservation.Reservation/GetByClient",
+            "/reservation.Reservation/Get",
+            "/reservation.Reservation/GetVisited",
+            "/reservation.Reservation/GetNoShows",
+        ]);
+    let reservation_client = ReservationClient::new(reservation_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
 
-    let seat_lock_client = SeatLockClient::connect(make_url(&config::SEAT_LOCK_SERVICE_ADDRESS))
-        .await
-        .expect("Failed to connect to the seat lock service")
+    let seat_lock_channel = ServiceChannel::lazy("seat-lock", &config::SEAT_LOCK_SERVICE_ADDRESS)
+        .retryable(&[
+            "/seatLock.SeatLock/GetLockByUserID",
+            "/seatLock.SeatLock/GetLockBySeatIndex",
+        ]);
+    let seat_lock_client = SeatLockClient::new(seat_lock_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    // let mail_client = MailClient::connect(make_url(&config::MAIL_SERVICE_ADDRESS))
-    //     .await
-    //     .expect("Failed to connect to the mail service")
+    // let mail_client = MailClient::new(ServiceChannel::lazy(
+    //     "mail",
+    //     &config::MAIL_SERVICE_ADDRESS,
+    // ))
     //     .send_compressed(CompressionEncoding::Zstd)
     //     .accept_compressed(CompressionEncoding::Zstd);
 
-    let notification_client =
-        NotificationClient::connect(make_url(&config::NOTIFICATION_SERVICE_ADDRESS))
-            .await
-            .expect("Failed to connect to the notification service")
-            .send_compressed(CompressionEncoding::Zstd)
-            .accept_compressed(CompressionEncoding::Zstd);
+    let notification_channel =
+        ServiceChannel::lazy("notification", &config::NOTIFICATION_SERVICE_ADDRESS).retryable(&[
+            "/notification.Notification/GetWaitlist",
+            "/notification.Notification/CheckHold",
+            "/notification.Notification/CheckStrikes",
+            "/notification.Notification/GetAnnouncement",
+            "/notification.Notification/GetAnnouncements",
+            "/notification.Notification/GetAnnouncementRecipients",
+        ]);
+    let notification_client = NotificationClient::new(notification_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
 
-    // let image_client = ImageClient::connect(make_url(&config::IMAGE_SERVICE_ADDRESS))
-    //     .await
-    //     .expect("Failed to connect to the image service")
-    //     .send_compressed(CompressionEncoding::Zstd)
-    //     .accept_compressed(CompressionEncoding::Zstd);
+    let image_channel = ServiceChannel::lazy("image", &config::IMAGE_SERVICE_ADDRESS)
+        .retryable(&["/image.Image/ListImages"])
+        .streaming(&["/image.Image/UploadImage", "/image.Image/GetImage"]);
+    let image_client = ImageClient::new(image_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
+
+    let image_worker_channel =
+        ServiceChannel::lazy("image-worker", &config::IMAGE_WORKER_SERVICE_ADDRESS)
+            .streaming(&["/image_worker.ImageWorker/ProcessImage"]);
+    let image_worker_client = ImageWorkerClient::new(image_worker_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
+
+    let billing_channel = ServiceChannel::lazy("billing", &config::BILLING_SERVICE_ADDRESS)
+        .retryable(&[
+            "/billing.Billing/GetInvoice",
+            "/billing.Billing/GetInvoices",
+            "/billing.Billing/GetInvoiceDocument",
+            "/billing.Billing/GetPlan",
+            "/billing.Billing/GetPlans",
+            "/billing.Billing/GetClientPlans",
+        ]);
+    let billing_client = BillingClient::new(billing_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
+
+    let analytics_channel = ServiceChannel::lazy("analytics", &config::ANALYTICS_SERVICE_ADDRESS)
+        .retryable(&[
+            "/analytics.Analytics/GetOccupancy",
+            "/analytics.Analytics/GetHeatmap",
+            "/analytics.Analytics/GetRates",
+            "/analytics.Analytics/GetRevenue",
+        ]);
+    let analytics_client = AnalyticsClient::new(analytics_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
+
+    let dependencies = Dependencies::new(vec![
+        ("admin", admin_channel),
+        ("client", client_channel),
+        ("coworking", coworking_channel),
+        ("reservation", reservation_channel),
+        ("seat-lock", seat_lock_channel),
+        ("notification", notification_channel),
+        ("image", image_channel),
+        ("image-worker", image_worker_channel),
+        ("billing", billing_channel),
+        ("analytics", analytics_channel),
+    ]);
 
     SolutionConfig {
         openapi: Swagger::openapi(),
+        metrics: utils::metrics::prometheus(),
         coworking_id: Uuid::parse_str(config::COWORKING_ID.as_str()).unwrap(),
         admin: admin_client,
         client: client_client,
         coworking: coworking_client,
         reservation: reservation_client,
-        // seat_lock: seat_lock_client,
+        seat_lock: seat_lock_client,
         // mail: mail_client,
         notification: notification_client,
-        // image: image_client,
+        image: image_client,
+        image_worker: image_worker_client,
+        billing: billing_client,
+        analytics: analytics_client,
+        dependencies,
     }
 }
 
@@ -136,10 +236,14 @@ impl SolutionConfig {
             .app_data(Data::new(self.client.clone()))
             .app_data(Data::new(self.coworking.clone()))
             .app_data(Data::new(self.reservation.clone()))
-            // .app_data(Data::new(self.seat_lock.clone()))
+            .app_data(Data::new(self.seat_lock.clone()))
             // .app_data(Data::new(self.mail.clone()))
             .app_data(Data::new(self.notification.clone()))
-            // .app_data(Data::new(self.image.clone()))
+            .app_data(Data::new(self.image.clone()))
+            .app_data(Data::new(self.image_worker.clone()))
+            .app_data(Data::new(self.billing.clone()))
+            .app_data(Data::new(self.analytics.clone()))
+            .app_data(Data::new(self.dependencies.clone()))
             .configure(routes::config)
             .default_service(get().to(not_found));
         }
@@ -1,28 +1,29 @@
-use actix_web::{App, HttpServer};
+use actix_web::{middleware::from_fn, App, HttpServer};
 use actix_web_lab::middleware::CatchPanic;
-use env_logger::Env;
 use gateway::{
     app_setup, config,
-    utils::{logger::CustomLogger, openapi::Swagger},
+    utils::{logger::request_logger, openapi::Swagger},
 };
+use service_helper::telemetry;
 use std::io::Result;
 use utoipa_actix_web::AppExt;
 
 #[actix_rt::main]
 async fn main() -> Result<()> {
-    let default_log_level = if cfg!(debug_assertions) {
-        "debug"
-    } else {
-        "info"
-    };
-    env_logger::init_from_env(Env::default().default_filter_or(default_log_level));
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("gateway");
 
     let config = app_setup().await;
 
     HttpServer::new(move || {
         App::new()
             .wrap(CatchPanic::default())
-            .wrap(CustomLogger::new())
+            .wrap(from_fn(request_logger))
+            .wrap(config.metrics.clone())
             .into_utoipa_app()
             .openapi(config.openapi.clone())
             .configure(config.clone().build())
@@ -30,6 +31,8 @@ async fn main() -> Result<()> {
             .into_app()
     })
     .bind(config::SERVER_ADDRESS.as_str())?
+    // actix handles SIGTERM and SIGINT on its own, only the drain timeout is shared
+    .shutdown_timeout(service_helper::config::SHUTDOWN_TIMEOUT.as_secs())
     .run()
     .await
 }
@@ -12,11 +12,11 @@ pub struct AdminForm {
     #[validate(nested)]
     pub credentials: Credentials,
 }
-impl Into<RegisterRequest> for AdminForm {
-    fn into(self) -> RegisterRequest {
-        RegisterRequest {
-            email: self.credentials.email,
-            password: self.credentials.password,
+impl From<AdminForm> for RegisterRequest {
+    fn from(value: AdminForm) -> Self {
+        Self {
+            email: value.credentials.email,
+            password: value.credentials.password,
         }
     }
 }
@@ -0,0 +1,211 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use convertions::timestamp_into_datetime;
+use protos::analytics::{
+    HeatmapResponse, OccupancyResponse, RatesResponse, RevenueResponse, SeatTypeRates,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::utils::pricing::round_to_cents;
+
+use super::PlanSeatType;
+
+/// Share of the capacity, zero when there is no capacity at all
+fn share(hours: f64, capacity_hours: f64) -> f64 {
+    if capacity_hours > 0.0 {
+        hours / capacity_hours
+    } else {
+        0.0
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct OccupancyPoint {
+    /// Start of the hour or the day
+    #[schema(format = DateTime)]
+    pub bucket: DateTime<Utc>,
+
+    pub seat_type: PlanSeatType,
+
+    #[schema(examples(12))]
+    pub seats: u32,
+
+    /// Seat hours available within the opening hours
+    #[schema(examples(156.0))]
+    pub capacity_hours: f64,
+
+    #[schema(examples(97.5))]
+    pub booked_hours: f64,
+
+    /// Hours between check-ins and check-outs
+    #[schema(examples(81.25))]
+    pub occupied_hours: f64,
+
+    /// Booked share of the capacity
+    #[schema(examples(0.625))]
+    pub occupancy: f64,
+
+    /// Actually occupied share of the capacity
+    #[schema(examples(0.52))]
+    pub utilisation: f64,
+}
+impl OccupancyPoint {
+    pub fn vec_from_proto(resp: OccupancyResponse) -> Vec<Self> {
+        resp.points
+            .into_iter()
+            .map(|point| Self {
+                bucket: timestamp_into_datetime(point.bucket).unwrap(),
+                seat_type: PlanSeatType::from_resp(&point.seat_type).unwrap(),
+                seats: point.seats,
+                capacity_hours: point.capacity_hours,
+                booked_hours: point.booked_hours,
+                occupied_hours: point.occupied_hours,
+                occupancy: share(point.booked_hours, point.capacity_hours),
+                utilisation: share(point.occupied_hours, point.capacity_hours),
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct HeatmapCell {
+    /// ISO weekday, 1 is Monday
+    #[schema(minimum = 1, maximum = 7, examples(1))]
+    pub weekday: u32,
+
+    /// Hour of the day in UTC
+    #[schema(minimum = 0, maximum = 23, examples(10))]
+    pub hour: u32,
+
+    #[schema(examples(0.625))]
+    pub occupancy: f64,
+
+    #[schema(examples(0.52))]
+    pub utilisation: f64,
+}
+impl HeatmapCell {
+    pub fn vec_from_proto(resp: HeatmapResponse) -> Vec<Self> {
+        resp.cells
+            .into_iter()
+            .map(|cell| Self {
+                weekday: cell.weekday,
+                hour: cell.hour,
+                occupancy: share(cell.booked_hours, cell.capacity_hours),
+                utilisation: share(cell.occupied_hours, cell.capacity_hours),
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize, ToSchema, Default, Debug)]
+pub struct RateCounts {
+    #[schema(examples(120))]
+    pub reservations: u32,
+
+    #[schema(examples(9))]
+    pub cancellations: u32,
+
+    #[schema(examples(6))]
+    pub no_shows: u32,
+
+    #[schema(examples(0.075))]
+    pub cancellation_rate: f64,
+
+    #[schema(examples(0.05))]
+    pub no_show_rate: f64,
+}
+impl RateCounts {
+    fn new(reservations: u32, cancellations: u32, no_shows: u32) -> Self {
+        Self {
+            reservations,
+            cancellations,
+            no_shows,
+            cancellation_rate: share(cancellations as f64, reservations as f64),
+            no_show_rate: share(no_shows as f64, reservations as f64),
+        }
+    }
+}
+impl From<&SeatTypeRates> for RateCounts {
+    fn from(rates: &SeatTypeRates) -> Self {
+        Self::new(rates.reservations, rates.cancellations, rates.no_shows)
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct SeatTypeRateCounts {
+    pub seat_type: PlanSeatType,
+
+    #[serde(flatten)]
+    pub counts: RateCounts,
+}
+
+/// Reservations are counted on the day they start
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Rates {
+    #[serde(flatten)]
+    pub total: RateCounts,
+
+    pub by_seat_type: Vec<SeatTypeRateCounts>,
+}
+impl From<RatesResponse> for Rates {
+    fn from(resp: RatesResponse) -> Self {
+        let (reservations, cancellations, no_shows) =
+            resp.rates.iter().fold((0, 0, 0), |(r, c, n), rates| {
+                (
+                    r + rates.reservations,
+                    c + rates.cancellations,
+                    n + rates.no_shows,
+                )
+            });
+
+        Self {
+            total: RateCounts::new(reservations, cancellations, no_shows),
+            by_seat_type: resp
+                .rates
+                .iter()
+                .map(|rates| SeatTypeRateCounts {
+                    seat_type: PlanSeatType::from_resp(&rates.seat_type).unwrap(),
+                    counts: rates.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct RevenuePoint {
+    #[schema(format = Date)]
+    pub day: NaiveDate,
+
+    pub seat_type: PlanSeatType,
+
+    #[schema(examples(18450.0))]
+    pub revenue: f64,
+}
+
+/// Revenue of the booked time at the seats' hourly `cost`, cancelled reservations excluded
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Revenue {
+    #[schema(examples(18450.0))]
+    pub total: f64,
+
+    pub days: Vec<RevenuePoint>,
+}
+impl From<RevenueResponse> for Revenue {
+    fn from(resp: RevenueResponse) -> Self {
+        let days: Vec<RevenuePoint> = resp
+            .points
+            .into_iter()
+            .map(|point| RevenuePoint {
+                day: timestamp_into_datetime(point.day).unwrap().date_naive(),
+                seat_type: PlanSeatType::from_resp(&point.seat_type).unwrap(),
+                revenue: round_to_cents(point.revenue),
+            })
+            .collect();
+
+        Self {
+            total: round_to_cents(days.iter().map(|point| point.revenue).sum()),
+            days,
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use convertions::timestamp_into_datetime;
+use protos::{
+    mail::Attachment,
+    notification::{
+        AnnouncementRecipientResponse, AnnouncementRecipientsResponse, AnnouncementResponse,
+        AnnouncementsResponse, CreateAnnouncementRequest,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::routes::ApiError;
+
+/// Clients the announcement is sent to
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnnouncementAudience {
+    /// Every client
+    All,
+    /// Clients with not canceled reservations on the date
+    ReservedOn {
+        #[schema(value_type = String, format = Date)]
+        date: NaiveDate,
+    },
+    /// Clients who opted in to notifications
+    OptedIn,
+}
+impl AnnouncementAudience {
+    fn from_resp(resp: &AnnouncementResponse) -> Option<Self> {
+        match resp.audience.as_str() {
+            "all" => Some(Self::All),
+            "opted_in" => Some(Self::OptedIn),
+            "reserved_on" => Some(Self::ReservedOn {
+                date: NaiveDate::from_ymd_opt(resp.year? as i32, resp.month?, resp.day?)?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct CreateAnnouncement {
+    pub audience: AnnouncementAudience,
+
+    #[validate(email)]
+    #[schema(format = Email)]
+    pub reply_to: Option<String>,
+
+    #[validate(length(min = 1, max = 200))]
+    #[schema(min_length = 1, max_length = 200)]
+    pub subject: String,
+
+    /// Plain text body, also used as the alternative to `html`
+    #[validate(length(min = 1))]
+    #[schema(min_length = 1)]
+    pub text: String,
+
+    pub html: Option<String>,
+
+    #[validate(nested)]
+    #[serde(default)]
+    pub attachments: Vec<AnnouncementAttachment>,
+}
+impl CreateAnnouncement {
+    pub fn into_proto(self) -> Result<CreateAnnouncementRequest, ApiError> {
+        let (audience, date) = match self.audience {
+            AnnouncementAudience::All => ("all", None),
+            AnnouncementAudience::ReservedOn { date } => ("reserved_on", Some(date)),
+            AnnouncementAudience::OptedIn => ("opted_in", None),
+        };
+
+        Ok(CreateAnnouncementRequest {
+            subject: self.subject,
+            text: self.text,
+            html: self.html,
+            reply_to: self.reply_to,
+            attachments: self
+                .attachments
+                .into_iter()
+                .map(AnnouncementAttachment::into_proto)
+                .collect::<Result<_, _>>()?,
+            audience: audience.to_string(),
+            day: date.map(|date| date.day()),
+            month: date.map(|date| date.month()),
+            year: date.map(|date| date.year() as u64),
+        })
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct AnnouncementAttachment {
+    #[validate(length(min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255)]
+    pub filename: String,
+
+    #[validate(length(min = 1, max = 255))]
+    #[schema(min_length = 1, max_length = 255, examples("application/pdf"))]
+    pub content_type: String,
+
+    /// Base64-encoded file content
+    #[schema(format = Byte)]
+    pub content: String,
+}
+impl AnnouncementAttachment {
+    fn into_proto(self) -> Result<Attachment, ApiError> {
+        let content = STANDARD.decode(&self.content).map_err(|_| {
+            ApiError::InvalidInput(format!(
+                "content of attachment `{}` is not valid base64",
+                self.filename
+            ))
+        })?;
+
+        Ok(Attachment {
+            filename: self.filename,
+            content_type: self.content_type,
+            content,
+        })
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub enum AnnouncementStatus {
+    Collecting,
+    Sending,
+    Completed,
+}
+impl AnnouncementStatus {
+    fn from_resp(status: &str) -> Option<Self> {
+        match status {
+            "collecting" => Some(Self::Collecting),
+            "sending" => Some(Self::Sending),
+            "completed" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct AnnouncementProgress {
+    pub total: u64,
+    pub pending: u64,
+    pub sent: u64,
+    pub failed: u64,
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Announcement {
+    pub id: Uuid,
+
+    pub subject: String,
+
+    pub audience: AnnouncementAudience,
+
+    pub status: AnnouncementStatus,
+
+    /// Recipients are known once the status is not `Collecting`
+    pub progress: AnnouncementProgress,
+
+    #[schema(format = DateTime)]
+    pub created_at: DateTime<Utc>,
+
+    #[schema(format = DateTime)]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+impl From<AnnouncementResponse> for Announcement {
+    fn from(resp: AnnouncementResponse) -> Self {
+        Self {
+            id: Uuid::parse_str(&resp.id).unwrap(),
+            audience: AnnouncementAudience::from_resp(&resp).unwrap(),
+            status: AnnouncementStatus::from_resp(&resp.status).unwrap(),
+            progress: AnnouncementProgress {
+                total: resp.total,
+                pending: resp.pending,
+                sent: resp.sent,
+                failed: resp.failed,
+            },
+            created_at: timestamp_into_datetime(resp.created_at).unwrap(),
+            completed_at: resp.completed_at.and_then(timestamp_into_datetime),
+            subject: resp.subject,
+        }
+    }
+}
+impl Announcement {
+    pub fn vec_from_proto(resp: AnnouncementsResponse) -> Vec<Self> {
+        resp.announcements.into_iter().map(Self::from).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug)]
+pub enum RecipientStatus {
+    Pending,
+    Sent,
+    Failed,
+}
+impl RecipientStatus {
+    fn from_resp(status: &str) -> Option<Self> {
+        match status {
+            "pending" => Some(Self::Pending),
+            "sent" => Some(Self::Sent),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+
+    pub fn to_proto(self) -> String {
+        match self {
+            Self::Pending => "pending",
+            Self::Sent => "sent",
+            Self::Failed => "failed",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct AnnouncementRecipient {
+    pub client_id: Uuid,
+
+    #[schema(format = Email)]
+    pub email: String,
+
+    pub status: RecipientStatus,
+
+    pub attempts: u32,
+
+    /// Last delivery error
+    pub error: Option<String>,
+
+    #[schema(format = DateTime)]
+    pub sent_at: Option<DateTime<Utc>>,
+}
+impl From<AnnouncementRecipientResponse> for AnnouncementRecipient {
+    fn from(resp: AnnouncementRecipientResponse) -> Self {
+        Self {
+            client_id: Uuid::parse_str(&resp.client_id).unwrap(),
+            email: resp.email,
+            status: RecipientStatus::from_resp(&resp.status).unwrap(),
+            attempts: resp.attempts,
+            error: resp.error,
+            sent_at: resp.sent_at.and_then(timestamp_into_datetime),
+        }
+    }
+}
+impl AnnouncementRecipient {
+    pub fn vec_from_proto(resp: AnnouncementRecipientsResponse) -> Vec<Self> {
+        resp.recipients.into_iter().map(Self::from).collect()
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use convertions::timestamp_into_datetime;
+use protos::client::CalendarTokenResponse;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct CalendarFeed {
+    /// Personal iCalendar feed to subscribe to. Anyone with the link can read it
+    pub url: String,
+
+    #[schema(format = DateTime)]
+    pub issued_at: DateTime<Utc>,
+}
+impl CalendarFeed {
+    pub fn from_proto(resp: CalendarTokenResponse, base_url: &str) -> Self {
+        Self {
+            url: format!(
+                "{base_url}/api/client/reservations.ics?token={}",
+                resp.token
+            ),
+            issued_at: timestamp_into_datetime(resp.created_at).unwrap(),
+        }
+    }
+}
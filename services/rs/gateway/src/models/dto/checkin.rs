@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct CheckIn {
+    /// Content of the scanned QR code
+    #[validate(length(min = 1, max = 1024))]
+    #[schema(min_length = 1, max_length = 1024)]
+    pub code: String,
+}
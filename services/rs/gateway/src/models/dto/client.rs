@@ -55,13 +55,13 @@ pub struct ClientForm {
     #[schema(default = false, examples(false, true))]
     pub send_notifications: bool,
 }
-impl Into<RegisterRequest> for ClientForm {
-    fn into(self) -> RegisterRequest {
-        RegisterRequest {
-            meta: self
+impl From<ClientForm> for RegisterRequest {
+    fn from(value: ClientForm) -> Self {
+        Self {
+            meta: value
                 .display
-                .into_meta(&self.credentials.email, self.send_notifications),
-            password: self.credentials.password,
+                .into_meta(&value.credentials.email, value.send_notifications),
+            password: value.credentials.password,
         }
     }
 }
@@ -15,19 +15,19 @@ pub struct Credentials {
     #[schema(format = Password, min_length = 8, max_length = 100)]
     pub password: String,
 }
-impl Into<AdminLoginRequest> for Credentials {
-    fn into(self) -> AdminLoginRequest {
-        AdminLoginRequest {
-            email: self.email,
-            password: self.password,
+impl From<Credentials> for AdminLoginRequest {
+    fn from(value: Credentials) -> Self {
+        Self {
+            email: value.email,
+            password: value.password,
         }
     }
 }
-impl Into<ClientLoginRequest> for Credentials {
-    fn into(self) -> ClientLoginRequest {
-        ClientLoginRequest {
-            email: self.email,
-            password: self.password,
+impl From<Credentials> for ClientLoginRequest {
+    fn from(value: Credentials) -> Self {
+        Self {
+            email: value.email,
+            password: value.password,
         }
     }
 }
@@ -0,0 +1,129 @@
+use chrono::NaiveDate;
+use convertions::timestamp_into_datetime;
+use protos::{
+    client::{ClientMeta, ClientResponse},
+    reservation::ReservationResponse,
+};
+
+use crate::utils::export::{Cell, ExportRow};
+
+fn full_name(meta: &ClientMeta) -> String {
+    [&meta.surname, &meta.name, &meta.patronymic]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| part.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub struct ReservationExportRow {
+    reservation: ReservationResponse,
+    client: Option<ClientMeta>,
+    seat_number: Option<u64>,
+}
+impl ReservationExportRow {
+    pub fn new(
+        reservation: ReservationResponse,
+        client: Option<ClientMeta>,
+        seat_number: Option<u64>,
+    ) -> Self {
+        Self {
+            reservation,
+            client,
+            seat_number,
+        }
+    }
+}
+impl ExportRow for ReservationExportRow {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "client_id",
+        "client_name",
+        "client_email",
+        "seat_id",
+        "seat_number",
+        "starts_at",
+        "ends_at",
+        "price",
+        "is_canceled",
+        "is_visited",
+        "checked_in_at",
+        "checked_out_at",
+        "canceled_at",
+    ];
+
+    fn into_cells(self) -> Vec<Cell> {
+        let Self {
+            reservation,
+            client,
+            seat_number,
+        } = self;
+
+        vec![
+            reservation.id.into(),
+            reservation.client_id.into(),
+            client.as_ref().map(full_name).into(),
+            client.map(|meta| meta.email).into(),
+            reservation.seat_id.into(),
+            seat_number.into(),
+            timestamp_into_datetime(reservation.starts_at).into(),
+            timestamp_into_datetime(reservation.ends_at).into(),
+            reservation.price.into(),
+            reservation.is_canceled.into(),
+            reservation.is_visited.into(),
+            reservation
+                .checked_in_at
+                .and_then(timestamp_into_datetime)
+                .into(),
+            reservation
+                .checked_out_at
+                .and_then(timestamp_into_datetime)
+                .into(),
+            reservation
+                .canceled_at
+                .and_then(timestamp_into_datetime)
+                .into(),
+        ]
+    }
+}
+
+pub struct ClientExportRow {
+    client: ClientResponse,
+    first_visit: NaiveDate,
+}
+impl ClientExportRow {
+    pub fn new(client: ClientResponse, first_visit: NaiveDate) -> Self {
+        Self {
+            client,
+            first_visit,
+        }
+    }
+}
+impl ExportRow for ClientExportRow {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "email",
+        "verified",
+        "is_internal",
+        "send_notifications",
+        "first_visit",
+    ];
+
+    fn into_cells(self) -> Vec<Cell> {
+        let Self {
+            client,
+            first_visit,
+        } = self;
+
+        vec![
+            client.id.into(),
+            full_name(&client.meta).into(),
+            client.meta.email.into(),
+            client.verified.into(),
+            client.meta.is_internal.into(),
+            client.meta.send_notifications.into(),
+            first_visit.into(),
+        ]
+    }
+}
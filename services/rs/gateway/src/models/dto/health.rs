@@ -0,0 +1,62 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct DependencyHealth {
+    pub status: HealthStatus,
+
+    /// Time the service took to answer the health check
+    #[schema(examples(1.25))]
+    pub latency_ms: f64,
+
+    /// Why the service is considered down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(examples("service is NOT_SERVING"))]
+    pub error: Option<String>,
+}
+impl DependencyHealth {
+    pub fn new(result: Result<(), String>, latency: Duration) -> Self {
+        Self {
+            status: match result {
+                Ok(()) => HealthStatus::Up,
+                Err(..) => HealthStatus::Down,
+            },
+            latency_ms: latency.as_secs_f64() * 1000.0,
+            error: result.err(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Readiness {
+    /// Down as soon as any of the dependencies is
+    pub status: HealthStatus,
+
+    pub dependencies: BTreeMap<String, DependencyHealth>,
+}
+impl Readiness {
+    pub fn new(dependencies: BTreeMap<String, DependencyHealth>) -> Self {
+        let status = if dependencies
+            .values()
+            .all(|dependency| dependency.status == HealthStatus::Up)
+        {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+
+        Self {
+            status,
+            dependencies,
+        }
+    }
+}
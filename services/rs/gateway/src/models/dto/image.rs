@@ -0,0 +1,15 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[allow(dead_code)]
+#[derive(ToSchema, Debug)]
+pub struct ImageForm {
+    #[schema(value_type = String, format = Binary, content_media_type = "image/jpeg, image/png, image/webp")]
+    pub file: Vec<u8>,
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Image {
+    pub id: Uuid,
+}
@@ -0,0 +1,189 @@
+use chrono::{DateTime, Utc};
+use convertions::timestamp_into_datetime;
+use protos::billing::{
+    InvoiceLine as InvoiceLineResponse, InvoiceResponse, InvoicesResponse,
+    IssueMonthlyInvoiceRequest, IssueReservationInvoiceRequest, PaymentResponse,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[validate(schema(function = "Self::validate_custom"))]
+pub struct IssueInvoice {
+    /// Bills a single reservation
+    pub reservation_id: Option<Uuid>,
+
+    /// Bills all reservations of the client in the month
+    pub client_id: Option<Uuid>,
+
+    #[validate(range(min = 2000, max = 9999))]
+    #[schema(format = UInt64)]
+    pub year: Option<u64>,
+
+    #[validate(range(min = 1, max = 12))]
+    #[schema(format = UInt32, minimum = 1, maximum = 12)]
+    pub month: Option<u32>,
+}
+impl IssueInvoice {
+    fn validate_custom(&self) -> Result<(), ValidationError> {
+        match (self.reservation_id, self.client_id, self.year, self.month) {
+            (Some(..), None, None, None) | (None, Some(..), Some(..), Some(..)) => Ok(()),
+            _ => Err(ValidationError::new(
+                "either `reservation_id` or `client_id`, `year` and `month` must be specified",
+            )),
+        }
+    }
+}
+
+pub enum IssueInvoiceRequest {
+    Reservation(IssueReservationInvoiceRequest),
+    Monthly(IssueMonthlyInvoiceRequest),
+}
+impl From<IssueInvoice> for IssueInvoiceRequest {
+    fn from(value: IssueInvoice) -> Self {
+        match value.reservation_id {
+            Some(reservation_id) => Self::Reservation(IssueReservationInvoiceRequest {
+                reservation_id: reservation_id.to_string(),
+            }),
+            None => Self::Monthly(IssueMonthlyInvoiceRequest {
+                client_id: value.client_id.unwrap().to_string(),
+                year: value.year.unwrap(),
+                month: value.month.unwrap(),
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub enum InvoiceKind {
+    Reservation,
+    Monthly,
+    Plan,
+}
+impl InvoiceKind {
+    fn from_resp(kind: &str) -> Option<Self> {
+        match kind {
+            "reservation" => Some(Self::Reservation),
+            "monthly" => Some(Self::Monthly),
+            "plan" => Some(Self::Plan),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub enum InvoiceStatus {
+    Pending,
+    Paid,
+    Refunded,
+}
+impl InvoiceStatus {
+    fn from_resp(status: &str) -> Option<Self> {
+        match status {
+            "pending" => Some(Self::Pending),
+            "paid" => Some(Self::Paid),
+            "refunded" => Some(Self::Refunded),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct InvoiceLine {
+    pub description: String,
+
+    pub hours: f64,
+
+    pub amount: f64,
+
+    pub reservation_id: Option<Uuid>,
+}
+impl From<InvoiceLineResponse> for InvoiceLine {
+    fn from(resp: InvoiceLineResponse) -> Self {
+        Self {
+            description: resp.description,
+            hours: resp.hours,
+            amount: resp.amount,
+            reservation_id: resp.reservation_id.map(|id| Uuid::parse_str(&id).unwrap()),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Invoice {
+    pub id: Uuid,
+
+    pub client_id: Uuid,
+
+    pub kind: InvoiceKind,
+
+    pub status: InvoiceStatus,
+
+    pub total: f64,
+
+    pub currency: String,
+
+    pub lines: Vec<InvoiceLine>,
+
+    pub reservation_id: Option<Uuid>,
+
+    #[schema(format = DateTime)]
+    pub period_starts_at: Option<DateTime<Utc>>,
+
+    #[schema(format = DateTime)]
+    pub period_ends_at: Option<DateTime<Utc>>,
+
+    #[schema(format = DateTime)]
+    pub issued_at: DateTime<Utc>,
+
+    #[schema(format = DateTime)]
+    pub paid_at: Option<DateTime<Utc>>,
+
+    #[schema(format = DateTime)]
+    pub refunded_at: Option<DateTime<Utc>>,
+}
+impl From<InvoiceResponse> for Invoice {
+    fn from(resp: InvoiceResponse) -> Self {
+        Self {
+            id: Uuid::parse_str(&resp.id).unwrap(),
+            client_id: Uuid::parse_str(&resp.client_id).unwrap(),
+            kind: InvoiceKind::from_resp(&resp.kind).unwrap(),
+            status: InvoiceStatus::from_resp(&resp.status).unwrap(),
+            total: resp.total,
+            currency: resp.currency,
+            lines: resp.lines.into_iter().map(InvoiceLine::from).collect(),
+            reservation_id: resp.reservation_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            period_starts_at: resp.period_starts_at.and_then(timestamp_into_datetime),
+            period_ends_at: resp.period_ends_at.and_then(timestamp_into_datetime),
+            issued_at: timestamp_into_datetime(resp.issued_at).unwrap(),
+            paid_at: resp.paid_at.and_then(timestamp_into_datetime),
+            refunded_at: resp.refunded_at.and_then(timestamp_into_datetime),
+        }
+    }
+}
+impl Invoice {
+    pub fn vec_from_proto(resp: InvoicesResponse) -> Vec<Self> {
+        resp.invoices.into_iter().map(Self::from).collect()
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Payment {
+    pub invoice_id: Uuid,
+
+    pub payment_id: String,
+
+    /// Page where the client confirms the payment, if the provider has one
+    pub confirmation_url: Option<String>,
+}
+impl From<PaymentResponse> for Payment {
+    fn from(resp: PaymentResponse) -> Self {
+        Self {
+            invoice_id: Uuid::parse_str(&resp.invoice_id).unwrap(),
+            payment_id: resp.payment_id,
+            confirmation_url: resp.confirmation_url,
+        }
+    }
+}
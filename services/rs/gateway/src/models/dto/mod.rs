@@ -1,13 +1,40 @@
 mod admin;
+mod analytics;
+mod announcement;
+mod calendar;
+mod checkin;
 mod client;
 mod coworking;
 mod credentials;
+mod export;
+mod health;
+mod image;
+mod invoice;
+mod plan;
 mod reservation;
 mod seat;
+mod seat_lock;
+mod waitlist;
 
 pub use admin::{Admin, AdminForm, AdminUpdate};
+pub use analytics::{HeatmapCell, OccupancyPoint, Rates, Revenue};
+pub use announcement::{
+    Announcement, AnnouncementAttachment, AnnouncementAudience, AnnouncementProgress,
+    AnnouncementRecipient, AnnouncementStatus, CreateAnnouncement, RecipientStatus,
+};
+pub use calendar::CalendarFeed;
+pub use checkin::CheckIn;
 pub use client::{Client, ClientForm, ClientUpdate};
 pub use coworking::{Coworking, CoworkingUpdate};
 pub use credentials::Credentials;
-pub use reservation::{CreateReservation, Reservation, ReservationUpdate};
-pub use seat::{CreateSeat, Seat, SeatUpdate};
+pub use export::{ClientExportRow, ReservationExportRow};
+pub use health::{DependencyHealth, HealthStatus, Readiness};
+pub use image::{Image, ImageForm};
+pub use invoice::{Invoice, InvoiceLine, IssueInvoice, IssueInvoiceRequest, Payment};
+pub use plan::{AssignPlan, ClientPlan, CreatePlan, Plan, PlanSeatType, PlanUpdate};
+pub use reservation::{
+    CreateReservation, ExtendReservation, Reservation, ReservationQuote, ReservationUpdate,
+};
+pub use seat::{CreateSeat, Seat, SeatType, SeatUpdate};
+pub use seat_lock::{HoldSeat, SeatHold};
+pub use waitlist::{JoinWaitlist, WaitlistEntry, WaitlistStatus};
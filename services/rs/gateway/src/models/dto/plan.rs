@@ -0,0 +1,173 @@
+use chrono::{DateTime, Utc};
+use convertions::timestamp_into_datetime;
+use protos::billing::{
+    ClientPlanResponse, ClientPlansResponse, CreatePlanRequest, PlanResponse, PlansResponse,
+    UpdatePlanRequest,
+};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Seat type a plan covers, regardless of the capacity
+#[derive(Deserialize, Serialize, Display, ToSchema, Clone, Debug)]
+pub enum PlanSeatType {
+    OpenSpace,
+    Cabin,
+    Room,
+}
+impl PlanSeatType {
+    pub fn from_resp(r#type: &str) -> Option<Self> {
+        match r#type {
+            "OpenSpace" => Some(Self::OpenSpace),
+            "Cabin" => Some(Self::Cabin),
+            "Room" => Some(Self::Room),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Validate, ToSchema, Debug)]
+pub struct CreatePlan {
+    #[validate(length(min = 1, max = 64))]
+    #[schema(min_length = 1, max_length = 64, examples("10-hour pack"))]
+    pub name: String,
+
+    #[validate(length(max = 512))]
+    #[schema(max_length = 512)]
+    pub description: Option<String>,
+
+    #[validate(range(exclusive_min = 0.0, max = 10_000.0))]
+    #[schema(format = Double, exclusive_minimum = 0.0, maximum = 10_000.0, examples(10.0))]
+    pub included_hours: f64,
+
+    /// Seat types the hours can be spent on, any seat if empty
+    #[validate(length(max = 3))]
+    #[schema(min_items = 0, max_items = 3)]
+    pub seat_types: Vec<PlanSeatType>,
+
+    #[validate(range(min = 1, max = 366))]
+    #[schema(format = UInt32, minimum = 1, maximum = 366, examples(30))]
+    pub validity_days: u32,
+
+    #[validate(range(min = 0.0, max = 1_000_000.0))]
+    #[schema(format = Double, minimum = 0.0, maximum = 1_000_000.0, examples(3000.0))]
+    pub price: f64,
+}
+impl From<CreatePlan> for CreatePlanRequest {
+    fn from(value: CreatePlan) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            included_hours: value.included_hours,
+            seat_types: value.seat_types.iter().map(|t| t.to_string()).collect(),
+            validity_days: value.validity_days,
+            price: value.price,
+        }
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct PlanUpdate {
+    #[validate(length(min = 1, max = 64))]
+    #[schema(min_length = 1, max_length = 64)]
+    pub name: Option<String>,
+
+    #[validate(length(max = 512))]
+    #[schema(max_length = 512)]
+    pub description: Option<String>,
+
+    #[validate(range(min = 0.0, max = 1_000_000.0))]
+    #[schema(format = Double, minimum = 0.0, maximum = 1_000_000.0)]
+    pub price: Option<f64>,
+
+    /// Inactive plans can't be bought anymore
+    pub is_active: Option<bool>,
+}
+impl PlanUpdate {
+    pub fn into_proto(self, id: Uuid) -> UpdatePlanRequest {
+        UpdatePlanRequest {
+            id: id.to_string(),
+            name: self.name,
+            description: self.description,
+            price: self.price,
+            is_active: self.is_active,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct Plan {
+    pub id: Uuid,
+
+    #[serde(flatten)]
+    pub inner: CreatePlan,
+
+    pub is_active: bool,
+}
+impl From<PlanResponse> for Plan {
+    fn from(resp: PlanResponse) -> Self {
+        Self {
+            id: Uuid::parse_str(&resp.id).unwrap(),
+            inner: CreatePlan {
+                name: resp.name,
+                description: resp.description,
+                included_hours: resp.included_hours,
+                seat_types: resp
+                    .seat_types
+                    .iter()
+                    .map(|t| PlanSeatType::from_resp(t).unwrap())
+                    .collect(),
+                validity_days: resp.validity_days,
+                price: resp.price,
+            },
+            is_active: resp.is_active,
+        }
+    }
+}
+impl Plan {
+    pub fn vec_from_proto(resp: PlansResponse) -> Vec<Self> {
+        resp.plans.into_iter().map(Self::from).collect()
+    }
+}
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+pub struct AssignPlan {
+    pub plan_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct ClientPlan {
+    pub id: Uuid,
+
+    pub plan: Plan,
+
+    pub hours_left: f64,
+
+    #[schema(format = DateTime)]
+    pub starts_at: DateTime<Utc>,
+
+    #[schema(format = DateTime)]
+    pub expires_at: DateTime<Utc>,
+
+    /// Invoice for the plan, free plans have none
+    pub invoice_id: Option<Uuid>,
+}
+impl From<ClientPlanResponse> for ClientPlan {
+    fn from(resp: ClientPlanResponse) -> Self {
+        Self {
+            id: Uuid::parse_str(&resp.id).unwrap(),
+            plan: resp.plan.into(),
+            hours_left: resp.hours_left,
+            starts_at: timestamp_into_datetime(resp.starts_at).unwrap(),
+            expires_at: timestamp_into_datetime(resp.expires_at).unwrap(),
+            invoice_id: resp.invoice_id.map(|id| Uuid::parse_str(&id).unwrap()),
+        }
+    }
+}
+impl ClientPlan {
+    pub fn vec_from_proto(resp: ClientPlansResponse) -> Vec<Self> {
+        resp.plans.into_iter().map(Self::from).collect()
+    }
+}
@@ -1,7 +1,10 @@
 use chrono::{DateTime, TimeDelta, Timelike, Utc};
 use convertions::{datetime_into_timestamp, timestamp_into_datetime};
-use protos::reservation::{
-    CreateRequest, ReservationResponse, ReservationsResponse, UpdateRequest,
+use protos::{
+    notification::{HoldRequest, ReleaseSlotRequest},
+    reservation::{
+        CreateRequest, ExtendRequest, ReservationResponse, ReservationsResponse, UpdateRequest,
+    },
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -18,6 +21,11 @@ pub struct CreateReservation {
 
     #[schema(format = DateTime)]
     pub ends_at: DateTime<Utc>,
+
+    #[validate(length(min = 1, max = 32))]
+    #[serde(default, skip_serializing)]
+    #[schema(write_only, min_length = 1, max_length = 32, examples("WELCOME10"))]
+    pub promo_code: Option<String>,
 }
 impl CreateReservation {
     fn validate_custom(&self) -> Result<(), ValidationError> {
@@ -38,10 +46,19 @@ impl CreateReservation {
         }
     }
 
-    pub fn into_proto(self, client_id: Uuid, is_admin: bool) -> CreateRequest {
+    pub fn into_proto(self, client_id: Uuid, price: f64) -> CreateRequest {
         CreateRequest {
             client_id: client_id.to_string(),
-            is_admin,
+            seat_id: self.seat_id.to_string(),
+            starts_at: datetime_into_timestamp(self.starts_at),
+            ends_at: datetime_into_timestamp(self.ends_at),
+            price: Some(price),
+        }
+    }
+
+    pub fn hold_request(&self, client_id: Uuid) -> HoldRequest {
+        HoldRequest {
+            client_id: client_id.to_string(),
             seat_id: self.seat_id.to_string(),
             starts_at: datetime_into_timestamp(self.starts_at),
             ends_at: datetime_into_timestamp(self.ends_at),
@@ -51,13 +68,47 @@ impl CreateReservation {
 impl From<ReservationResponse> for CreateReservation {
     fn from(resp: ReservationResponse) -> Self {
         Self {
-            seat_id: Uuid::parse_str(&resp.client_id.to_string()).unwrap(),
+            seat_id: Uuid::parse_str(&resp.seat_id).unwrap(),
             starts_at: timestamp_into_datetime(resp.starts_at).unwrap(),
             ends_at: timestamp_into_datetime(resp.ends_at).unwrap(),
+            promo_code: None,
         }
     }
 }
 
+#[derive(Serialize, ToSchema, Debug)]
+pub struct ReservationQuote {
+    pub seat_id: Uuid,
+
+    #[schema(format = DateTime)]
+    pub starts_at: DateTime<Utc>,
+
+    #[schema(format = DateTime)]
+    pub ends_at: DateTime<Utc>,
+
+    #[schema(examples(300.0))]
+    pub hourly_cost: f64,
+
+    #[schema(examples(1.5))]
+    pub peak_hours: f64,
+
+    #[schema(examples(0.5))]
+    pub off_peak_hours: f64,
+
+    /// Price with peak and off-peak multipliers, before discounts
+    #[schema(examples(682.5))]
+    pub base_price: f64,
+
+    #[schema(examples(68.25))]
+    pub discount: f64,
+
+    #[schema(examples("WELCOME10"))]
+    pub promo_code: Option<String>,
+
+    #[schema(examples(614.25))]
+    pub price: f64,
+}
+
 #[derive(Serialize, ToSchema, Debug)]
 pub struct Reservation {
     pub id: Uuid,
@@ -68,18 +119,55 @@ pub struct Reservation {
 
     #[schema(default = false, examples(false, true))]
     pub cancelled: bool,
+
+    /// Price fixed when the reservation was created, quoted again when it is moved or resized
+    #[schema(examples(614.25))]
+    pub price: Option<f64>,
+
+    #[schema(format = DateTime)]
+    pub checked_in_at: Option<DateTime<Utc>>,
+
+    /// Set on check-out or automatically once the reservation ends
+    #[schema(format = DateTime)]
+    pub checked_out_at: Option<DateTime<Utc>>,
+
+    #[schema(examples(120))]
+    pub booked_minutes: i64,
+
+    /// Time actually spent at the seat, known once the client checked out
+    #[schema(examples(95))]
+    pub occupied_minutes: Option<i64>,
 }
 impl From<ReservationResponse> for Reservation {
     fn from(resp: ReservationResponse) -> Self {
+        let inner: CreateReservation = resp.clone().into();
+        let checked_in_at = resp.checked_in_at.and_then(timestamp_into_datetime);
+        let checked_out_at = resp.checked_out_at.and_then(timestamp_into_datetime);
+
         Self {
             id: Uuid::parse_str(&resp.id).unwrap(),
-            client_id: Uuid::parse_str(&resp.id).unwrap(),
-            inner: resp.clone().into(),
+            client_id: Uuid::parse_str(&resp.client_id).unwrap(),
+            booked_minutes: (inner.ends_at - inner.starts_at).num_minutes(),
+            occupied_minutes: checked_in_at.zip(checked_out_at).map(
+                |(checked_in_at, checked_out_at)| (checked_out_at - checked_in_at).num_minutes(),
+            ),
+            inner,
             cancelled: resp.is_canceled,
+            price: resp.price,
+            checked_in_at,
+            checked_out_at,
         }
     }
 }
 impl Reservation {
+    pub fn release_slot_request(&self) -> ReleaseSlotRequest {
+        ReleaseSlotRequest {
+            seat_id: self.inner.seat_id.to_string(),
+            starts_at: datetime_into_timestamp(self.inner.starts_at),
+            ends_at: datetime_into_timestamp(self.inner.ends_at),
+        }
+    }
+
     pub fn vec_from_proto(resp: ReservationsResponse) -> Vec<Self> {
         resp.reservations
             .iter()
@@ -88,6 +176,41 @@ impl Reservation {
     }
 }
 
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[validate(schema(function = "Self::validate_custom"))]
+pub struct ExtendReservation {
+    #[schema(format = DateTime)]
+    pub ends_at: DateTime<Utc>,
+}
+impl ExtendReservation {
+    fn validate_custom(&self) -> Result<(), ValidationError> {
+        if self.ends_at.hour() < 8 || self.ends_at.hour() >= 21 {
+            return Err(ValidationError::new(
+                "`ends_at` hour must be between 8 and 21",
+            ));
+        }
+        Ok(())
+    }
+
+    /// The extra time the reservation is extended by, to be priced and checked against holds.
+    pub fn extension(&self, current: &Reservation) -> CreateReservation {
+        CreateReservation {
+            seat_id: current.inner.seat_id,
+            starts_at: current.inner.ends_at,
+            ends_at: self.ends_at,
+            promo_code: None,
+        }
+    }
+
+    pub fn into_proto(self, reservation_id: Uuid, price: Option<f64>) -> ExtendRequest {
+        ExtendRequest {
+            id: reservation_id.to_string(),
+            ends_at: datetime_into_timestamp(self.ends_at),
+            price,
+        }
+    }
+}
+
 #[derive(Deserialize, Validate, ToSchema, Debug)]
 #[validate(schema(function = "Self::validate_custom"))]
 pub struct ReservationUpdate {
@@ -98,6 +221,12 @@ pub struct ReservationUpdate {
 
     #[schema(format = DateTime)]
     pub ends_at: Option<DateTime<Utc>>,
+
+    /// Applied to the new price when the reservation is moved or resized
+    #[validate(length(min = 1, max = 32))]
+    #[serde(default)]
+    #[schema(min_length = 1, max_length = 32, examples("WELCOME10"))]
+    pub promo_code: Option<String>,
 }
 impl ReservationUpdate {
     fn validate_custom(&self) -> Result<(), ValidationError> {
@@ -121,21 +250,29 @@ impl ReservationUpdate {
             _ => Ok(()),
         }
     }
-    pub fn into_proto(
-        self,
-        reservation_id: Uuid,
-        entity_id: Uuid,
-        is_admin: bool,
-    ) -> UpdateRequest {
+    pub fn into_proto(self, reservation_id: Uuid, price: Option<f64>) -> UpdateRequest {
         UpdateRequest {
             id: reservation_id.to_string(),
-            client_id: entity_id.to_string(),
-            is_admin,
             seat_id: self.seat_id.map(|i| i.to_string()),
             starts_at: self.starts_at.map(datetime_into_timestamp),
             ends_at: self.ends_at.map(datetime_into_timestamp),
             is_canceled: None,
             is_visited: None,
+            price,
+        }
+    }
+
+    pub fn changes_slot(&self) -> bool {
+        self.seat_id.is_some() || self.starts_at.is_some() || self.ends_at.is_some()
+    }
+
+    /// The slot the reservation ends up in, to be priced and checked against holds.
+    pub fn slot(&self, current: &Reservation) -> CreateReservation {
+        CreateReservation {
+            seat_id: self.seat_id.unwrap_or(current.inner.seat_id),
+            starts_at: self.starts_at.unwrap_or(current.inner.starts_at),
+            ends_at: self.ends_at.unwrap_or(current.inner.ends_at),
+            promo_code: self.promo_code.clone(),
         }
     }
 }
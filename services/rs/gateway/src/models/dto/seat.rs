@@ -12,7 +12,7 @@ pub enum SeatType {
     Room(u64),
 }
 impl SeatType {
-    fn validate_custom(&self) -> Result<(), ValidationError> {
+    pub fn validate_custom(&self) -> Result<(), ValidationError> {
         match self {
             Self::Cabin(capacity) if *capacity < 1 || 2 < *capacity => Err(ValidationError::new(
                 "field value `capacity` for seat type `cabin` must be between 1 and 2",
@@ -25,13 +25,24 @@ impl SeatType {
     }
 
     fn from_resp(resp: &SeatResponse) -> Option<Self> {
-        match resp.r#type.as_str() {
+        Self::from_parts(&resp.r#type, resp.capacity)
+    }
+
+    pub fn from_parts(r#type: &str, capacity: u64) -> Option<Self> {
+        match r#type {
             "OpenSpace" => Some(Self::OpenSpace),
-            "Cabin" => Some(Self::Cabin(resp.capacity)),
-            "Room" => Some(Self::Room(resp.capacity)),
+            "Cabin" => Some(Self::Cabin(capacity)),
+            "Room" => Some(Self::Room(capacity)),
             _ => None,
         }
     }
+
+    pub fn capacity(&self) -> u64 {
+        match self {
+            Self::OpenSpace => 1,
+            Self::Cabin(capacity) | Self::Room(capacity) => *capacity,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Display, ToSchema, Debug)]
@@ -65,17 +76,14 @@ pub struct CreateSeat {
     #[schema(format = Double, exclusive_minimum = 0.0, maximum = 1_000_000.0, examples(100.0))]
     pub cost: f64,
 }
-impl Into<CreateSeatRequest> for CreateSeat {
-    fn into(self) -> CreateSeatRequest {
-        CreateSeatRequest {
-            r#type: self.r#type.to_string(),
-            number: self.number,
-            capacity: match self.r#type {
-                SeatType::OpenSpace => 1,
-                SeatType::Room(capacity) | SeatType::Cabin(capacity) => capacity,
-            },
-            features: self.features.iter().map(|f| f.to_string()).collect(),
-            cost: self.cost,
+impl From<CreateSeat> for CreateSeatRequest {
+    fn from(value: CreateSeat) -> Self {
+        Self {
+            r#type: value.r#type.to_string(),
+            number: value.number,
+            capacity: value.r#type.capacity(),
+            features: value.features.iter().map(|f| f.to_string()).collect(),
+            cost: value.cost,
         }
     }
 }
@@ -130,10 +138,7 @@ impl SeatUpdate {
         UpdateSeatRequest {
             id: id.to_string(),
             r#type: self.r#type.clone().map(|t| t.to_string()),
-            capacity: self.r#type.map(|t| match t {
-                SeatType::OpenSpace => 1,
-                SeatType::Cabin(capacity) | SeatType::Room(capacity) => capacity,
-            }),
+            capacity: self.r#type.map(|t| t.capacity()),
             number: self.number,
             features: self.features.iter().map(|f| f.to_string()).collect(),
             cost: self.cost,
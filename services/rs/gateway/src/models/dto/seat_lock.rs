@@ -0,0 +1,46 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use protos::seat_lock::{LockResponse, SetLockRequest};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema, Default, Debug)]
+pub struct HoldSeat {
+    #[validate(range(min = 30, max = 1800))]
+    #[schema(format = UInt64, minimum = 30, maximum = 1800, examples(300))]
+    pub ttl_seconds: Option<u64>,
+}
+impl HoldSeat {
+    pub fn into_proto(self, seat_id: Uuid, holder_id: Uuid) -> SetLockRequest {
+        SetLockRequest {
+            user_id: holder_id.to_string(),
+            seat_index: seat_id.to_string(),
+            ttl_seconds: self.ttl_seconds,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct SeatHold {
+    pub seat_id: Uuid,
+    pub holder_id: Uuid,
+
+    #[schema(format = UInt64, examples(300))]
+    pub ttl_seconds: u64,
+
+    #[schema(format = DateTime)]
+    pub expires_at: DateTime<Utc>,
+}
+impl From<LockResponse> for SeatHold {
+    fn from(resp: LockResponse) -> Self {
+        let ttl_seconds = resp.ttl_seconds.unwrap_or_default();
+
+        Self {
+            seat_id: Uuid::parse_str(&resp.seat_index.unwrap_or_default()).unwrap(),
+            holder_id: Uuid::parse_str(&resp.user_id.unwrap_or_default()).unwrap(),
+            ttl_seconds,
+            expires_at: Utc::now() + TimeDelta::seconds(ttl_seconds as i64),
+        }
+    }
+}
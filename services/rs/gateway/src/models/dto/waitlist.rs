@@ -0,0 +1,133 @@
+use chrono::{DateTime, TimeDelta, Timelike, Utc};
+use convertions::{datetime_into_timestamp, timestamp_into_datetime};
+use protos::notification::{JoinWaitlistRequest, WaitlistEntriesResponse, WaitlistEntryResponse};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use super::seat::SeatType;
+
+#[derive(Deserialize, Validate, ToSchema, Debug)]
+#[validate(schema(function = "Self::validate_custom"))]
+pub struct JoinWaitlist {
+    pub seat_id: Option<Uuid>,
+
+    #[validate(custom(function = "SeatType::validate_custom"))]
+    pub seat_type: Option<SeatType>,
+
+    #[schema(format = DateTime)]
+    pub starts_at: DateTime<Utc>,
+
+    #[schema(format = DateTime)]
+    pub ends_at: DateTime<Utc>,
+}
+impl JoinWaitlist {
+    fn validate_custom(&self) -> Result<(), ValidationError> {
+        match (&self.seat_id, &self.seat_type) {
+            (None, None) => {
+                return Err(ValidationError::new(
+                    "either `seat_id` or `seat_type` must be specified",
+                ))
+            }
+            (Some(..), Some(..)) => {
+                return Err(ValidationError::new(
+                    "only one of `seat_id` and `seat_type` can be specified",
+                ))
+            }
+            _ => (),
+        }
+
+        match (self.starts_at, self.ends_at) {
+            (starts, ..) if starts <= Utc::now() => {
+                Err(ValidationError::new("`starts_at` must be in the future"))
+            }
+            (starts, ..) if starts.hour() < 8 || starts.hour() >= 21 => Err(ValidationError::new(
+                "`starts_at` hour must be between 8 and 21",
+            )),
+            (.., ends) if ends.hour() < 8 || ends.hour() >= 21 => Err(ValidationError::new(
+                "`ends_at` hour must be between 8 and 21",
+            )),
+            (starts, ends) if ends - starts < TimeDelta::minutes(15) => Err(ValidationError::new(
+                "`starts_at` must be earlier than `ends_at` by at least 15 minutes",
+            )),
+            (starts, ends) if ends - starts >= TimeDelta::hours(13) => Err(ValidationError::new(
+                "`starts_at` must be earlier than `ends_at` by at most 13 hours",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn into_proto(self, client_id: Uuid) -> JoinWaitlistRequest {
+        JoinWaitlistRequest {
+            client_id: client_id.to_string(),
+            seat_id: self.seat_id.map(|id| id.to_string()),
+            seat_type: self.seat_type.as_ref().map(|t| t.to_string()),
+            seat_capacity: self.seat_type.map(|t| t.capacity()),
+            starts_at: datetime_into_timestamp(self.starts_at),
+            ends_at: datetime_into_timestamp(self.ends_at),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub enum WaitlistStatus {
+    Waiting,
+    Offered,
+    Fulfilled,
+    Expired,
+}
+impl WaitlistStatus {
+    fn from_resp(status: &str) -> Option<Self> {
+        match status {
+            "waiting" => Some(Self::Waiting),
+            "offered" => Some(Self::Offered),
+            "fulfilled" => Some(Self::Fulfilled),
+            "expired" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct WaitlistEntry {
+    pub id: Uuid,
+
+    pub seat_id: Option<Uuid>,
+
+    pub seat_type: Option<SeatType>,
+
+    #[schema(format = DateTime)]
+    pub starts_at: DateTime<Utc>,
+
+    #[schema(format = DateTime)]
+    pub ends_at: DateTime<Utc>,
+
+    pub status: WaitlistStatus,
+
+    pub offered_seat_id: Option<Uuid>,
+
+    #[schema(format = DateTime)]
+    pub hold_expires_at: Option<DateTime<Utc>>,
+}
+impl From<WaitlistEntryResponse> for WaitlistEntry {
+    fn from(resp: WaitlistEntryResponse) -> Self {
+        Self {
+            id: Uuid::parse_str(&resp.id).unwrap(),
+            seat_id: resp.seat_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            seat_type: resp
+                .seat_type
+                .and_then(|t| SeatType::from_parts(&t, resp.seat_capacity.unwrap_or(1))),
+            starts_at: timestamp_into_datetime(resp.starts_at).unwrap(),
+            ends_at: timestamp_into_datetime(resp.ends_at).unwrap(),
+            status: WaitlistStatus::from_resp(&resp.status).unwrap(),
+            offered_seat_id: resp.offered_seat_id.map(|id| Uuid::parse_str(&id).unwrap()),
+            hold_expires_at: resp.hold_expires_at.and_then(timestamp_into_datetime),
+        }
+    }
+}
+impl WaitlistEntry {
+    pub fn vec_from_proto(resp: WaitlistEntriesResponse) -> Vec<Self> {
+        resp.entries.into_iter().map(Self::from).collect()
+    }
+}
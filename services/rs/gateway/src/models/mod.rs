@@ -1,5 +1,6 @@
 use actix_web::{body::EitherBody, web::Json, HttpRequest, HttpResponse, Responder};
 use serde::{Deserialize, Serialize};
+use service_helper::request_id;
 use utoipa::ToSchema;
 
 mod date;
@@ -14,6 +15,18 @@ pub use date::Date;
 pub struct ApiError<'a> {
     pub error: &'a str,
     pub description: String,
+    /// Id of the request, to be mentioned when reporting the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+impl<'a> ApiError<'a> {
+    pub fn new(error: &'a str, description: String) -> Self {
+        Self {
+            error,
+            description,
+            request_id: request_id::current(),
+        }
+    }
 }
 
 #[derive(Serialize, Debug)]
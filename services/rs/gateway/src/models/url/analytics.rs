@@ -0,0 +1,94 @@
+use chrono::{NaiveDate, NaiveTime, TimeDelta};
+use convertions::datetime_into_timestamp;
+use protos::analytics::{Granularity, HeatmapRequest, OccupancyRequest, PeriodRequest};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::dto::PlanSeatType;
+
+#[derive(Deserialize, IntoParams, Debug)]
+pub struct PeriodQuery {
+    /// First day of the period
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+
+    /// Last day of the period, inclusive
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+}
+impl From<PeriodQuery> for PeriodRequest {
+    fn from(query: PeriodQuery) -> Self {
+        Self {
+            starts_at: day_start(query.from),
+            ends_at: day_start(query.to + TimeDelta::days(1)),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Default, Clone, Copy, Debug)]
+pub enum AnalyticsGranularity {
+    Hour,
+    #[default]
+    Day,
+}
+impl From<AnalyticsGranularity> for Granularity {
+    fn from(granularity: AnalyticsGranularity) -> Self {
+        match granularity {
+            AnalyticsGranularity::Hour => Self::Hour,
+            AnalyticsGranularity::Day => Self::Day,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+pub struct OccupancyQuery {
+    /// First day of the period
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+
+    /// Last day of the period, inclusive
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+
+    #[param(inline)]
+    pub granularity: Option<AnalyticsGranularity>,
+}
+impl From<OccupancyQuery> for OccupancyRequest {
+    fn from(query: OccupancyQuery) -> Self {
+        let mut request = Self {
+            starts_at: day_start(query.from),
+            ends_at: day_start(query.to + TimeDelta::days(1)),
+            granularity: 0,
+        };
+        request.set_granularity(query.granularity.unwrap_or_default().into());
+        request
+    }
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+pub struct HeatmapQuery {
+    /// First day of the period
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+
+    /// Last day of the period, inclusive
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+
+    /// Only include seats of this type
+    #[param(inline)]
+    pub seat_type: Option<PlanSeatType>,
+}
+impl From<HeatmapQuery> for HeatmapRequest {
+    fn from(query: HeatmapQuery) -> Self {
+        Self {
+            starts_at: day_start(query.from),
+            ends_at: day_start(query.to + TimeDelta::days(1)),
+            seat_type: query.seat_type.map(|seat_type| seat_type.to_string()),
+        }
+    }
+}
+
+fn day_start(day: NaiveDate) -> prost_types::Timestamp {
+    datetime_into_timestamp(day.and_time(NaiveTime::MIN).and_utc())
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::dto::RecipientStatus;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct AnnouncementPath {
+    pub announcement_id: Uuid,
+}
+
+#[derive(Deserialize, IntoParams, Validate, Debug)]
+pub struct AnnouncementRecipientsQuery {
+    #[validate(range(min = 0))]
+    #[param(format = UInt32, minimum = 0, default = 7, example = 7)]
+    pub limit: Option<u32>,
+
+    #[validate(range(min = 0))]
+    #[param(format = UInt64, minimum = 0, default = 0, example = 1)]
+    pub offset: Option<u64>,
+
+    /// Only recipients with this delivery status
+    #[param(inline)]
+    pub status: Option<RecipientStatus>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams, Debug)]
+pub struct CalendarFeedQuery {
+    /// Calendar feed token
+    pub token: String,
+}
@@ -0,0 +1,52 @@
+use chrono::{NaiveDate, TimeDelta};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+const MAX_EXPORT_DAYS: i64 = 366;
+
+#[derive(Deserialize, ToSchema, Default, Clone, Copy, Debug)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+#[derive(Deserialize, IntoParams, Validate, Debug)]
+#[validate(schema(function = "Self::validate_custom"))]
+pub struct ExportQuery {
+    /// First day of the period
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+
+    /// Last day of the period, inclusive
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+
+    /// Spreadsheet format of the export
+    #[param(inline)]
+    pub format: Option<ExportFormat>,
+}
+impl ExportQuery {
+    fn validate_custom(&self) -> Result<(), ValidationError> {
+        if self.to < self.from {
+            return Err(ValidationError::new("`to` must not be before `from`"));
+        }
+        if self.to - self.from >= TimeDelta::days(MAX_EXPORT_DAYS) {
+            return Err(ValidationError::new(
+                "the period must not be longer than 366 days",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn days(&self) -> Vec<NaiveDate> {
+        let to = self.to;
+        self.from.iter_days().take_while(|day| *day <= to).collect()
+    }
+
+    /// File name of the export, without an extension.
+    pub fn file_name(&self, name: &str) -> String {
+        format!("{name}_{}_{}", self.from, self.to)
+    }
+}
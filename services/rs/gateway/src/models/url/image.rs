@@ -0,0 +1,39 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct ImagePath {
+    pub image_id: Uuid,
+}
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct SeatImagePath {
+    pub seat_id: Uuid,
+    pub image_id: Uuid,
+}
+
+#[derive(Deserialize, ToSchema, Default, Clone, Copy, Debug)]
+pub enum ImageVariant {
+    #[default]
+    Original,
+    Medium,
+    Thumbnail,
+}
+impl From<ImageVariant> for protos::image::ImageVariant {
+    fn from(variant: ImageVariant) -> Self {
+        match variant {
+            ImageVariant::Original => Self::Original,
+            ImageVariant::Medium => Self::Medium,
+            ImageVariant::Thumbnail => Self::Thumbnail,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+pub struct ImageQuery {
+    /// Image variant to download. Medium and thumbnail variants are downscaled WebP images
+    #[param(inline)]
+    pub variant: Option<ImageVariant>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct InvoicePath {
+    pub invoice_id: Uuid,
+}
@@ -1,12 +1,27 @@
 mod admin;
+mod analytics;
+mod announcement;
+mod calendar;
 mod client;
+mod export;
+mod image;
+mod invoice;
 mod pagination;
+mod plan;
 mod reservation;
 mod seat;
+mod waitlist;
 
 pub use admin::AdminPath;
+pub use analytics::{AnalyticsGranularity, HeatmapQuery, OccupancyQuery, PeriodQuery};
+pub use announcement::{AnnouncementPath, AnnouncementRecipientsQuery};
+pub use calendar::CalendarFeedQuery;
 pub use client::ClientPath;
+pub use export::{ExportFormat, ExportQuery};
+pub use image::{ImagePath, ImageQuery, ImageVariant, SeatImagePath};
+pub use invoice::InvoicePath;
 pub use pagination::{ByDateWithPaginationQuery, Pagination};
-pub use reservation::ReservationPath;
+pub use plan::PlanPath;
+pub use reservation::{QrFormat, QrQuery, ReservationPath};
 pub use seat::SeatPath;
-
+pub use waitlist::WaitlistEntryPath;
@@ -27,28 +27,24 @@ impl Pagination {
             Some(limit) => limit,
             None => 7,
         };
-        let offset = match self.offset {
-            Some(offset) => offset,
-            None => 0,
-        };
+        let offset = self.offset.unwrap_or_default();
         (limit, offset)
     }
-    pub fn into_proto(self, id: Uuid, is_admin: bool) -> GetByClientRequest {
+    pub fn into_proto(self, id: Uuid) -> GetByClientRequest {
         let (limit, offset) = self.parse();
 
         GetByClientRequest {
             client_id: id.to_string(),
-            is_admin,
             limit,
             offset,
         }
     }
 }
-impl Into<GetSeatsRequest> for Pagination {
-    fn into(self) -> GetSeatsRequest {
-        let (limit, offset) = self.parse();
+impl From<Pagination> for GetSeatsRequest {
+    fn from(value: Pagination) -> Self {
+        let (limit, offset) = value.parse();
 
-        GetSeatsRequest { limit, offset }
+        Self { limit, offset }
     }
 }
 
@@ -95,11 +91,11 @@ impl ByDateWithPaginationQuery {
         (limit, offset, day, month, year)
     }
 }
-impl Into<GetRequest> for ByDateWithPaginationQuery {
-    fn into(self) -> GetRequest {
-        let (limit, offset, day, month, year) = self.fields();
+impl From<ByDateWithPaginationQuery> for GetRequest {
+    fn from(value: ByDateWithPaginationQuery) -> Self {
+        let (limit, offset, day, month, year) = value.fields();
 
-        GetRequest {
+        Self {
             limit,
             offset,
             day,
@@ -108,16 +104,17 @@ impl Into<GetRequest> for ByDateWithPaginationQuery {
         }
     }
 }
-impl Into<ClientsRequest> for ByDateWithPaginationQuery {
-    fn into(self) -> ClientsRequest {
-        let (limit, offset, day, month, year) = self.fields();
+impl From<ByDateWithPaginationQuery> for ClientsRequest {
+    fn from(value: ByDateWithPaginationQuery) -> Self {
+        let (limit, offset, day, month, year) = value.fields();
 
-        ClientsRequest {
+        Self {
             limit,
             offset,
             day,
             month,
             year,
+            ids: vec![],
         }
     }
 }
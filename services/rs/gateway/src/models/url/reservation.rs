@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -6,3 +7,17 @@ use validator::Validate;
 pub struct ReservationPath {
     pub reservation_id: Uuid,
 }
+
+#[derive(Deserialize, ToSchema, Default, Clone, Copy, Debug)]
+pub enum QrFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+#[derive(Deserialize, IntoParams, Debug)]
+pub struct QrQuery {
+    /// Image format of the check-in code
+    #[param(inline)]
+    pub format: Option<QrFormat>,
+}
@@ -0,0 +1,188 @@
+use actix_web::{
+    get, post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use actix_web_lab::extract::Query;
+use protos::analytics::analytics_client::AnalyticsClient;
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::{
+    models::{
+        dto::{HeatmapCell, OccupancyPoint, Rates, Revenue},
+        url::{HeatmapQuery, OccupancyQuery, PeriodQuery},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/analytics")
+            .wrap(default_cors())
+            .service(occupancy_handler)
+            .service(heatmap_handler)
+            .service(rates_handler)
+            .service(revenue_handler)
+            .service(rebuild_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "analytics",
+    operation_id = "get_occupancy",
+    description = "Booked and actually occupied seat hours per seat type, by hour or by day. \
+        Hours are in UTC and limited to the opening hours",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        OccupancyQuery
+    ),
+    responses(
+        (status = 200, body = Vec<OccupancyPoint>),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/occupancy")]
+async fn occupancy_handler(
+    analytics_client: Data<AnalyticsClient<ServiceChannel>>,
+    Query(query): Query<OccupancyQuery>,
+) -> Result<Json<Vec<OccupancyPoint>>, ApiError> {
+    let response = (**analytics_client)
+        .clone()
+        .get_occupancy(Request::new(query.into()))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(OccupancyPoint::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "analytics",
+    operation_id = "get_utilisation_heatmap",
+    description = "Occupancy and utilisation by weekday and hour over the period",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        HeatmapQuery
+    ),
+    responses(
+        (status = 200, body = Vec<HeatmapCell>),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/heatmap")]
+async fn heatmap_handler(
+    analytics_client: Data<AnalyticsClient<ServiceChannel>>,
+    Query(query): Query<HeatmapQuery>,
+) -> Result<Json<Vec<HeatmapCell>>, ApiError> {
+    let response = (**analytics_client)
+        .clone()
+        .get_heatmap(Request::new(query.into()))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(HeatmapCell::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "analytics",
+    operation_id = "get_cancellation_rates",
+    description = "No-show and cancellation rates over the period",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        PeriodQuery
+    ),
+    responses(
+        (status = 200, body = Rates),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/rates")]
+async fn rates_handler(
+    analytics_client: Data<AnalyticsClient<ServiceChannel>>,
+    Query(query): Query<PeriodQuery>,
+) -> Result<Json<Rates>, ApiError> {
+    let response = (**analytics_client)
+        .clone()
+        .get_rates(Request::new(query.into()))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "analytics",
+    operation_id = "get_revenue",
+    description = "Daily revenue per seat type over the period",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        PeriodQuery
+    ),
+    responses(
+        (status = 200, body = Revenue),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/revenue")]
+async fn revenue_handler(
+    analytics_client: Data<AnalyticsClient<ServiceChannel>>,
+    Query(query): Query<PeriodQuery>,
+) -> Result<Json<Revenue>, ApiError> {
+    let response = (**analytics_client)
+        .clone()
+        .get_revenue(Request::new(query.into()))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "analytics",
+    operation_id = "rebuild_analytics",
+    description = "Recomputes the rollups of the period. The last few days are kept up to date \
+        automatically, this is meant for backfilling older history",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        PeriodQuery
+    ),
+    responses(
+        (status = 204),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("/rebuild")]
+async fn rebuild_handler(
+    analytics_client: Data<AnalyticsClient<ServiceChannel>>,
+    Query(query): Query<PeriodQuery>,
+) -> Result<HttpResponse, ApiError> {
+    (**analytics_client)
+        .clone()
+        .rebuild(Request::new(query.into()))
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
@@ -0,0 +1,179 @@
+use actix_web::{
+    get, post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use actix_web_lab::extract::{Path, Query};
+use protos::notification::{
+    notification_client::NotificationClient, AnnouncementRequest, GetAnnouncementRecipientsRequest,
+    GetAnnouncementsRequest,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    models::{
+        dto::{Announcement, AnnouncementRecipient, CreateAnnouncement},
+        url::{AnnouncementPath, AnnouncementRecipientsQuery, Pagination},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/announcements")
+            .wrap(default_cors())
+            .service(post_handler)
+            .service(get_handler)
+            .service(get_by_id_handler)
+            .service(get_recipients_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "announcements",
+    operation_id = "create_announcement",
+    description = "Broadcasts an email to the audience. Every recipient gets a separate email, \
+        they are sent in the background in batches",
+    security(
+        ("admin" = [])
+    ),
+    responses(
+        (status = 202, description = "Announcement was queued", body = Announcement),
+        (status = 400, description = "Invalid body", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    Json(body): Json<CreateAnnouncement>,
+) -> Result<HttpResponse, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let response = (**notification_client)
+        .clone()
+        .create_announcement(Request::new(body.into_proto()?))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(HttpResponse::Accepted().json(Announcement::from(response)))
+}
+
+#[utoipa::path(
+    tag = "announcements",
+    operation_id = "get_announcements",
+    description = "Fetches announcements with their delivery progress, newest first",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        Pagination
+    ),
+    responses(
+        (status = 200, body = Vec<Announcement>),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    Query(query): Query<Pagination>,
+) -> Result<Json<Vec<Announcement>>, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let (limit, offset) = query.parse();
+
+    let response = (**notification_client)
+        .clone()
+        .get_announcements(Request::new(GetAnnouncementsRequest { limit, offset }))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(Announcement::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "announcements",
+    operation_id = "get_announcement",
+    description = "Fetches the announcement with its delivery progress",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("announcement_id" = Uuid, description = "Announcement ID")
+    ),
+    responses(
+        (status = 200, body = Announcement),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/{announcement_id}")]
+async fn get_by_id_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    Path(path): Path<AnnouncementPath>,
+) -> Result<Json<Announcement>, ApiError> {
+    let response = (**notification_client)
+        .clone()
+        .get_announcement(Request::new(AnnouncementRequest {
+            id: path.announcement_id.to_string(),
+        }))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "announcements",
+    operation_id = "get_announcement_recipients",
+    description = "Fetches delivery statuses of the announcement recipients",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("announcement_id" = Uuid, description = "Announcement ID"),
+        AnnouncementRecipientsQuery
+    ),
+    responses(
+        (status = 200, body = Vec<AnnouncementRecipient>),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/{announcement_id}/recipients")]
+async fn get_recipients_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    Path(path): Path<AnnouncementPath>,
+    Query(query): Query<AnnouncementRecipientsQuery>,
+) -> Result<Json<Vec<AnnouncementRecipient>>, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let (limit, offset) = Pagination {
+        limit: query.limit,
+        offset: query.offset,
+    }
+    .parse();
+
+    let response = (**notification_client)
+        .clone()
+        .get_announcement_recipients(Request::new(GetAnnouncementRecipientsRequest {
+            id: path.announcement_id.to_string(),
+            limit,
+            offset,
+            status: query.status.map(|status| status.to_proto()),
+        }))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(AnnouncementRecipient::vec_from_proto(response)))
+}
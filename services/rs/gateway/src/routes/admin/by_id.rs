@@ -17,7 +17,8 @@ use actix_web::{
 };
 use actix_web_lab::extract::Path;
 use protos::admin::{admin_client::AdminClient, AdminRequest};
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
@@ -50,14 +51,14 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     Path(path): Path<AdminPath>,
 ) -> Result<Json<Admin>, ApiError> {
     let request = Request::new(AdminRequest {
         id: path.admin_id.to_string(),
     });
 
-    let response = (&**admin_client)
+    let response = (**admin_client)
         .clone()
         .get(request)
         .await
@@ -86,7 +87,7 @@ async fn get_handler(
 )]
 #[patch("")]
 pub async fn patch_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     Path(path): Path<AdminPath>,
     Json(body): Json<AdminUpdate>,
 ) -> Result<Json<Admin>, ApiError> {
@@ -94,7 +95,7 @@ pub async fn patch_handler(
 
     let request = Request::new(body.into_proto(path.admin_id));
 
-    let response = (&**admin_client)
+    let response = (**admin_client)
         .clone()
         .edit(request)
         .await
@@ -122,14 +123,14 @@ pub async fn patch_handler(
 )]
 #[delete("")]
 pub async fn delete_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     Path(path): Path<AdminPath>,
 ) -> Result<HttpResponse, ApiError> {
     let request = Request::new(AdminRequest {
         id: path.admin_id.to_string(),
     });
 
-    (&**admin_client)
+    (**admin_client)
         .clone()
         .delete(request)
         .await
@@ -0,0 +1,279 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpRequest, HttpResponse,
+};
+use actix_web_lab::extract::Query;
+use chrono::{Datelike, NaiveDate};
+use convertions::timestamp_into_datetime;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use protos::{
+    client::{client_client::ClientClient, ClientsRequest},
+    coworking::{coworking_client::CoworkingClient, GetSeatsRequest},
+    reservation::{reservation_client::ReservationClient, GetRequest},
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use tonic::Code;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{
+        dto::{ClientExportRow, ReservationExportRow},
+        url::ExportQuery,
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{
+        cors::default_cors, export::export_response, services::ServiceError,
+        validation::validation_errors_to_err,
+    },
+};
+
+const SEATS_PAGE_SIZE: u32 = 57;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/export")
+            .wrap(default_cors())
+            .service(reservations_handler)
+            .service(clients_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "export",
+    operation_id = "export_reservations",
+    description = "Exports the reservations starting within the period as a CSV or XLSX spreadsheet, \
+        along with client names and seat numbers. Times are in UTC",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ExportQuery
+    ),
+    responses(
+        (status = 200, description = "Reservations spreadsheet, in XLSX if requested", content_type = "text/csv"),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/reservations")]
+async fn reservations_handler(
+    request: HttpRequest,
+    entity: ReqData<AuthEntity>,
+    client_client: Data<ClientClient<ServiceChannel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    Query(query): Query<ExportQuery>,
+) -> Result<HttpResponse, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let seat_numbers = Rc::new(get_seat_numbers(&coworking_client).await?);
+
+    let batches = reservation_batches(
+        query.days(),
+        entity.principal(),
+        (**client_client).clone(),
+        (**reservation_client).clone(),
+        seat_numbers,
+    );
+
+    export_response(
+        query.format.unwrap_or_default(),
+        query.file_name("reservations"),
+        batches,
+        &request,
+    )
+    .await
+}
+
+#[utoipa::path(
+    tag = "export",
+    operation_id = "export_clients",
+    description = "Exports the clients who visited the coworking within the period \
+        as a CSV or XLSX spreadsheet, along with the day of their first visit",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ExportQuery
+    ),
+    responses(
+        (status = 200, description = "Clients spreadsheet, in XLSX if requested", content_type = "text/csv"),
+        (status = 400, description = "Invalid period", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/clients")]
+async fn clients_handler(
+    request: HttpRequest,
+    entity: ReqData<AuthEntity>,
+    client_client: Data<ClientClient<ServiceChannel>>,
+    Query(query): Query<ExportQuery>,
+) -> Result<HttpResponse, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let batches = client_batches(query.days(), entity.principal(), (**client_client).clone());
+
+    export_response(
+        query.format.unwrap_or_default(),
+        query.file_name("clients"),
+        batches,
+        &request,
+    )
+    .await
+}
+
+/// Fetches the reservations day by day. A reservation is exported on the day it starts,
+/// the clients of every day are fetched with a single request.
+/// The batches are fetched while the response is streamed, past the scope of the auth middleware,
+/// so they are fetched on behalf of the principal passed in.
+fn reservation_batches(
+    days: Vec<NaiveDate>,
+    principal: Principal,
+    client_client: ClientClient<ServiceChannel>,
+    reservation_client: ReservationClient<ServiceChannel>,
+    seat_numbers: Rc<HashMap<String, u64>>,
+) -> impl Stream<Item = Result<Vec<ReservationExportRow>, ApiError>> {
+    stream::iter(days).then(move |day| {
+        let mut client_client = client_client.clone();
+        let mut reservation_client = reservation_client.clone();
+        let seat_numbers = seat_numbers.clone();
+
+        principal::scope(principal.clone(), async move {
+            let mut reservations: Vec<_> = reservation_client
+                .get(GetRequest {
+                    limit: 0,
+                    offset: 0,
+                    day: Some(day.day()),
+                    month: Some(day.month()),
+                    year: Some(day.year() as u64),
+                })
+                .await
+                .map_err(ServiceError::from)?
+                .into_inner()
+                .reservations
+                .into_iter()
+                .filter(|reservation| {
+                    timestamp_into_datetime(reservation.starts_at)
+                        .is_some_and(|starts_at| starts_at.date_naive() == day)
+                })
+                .collect();
+            if reservations.is_empty() {
+                return Ok(vec![]);
+            }
+            reservations.sort_by_key(|reservation| reservation.starts_at.seconds);
+
+            let ids: HashSet<_> = reservations
+                .iter()
+                .map(|reservation| reservation.client_id.clone())
+                .collect();
+
+            let clients: HashMap<_, _> = client_client
+                .get_multiple(ClientsRequest {
+                    limit: 0,
+                    offset: 0,
+                    day: None,
+                    month: None,
+                    year: None,
+                    ids: ids.into_iter().collect(),
+                })
+                .await
+                .map_err(ServiceError::from)?
+                .into_inner()
+                .clients
+                .into_iter()
+                .map(|client| (client.id, client.meta))
+                .collect();
+
+            Ok(reservations
+                .into_iter()
+                .map(|reservation| {
+                    let client = clients.get(&reservation.client_id).cloned();
+                    let seat_number = seat_numbers.get(&reservation.seat_id).copied();
+                    ReservationExportRow::new(reservation, client, seat_number)
+                })
+                .collect())
+        })
+    })
+}
+
+/// Fetches the visitors day by day, skipping the ones already exported for an earlier day.
+fn client_batches(
+    days: Vec<NaiveDate>,
+    principal: Principal,
+    client_client: ClientClient<ServiceChannel>,
+) -> impl Stream<Item = Result<Vec<ClientExportRow>, ApiError>> {
+    let mut exported = HashSet::new();
+
+    stream::iter(days)
+        .then(move |day| {
+            let mut client_client = client_client.clone();
+
+            principal::scope(principal.clone(), async move {
+                let clients = client_client
+                    .get_multiple(ClientsRequest {
+                        limit: 0,
+                        offset: 0,
+                        day: Some(day.day()),
+                        month: Some(day.month()),
+                        year: Some(day.year() as u64),
+                        ids: vec![],
+                    })
+                    .await
+                    .map_err(ServiceError::from)?
+                    .into_inner()
+                    .clients;
+
+                Ok::<_, ApiError>((day, clients))
+            })
+        })
+        .map_ok(move |(day, clients)| {
+            clients
+                .into_iter()
+                .filter(|client| exported.insert(client.id.clone()))
+                .map(|client| ClientExportRow::new(client, day))
+                .collect()
+        })
+}
+
+async fn get_seat_numbers(
+    coworking_client: &CoworkingClient<ServiceChannel>,
+) -> Result<HashMap<String, u64>, ApiError> {
+    let mut seat_numbers = HashMap::new();
+    let mut offset = 0;
+    loop {
+        let page = match coworking_client
+            .clone()
+            .get_seats(GetSeatsRequest {
+                limit: SEATS_PAGE_SIZE,
+                offset,
+            })
+            .await
+        {
+            Ok(response) => response.into_inner().seats,
+            Err(status) if status.code() == Code::NotFound => break,
+            Err(status) => return Err(ServiceError::from(status).into()),
+        };
+        let fetched = page.len();
+
+        seat_numbers.extend(page.into_iter().map(|seat| (seat.id, seat.number)));
+
+        if fetched < SEATS_PAGE_SIZE as usize {
+            break;
+        }
+        offset += SEATS_PAGE_SIZE as u64;
+    }
+
+    Ok(seat_numbers)
+}
@@ -3,7 +3,11 @@ use actix_web::{
     web::{Data, Json},
 };
 use protos::admin::admin_client::AdminClient;
-use tonic::{transport::Channel, Request};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use tonic::Request;
 use validator::Validate;
 
 use crate::{
@@ -23,19 +27,21 @@ use crate::{
 )]
 #[post("/login")]
 pub async fn post_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     Json(body): Json<Credentials>,
 ) -> Result<Json<AdminAuthResponse>, ApiError> {
     body.validate().map_err(validation_errors_to_err)?;
 
     let request = Request::new(body.into());
 
-    let response = (&**admin_client)
-        .clone()
-        .login(request)
-        .await
-        .map_err(ServiceError::from)?
-        .into_inner();
+    // nobody is signed in yet, so the gateway calls on its own behalf
+    let response = principal::scope(
+        Principal::service("gateway"),
+        (**admin_client).clone().login(request),
+    )
+    .await
+    .map_err(ServiceError::from)?
+    .into_inner();
 
     Ok(Json(response.into()))
 }
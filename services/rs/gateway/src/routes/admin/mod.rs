@@ -15,13 +15,17 @@ use actix_web::{
     HttpResponse,
 };
 use protos::admin::{admin_client::AdminClient, AdminRequest};
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
 use super::ApiError;
 
+mod analytics;
+mod announcement;
 mod by_id;
+mod export;
 mod login;
 mod password;
 mod register;
@@ -40,6 +44,9 @@ pub fn config(cfg: &mut ServiceConfig) {
                     .service(patch_handler)
                     .service(delete_handler)
                     .service(password::put_handler)
+                    .configure(analytics::config)
+                    .configure(announcement::config)
+                    .configure(export::config)
                     .configure(by_id::config),
             ),
     );
@@ -59,7 +66,7 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
 ) -> Result<Json<Admin>, ApiError> {
     let admin = entity.into_inner().into_admin()?;
@@ -68,7 +75,7 @@ async fn get_handler(
         id: admin.id.to_string(),
     });
 
-    let response = (&**admin_client)
+    let response = (**admin_client)
         .clone()
         .get(request)
         .await
@@ -93,7 +100,7 @@ async fn get_handler(
 )]
 #[patch("")]
 async fn patch_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     Json(body): Json<AdminUpdate>,
 ) -> Result<Json<Admin>, ApiError> {
@@ -103,7 +110,7 @@ async fn patch_handler(
 
     let request = Request::new(body.into_proto(admin.id));
 
-    let response = (&**admin_client)
+    let response = (**admin_client)
         .clone()
         .edit(request)
         .await
@@ -128,7 +135,7 @@ async fn patch_handler(
 )]
 #[delete("")]
 async fn delete_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
 ) -> Result<HttpResponse, ApiError> {
     let admin = entity.into_inner().into_admin()?;
@@ -137,7 +144,7 @@ async fn delete_handler(
         id: admin.id.to_string(),
     });
 
-    (&**admin_client)
+    (**admin_client)
         .clone()
         .delete(request)
         .await
@@ -13,7 +13,8 @@ use actix_web::{
 use protos::admin::{
     admin_client::AdminClient, ChangePasswordRequest as ProtoChangePasswordRequest,
 };
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use validator::Validate;
 
 #[utoipa::path(
@@ -32,7 +33,7 @@ use validator::Validate;
 )]
 #[put("/password")]
 pub async fn put_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     body: Json<ChangePasswordRequest>,
 ) -> Result<Json<AdminAuthResponse>, ApiError> {
@@ -46,7 +47,7 @@ pub async fn put_handler(
         new_password: body.new_password.clone(),
     });
 
-    let response = (&**admin_client)
+    let response = (**admin_client)
         .clone()
         .change_password(request)
         .await
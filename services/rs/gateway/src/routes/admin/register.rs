@@ -3,7 +3,8 @@ use actix_web::{
     web::{Data, Json},
 };
 use protos::admin::admin_client::AdminClient;
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use validator::Validate;
 
 use crate::{
@@ -27,14 +28,14 @@ use crate::{
 )]
 #[post("/register")]
 pub async fn post_handler(
-    admin_client: Data<AdminClient<Channel>>,
+    admin_client: Data<AdminClient<ServiceChannel>>,
     Json(body): Json<AdminForm>,
 ) -> Result<Json<AdminAuthResponse>, ApiError> {
     body.validate().map_err(validation_errors_to_err)?;
 
     let request = Request::new(body.into());
 
-    let response = (&**admin_client)
+    let response = (**admin_client)
         .clone()
         .register(request)
         .await
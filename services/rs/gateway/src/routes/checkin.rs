@@ -0,0 +1,98 @@
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpRequest,
+};
+use protos::{
+    billing::billing_client::BillingClient,
+    reservation::{reservation_client::ReservationClient, GetByIdRequest},
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use subtle::ConstantTimeEq;
+use tonic::Request;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    config,
+    models::{
+        dto::{CheckIn, Reservation},
+        ApiError as ApiErrorModel,
+    },
+    utils::{
+        checkin::{check_reservation, mark_visited, parse_token},
+        services::ServiceError,
+        validation::validation_errors_to_err,
+    },
+};
+
+use super::ApiError;
+
+const DEVICE_KEY_HEADER: &str = "X-Device-Key";
+
+#[utoipa::path(
+    tag = "reservations",
+    operation_id = "check_in",
+    description = "Marks the visit by the scanned check-in code. Only the front desk devices may call it, \
+        passing their key in the `X-Device-Key` header",
+    responses(
+        (status = 200, body = Reservation),
+        (status = 400, description = "Code is invalid, outside of its window or the reservation is cancelled", body = ApiErrorModel),
+        (status = 403, description = "Invalid device key", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel)
+    ),
+)]
+#[post("/checkin")]
+pub async fn post_handler(
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    req: HttpRequest,
+    Json(body): Json<CheckIn>,
+) -> Result<Json<Reservation>, ApiError> {
+    let device_key = req
+        .headers()
+        .get(DEVICE_KEY_HEADER)
+        .map_or(&[][..], |value| value.as_bytes());
+    if !bool::from(device_key.ct_eq(config::CHECKIN_DEVICE_KEY.expose().as_bytes())) {
+        return Err(ApiError::NotOwner);
+    }
+
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let reservation_id = parse_token(&body.code)?;
+
+    // the code is signed by the gateway, so the visit is marked by the gateway itself
+    principal::scope(
+        Principal::service("gateway"),
+        check_in(&reservation_client, &billing_client, reservation_id),
+    )
+    .await
+    .map(Json)
+}
+
+async fn check_in(
+    reservation_client: &ReservationClient<ServiceChannel>,
+    billing_client: &BillingClient<ServiceChannel>,
+    reservation_id: Uuid,
+) -> Result<Reservation, ApiError> {
+    let reservation = reservation_client
+        .clone()
+        .get_by_id(Request::new(GetByIdRequest {
+            id: reservation_id.to_string(),
+        }))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    check_reservation(&reservation)?;
+    if reservation.is_visited {
+        return Ok(reservation.into());
+    }
+
+    let response = mark_visited(reservation_client, billing_client, reservation_id).await?;
+
+    Ok(response.into())
+}
@@ -0,0 +1,139 @@
+use actix_multipart::Multipart;
+use actix_web::{
+    delete, get, post,
+    web::{Data, Query, ReqData},
+    HttpResponse,
+};
+use protos::{
+    image::{image_client::ImageClient, DeleteImageRequest, ImageType},
+    image_worker::image_worker_client::ImageWorkerClient,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{
+        dto::{Image, ImageForm},
+        url::ImageQuery,
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{
+        cors::default_cors,
+        images::{download_image, upload_image},
+        services::ServiceError,
+    },
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/avatar")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(post_handler)
+            .service(delete_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "get_client_avatar",
+    description = "Downloads current client avatar",
+    security(
+        ("client" = [])
+    ),
+    params(
+        ImageQuery
+    ),
+    responses(
+        (status = 200, description = "Image content", content_type = "image/*"),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Query(query): Query<ImageQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    download_image(
+        &image_client,
+        client.id.to_string(),
+        ImageType::Avatar,
+        query.variant.unwrap_or_default(),
+    )
+    .await
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "upload_client_avatar",
+    description = "Uploads (or replaces) current client avatar. Accepts JPEG, PNG and WebP",
+    security(
+        ("client" = [])
+    ),
+    request_body(content = ImageForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, body = Image),
+        (status = 400, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 413, description = "Image is too large", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    image_worker_client: Data<ImageWorkerClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    payload: Multipart,
+) -> Result<HttpResponse, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    upload_image(
+        &image_worker_client,
+        payload,
+        client.id.to_string(),
+        ImageType::Avatar,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(Image { id: client.id }))
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "delete_client_avatar",
+    description = "Deletes current client avatar",
+    security(
+        ("client" = [])
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("")]
+async fn delete_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+) -> Result<HttpResponse, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    let request = Request::new(DeleteImageRequest {
+        id: client.id.to_string(),
+        image_type: ImageType::Avatar.into(),
+    });
+
+    (**image_client)
+        .clone()
+        .delete_image(request)
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
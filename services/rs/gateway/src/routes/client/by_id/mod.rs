@@ -17,7 +17,8 @@ use actix_web::{
 };
 use actix_web_lab::extract::Path;
 use protos::client::{client_client::ClientClient, ClientRequest};
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
@@ -53,14 +54,14 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     Path(path): Path<ClientPath>,
 ) -> Result<Json<Client>, ApiError> {
     let request = Request::new(ClientRequest {
         id: path.client_id.to_string(),
     });
 
-    let response = (&**client_client)
+    let response = (**client_client)
         .clone()
         .get(request)
         .await
@@ -89,7 +90,7 @@ async fn get_handler(
 )]
 #[patch("")]
 pub async fn patch_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     Path(path): Path<ClientPath>,
     Json(body): Json<ClientUpdate>,
 ) -> Result<Json<Client>, ApiError> {
@@ -97,7 +98,7 @@ pub async fn patch_handler(
 
     let request = Request::new(body.into_proto(path.client_id));
 
-    let response = (&**client_client)
+    let response = (**client_client)
         .clone()
         .edit(request)
         .await
@@ -125,14 +126,14 @@ pub async fn patch_handler(
 )]
 #[delete("")]
 pub async fn delete_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     Path(path): Path<ClientPath>,
 ) -> Result<HttpResponse, ApiError> {
     let request = Request::new(ClientRequest {
         id: path.client_id.to_string(),
     });
 
-    (&**client_client)
+    (**client_client)
         .clone()
         .delete(request)
         .await
@@ -4,7 +4,8 @@ use actix_web::{
 };
 use actix_web_lab::extract::Path;
 use protos::reservation::reservation_client::ReservationClient;
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use validator::Validate;
 
 use crate::{
@@ -35,15 +36,15 @@ use crate::{
 )]
 #[get("/reservations")]
 async fn get_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
     Path(path): Path<ClientPath>,
     Query(query): Query<Pagination>,
 ) -> Result<Json<Vec<Reservation>>, ApiError> {
     query.validate().map_err(validation_errors_to_err)?;
 
-    let request = Request::new(query.into_proto(path.client_id, true));
+    let request = Request::new(query.into_proto(path.client_id));
 
-    let response = (&**reservation_client)
+    let response = (**reservation_client)
         .clone()
         .get_by_client(request)
         .await
@@ -0,0 +1,149 @@
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, ReqData},
+    HttpRequest, HttpResponse,
+};
+use actix_web_lab::extract::Query;
+use protos::{
+    client::{client_client::ClientClient, CalendarTokenRequest, ClientRequest},
+    coworking::coworking_client::CoworkingClient,
+    reservation::reservation_client::ReservationClient,
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use uuid::Uuid;
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{dto::CalendarFeed, url::CalendarFeedQuery, ApiError as ApiErrorModel},
+    routes::ApiError,
+    utils::{calendar::reservation_feed, cors::default_cors, services::ServiceError},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/calendar")
+            .wrap(default_cors())
+            .service(post_handler)
+            .service(delete_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "get_client_calendar_feed",
+    description = "iCalendar feed with all client reservations, for subscribing from calendar apps. \
+        Authorized by the feed token instead of the session",
+    params(
+        CalendarFeedQuery
+    ),
+    responses(
+        (status = 200, description = "iCalendar feed", content_type = "text/calendar"),
+        (status = 401, description = "Invalid or revoked token", body = ApiErrorModel)
+    ),
+)]
+#[get("/reservations.ics")]
+pub async fn feed_handler(
+    client_client: Data<ClientClient<ServiceChannel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    coworking_id: Data<Uuid>,
+    Query(query): Query<CalendarFeedQuery>,
+) -> Result<HttpResponse, ApiError> {
+    // nobody is signed in until the token is resolved, so it's resolved on the gateway's own behalf
+    let client = principal::scope(
+        Principal::service("gateway"),
+        (**client_client)
+            .clone()
+            .get_by_calendar_token(Request::new(CalendarTokenRequest { token: query.token })),
+    )
+    .await
+    .map_err(ServiceError::from)?
+    .into_inner();
+
+    // the feed token stands in for the session, so the feed is fetched on behalf of its owner
+    let feed = principal::scope(
+        Principal::client(&client.id),
+        reservation_feed(
+            &coworking_client,
+            &reservation_client,
+            **coworking_id,
+            Uuid::parse_str(&client.id).unwrap(),
+        ),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(calendar::CONTENT_TYPE)
+        .body(feed))
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "issue_client_calendar_feed",
+    description = "Issues a personal calendar feed URL. The previously issued URL stops working",
+    security(
+        ("client" = [])
+    ),
+    responses(
+        (status = 200, body = CalendarFeed),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("/token")]
+async fn post_handler(
+    request: HttpRequest,
+    client_client: Data<ClientClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+) -> Result<Json<CalendarFeed>, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    let response = (**client_client)
+        .clone()
+        .issue_calendar_token(Request::new(ClientRequest {
+            id: client.id.to_string(),
+        }))
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    let connection = request.connection_info();
+    let base_url = format!("{}://{}", connection.scheme(), connection.host());
+
+    Ok(Json(CalendarFeed::from_proto(response, &base_url)))
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "revoke_client_calendar_feed",
+    description = "Revokes the calendar feed URL",
+    security(
+        ("client" = [])
+    ),
+    responses(
+        (status = 204),
+        (status = 404, description = "No calendar feed was issued", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("/token")]
+async fn delete_handler(
+    client_client: Data<ClientClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+) -> Result<HttpResponse, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    (**client_client)
+        .clone()
+        .revoke_calendar_token(Request::new(ClientRequest {
+            id: client.id.to_string(),
+        }))
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
@@ -15,17 +15,22 @@ use actix_web::{
     HttpResponse,
 };
 use protos::client::{client_client::ClientClient, ClientRequest};
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
 use super::ApiError;
 
+mod avatar;
 mod by_id;
+mod calendar;
 mod login;
 mod password;
+mod plan;
 mod register;
 mod reservations;
+mod waitlist;
 
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(
@@ -33,6 +38,7 @@ pub fn config(cfg: &mut ServiceConfig) {
             .wrap(default_cors())
             .service(register::post_handler)
             .service(login::post_handler)
+            .service(calendar::feed_handler)
             .service(
                 scope("")
                     .wrap(default_cors())
@@ -42,6 +48,10 @@ pub fn config(cfg: &mut ServiceConfig) {
                     .service(delete_handler)
                     .service(password::put_handler)
                     .service(reservations::get_handler)
+                    .configure(avatar::config)
+                    .configure(calendar::config)
+                    .configure(plan::config)
+                    .configure(waitlist::config)
                     .configure(by_id::config),
             ),
     );
@@ -61,7 +71,7 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
 ) -> Result<Json<Client>, ApiError> {
     let client = entity.into_inner().into_client()?;
@@ -70,7 +80,7 @@ async fn get_handler(
         id: client.id.to_string(),
     });
 
-    let response = (&**client_client)
+    let response = (**client_client)
         .clone()
         .get(request)
         .await
@@ -95,7 +105,7 @@ async fn get_handler(
 )]
 #[patch("")]
 async fn patch_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     Json(body): Json<ClientUpdate>,
 ) -> Result<Json<Client>, ApiError> {
@@ -105,7 +115,7 @@ async fn patch_handler(
 
     let request = Request::new(body.into_proto(client.id));
 
-    let response = (&**client_client)
+    let response = (**client_client)
         .clone()
         .edit(request)
         .await
@@ -130,7 +140,7 @@ async fn patch_handler(
 )]
 #[delete("")]
 async fn delete_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
 ) -> Result<HttpResponse, ApiError> {
     let client = entity.into_inner().into_client()?;
@@ -139,7 +149,7 @@ async fn delete_handler(
         id: client.id.to_string(),
     });
 
-    (&**client_client)
+    (**client_client)
         .clone()
         .delete(request)
         .await
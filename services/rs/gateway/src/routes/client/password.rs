@@ -13,7 +13,8 @@ use actix_web::{
 use protos::client::{
     client_client::ClientClient, ChangePasswordRequest as ProtoChangePasswordRequest,
 };
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use validator::Validate;
 
 #[utoipa::path(
@@ -32,7 +33,7 @@ use validator::Validate;
 )]
 #[put("/password")]
 pub async fn put_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     body: Json<ChangePasswordRequest>,
 ) -> Result<Json<ClientAuthResponse>, ApiError> {
@@ -46,7 +47,7 @@ pub async fn put_handler(
         new_password: body.new_password.clone(),
     });
 
-    let response = (&**client_client)
+    let response = (**client_client)
         .clone()
         .change_password(request)
         .await
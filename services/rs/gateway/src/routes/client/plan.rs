@@ -0,0 +1,102 @@
+use actix_web::{
+    get, post,
+    web::{Data, Json, ReqData},
+};
+use protos::billing::{billing_client::BillingClient, AssignPlanRequest, GetClientPlansRequest};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{
+        dto::{AssignPlan, ClientPlan},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/plan")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(post_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "get_client_plans",
+    description = "Fetches the client's active plans along with the hours left on them",
+    security(
+        ("client" = [])
+    ),
+    responses(
+        (status = 200, body = Vec<ClientPlan>),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+) -> Result<Json<Vec<ClientPlan>>, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    let request = Request::new(GetClientPlansRequest {
+        client_id: client.id.to_string(),
+        include_expired: false,
+    });
+
+    let response = (**billing_client)
+        .clone()
+        .get_client_plans(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(ClientPlan::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "buy_plan",
+    description = "Buys a plan. The hours can be spent right away, \
+        while a pending invoice is issued for a paid plan",
+    security(
+        ("client" = [])
+    ),
+    responses(
+        (status = 200, body = ClientPlan),
+        (status = 400, description = "Invalid body or the plan is no longer available", body = ApiErrorModel),
+        (status = 404, description = "Plan was not found", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Json(body): Json<AssignPlan>,
+) -> Result<Json<ClientPlan>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let client = entity.into_inner().into_client()?;
+
+    let request = Request::new(AssignPlanRequest {
+        client_id: client.id.to_string(),
+        plan_id: body.plan_id.to_string(),
+    });
+
+    let response = (**billing_client)
+        .clone()
+        .assign_plan(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
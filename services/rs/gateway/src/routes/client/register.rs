@@ -3,7 +3,11 @@ use actix_web::{
     web::{Data, Json},
 };
 use protos::client::client_client::ClientClient;
-use tonic::{transport::Channel, Request};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use tonic::Request;
 use validator::Validate;
 
 use crate::{
@@ -24,19 +28,21 @@ use crate::{
 )]
 #[post("/register")]
 pub async fn post_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     Json(body): Json<ClientForm>,
 ) -> Result<Json<ClientAuthResponse>, ApiError> {
     body.validate().map_err(validation_errors_to_err)?;
 
     let request = Request::new(body.into());
 
-    let response = (&**client_client)
-        .clone()
-        .register(request)
-        .await
-        .map_err(ServiceError::from)?
-        .into_inner();
+    // nobody is signed in yet, so the gateway calls on its own behalf
+    let response = principal::scope(
+        Principal::service("gateway"),
+        (**client_client).clone().register(request),
+    )
+    .await
+    .map_err(ServiceError::from)?
+    .into_inner();
 
     Ok(Json(response.into()))
 }
@@ -3,7 +3,8 @@ use actix_web::{
     web::{Data, Json, Query, ReqData},
 };
 use protos::reservation::reservation_client::ReservationClient;
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use validator::Validate;
 
 use crate::{
@@ -30,7 +31,7 @@ use crate::{
 )]
 #[get("/reservations")]
 async fn get_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     Query(query): Query<Pagination>,
 ) -> Result<Json<Vec<Reservation>>, ApiError> {
@@ -38,9 +39,9 @@ async fn get_handler(
 
     let client = entity.into_inner().into_client()?;
 
-    let request = Request::new(query.into_proto(client.id, false));
+    let request = Request::new(query.into_proto(client.id));
 
-    let response = (&**reservation_client)
+    let response = (**reservation_client)
         .clone()
         .get_by_client(request)
         .await
@@ -0,0 +1,152 @@
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Query, ReqData},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use protos::notification::{
+    notification_client::NotificationClient, GetWaitlistRequest, LeaveWaitlistRequest,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{
+        dto::{JoinWaitlist, WaitlistEntry},
+        url::{Pagination, WaitlistEntryPath},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/waitlist")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(post_handler)
+            .service(delete_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "get_client_waitlist",
+    description = "Fetches client waitlist entries",
+    security(
+        ("client" = [])
+    ),
+    params(
+        Pagination
+    ),
+    responses(
+        (status = 200, body = Vec<WaitlistEntry>),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Query(query): Query<Pagination>,
+) -> Result<Json<Vec<WaitlistEntry>>, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let client = entity.into_inner().into_client()?;
+
+    let (limit, offset) = query.parse();
+
+    let request = Request::new(GetWaitlistRequest {
+        client_id: client.id.to_string(),
+        limit,
+        offset,
+    });
+
+    let response = (**notification_client)
+        .clone()
+        .get_waitlist(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(WaitlistEntry::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "join_waitlist",
+    description = "Joins the waitlist for a specific seat or for any seat of a type. \
+        Once a matching reservation is cancelled, the slot is held for the client \
+        and an email with the offer is sent",
+    security(
+        ("client" = [])
+    ),
+    responses(
+        (status = 200, body = WaitlistEntry),
+        (status = 400, description = "Invalid body", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Json(body): Json<JoinWaitlist>,
+) -> Result<Json<WaitlistEntry>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let client = entity.into_inner().into_client()?;
+
+    let request = Request::new(body.into_proto(client.id));
+
+    let response = (**notification_client)
+        .clone()
+        .join_waitlist(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "clients",
+    operation_id = "leave_waitlist",
+    description = "Leaves the waitlist. A held seat is passed on to the next client in line",
+    security(
+        ("client" = [])
+    ),
+    params(
+        ("entry_id" = Uuid, description = "Waitlist entry ID")
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("/{entry_id}")]
+async fn delete_handler(
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Path(path): Path<WaitlistEntryPath>,
+) -> Result<HttpResponse, ApiError> {
+    let client = entity.into_inner().into_client()?;
+
+    let request = Request::new(LeaveWaitlistRequest {
+        id: path.entry_id.to_string(),
+        client_id: client.id.to_string(),
+    });
+
+    (**notification_client)
+        .clone()
+        .leave_waitlist(request)
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
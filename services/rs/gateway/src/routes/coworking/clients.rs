@@ -4,7 +4,8 @@ use actix_web::{
 };
 use actix_web_lab::extract::Query;
 use protos::client::client_client::ClientClient;
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 
 use crate::{
     models::{dto::Client, url::ByDateWithPaginationQuery, ApiError as ApiErrorModel},
@@ -26,12 +27,12 @@ use crate::{
 )]
 #[get("/clients")]
 async fn get_handler(
-    client_client: Data<ClientClient<Channel>>,
+    client_client: Data<ClientClient<ServiceChannel>>,
     Query(query): Query<ByDateWithPaginationQuery>,
 ) -> Result<Json<Vec<Client>>, ApiError> {
     let request = Request::new(query.into());
 
-    let response = (&**client_client)
+    let response = (**client_client)
         .clone()
         .get_multiple(request)
         .await
@@ -0,0 +1,165 @@
+use actix_multipart::Multipart;
+use actix_web::{
+    delete, get,
+    middleware::from_fn,
+    post,
+    web::{Data, Json, Query},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use protos::{
+    image::{image_client::ImageClient, DeleteImageRequest, ImageType},
+    image_worker::image_worker_client::ImageWorkerClient,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use uuid::Uuid;
+
+use crate::{
+    auth::middleware::admin_auth_middleware,
+    models::{
+        dto::{Image, ImageForm},
+        url::{ImagePath, ImageQuery},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{
+        cors::default_cors,
+        images::{download_image, list_images, upload_image},
+        services::ServiceError,
+    },
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/images")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(get_by_id_handler)
+            .service(
+                scope("")
+                    .wrap(default_cors())
+                    .wrap(from_fn(admin_auth_middleware))
+                    .service(post_handler)
+                    .service(delete_handler),
+            ),
+    );
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "get_coworking_images",
+    description = "Lists coworking image IDs",
+    responses(
+        (status = 200, body = Vec<Image>)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    coworking_id: Data<Uuid>,
+) -> Result<Json<Vec<Image>>, ApiError> {
+    let ids = list_images(&image_client, **coworking_id, ImageType::Coworking).await?;
+
+    Ok(Json(ids.into_iter().map(|id| Image { id }).collect()))
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "get_coworking_image",
+    description = "Downloads coworking image",
+    params(
+        ("image_id" = Uuid, description = "Image ID"),
+        ImageQuery
+    ),
+    responses(
+        (status = 200, description = "Image content", content_type = "image/*"),
+        (status = 404, body = ApiErrorModel)
+    ),
+)]
+#[get("/{image_id}")]
+async fn get_by_id_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    coworking_id: Data<Uuid>,
+    Path(path): Path<ImagePath>,
+    Query(query): Query<ImageQuery>,
+) -> Result<HttpResponse, ApiError> {
+    download_image(
+        &image_client,
+        format!("{}/{}", **coworking_id, path.image_id),
+        ImageType::Coworking,
+        query.variant.unwrap_or_default(),
+    )
+    .await
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "upload_coworking_image",
+    description = "Uploads coworking image. Accepts JPEG, PNG and WebP",
+    security(
+        ("admin" = [])
+    ),
+    request_body(content = ImageForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, body = Image),
+        (status = 400, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 413, description = "Image is too large", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    image_worker_client: Data<ImageWorkerClient<ServiceChannel>>,
+    coworking_id: Data<Uuid>,
+    payload: Multipart,
+) -> Result<HttpResponse, ApiError> {
+    let id = Uuid::now_v7();
+
+    upload_image(
+        &image_worker_client,
+        payload,
+        format!("{}/{id}", **coworking_id),
+        ImageType::Coworking,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(Image { id }))
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "delete_coworking_image",
+    description = "Deletes coworking image",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("image_id" = Uuid, description = "Image ID")
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("/{image_id}")]
+async fn delete_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    coworking_id: Data<Uuid>,
+    Path(path): Path<ImagePath>,
+) -> Result<HttpResponse, ApiError> {
+    let request = Request::new(DeleteImageRequest {
+        id: format!("{}/{}", **coworking_id, path.image_id),
+        image_type: ImageType::Coworking.into(),
+    });
+
+    (**image_client)
+        .clone()
+        .delete_image(request)
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
@@ -14,12 +14,14 @@ use actix_web::{
     web::{Data, Json},
 };
 use protos::coworking::{coworking_client::CoworkingClient, GetCoworkingByIdRequest};
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use uuid::Uuid;
 use validator::Validate;
 
 mod clients;
+mod images;
 mod seats;
 
 pub fn config(cfg: &mut ServiceConfig) {
@@ -27,6 +29,7 @@ pub fn config(cfg: &mut ServiceConfig) {
         scope("/coworking")
             .wrap(default_cors())
             .service(get_handler)
+            .configure(images::config)
             .configure(seats::config)
             .service(
                 scope("")
@@ -49,14 +52,14 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     coworking_id: Data<Uuid>,
 ) -> Result<Json<Coworking>, ApiError> {
     let request = Request::new(GetCoworkingByIdRequest {
         id: coworking_id.to_string(),
     });
 
-    let response = (&**coworking_client)
+    let response = (**coworking_client)
         .clone()
         .get_by_id(request)
         .await
@@ -81,7 +84,7 @@ async fn get_handler(
 )]
 #[patch("")]
 async fn patch_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     coworking_id: Data<Uuid>,
     Json(body): Json<CoworkingUpdate>,
 ) -> Result<Json<Coworking>, ApiError> {
@@ -89,7 +92,7 @@ async fn patch_handler(
 
     let request = Request::new(body.into_proto(**coworking_id));
 
-    let response = (&**coworking_client)
+    let response = (**coworking_client)
         .clone()
         .update(request)
         .await
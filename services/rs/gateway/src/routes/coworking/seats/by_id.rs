@@ -17,7 +17,8 @@ use actix_web::{
 };
 use actix_web_lab::extract::Path;
 use protos::coworking::{coworking_client::CoworkingClient, SeatRequest};
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
@@ -50,14 +51,14 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     Path(path): Path<SeatPath>,
 ) -> Result<Json<Seat>, ApiError> {
     let request = Request::new(SeatRequest {
         id: path.seat_id.to_string(),
     });
 
-    let response = (&**coworking_client)
+    let response = (**coworking_client)
         .clone()
         .get_seat(request)
         .await
@@ -86,7 +87,7 @@ async fn get_handler(
 )]
 #[patch("")]
 pub async fn patch_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     Path(path): Path<SeatPath>,
     Json(body): Json<SeatUpdate>,
 ) -> Result<Json<Seat>, ApiError> {
@@ -94,7 +95,7 @@ pub async fn patch_handler(
 
     let request = Request::new(body.into_proto(path.seat_id));
 
-    let response = (&**coworking_client)
+    let response = (**coworking_client)
         .clone()
         .update_seat(request)
         .await
@@ -122,14 +123,14 @@ pub async fn patch_handler(
 )]
 #[delete("")]
 pub async fn delete_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     Path(path): Path<SeatPath>,
 ) -> Result<HttpResponse, ApiError> {
     let request = Request::new(SeatRequest {
         id: path.seat_id.to_string(),
     });
 
-    (&**coworking_client)
+    (**coworking_client)
         .clone()
         .delete_seat(request)
         .await
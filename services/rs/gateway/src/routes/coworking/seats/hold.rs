@@ -0,0 +1,131 @@
+use crate::{
+    auth::middleware::{any_auth_middleware, AuthEntity},
+    models::{
+        dto::{HoldSeat, SeatHold},
+        url::SeatPath,
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+use actix_web::{
+    delete,
+    middleware::from_fn,
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use protos::{
+    coworking::{coworking_client::CoworkingClient, SeatRequest},
+    seat_lock::{seat_lock_client::SeatLockClient, ReleaseLockRequest},
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/{seat_id}/hold")
+            .wrap(default_cors())
+            .wrap(from_fn(any_auth_middleware))
+            .service(post_handler)
+            .service(delete_handler),
+    );
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "hold_seat",
+    description = "Temporarily holds the seat so nobody else can book it until the hold expires. \
+        Holding the seat again extends the hold; a holder keeps at most one seat at a time",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("seat_id" = Uuid, description = "Seat ID")
+    ),
+    responses(
+        (status = 200, body = SeatHold),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 409, description = "Seat is held by someone else", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    seat_lock_client: Data<SeatLockClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Path(path): Path<SeatPath>,
+    Json(body): Json<HoldSeat>,
+) -> Result<Json<SeatHold>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let id = match entity.into_inner() {
+        AuthEntity::Client(client) => client.id,
+        AuthEntity::Admin(admin) => admin.id,
+    };
+
+    (**coworking_client)
+        .clone()
+        .get_seat(SeatRequest {
+            id: path.seat_id.to_string(),
+        })
+        .await
+        .map_err(ServiceError::from)?;
+
+    let request = Request::new(body.into_proto(path.seat_id, id));
+
+    let response = (**seat_lock_client)
+        .clone()
+        .set_lock(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "release_seat",
+    description = "Releases the seat hold. Does nothing if the seat isn't held by the caller",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("seat_id" = Uuid, description = "Seat ID")
+    ),
+    responses(
+        (status = 204),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("")]
+async fn delete_handler(
+    seat_lock_client: Data<SeatLockClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Path(path): Path<SeatPath>,
+) -> Result<HttpResponse, ApiError> {
+    let id = match entity.into_inner() {
+        AuthEntity::Client(client) => client.id,
+        AuthEntity::Admin(admin) => admin.id,
+    };
+
+    let request = Request::new(ReleaseLockRequest {
+        user_id: id.to_string(),
+        seat_index: path.seat_id.to_string(),
+    });
+
+    (**seat_lock_client)
+        .clone()
+        .release_lock(request)
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
@@ -0,0 +1,182 @@
+use actix_multipart::Multipart;
+use actix_web::{
+    delete, get,
+    middleware::from_fn,
+    post,
+    web::{Data, Json, Query},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use protos::{
+    coworking::{coworking_client::CoworkingClient, SeatRequest},
+    image::{image_client::ImageClient, DeleteImageRequest, ImageType},
+    image_worker::image_worker_client::ImageWorkerClient,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use uuid::Uuid;
+
+use crate::{
+    auth::middleware::admin_auth_middleware,
+    models::{
+        dto::{Image, ImageForm},
+        url::{ImageQuery, SeatImagePath, SeatPath},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{
+        cors::default_cors,
+        images::{download_image, list_images, upload_image},
+        services::ServiceError,
+    },
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/{seat_id}/images")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(get_by_id_handler)
+            .service(
+                scope("")
+                    .wrap(default_cors())
+                    .wrap(from_fn(admin_auth_middleware))
+                    .service(post_handler)
+                    .service(delete_handler),
+            ),
+    );
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "get_seat_images",
+    description = "Lists seat image IDs",
+    params(
+        ("seat_id" = Uuid, description = "Seat ID")
+    ),
+    responses(
+        (status = 200, body = Vec<Image>)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    Path(path): Path<SeatPath>,
+) -> Result<Json<Vec<Image>>, ApiError> {
+    let ids = list_images(&image_client, path.seat_id, ImageType::Seat).await?;
+
+    Ok(Json(ids.into_iter().map(|id| Image { id }).collect()))
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "get_seat_image",
+    description = "Downloads seat image",
+    params(
+        ("seat_id" = Uuid, description = "Seat ID"),
+        ("image_id" = Uuid, description = "Image ID"),
+        ImageQuery
+    ),
+    responses(
+        (status = 200, description = "Image content", content_type = "image/*"),
+        (status = 404, body = ApiErrorModel)
+    ),
+)]
+#[get("/{image_id}")]
+async fn get_by_id_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    Path(path): Path<SeatImagePath>,
+    Query(query): Query<ImageQuery>,
+) -> Result<HttpResponse, ApiError> {
+    download_image(
+        &image_client,
+        format!("{}/{}", path.seat_id, path.image_id),
+        ImageType::Seat,
+        query.variant.unwrap_or_default(),
+    )
+    .await
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "upload_seat_image",
+    description = "Uploads seat image. Accepts JPEG, PNG and WebP",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("seat_id" = Uuid, description = "Seat ID")
+    ),
+    request_body(content = ImageForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, body = Image),
+        (status = 400, body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 413, description = "Image is too large", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    image_worker_client: Data<ImageWorkerClient<ServiceChannel>>,
+    Path(path): Path<SeatPath>,
+    payload: Multipart,
+) -> Result<HttpResponse, ApiError> {
+    (**coworking_client)
+        .clone()
+        .get_seat(SeatRequest {
+            id: path.seat_id.to_string(),
+        })
+        .await
+        .map_err(ServiceError::from)?;
+
+    let id = Uuid::now_v7();
+
+    upload_image(
+        &image_worker_client,
+        payload,
+        format!("{}/{id}", path.seat_id),
+        ImageType::Seat,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(Image { id }))
+}
+
+#[utoipa::path(
+    tag = "coworkings",
+    operation_id = "delete_seat_image",
+    description = "Deletes seat image",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("seat_id" = Uuid, description = "Seat ID"),
+        ("image_id" = Uuid, description = "Image ID")
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("/{image_id}")]
+async fn delete_handler(
+    image_client: Data<ImageClient<ServiceChannel>>,
+    Path(path): Path<SeatImagePath>,
+) -> Result<HttpResponse, ApiError> {
+    let request = Request::new(DeleteImageRequest {
+        id: format!("{}/{}", path.seat_id, path.image_id),
+        image_type: ImageType::Seat.into(),
+    });
+
+    (**image_client)
+        .clone()
+        .delete_image(request)
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
@@ -16,17 +16,22 @@ use actix_web::{
 };
 use actix_web_lab::extract::Query;
 use protos::coworking::coworking_client::CoworkingClient;
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
 mod by_id;
+mod hold;
+mod images;
 
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/seats")
             .wrap(default_cors())
             .service(get_handler)
+            .configure(hold::config)
+            .configure(images::config)
             .service(
                 scope("")
                     .wrap(default_cors())
@@ -52,14 +57,14 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[post("")]
 async fn post_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     Json(body): Json<CreateSeat>,
 ) -> Result<Json<Seat>, ApiError> {
     body.validate().map_err(validation_errors_to_err)?;
 
     let request = Request::new(body.into());
 
-    let response = (&**coworking_client)
+    let response = (**coworking_client)
         .clone()
         .create_seat(request)
         .await
@@ -83,12 +88,12 @@ async fn post_handler(
 )]
 #[get("")]
 async fn get_handler(
-    coworking_client: Data<CoworkingClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
     Query(query): Query<Pagination>,
 ) -> Result<Json<Vec<Seat>>, ApiError> {
     let request = Request::new(query.into());
 
-    let response = (&**coworking_client)
+    let response = (**coworking_client)
         .clone()
         .get_seats(request)
         .await
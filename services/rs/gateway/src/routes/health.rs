@@ -1,14 +1,60 @@
-use actix_web::{get, HttpResponse};
+use actix_web::{get, web::Data, HttpResponse};
+
+use crate::{
+    models::{
+        dto::{HealthStatus, Readiness},
+        EmptyResponse,
+    },
+    utils::health::Dependencies,
+};
 
 #[utoipa::path(
     tag = "health",
     operation_id = "health",
-    description = "Test that API is working",
+    description = "Test that API is ready to serve requests, same as `/health/ready`",
     responses(
-        (status = 200, description = "API is working"),
+        (status = 200, description = "API and all the services it depends on are up", body = Readiness),
+        (status = 503, description = "Some of the services are down", body = Readiness),
     ),
 )]
 #[get("/health")]
-pub async fn get_handler() -> HttpResponse {
-    HttpResponse::Ok().into()
+pub async fn get_handler(dependencies: Data<Dependencies>) -> HttpResponse {
+    readiness(&dependencies).await
+}
+
+#[utoipa::path(
+    tag = "health",
+    operation_id = "health_live",
+    description = "Test that API process is running",
+    responses(
+        (status = 200, description = "API is running"),
+    ),
+)]
+#[get("/health/live")]
+pub async fn get_live_handler() -> HttpResponse {
+    HttpResponse::Ok().json(EmptyResponse::default())
+}
+
+#[utoipa::path(
+    tag = "health",
+    operation_id = "health_ready",
+    description = "Test that API is ready to serve requests, checking every service it depends on. \
+        Meant for diagnostics, the gateway keeps serving what it can while some services are down",
+    responses(
+        (status = 200, description = "API and all the services it depends on are up", body = Readiness),
+        (status = 503, description = "Some of the services are down", body = Readiness),
+    ),
+)]
+#[get("/health/ready")]
+pub async fn get_ready_handler(dependencies: Data<Dependencies>) -> HttpResponse {
+    readiness(&dependencies).await
+}
+
+async fn readiness(dependencies: &Dependencies) -> HttpResponse {
+    let readiness = dependencies.check().await;
+
+    match readiness.status {
+        HealthStatus::Up => HttpResponse::Ok().json(readiness),
+        HealthStatus::Down => HttpResponse::ServiceUnavailable().json(readiness),
+    }
 }
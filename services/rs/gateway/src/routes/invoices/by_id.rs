@@ -0,0 +1,195 @@
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    middleware::from_fn,
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use protos::billing::{billing_client::BillingClient, GetInvoiceRequest, RefundRequest};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::{
+    auth::middleware::admin_auth_middleware,
+    models::{
+        dto::{Invoice, Payment},
+        url::InvoicePath,
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/{invoice_id}")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(document_handler)
+            .service(pay_handler)
+            .service(
+                scope("")
+                    .wrap(default_cors())
+                    .wrap(from_fn(admin_auth_middleware))
+                    .service(refund_handler),
+            ),
+    );
+}
+
+fn invoice_request(path: InvoicePath) -> GetInvoiceRequest {
+    GetInvoiceRequest {
+        id: path.invoice_id.to_string(),
+    }
+}
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "get_invoice",
+    description = "Get invoice by ID",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("invoice_id" = Uuid, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, body = Invoice),
+        (status = 403, description = "Invoice belongs to another client", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<InvoicePath>,
+) -> Result<Json<Invoice>, ApiError> {
+    let request = Request::new(invoice_request(path));
+
+    let response = (**billing_client)
+        .clone()
+        .get_invoice(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "get_invoice_document",
+    description = "Renders the invoice as a printable HTML document",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("invoice_id" = Uuid, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, description = "Invoice document", content_type = "text/html"),
+        (status = 403, description = "Invoice belongs to another client", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/document")]
+async fn document_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<InvoicePath>,
+) -> Result<HttpResponse, ApiError> {
+    let request = Request::new(invoice_request(path));
+
+    let response = (**billing_client)
+        .clone()
+        .get_invoice_document(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(HttpResponse::Ok()
+        .content_type(response.content_type)
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Inline,
+            parameters: vec![DispositionParam::Filename(response.filename)],
+        })
+        .body(response.content))
+}
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "pay_invoice",
+    description = "Starts a payment of a pending invoice. The invoice becomes paid \
+        once the payment provider confirms it",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("invoice_id" = Uuid, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, body = Payment),
+        (status = 403, description = "Invoice belongs to another client", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 409, description = "Invoice is not pending payment", body = ApiErrorModel)
+    ),
+)]
+#[post("/pay")]
+async fn pay_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<InvoicePath>,
+) -> Result<Json<Payment>, ApiError> {
+    let request = Request::new(invoice_request(path));
+
+    let response = (**billing_client)
+        .clone()
+        .pay(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "refund_invoice",
+    description = "Refunds a paid invoice through the payment provider",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("invoice_id" = Uuid, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, body = Invoice),
+        (status = 400, description = "Invoice is not paid", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("/refund")]
+async fn refund_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<InvoicePath>,
+) -> Result<Json<Invoice>, ApiError> {
+    let request = Request::new(RefundRequest {
+        id: path.invoice_id.to_string(),
+    });
+
+    let response = (**billing_client)
+        .clone()
+        .refund(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
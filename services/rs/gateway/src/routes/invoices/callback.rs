@@ -0,0 +1,55 @@
+use actix_web::{
+    post,
+    web::{Bytes, Data},
+    HttpRequest, HttpResponse,
+};
+use protos::billing::{billing_client::BillingClient, PaymentCallbackRequest};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use tonic::Request;
+
+use crate::{models::ApiError as ApiErrorModel, routes::ApiError, utils::services::ServiceError};
+
+const SIGNATURE_HEADER: &str = "X-Signature";
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "payment_callback",
+    description = "Receives payment notifications from the payment provider. \
+        The body is passed to the billing service as is, along with the `X-Signature` header",
+    request_body(content = String, description = "Provider-specific payload", content_type = "application/json"),
+    responses(
+        (status = 204),
+        (status = 400, description = "Malformed callback or invalid signature", body = ApiErrorModel),
+        (status = 404, description = "Payment is unknown", body = ApiErrorModel)
+    ),
+)]
+#[post("/callback")]
+async fn post_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    req: HttpRequest,
+    body: Bytes,
+) -> Result<HttpResponse, ApiError> {
+    let signature = req
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let request = Request::new(PaymentCallbackRequest {
+        payload: body.to_vec(),
+        signature,
+    });
+
+    // the callback comes from the provider, so the gateway passes it on on its own behalf
+    principal::scope(
+        Principal::service("gateway"),
+        (**billing_client).clone().handle_payment_callback(request),
+    )
+    .await
+    .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
@@ -0,0 +1,132 @@
+use actix_web::{
+    get,
+    middleware::from_fn,
+    post,
+    web::{Data, Json, Query, ReqData},
+};
+use protos::billing::{billing_client::BillingClient, GetInvoicesRequest};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    auth::middleware::{any_auth_middleware, AuthEntity},
+    models::{
+        dto::{Invoice, IssueInvoice, IssueInvoiceRequest},
+        url::Pagination,
+        ApiError as ApiErrorModel,
+    },
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+
+use super::ApiError;
+
+mod by_id;
+mod callback;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/invoices")
+            .wrap(default_cors())
+            .service(callback::post_handler)
+            .service(
+                scope("")
+                    .wrap(default_cors())
+                    .wrap(from_fn(any_auth_middleware))
+                    .service(get_handler)
+                    .service(post_handler)
+                    .configure(by_id::config),
+            ),
+    );
+}
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "get_invoices",
+    description = "Fetches invoices, newest first. Clients get only their own invoices",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        Pagination
+    ),
+    responses(
+        (status = 200, body = Vec<Invoice>),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Query(query): Query<Pagination>,
+) -> Result<Json<Vec<Invoice>>, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let client_id = match entity.into_inner() {
+        AuthEntity::Client(client) => Some(client.id.to_string()),
+        AuthEntity::Admin(..) => None,
+    };
+
+    let (limit, offset) = query.parse();
+
+    let request = Request::new(GetInvoicesRequest {
+        client_id,
+        limit,
+        offset,
+    });
+
+    let response = (**billing_client)
+        .clone()
+        .get_invoices(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(Invoice::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "invoices",
+    operation_id = "issue_invoice",
+    description = "Issues an invoice either for a single reservation or for all reservations \
+        of a client in a month. Cancelled and already invoiced reservations are skipped",
+    security(
+        ("admin" = [])
+    ),
+    responses(
+        (status = 200, body = Invoice),
+        (status = 400, description = "Invalid body or cancelled reservation", body = ApiErrorModel),
+        (status = 404, description = "Reservation was not found or there is nothing to invoice", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 409, description = "Reservation was already invoiced", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Json(body): Json<IssueInvoice>,
+) -> Result<Json<Invoice>, ApiError> {
+    entity.into_inner().into_admin()?;
+
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let response = match body.into() {
+        IssueInvoiceRequest::Reservation(request) => (**billing_client)
+            .clone()
+            .issue_reservation_invoice(request)
+            .await
+            .map_err(ServiceError::from)?,
+        IssueInvoiceRequest::Monthly(request) => (**billing_client)
+            .clone()
+            .issue_monthly_invoice(request)
+            .await
+            .map_err(ServiceError::from)?,
+    }
+    .into_inner();
+
+    Ok(Json(response.into()))
+}
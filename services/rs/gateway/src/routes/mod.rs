@@ -2,11 +2,14 @@ use actix_web::{http::StatusCode, HttpResponse, ResponseError};
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 mod admin;
+mod checkin;
 mod client;
 mod coworking;
 mod health;
+mod invoices;
 mod not_found;
 mod ping;
+mod plans;
 mod reservations;
 
 use crate::{
@@ -22,9 +25,14 @@ pub fn config(cfg: &mut ServiceConfig) {
             .wrap(default_cors())
             .service(ping::get_handler)
             .service(health::get_handler)
+            .service(health::get_live_handler)
+            .service(health::get_ready_handler)
+            .service(checkin::post_handler)
             .configure(admin::config)
             .configure(client::config)
             .configure(coworking::config)
+            .configure(invoices::config)
+            .configure(plans::config)
             .configure(reservations::config),
     );
 }
@@ -49,24 +57,32 @@ pub enum ApiError {
     #[error("Error while validating input: {0}")]
     Validation(String),
 
+    #[error("Payload is larger than {0} bytes")]
+    PayloadTooLarge(u64),
+
     #[error("Authentication error: {0}")]
     Authentication(#[from] AuthenticationError),
+
+    #[error("Export error: {0}")]
+    Export(String),
 }
 
 impl ApiError {
     pub fn as_api_error<'a>(&self) -> crate::models::ApiError<'a> {
-        crate::models::ApiError {
-            error: match self {
+        crate::models::ApiError::new(
+            match self {
                 Self::Service(err) => err.error_name(),
                 Self::NotFound => "not_found",
                 Self::NotOwner => "not_owner",
                 Self::Json(..) => "json_error",
                 Self::InvalidInput(..) => "invalid_input",
                 Self::Validation(..) => "invalid_input",
+                Self::PayloadTooLarge(..) => "payload_too_large",
                 Self::Authentication(err) => err.error_name(),
+                Self::Export(..) => "export_error",
             },
-            description: self.to_string(),
-        }
+            self.to_string(),
+        )
     }
 }
 
@@ -79,7 +95,9 @@ impl ResponseError for ApiError {
             Self::Json(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
             Self::Validation(..) => StatusCode::BAD_REQUEST,
+            Self::PayloadTooLarge(..) => StatusCode::PAYLOAD_TOO_LARGE,
             Self::Authentication(err) => err.status_code(),
+            Self::Export(..) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
@@ -0,0 +1,146 @@
+use actix_web::{
+    delete, get,
+    middleware::from_fn,
+    patch,
+    web::{Data, Json},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use protos::billing::{billing_client::BillingClient, PlanRequest};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    auth::middleware::admin_auth_middleware,
+    models::{
+        dto::{Plan, PlanUpdate},
+        url::PlanPath,
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/{plan_id}")
+            .wrap(default_cors())
+            .service(get_handler)
+            .service(
+                scope("")
+                    .wrap(default_cors())
+                    .wrap(from_fn(admin_auth_middleware))
+                    .service(patch_handler)
+                    .service(delete_handler),
+            ),
+    );
+}
+
+#[utoipa::path(
+    tag = "plans",
+    operation_id = "get_plan",
+    description = "Get plan by ID",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("plan_id" = Uuid, description = "Plan ID")
+    ),
+    responses(
+        (status = 200, body = Plan),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<PlanPath>,
+) -> Result<Json<Plan>, ApiError> {
+    let request = Request::new(PlanRequest {
+        id: path.plan_id.to_string(),
+    });
+
+    let response = (**billing_client)
+        .clone()
+        .get_plan(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "plans",
+    operation_id = "edit_plan",
+    description = "Edit plan by ID. Only the terms that don't affect the hours already sold can be changed",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("plan_id" = Uuid, description = "Plan ID")
+    ),
+    responses(
+        (status = 200, body = Plan),
+        (status = 400, description = "Invalid body", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[patch("")]
+async fn patch_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<PlanPath>,
+    Json(body): Json<PlanUpdate>,
+) -> Result<Json<Plan>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let request = Request::new(body.into_proto(path.plan_id));
+
+    let response = (**billing_client)
+        .clone()
+        .update_plan(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
+
+#[utoipa::path(
+    tag = "plans",
+    operation_id = "delete_plan",
+    description = "Withdraws the plan from sale. Clients who bought it keep their hours",
+    security(
+        ("admin" = [])
+    ),
+    params(
+        ("plan_id" = Uuid, description = "Plan ID")
+    ),
+    responses(
+        (status = 204),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[delete("")]
+async fn delete_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Path(path): Path<PlanPath>,
+) -> Result<HttpResponse, ApiError> {
+    let request = Request::new(PlanRequest {
+        id: path.plan_id.to_string(),
+    });
+
+    (**billing_client)
+        .clone()
+        .delete_plan(request)
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(HttpResponse::NoContent().into())
+}
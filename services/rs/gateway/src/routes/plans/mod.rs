@@ -0,0 +1,117 @@
+use actix_web::{
+    get,
+    middleware::from_fn,
+    post,
+    web::{Data, Json, Query, ReqData},
+};
+use protos::billing::{billing_client::BillingClient, CreatePlanRequest, GetPlansRequest};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+use validator::Validate;
+
+use crate::{
+    auth::middleware::{admin_auth_middleware, any_auth_middleware, AuthEntity},
+    models::{
+        dto::{CreatePlan, Plan},
+        url::Pagination,
+        ApiError as ApiErrorModel,
+    },
+    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+};
+
+use super::ApiError;
+
+mod by_id;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/plans")
+            .wrap(default_cors())
+            .wrap(from_fn(any_auth_middleware))
+            .service(get_handler)
+            .configure(by_id::config)
+            .service(
+                scope("")
+                    .wrap(default_cors())
+                    .wrap(from_fn(admin_auth_middleware))
+                    .service(post_handler),
+            ),
+    );
+}
+
+#[utoipa::path(
+    tag = "plans",
+    operation_id = "get_plans",
+    description = "Fetches membership plans and hour packages. Admins also get the inactive ones",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        Pagination
+    ),
+    responses(
+        (status = 200, body = Vec<Plan>),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("")]
+async fn get_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Query(query): Query<Pagination>,
+) -> Result<Json<Vec<Plan>>, ApiError> {
+    query.validate().map_err(validation_errors_to_err)?;
+
+    let include_inactive = matches!(entity.into_inner(), AuthEntity::Admin(..));
+    let (limit, offset) = query.parse();
+
+    let request = Request::new(GetPlansRequest {
+        include_inactive,
+        limit,
+        offset,
+    });
+
+    let response = (**billing_client)
+        .clone()
+        .get_plans(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(Plan::vec_from_proto(response)))
+}
+
+#[utoipa::path(
+    tag = "plans",
+    operation_id = "create_plan",
+    description = "Creates a plan. Its hours, seat types and validity can't be changed afterwards, \
+        since clients may have already bought it",
+    security(
+        ("admin" = [])
+    ),
+    responses(
+        (status = 200, body = Plan),
+        (status = 400, description = "Invalid body", body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("")]
+async fn post_handler(
+    billing_client: Data<BillingClient<ServiceChannel>>,
+    Json(body): Json<CreatePlan>,
+) -> Result<Json<Plan>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let request = Request::new(CreatePlanRequest::from(body));
+
+    let response = (**billing_client)
+        .clone()
+        .create_plan(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
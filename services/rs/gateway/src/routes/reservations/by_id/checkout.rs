@@ -0,0 +1,52 @@
+use actix_web::{
+    post,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::Path;
+use protos::reservation::{reservation_client::ReservationClient, DeleteRequest};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+
+use crate::{
+    models::{dto::Reservation, url::ReservationPath, ApiError as ApiErrorModel},
+    routes::ApiError,
+    utils::services::ServiceError,
+};
+
+#[utoipa::path(
+    tag = "reservations",
+    operation_id = "check_out_reservation",
+    description = "Checks out of a visited reservation, recording when the seat was actually left. \
+        Reservations nobody checked out of are checked out automatically when they end",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("reservation_id" = Uuid, description = "Reservation ID")
+    ),
+    responses(
+        (status = 200, body = Reservation),
+        (status = 400, description = "Reservation is cancelled or wasn't checked in", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("/checkout")]
+async fn post_handler(
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    Path(path): Path<ReservationPath>,
+) -> Result<Json<Reservation>, ApiError> {
+    let request = Request::new(DeleteRequest {
+        id: path.reservation_id.to_string(),
+    });
+
+    let response = (**reservation_client)
+        .clone()
+        .check_out(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(Json(response.into()))
+}
@@ -1,22 +1,23 @@
 use actix_web::{
     post,
-    web::{Data, Json, ReqData},
+    web::{Data, Json},
 };
 use actix_web_lab::extract::Path;
-use protos::reservation::{reservation_client::ReservationClient, UpdateRequest};
-use tonic::{transport::Channel, Request};
+use protos::{
+    billing::billing_client::BillingClient, reservation::reservation_client::ReservationClient,
+};
+use service_helper::channel::ServiceChannel;
 
 use crate::{
-    auth::middleware::AuthEntity,
     models::{dto::Reservation, url::ReservationPath, ApiError as ApiErrorModel},
     routes::ApiError,
-    utils::services::ServiceError,
+    utils::checkin::mark_visited,
 };
 
 #[utoipa::path(
     tag = "reservations",
     operation_id = "confirm_reservation",
-    description = "Confirm reservation by ID",
+    description = "Confirm reservation by ID. An invoice for the visit is issued right away",
     security(
         ("admin" = [])
     ),
@@ -31,32 +32,11 @@ use crate::{
 )]
 #[post("/confirm")]
 async fn post_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
-    entity: ReqData<AuthEntity>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    billing_client: Data<BillingClient<ServiceChannel>>,
     Path(path): Path<ReservationPath>,
 ) -> Result<Json<Reservation>, ApiError> {
-    let (id, is_admin) = match entity.into_inner() {
-        AuthEntity::Client(client) => (client.id, false),
-        AuthEntity::Admin(admin) => (admin.id, true),
-    };
-
-    let request = Request::new(UpdateRequest {
-        id: path.reservation_id.to_string(),
-        client_id: id.to_string(),
-        is_admin,
-        seat_id: None,
-        starts_at: None,
-        ends_at: None,
-        is_canceled: None,
-        is_visited: Some(true),
-    });
-
-    let response = (&**reservation_client)
-        .clone()
-        .update(request)
-        .await
-        .map_err(ServiceError::from)?
-        .into_inner();
+    let response = mark_visited(&reservation_client, &billing_client, path.reservation_id).await?;
 
     Ok(Json(response.into()))
 }
@@ -0,0 +1,120 @@
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::Path;
+use chrono::TimeDelta;
+use protos::{
+    coworking::coworking_client::CoworkingClient,
+    notification::notification_client::NotificationClient,
+    reservation::{reservation_client::ReservationClient, GetByIdRequest},
+    seat_lock::seat_lock_client::SeatLockClient,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+use validator::Validate;
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{
+        dto::{ExtendReservation, Reservation},
+        url::ReservationPath,
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{
+        pricing::{quote_reservation, round_to_cents},
+        seat_lock::with_seat_lock,
+        services::ServiceError,
+        validation::validation_errors_to_err,
+    },
+};
+
+#[utoipa::path(
+    tag = "reservations",
+    operation_id = "extend_reservation",
+    description = "Extends the stay by moving the end of the reservation later, as long as the seat \
+        is free afterwards. The extra time is priced like a new reservation and added to the price",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("reservation_id" = Uuid, description = "Reservation ID")
+    ),
+    responses(
+        (status = 200, body = Reservation),
+        (status = 400, description = "Invalid body, or the reservation is cancelled or already checked out", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 409, description = "Seat is booked, locked by someone else or held for a waitlisted client \
+            right after the reservation", body = ApiErrorModel)
+    ),
+)]
+#[post("/extend")]
+async fn post_handler(
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    seat_lock_client: Data<SeatLockClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Path(path): Path<ReservationPath>,
+    Json(body): Json<ExtendReservation>,
+) -> Result<Json<Reservation>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let (id, is_internal) = match entity.into_inner() {
+        AuthEntity::Client(client) => (client.id, client.is_internal),
+        AuthEntity::Admin(admin) => (admin.id, false),
+    };
+
+    let current: Reservation = (**reservation_client)
+        .clone()
+        .get_by_id(GetByIdRequest {
+            id: path.reservation_id.to_string(),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner()
+        .into();
+
+    if body.ends_at <= current.inner.ends_at {
+        return Err(ApiError::InvalidInput(
+            "`ends_at` must be later than the current end of the reservation".into(),
+        ));
+    }
+    if body.ends_at - current.inner.starts_at >= TimeDelta::hours(13) {
+        return Err(ApiError::InvalidInput(
+            "reservation can't be longer than 13 hours".into(),
+        ));
+    }
+
+    let extension = body.extension(&current);
+    let quote = quote_reservation(&coworking_client, &extension, is_internal).await?;
+    let price = current
+        .price
+        .map(|price| round_to_cents(price + quote.price));
+
+    let hold_request = extension.hold_request(current.client_id);
+    let request = Request::new(body.into_proto(path.reservation_id, price));
+
+    let response = with_seat_lock(&seat_lock_client, current.inner.seat_id, id, async {
+        (**notification_client)
+            .clone()
+            .check_hold(hold_request)
+            .await
+            .map_err(ServiceError::from)?;
+
+        let response = (**reservation_client)
+            .clone()
+            .extend(request)
+            .await
+            .map_err(ServiceError::from)?
+            .into_inner();
+
+        Ok::<_, ApiError>(response)
+    })
+    .await?;
+
+    Ok(Json(response.into()))
+}
@@ -6,7 +6,10 @@ use crate::{
         ApiError as ApiErrorModel,
     },
     routes::ApiError,
-    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+    utils::{
+        cors::default_cors, plans::restore_hours, pricing::quote_reservation,
+        seat_lock::with_seat_lock, services::ServiceError, validation::validation_errors_to_err,
+    },
 };
 use actix_web::{
     delete, get,
@@ -16,12 +19,22 @@ use actix_web::{
     HttpResponse,
 };
 use actix_web_lab::extract::Path;
-use protos::reservation::{reservation_client::ReservationClient, DeleteRequest, GetByIdRequest};
-use tonic::{transport::Channel, Request};
+use protos::{
+    billing::billing_client::BillingClient,
+    coworking::coworking_client::CoworkingClient,
+    notification::notification_client::NotificationClient,
+    reservation::{reservation_client::ReservationClient, DeleteRequest, GetByIdRequest},
+    seat_lock::seat_lock_client::SeatLockClient,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
+mod checkout;
 mod confirm;
+mod extend;
+mod qr;
 
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(
@@ -31,6 +44,9 @@ pub fn config(cfg: &mut ServiceConfig) {
             .service(get_handler)
             .service(patch_handler)
             .service(delete_handler)
+            .service(qr::get_handler)
+            .service(checkout::post_handler)
+            .service(extend::post_handler)
             .service(
                 scope("")
                     .wrap(default_cors())
@@ -59,22 +75,14 @@ pub fn config(cfg: &mut ServiceConfig) {
 )]
 #[get("")]
 async fn get_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
-    entity: ReqData<AuthEntity>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
     Path(path): Path<ReservationPath>,
 ) -> Result<Json<Reservation>, ApiError> {
-    let (id, is_admin) = match entity.into_inner() {
-        AuthEntity::Client(client) => (client.id, false),
-        AuthEntity::Admin(admin) => (admin.id, true),
-    };
-
     let request = Request::new(GetByIdRequest {
         id: path.reservation_id.to_string(),
-        client_id: id.to_string(),
-        is_admin,
     });
 
-    let response = (&**reservation_client)
+    let response = (**reservation_client)
         .clone()
         .get_by_id(request)
         .await
@@ -87,7 +95,8 @@ async fn get_handler(
 #[utoipa::path(
     tag = "reservations",
     operation_id = "reschedule_reservation",
-    description = "Edit reservation by ID",
+    description = "Edit reservation by ID. A moved or resized reservation is priced again like \
+        a new one, while reservations paid with plan hours stay free",
     security(
         ("admin" = []),
         ("client" = [])
@@ -97,32 +106,79 @@ async fn get_handler(
     ),
     responses(
         (status = 200, body = Reservation),
+        (status = 400, description = "Invalid body or promo code", body = ApiErrorModel),
         (status = 404, body = ApiErrorModel),
-        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 409, description = "Seat is already booked, locked by someone else or held for a waitlisted client", body = ApiErrorModel)
     ),
 )]
 #[patch("")]
 async fn patch_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    seat_lock_client: Data<SeatLockClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     Path(path): Path<ReservationPath>,
     Json(body): Json<ReservationUpdate>,
 ) -> Result<Json<Reservation>, ApiError> {
     body.validate().map_err(validation_errors_to_err)?;
 
-    let (id, is_admin) = match entity.into_inner() {
-        AuthEntity::Client(client) => (client.id, false),
-        AuthEntity::Admin(admin) => (admin.id, true),
+    let (id, is_internal) = match entity.into_inner() {
+        AuthEntity::Client(client) => (client.id, client.is_internal),
+        AuthEntity::Admin(admin) => (admin.id, false),
     };
 
-    let request = Request::new(body.into_proto(path.reservation_id, id, is_admin));
+    if !body.changes_slot() {
+        let request = Request::new(body.into_proto(path.reservation_id, None));
+        let response = (**reservation_client)
+            .clone()
+            .update(request)
+            .await
+            .map_err(ServiceError::from)?
+            .into_inner();
+
+        return Ok(Json(response.into()));
+    }
 
-    let response = (&**reservation_client)
+    let current: Reservation = (**reservation_client)
         .clone()
-        .update(request)
+        .get_by_id(GetByIdRequest {
+            id: path.reservation_id.to_string(),
+        })
         .await
         .map_err(ServiceError::from)?
-        .into_inner();
+        .into_inner()
+        .into();
+
+    let slot = body.slot(&current);
+    let quote = quote_reservation(&coworking_client, &slot, is_internal).await?;
+    // a zero price means the reservation was drawn from plan hours
+    let price = current
+        .price
+        .filter(|price| *price > 0.0)
+        .map(|_| quote.price);
+
+    let hold_request = slot.hold_request(current.client_id);
+    let request = Request::new(body.into_proto(path.reservation_id, price));
+
+    let response = with_seat_lock(&seat_lock_client, slot.seat_id, id, async {
+        (**notification_client)
+            .clone()
+            .check_hold(hold_request)
+            .await
+            .map_err(ServiceError::from)?;
+
+        let response = (**reservation_client)
+            .clone()
+            .update(request)
+            .await
+            .map_err(ServiceError::from)?
+            .into_inner();
+
+        Ok::<_, ApiError>(response)
+    })
+    .await?;
 
     Ok(Json(response.into()))
 }
@@ -130,7 +186,7 @@ async fn patch_handler(
 #[utoipa::path(
     tag = "reservations",
     operation_id = "cancel_reservation",
-    description = "Cancel reservation by ID",
+    description = "Cancel reservation by ID. Hours drawn from a plan are given back",
     security(
         ("admin" = []),
         ("client" = [])
@@ -146,26 +202,43 @@ async fn patch_handler(
 )]
 #[delete("")]
 async fn delete_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
-    entity: ReqData<AuthEntity>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    billing_client: Data<BillingClient<ServiceChannel>>,
     Path(path): Path<ReservationPath>,
 ) -> Result<HttpResponse, ApiError> {
-    let (id, is_admin) = match entity.into_inner() {
-        AuthEntity::Client(client) => (client.id, false),
-        AuthEntity::Admin(admin) => (admin.id, true),
-    };
+    let reservation: Reservation = (**reservation_client)
+        .clone()
+        .get_by_id(GetByIdRequest {
+            id: path.reservation_id.to_string(),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner()
+        .into();
 
     let request = Request::new(DeleteRequest {
         id: path.reservation_id.to_string(),
-        client_id: id.to_string(),
-        is_admin,
     });
 
-    (&**reservation_client)
+    (**reservation_client)
         .clone()
         .delete(request)
         .await
         .map_err(ServiceError::from)?;
 
+    if !reservation.cancelled {
+        restore_hours(&billing_client, None, Some(path.reservation_id.to_string())).await;
+
+        // the cancellation itself has already succeeded, so the waitlist must not fail the request
+        if let Err(err) = (**notification_client)
+            .clone()
+            .release_slot(reservation.release_slot_request())
+            .await
+        {
+            tracing::error!("Failed to offer the released slot to the waitlist: {err}");
+        }
+    }
+
     Ok(HttpResponse::NoContent().into())
 }
@@ -0,0 +1,106 @@
+use std::io::Cursor;
+
+use actix_web::{
+    get,
+    web::{Data, Query},
+    HttpResponse,
+};
+use actix_web_lab::extract::Path;
+use image::{ImageFormat, Luma};
+use protos::reservation::{reservation_client::ReservationClient, GetByIdRequest};
+use qrcode::{render::svg, QrCode};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
+
+use crate::{
+    models::{
+        dto::Reservation,
+        url::{QrFormat, QrQuery, ReservationPath},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{checkin::new_token, services::ServiceError},
+};
+
+const QR_SIZE: u32 = 320;
+
+#[utoipa::path(
+    tag = "reservations",
+    operation_id = "get_reservation_qr",
+    description = "Renders the check-in code of the reservation. The code is accepted by the front desk \
+        from a bit before the reservation starts until it ends",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    params(
+        ("reservation_id" = Uuid, description = "Reservation ID"),
+        QrQuery
+    ),
+    responses(
+        (status = 200, description = "Check-in QR code", content_type = "image/*"),
+        (status = 400, description = "Reservation is cancelled", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[get("/qr")]
+async fn get_handler(
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    Path(path): Path<ReservationPath>,
+    Query(query): Query<QrQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let request = Request::new(GetByIdRequest {
+        id: path.reservation_id.to_string(),
+    });
+
+    let reservation: Reservation = (**reservation_client)
+        .clone()
+        .get_by_id(request)
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner()
+        .into();
+
+    if reservation.cancelled {
+        return Err(ApiError::InvalidInput(
+            "cancelled reservations have no check-in code".into(),
+        ));
+    }
+
+    let token = new_token(
+        reservation.id,
+        reservation.inner.starts_at,
+        reservation.inner.ends_at,
+    );
+    let code =
+        QrCode::new(token.as_bytes()).map_err(|err| ApiError::InvalidInput(err.to_string()))?;
+
+    let response = match query.format.unwrap_or_default() {
+        QrFormat::Png => {
+            let image = code
+                .render::<Luma<u8>>()
+                .min_dimensions(QR_SIZE, QR_SIZE)
+                .build();
+
+            let mut bytes = Cursor::new(Vec::new());
+            image
+                .write_to(&mut bytes, ImageFormat::Png)
+                .expect("Failed to encode the QR code");
+
+            HttpResponse::Ok()
+                .content_type("image/png")
+                .body(bytes.into_inner())
+        }
+        QrFormat::Svg => {
+            let image = code
+                .render::<svg::Color>()
+                .min_dimensions(QR_SIZE, QR_SIZE)
+                .build();
+
+            HttpResponse::Ok().content_type("image/svg+xml").body(image)
+        }
+    };
+
+    Ok(response)
+}
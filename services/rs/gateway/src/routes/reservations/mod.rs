@@ -7,10 +7,14 @@ use actix_web::{
 use actix_web_lab::extract::Query;
 use prost_types::Timestamp;
 use protos::{
-    notification::{notification_client::NotificationClient, ScheduleRequest},
+    billing::billing_client::BillingClient,
+    coworking::coworking_client::CoworkingClient,
+    notification::{notification_client::NotificationClient, CheckStrikesRequest, ScheduleRequest},
     reservation::reservation_client::ReservationClient,
+    seat_lock::seat_lock_client::SeatLockClient,
 };
-use tonic::{transport::Channel, Request};
+use service_helper::channel::ServiceChannel;
+use tonic::Request;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 use validator::Validate;
 
@@ -21,12 +25,21 @@ use crate::{
         url::ByDateWithPaginationQuery,
         ApiError as ApiErrorModel,
     },
-    utils::{cors::default_cors, services::ServiceError, validation::validation_errors_to_err},
+    utils::{
+        cors::default_cors,
+        metrics::RESERVATIONS_CREATED,
+        plans::{attach_hours, draw_down_hours, restore_hours},
+        pricing::quote_reservation,
+        seat_lock::with_seat_lock,
+        services::ServiceError,
+        validation::validation_errors_to_err,
+    },
 };
 
 use super::ApiError;
 
 mod by_id;
+mod quote;
 
 pub fn config(cfg: &mut ServiceConfig) {
     cfg.service(
@@ -34,6 +47,7 @@ pub fn config(cfg: &mut ServiceConfig) {
             .wrap(default_cors())
             .wrap(from_fn(any_auth_middleware))
             .service(post_handler)
+            .service(quote::post_handler)
             .configure(by_id::config),
     );
 }
@@ -41,39 +55,110 @@ pub fn config(cfg: &mut ServiceConfig) {
 #[utoipa::path(
     tag = "reservations",
     operation_id = "create_reservation",
-    description = "Creates a new reservation",
+    description = "Creates a new reservation. Its price is calculated the same way as by the quote \
+        and stays fixed afterwards. When the client has a plan covering the seat, the hours are \
+        taken off the plan instead and the reservation is free. Clients with too many recent \
+        no-shows can't book",
     security(
         ("admin" = []),
         ("client" = [])
     ),
     responses(
         (status = 200, body = Reservation),
+        (status = 400, description = "Invalid body or promo code", body = ApiErrorModel),
         (status = 404, body = ApiErrorModel),
-        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+        (status = 401, description = "Unauthorized", body = ApiErrorModel),
+        (status = 403, description = "Booking is blocked after repeated no-shows", body = ApiErrorModel),
+        (status = 409, description = "Seat is already booked, locked by someone else, held for a waitlisted client \
+            or there aren't enough hours left on the plan", body = ApiErrorModel)
     ),
 )]
 #[post("")]
 async fn post_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
-    notification_client: Data<NotificationClient<Channel>>,
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
+    notification_client: Data<NotificationClient<ServiceChannel>>,
+    seat_lock_client: Data<SeatLockClient<ServiceChannel>>,
+    billing_client: Data<BillingClient<ServiceChannel>>,
     entity: ReqData<AuthEntity>,
     Json(body): Json<CreateReservation>,
 ) -> Result<Json<Reservation>, ApiError> {
     body.validate().map_err(validation_errors_to_err)?;
 
-    let (id, is_admin, send_notification) = match entity.into_inner() {
-        AuthEntity::Client(client) => (client.id, false, client.send_notifications),
-        AuthEntity::Admin(admin) => (admin.id, true, false),
+    let (id, is_admin, is_internal, send_notification) = match entity.into_inner() {
+        AuthEntity::Client(client) => (
+            client.id,
+            false,
+            client.is_internal,
+            client.send_notifications,
+        ),
+        AuthEntity::Admin(admin) => (admin.id, true, false, false),
     };
 
-    let request = Request::new(body.into_proto(id, is_admin));
+    if !is_admin {
+        (**notification_client)
+            .clone()
+            .check_strikes(CheckStrikesRequest {
+                client_id: id.to_string(),
+            })
+            .await
+            .map_err(ServiceError::from)?;
+    }
+
+    let quote = quote_reservation(&coworking_client, &body, is_internal).await?;
 
-    let response = (&**reservation_client)
-        .clone()
-        .create(request)
-        .await
-        .map_err(ServiceError::from)?
-        .into_inner();
+    let seat_id = body.seat_id;
+    let hold_request = body.hold_request(id);
+
+    let response = with_seat_lock(&seat_lock_client, seat_id, id, async {
+        (**notification_client)
+            .clone()
+            .check_hold(hold_request.clone())
+            .await
+            .map_err(ServiceError::from)?;
+
+        let usage_id = if is_admin {
+            None
+        } else {
+            draw_down_hours(&billing_client, id, &body).await?
+        };
+        let price = if usage_id.is_some() { 0.0 } else { quote.price };
+
+        let request = Request::new(body.into_proto(id, price));
+
+        let response = match (**reservation_client).clone().create(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) => {
+                if usage_id.is_some() {
+                    restore_hours(&billing_client, usage_id, None).await;
+                }
+                return Err(ApiError::from(ServiceError::from(status)));
+            }
+        };
+
+        RESERVATIONS_CREATED
+            .with_label_values(&[if usage_id.is_some() { "hours" } else { "price" }])
+            .inc();
+
+        if let Some(usage_id) = usage_id {
+            attach_hours(&billing_client, usage_id, &response.id).await;
+        }
+
+        // the reservation exists by now, a hold left behind only expires later
+        if let Err(err) = (**notification_client)
+            .clone()
+            .fulfill_hold(hold_request)
+            .await
+        {
+            tracing::error!(
+                "Failed to fulfill the hold for reservation {}: {err}",
+                response.id
+            );
+        }
+
+        Ok(response)
+    })
+    .await?;
 
     if send_notification {
         let send_at = Timestamp {
@@ -81,7 +166,7 @@ async fn post_handler(
             nanos: 0,
         };
 
-        (&**notification_client)
+        (**notification_client)
             .clone()
             .schedule(ScheduleRequest {
                 id: id.to_string(),
@@ -112,14 +197,14 @@ async fn post_handler(
 )]
 #[get("")]
 async fn get_handler(
-    reservation_client: Data<ReservationClient<Channel>>,
+    reservation_client: Data<ReservationClient<ServiceChannel>>,
     Query(query): Query<ByDateWithPaginationQuery>,
 ) -> Result<Json<Vec<Reservation>>, ApiError> {
     query.validate().map_err(validation_errors_to_err)?;
 
     let request = Request::new(query.into());
 
-    let response = (&**reservation_client)
+    let response = (**reservation_client)
         .clone()
         .get(request)
         .await
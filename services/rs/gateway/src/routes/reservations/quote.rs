@@ -0,0 +1,51 @@
+use actix_web::{
+    post,
+    web::{Data, Json, ReqData},
+};
+use protos::coworking::coworking_client::CoworkingClient;
+use service_helper::channel::ServiceChannel;
+use validator::Validate;
+
+use crate::{
+    auth::middleware::AuthEntity,
+    models::{
+        dto::{CreateReservation, ReservationQuote},
+        ApiError as ApiErrorModel,
+    },
+    routes::ApiError,
+    utils::{pricing::quote_reservation, validation::validation_errors_to_err},
+};
+
+#[utoipa::path(
+    tag = "reservations",
+    operation_id = "quote_reservation",
+    description = "Calculates the price of a reservation without booking it. \
+        Accepts the same body as reservation creation",
+    security(
+        ("admin" = []),
+        ("client" = [])
+    ),
+    responses(
+        (status = 200, body = ReservationQuote),
+        (status = 400, description = "Invalid body or promo code", body = ApiErrorModel),
+        (status = 404, body = ApiErrorModel),
+        (status = 401, description = "Unauthorized", body = ApiErrorModel)
+    ),
+)]
+#[post("/quote")]
+async fn post_handler(
+    coworking_client: Data<CoworkingClient<ServiceChannel>>,
+    entity: ReqData<AuthEntity>,
+    Json(body): Json<CreateReservation>,
+) -> Result<Json<ReservationQuote>, ApiError> {
+    body.validate().map_err(validation_errors_to_err)?;
+
+    let is_internal = match entity.into_inner() {
+        AuthEntity::Client(client) => client.is_internal,
+        AuthEntity::Admin(..) => false,
+    };
+
+    let quote = quote_reservation(&coworking_client, &body, is_internal).await?;
+
+    Ok(Json(quote))
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use calendar::{Calendar, Event, EventStatus};
+use chrono::Utc;
+use convertions::timestamp_into_datetime;
+use protos::{
+    coworking::{
+        coworking_client::CoworkingClient, CoworkingResponse, GetCoworkingByIdRequest, SeatRequest,
+        SeatResponse,
+    },
+    reservation::{reservation_client::ReservationClient, GetByClientRequest, ReservationResponse},
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Code;
+use uuid::Uuid;
+
+use crate::routes::ApiError;
+
+use super::services::ServiceError;
+
+const RESERVATIONS_PAGE_SIZE: u32 = 100;
+
+/// Renders all reservations of the client as an iCalendar feed.
+/// Cancelled reservations stay in the feed, so subscribed calendars drop their events.
+pub async fn reservation_feed(
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+    coworking_id: Uuid,
+    client_id: Uuid,
+) -> Result<String, ApiError> {
+    let coworking = coworking_client
+        .clone()
+        .get_by_id(GetCoworkingByIdRequest {
+            id: coworking_id.to_string(),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    let mut seats: HashMap<String, Option<SeatResponse>> = HashMap::new();
+    let mut events = vec![];
+    let mut offset = 0;
+    loop {
+        let reservations = reservation_client
+            .clone()
+            .get_by_client(GetByClientRequest {
+                client_id: client_id.to_string(),
+                limit: RESERVATIONS_PAGE_SIZE,
+                offset,
+            })
+            .await
+            .map_err(ServiceError::from)?
+            .into_inner()
+            .reservations;
+        let fetched = reservations.len();
+
+        for reservation in reservations {
+            if !seats.contains_key(&reservation.seat_id) {
+                let seat = match coworking_client
+                    .clone()
+                    .get_seat(SeatRequest {
+                        id: reservation.seat_id.clone(),
+                    })
+                    .await
+                {
+                    Ok(response) => Some(response.into_inner()),
+                    Err(status) if status.code() == Code::NotFound => None,
+                    Err(status) => return Err(ServiceError::from(status).into()),
+                };
+                seats.insert(reservation.seat_id.clone(), seat);
+            }
+
+            // the seat could have been removed since
+            if let Some(seat) = &seats[&reservation.seat_id] {
+                events.push(reservation_event(&reservation, &coworking, seat));
+            }
+        }
+
+        if fetched < RESERVATIONS_PAGE_SIZE as usize {
+            break;
+        }
+        offset += RESERVATIONS_PAGE_SIZE as u64;
+    }
+
+    Ok(Calendar::new(Some(coworking.name.clone()), events).to_ics(Utc::now()))
+}
+
+fn reservation_event(
+    reservation: &ReservationResponse,
+    coworking: &CoworkingResponse,
+    seat: &SeatResponse,
+) -> Event {
+    Event {
+        uid: format!("{}@{}", reservation.id, coworking.id),
+        starts_at: timestamp_into_datetime(reservation.starts_at).unwrap(),
+        ends_at: timestamp_into_datetime(reservation.ends_at).unwrap(),
+        summary: format!("Коворкинг {}, место №{}", coworking.name, seat.number),
+        location: coworking.address.clone(),
+        description: format!(
+            "Место №{}, {}\nОсобенности: {}",
+            seat.number,
+            seat.r#type,
+            seat.features.join(", ")
+        ),
+        status: match reservation.is_canceled {
+            true => EventStatus::Cancelled,
+            false => EventStatus::Confirmed,
+        },
+    }
+}
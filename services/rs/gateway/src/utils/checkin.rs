@@ -0,0 +1,292 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use convertions::timestamp_into_datetime;
+use jsonwebtoken::{
+    decode, encode, errors::ErrorKind, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use protos::{
+    billing::{billing_client::BillingClient, IssueReservationInvoiceRequest},
+    reservation::{reservation_client::ReservationClient, ReservationResponse, UpdateRequest},
+};
+use serde::{Deserialize, Serialize};
+use service_helper::channel::ServiceChannel;
+use uuid::Uuid;
+
+use crate::{config, routes::ApiError};
+
+use super::services::ServiceError;
+
+const TOKEN_KIND: &str = "checkin";
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Claims {
+    sub: String,
+    nbf: i64,
+    exp: i64,
+}
+
+/// Signs a check-in code that is valid from a bit before the reservation starts until it ends.
+pub fn new_token(reservation_id: Uuid, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> String {
+    sign(
+        reservation_id,
+        starts_at - early_window(),
+        ends_at,
+        config::CHECKIN_SECRET.expose().as_bytes(),
+    )
+}
+
+/// Checks the code's signature and window, returning the reservation it was issued for.
+pub fn parse_token(token: &str) -> Result<Uuid, ApiError> {
+    verify(token, config::CHECKIN_SECRET.expose().as_bytes())
+}
+
+/// Checks that the reservation can be checked in to right now. The code carries the times
+/// the reservation had when it was issued, so a moved or cancelled one is caught only here.
+pub fn check_reservation(reservation: &ReservationResponse) -> Result<(), ApiError> {
+    check_window(reservation, early_window(), Utc::now())
+}
+
+fn early_window() -> TimeDelta {
+    TimeDelta::minutes(*config::CHECKIN_EARLY_MINUTES)
+}
+
+fn sign(
+    reservation_id: Uuid,
+    opens_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    secret: &[u8],
+) -> String {
+    let mut header = Header::new(Algorithm::HS256);
+    header.kid = Some(TOKEN_KIND.to_owned());
+
+    encode(
+        &header,
+        &Claims {
+            sub: reservation_id.to_string(),
+            nbf: opens_at.timestamp(),
+            exp: ends_at.timestamp(),
+        },
+        &EncodingKey::from_secret(secret),
+    )
+    .unwrap()
+}
+
+fn verify(token: &str, secret: &[u8]) -> Result<Uuid, ApiError> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.validate_nbf = true;
+    validation.leeway = 0;
+
+    let claims = decode::<Claims>(token, &DecodingKey::from_secret(secret), &validation)
+        .map_err(|err| match err.kind() {
+            ErrorKind::ExpiredSignature | ErrorKind::ImmatureSignature => outside_window(),
+            _ => ApiError::InvalidInput("check-in code is invalid".into()),
+        })?
+        .claims;
+
+    Uuid::parse_str(&claims.sub)
+        .map_err(|_| ApiError::InvalidInput("check-in code is invalid".into()))
+}
+
+fn check_window(
+    reservation: &ReservationResponse,
+    early: TimeDelta,
+    now: DateTime<Utc>,
+) -> Result<(), ApiError> {
+    if reservation.is_canceled {
+        return Err(ApiError::InvalidInput(
+            "reservation has been cancelled".into(),
+        ));
+    }
+
+    let starts_at = timestamp_into_datetime(reservation.starts_at).ok_or_else(outside_window)?;
+    let ends_at = timestamp_into_datetime(reservation.ends_at).ok_or_else(outside_window)?;
+    if now < starts_at - early || now >= ends_at {
+        return Err(outside_window());
+    }
+
+    Ok(())
+}
+
+fn outside_window() -> ApiError {
+    ApiError::InvalidInput("check-in code is outside of its valid time window".into())
+}
+
+/// Marks the reservation as visited and issues an invoice for the visit.
+pub async fn mark_visited(
+    reservation_client: &ReservationClient<ServiceChannel>,
+    billing_client: &BillingClient<ServiceChannel>,
+    reservation_id: Uuid,
+) -> Result<ReservationResponse, ServiceError> {
+    let response = reservation_client
+        .clone()
+        .update(UpdateRequest {
+            id: reservation_id.to_string(),
+            seat_id: None,
+            starts_at: None,
+            ends_at: None,
+            is_canceled: None,
+            is_visited: Some(true),
+            price: None,
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    // the visit is confirmed anyway, the invoice can be issued by hand later
+    if let Err(err) = billing_client
+        .clone()
+        .issue_reservation_invoice(IssueReservationInvoiceRequest {
+            reservation_id: response.id.clone(),
+        })
+        .await
+    {
+        tracing::warn!(
+            "Failed to issue an invoice for reservation {}: {}",
+            response.id,
+            err.message()
+        );
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Timestamp;
+
+    use super::*;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    fn timestamp(at: DateTime<Utc>) -> Timestamp {
+        Timestamp {
+            seconds: at.timestamp(),
+            nanos: 0,
+        }
+    }
+
+    fn reservation(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> ReservationResponse {
+        ReservationResponse {
+            starts_at: timestamp(starts_at),
+            ends_at: timestamp(ends_at),
+            ..Default::default()
+        }
+    }
+
+    fn is_outside_window<T>(result: Result<T, ApiError>) -> bool {
+        matches!(result, Err(ApiError::InvalidInput(message)) if message.contains("time window"))
+    }
+
+    #[test]
+    fn parses_signed_tokens() {
+        let id = Uuid::now_v7();
+        let now = Utc::now();
+        let token = sign(
+            id,
+            now - TimeDelta::minutes(5),
+            now + TimeDelta::hours(1),
+            SECRET,
+        );
+
+        assert_eq!(verify(&token, SECRET).unwrap(), id);
+    }
+
+    #[test]
+    fn rejects_tampered_signatures() {
+        let now = Utc::now();
+        let token = sign(
+            Uuid::now_v7(),
+            now - TimeDelta::minutes(5),
+            now + TimeDelta::hours(1),
+            SECRET,
+        );
+        let (unsigned, _) = token.rsplit_once('.').unwrap();
+        let forged = sign(
+            Uuid::now_v7(),
+            now - TimeDelta::minutes(5),
+            now + TimeDelta::hours(1),
+            b"other-secret",
+        );
+        let (_, signature) = forged.rsplit_once('.').unwrap();
+
+        assert!(verify(&format!("{unsigned}.{signature}"), SECRET).is_err());
+        assert!(verify(&forged, SECRET).is_err());
+    }
+
+    #[test]
+    fn rejects_tokens_before_they_open() {
+        let now = Utc::now();
+        let token = sign(
+            Uuid::now_v7(),
+            now + TimeDelta::minutes(5),
+            now + TimeDelta::hours(1),
+            SECRET,
+        );
+
+        assert!(is_outside_window(verify(&token, SECRET)));
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        let now = Utc::now();
+        let token = sign(
+            Uuid::now_v7(),
+            now - TimeDelta::hours(2),
+            now - TimeDelta::hours(1),
+            SECRET,
+        );
+
+        assert!(is_outside_window(verify(&token, SECRET)));
+    }
+
+    #[test]
+    fn checks_in_within_reservation_window() {
+        let now = Utc::now();
+        let early = TimeDelta::minutes(30);
+
+        assert!(check_window(
+            &reservation(now + TimeDelta::minutes(20), now + TimeDelta::hours(1)),
+            early,
+            now
+        )
+        .is_ok());
+        assert!(check_window(
+            &reservation(now - TimeDelta::hours(1), now + TimeDelta::minutes(1)),
+            early,
+            now
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_moved_reservations() {
+        let now = Utc::now();
+        let early = TimeDelta::minutes(30);
+
+        // moved to later in the day after the code was issued
+        assert!(is_outside_window(check_window(
+            &reservation(now + TimeDelta::hours(2), now + TimeDelta::hours(3)),
+            early,
+            now
+        )));
+        // shortened and already over
+        assert!(is_outside_window(check_window(
+            &reservation(now - TimeDelta::hours(2), now - TimeDelta::minutes(1)),
+            early,
+            now
+        )));
+    }
+
+    #[test]
+    fn rejects_cancelled_reservations() {
+        let now = Utc::now();
+        let reservation = ReservationResponse {
+            is_canceled: true,
+            ..reservation(now, now + TimeDelta::hours(1))
+        };
+
+        assert!(matches!(
+            check_window(&reservation, TimeDelta::minutes(30), now),
+            Err(ApiError::InvalidInput(message)) if message.contains("cancelled")
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+use std::io::{Seek, SeekFrom};
+
+use actix_files::NamedFile;
+use actix_web::{
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web::{self, Bytes},
+    HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+
+use crate::{models::url::ExportFormat, routes::ApiError};
+
+/// Rows past this one don't fit into a single XLSX worksheet.
+const XLSX_MAX_ROW: u32 = 1_048_575;
+
+/// Spreadsheet apps take a text starting with one of these for a formula.
+const FORMULA_PREFIXES: &[char] = &['=', '+', '-', '@', '\t', '\r'];
+
+/// Whether the text has to be kept from being read as a formula, see [`FORMULA_PREFIXES`].
+fn is_formula_like(text: &str) -> bool {
+    text.starts_with(FORMULA_PREFIXES)
+}
+
+/// A single value of an exported row.
+pub enum Cell {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    Empty,
+}
+impl From<String> for Cell {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl From<f64> for Cell {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+impl From<u64> for Cell {
+    fn from(value: u64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+impl From<bool> for Cell {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+impl From<NaiveDate> for Cell {
+    fn from(value: NaiveDate) -> Self {
+        Self::Date(value)
+    }
+}
+impl From<DateTime<Utc>> for Cell {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self::DateTime(value.naive_utc())
+    }
+}
+impl<T: Into<Cell>> From<Option<T>> for Cell {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Empty)
+    }
+}
+impl Cell {
+    fn to_csv_field(&self) -> String {
+        match self {
+            Self::Text(text) if is_formula_like(text) => format!("'{text}"),
+            Self::Text(text) => text.clone(),
+            Self::Number(number) => number.to_string(),
+            Self::Bool(boolean) => boolean.to_string(),
+            Self::Date(date) => date.to_string(),
+            Self::DateTime(datetime) => datetime.format("%Y-%m-%d %H:%M:%S").to_string(),
+            Self::Empty => String::new(),
+        }
+    }
+}
+
+/// A row of an admin export, written in the order of its `HEADERS`.
+pub trait ExportRow {
+    const HEADERS: &'static [&'static str];
+
+    fn into_cells(self) -> Vec<Cell>;
+}
+
+/// Writes the row batches as a spreadsheet in the requested format.
+/// The batches are pulled one by one, so only a single batch is held in memory at a time.
+pub async fn export_response<R, S>(
+    format: ExportFormat,
+    name: String,
+    batches: S,
+    request: &HttpRequest,
+) -> Result<HttpResponse, ApiError>
+where
+    R: ExportRow + 'static,
+    S: Stream<Item = Result<Vec<R>, ApiError>> + 'static,
+{
+    match format {
+        ExportFormat::Csv => Ok(csv_response(name, batches)),
+        ExportFormat::Xlsx => xlsx_response(name, batches, request).await,
+    }
+}
+
+/// Streams the rows as CSV, sending every batch as soon as it is fetched.
+fn csv_response<R, S>(name: String, batches: S) -> HttpResponse
+where
+    R: ExportRow + 'static,
+    S: Stream<Item = Result<Vec<R>, ApiError>> + 'static,
+{
+    let headers = stream::once(async {
+        csv_chunk(std::iter::once(
+            R::HEADERS.iter().map(|header| header.to_string()).collect(),
+        ))
+    });
+
+    let rows = batches.map(|batch| {
+        batch.and_then(|rows| {
+            csv_chunk(rows.into_iter().map(|row| {
+                row.into_cells()
+                    .iter()
+                    .map(Cell::to_csv_field)
+                    .collect::<Vec<_>>()
+            }))
+        })
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!("{name}.csv"))],
+        })
+        .streaming(headers.chain(rows))
+}
+
+fn csv_chunk(records: impl Iterator<Item = Vec<String>>) -> Result<Bytes, ApiError> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for record in records {
+        writer
+            .write_record(record)
+            .map_err(|err| ApiError::Export(err.to_string()))?;
+    }
+
+    writer
+        .into_inner()
+        .map(Bytes::from)
+        .map_err(|err| ApiError::Export(err.to_string()))
+}
+
+/// Writes the rows into a temporary XLSX file and streams it back.
+/// The worksheet runs in constant memory mode, flushing every finished row to disk.
+async fn xlsx_response<R, S>(
+    name: String,
+    batches: S,
+    request: &HttpRequest,
+) -> Result<HttpResponse, ApiError>
+where
+    R: ExportRow,
+    S: Stream<Item = Result<Vec<R>, ApiError>>,
+{
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet_with_constant_memory();
+
+    let header_format = Format::new().set_bold();
+    for (col, header) in R::HEADERS.iter().enumerate() {
+        worksheet
+            .write_string_with_format(0, col as u16, *header, &header_format)
+            .map_err(xlsx_error)?;
+    }
+
+    let formats = CellFormats {
+        text: Format::new().set_quote_prefix(),
+        date: Format::new().set_num_format("yyyy-mm-dd"),
+        datetime: Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+    };
+
+    let mut row = 0;
+    let mut batches = std::pin::pin!(batches);
+    while let Some(rows) = batches.try_next().await? {
+        for cells in rows.into_iter().map(R::into_cells) {
+            if row == XLSX_MAX_ROW {
+                return Err(ApiError::InvalidInput(
+                    "too many rows for an XLSX worksheet, export as CSV instead".to_string(),
+                ));
+            }
+            row += 1;
+
+            for (col, cell) in cells.into_iter().enumerate() {
+                write_xlsx_cell(worksheet, row, col as u16, cell, &formats).map_err(xlsx_error)?;
+            }
+        }
+    }
+
+    let file = web::block(move || -> Result<_, ApiError> {
+        let mut file = tempfile::tempfile().map_err(|err| ApiError::Export(err.to_string()))?;
+        workbook.save_to_writer(&mut file).map_err(xlsx_error)?;
+        file.seek(SeekFrom::Start(0))
+            .map_err(|err| ApiError::Export(err.to_string()))?;
+        Ok(file)
+    })
+    .await
+    .map_err(|err| ApiError::Export(err.to_string()))??;
+
+    let file = NamedFile::from_file(file, format!("{name}.xlsx"))
+        .map_err(|err| ApiError::Export(err.to_string()))?
+        .set_content_disposition(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!("{name}.xlsx"))],
+        });
+
+    Ok(file.into_response(request))
+}
+
+struct CellFormats {
+    /// Quote-prefixed, so that the text is shown as is and never evaluated as a formula.
+    text: Format,
+    date: Format,
+    datetime: Format,
+}
+
+fn write_xlsx_cell(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    cell: Cell,
+    formats: &CellFormats,
+) -> Result<(), XlsxError> {
+    match cell {
+        Cell::Text(text) if is_formula_like(&text) => {
+            worksheet.write_string_with_format(row, col, text, &formats.text)?
+        }
+        Cell::Text(text) => worksheet.write_string(row, col, text)?,
+        Cell::Number(number) => worksheet.write_number(row, col, number)?,
+        Cell::Bool(boolean) => worksheet.write_boolean(row, col, boolean)?,
+        Cell::Date(date) => worksheet.write_datetime_with_format(row, col, date, &formats.date)?,
+        Cell::DateTime(datetime) => {
+            worksheet.write_datetime_with_format(row, col, datetime, &formats.datetime)?
+        }
+        Cell::Empty => worksheet,
+    };
+    Ok(())
+}
+
+fn xlsx_error(err: XlsxError) -> ApiError {
+    ApiError::Export(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csv_field(text: &str) -> String {
+        Cell::Text(text.to_string()).to_csv_field()
+    }
+
+    #[test]
+    fn neutralizes_formulas_in_csv() {
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "'=HYPERLINK(\"x\")");
+        assert_eq!(csv_field("+7 900 000-00-00"), "'+7 900 000-00-00");
+        assert_eq!(csv_field("-1+1"), "'-1+1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\t=1"), "'\t=1");
+    }
+
+    #[test]
+    fn keeps_plain_text_in_csv() {
+        assert_eq!(csv_field("Ivan Ivanov"), "Ivan Ivanov");
+        assert_eq!(csv_field("a=b"), "a=b");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn keeps_numbers_in_csv() {
+        assert_eq!(Cell::Number(-5.0).to_csv_field(), "-5");
+    }
+}
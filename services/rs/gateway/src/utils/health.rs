@@ -0,0 +1,35 @@
+use std::{sync::Arc, time::Instant};
+
+use futures::future::join_all;
+use service_helper::{
+    channel::ServiceChannel,
+    health::{check_service, CHECK_TIMEOUT},
+};
+
+use crate::models::dto::{DependencyHealth, Readiness};
+
+/// Services the gateway can't serve requests without.
+#[derive(Clone)]
+pub struct Dependencies(Arc<Vec<(&'static str, ServiceChannel)>>);
+impl Dependencies {
+    pub fn new(services: Vec<(&'static str, ServiceChannel)>) -> Self {
+        Self(Arc::new(services))
+    }
+
+    /// Checks the health of all the services at once.
+    pub async fn check(&self) -> Readiness {
+        let checks = self.0.iter().map(|(name, channel)| async move {
+            let started_at = Instant::now();
+            let result = tokio::time::timeout(CHECK_TIMEOUT, check_service(channel.clone()))
+                .await
+                .unwrap_or_else(|_| Err("timed out".to_string()));
+
+            (
+                name.to_string(),
+                DependencyHealth::new(result, started_at.elapsed()),
+            )
+        });
+
+        Readiness::new(join_all(checks).await.into_iter().collect())
+    }
+}
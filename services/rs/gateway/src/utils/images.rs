@@ -0,0 +1,178 @@
+use actix_multipart::{Field, Multipart};
+use actix_web::{web::Bytes, HttpResponse};
+use futures::{channel::mpsc, stream, SinkExt, StreamExt, TryStreamExt};
+use protos::{
+    image::{
+        image_client::ImageClient, upload_image_request::Data, GetImageRequest, ImageType,
+        ListImagesRequest, Metadata, UploadImageRequest,
+    },
+    image_worker::image_worker_client::ImageWorkerClient,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Status;
+use uuid::Uuid;
+
+use crate::{config, models::url::ImageVariant, routes::ApiError};
+
+use super::services::ServiceError;
+
+pub const IMAGE_CONTENT_TYPES: [&str; 3] = ["image/jpeg", "image/png", "image/webp"];
+
+const UPLOAD_BUFFER_CHUNKS: usize = 4;
+
+/// Streams the `file` field of the multipart payload to the image worker chunk by chunk.
+/// The worker stores the original along with its downscaled variants.
+pub async fn upload_image(
+    client: &ImageWorkerClient<ServiceChannel>,
+    mut payload: Multipart,
+    id: String,
+    image_type: ImageType,
+) -> Result<(), ApiError> {
+    let field = loop {
+        match payload
+            .try_next()
+            .await
+            .map_err(|err| ApiError::InvalidInput(err.to_string()))?
+        {
+            Some(field) if field.name() == Some("file") => break field,
+            Some(..) => continue,
+            None => {
+                return Err(ApiError::InvalidInput(
+                    "multipart field `file` is missing".to_string(),
+                ))
+            }
+        }
+    };
+
+    let content_type = field
+        .content_type()
+        .map(|mime| mime.essence_str().to_string())
+        .filter(|content_type| IMAGE_CONTENT_TYPES.contains(&content_type.as_str()))
+        .ok_or_else(|| {
+            ApiError::InvalidInput(format!(
+                "image content type must be one of: {}",
+                IMAGE_CONTENT_TYPES.join(", ")
+            ))
+        })?;
+
+    let (tx, rx) = mpsc::channel(UPLOAD_BUFFER_CHUNKS);
+
+    let metadata = UploadImageRequest {
+        data: Some(Data::Metadata(Metadata {
+            id,
+            content_type,
+            image_type: image_type.into(),
+            variant: None,
+        })),
+    };
+
+    // if forwarding fails, `try_join!` drops the upload, which cancels the call
+    // and keeps a truncated image from being stored
+    futures::try_join!(forward_chunks(field, metadata, tx), async {
+        client
+            .clone()
+            .process_image(rx)
+            .await
+            .map_err(ServiceError::from)
+            .map_err(ApiError::from)
+    })?;
+
+    Ok(())
+}
+
+async fn forward_chunks(
+    mut field: Field,
+    metadata: UploadImageRequest,
+    mut tx: mpsc::Sender<UploadImageRequest>,
+) -> Result<(), ApiError> {
+    if tx.send(metadata).await.is_err() {
+        // the call has already failed, its error is reported by the upload itself
+        return Ok(());
+    }
+
+    let mut size = 0;
+    while let Some(chunk) = field
+        .try_next()
+        .await
+        .map_err(|err| ApiError::InvalidInput(err.to_string()))?
+    {
+        size += chunk.len() as u64;
+        if size > *config::IMAGE_MAX_SIZE {
+            return Err(ApiError::PayloadTooLarge(*config::IMAGE_MAX_SIZE));
+        }
+
+        let request = UploadImageRequest {
+            data: Some(Data::Content(chunk.to_vec())),
+        };
+        if tx.send(request).await.is_err() {
+            return Ok(());
+        }
+    }
+
+    if size == 0 {
+        return Err(ApiError::InvalidInput("image file is empty".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Passes the image service stream through as the response body.
+pub async fn download_image(
+    client: &ImageClient<ServiceChannel>,
+    id: String,
+    image_type: ImageType,
+    variant: ImageVariant,
+) -> Result<HttpResponse, ApiError> {
+    let mut chunks = client
+        .clone()
+        .get_image(GetImageRequest {
+            id,
+            image_type: image_type.into(),
+            variant: Some(protos::image::ImageVariant::from(variant).into()),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    // the first chunk carries the content type
+    let first = chunks
+        .message()
+        .await
+        .map_err(ServiceError::from)?
+        .ok_or(ApiError::NotFound)?;
+
+    let content_type = first
+        .content_type
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+
+    let body = stream::once(async { Ok::<_, Status>(Bytes::from(first.content)) })
+        .chain(chunks.map_ok(|chunk| Bytes::from(chunk.content)));
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .streaming(body))
+}
+
+/// Lists the ids of the images stored under `{prefix}/{image_id}`.
+pub async fn list_images(
+    client: &ImageClient<ServiceChannel>,
+    prefix: Uuid,
+    image_type: ImageType,
+) -> Result<Vec<Uuid>, ApiError> {
+    let response = client
+        .clone()
+        .list_images(ListImagesRequest {
+            prefix: format!("{prefix}/"),
+            image_type: image_type.into(),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(response
+        .ids
+        .iter()
+        .filter_map(|id| id.rsplit('/').next())
+        .filter_map(|id| Uuid::parse_str(id).ok())
+        .collect())
+}
@@ -1,16 +1,111 @@
-use actix_contrib_logger::middleware::Logger;
-use log::Level;
-
-pub struct CustomLogger;
-
-impl CustomLogger {
-    pub fn new() -> Logger {
-        Logger::new("%a \"%r\" %s (took %D ms to serve)").custom_level(|status| {
-            if status.is_server_error() {
-                Level::Error
-            } else {
-                Level::Debug
-            }
-        })
+use std::time::Instant;
+
+use actix_web::{
+    body::{BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    error::InternalError,
+    http::header::{HeaderMap, HeaderName, HeaderValue},
+    middleware::Next,
+    Error,
+};
+use opentelemetry::{global, propagation::Extractor};
+use service_helper::request_id;
+use tracing::{field, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static(request_id::HEADER);
+const REQUEST_ID_MAX_LENGTH: usize = 128;
+
+/// Serves every request inside a span tagged with the request id, taken from
+/// the `X-Request-Id` header or generated, and logs its outcome.
+/// The id is returned in the same header, included into the error bodies
+/// and passed on to the services.
+pub async fn request_logger(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let request_id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid_request_id(id))
+        .map(str::to_owned)
+        .unwrap_or_else(|| Uuid::now_v7().to_string());
+
+    let route = req.match_pattern().unwrap_or_else(|| "default".to_string());
+    let span = tracing::info_span!(
+        "HTTP request",
+        otel.name = %format!("{} {route}", req.method()),
+        otel.kind = "server",
+        otel.status_code = field::Empty,
+        http.request.method = %req.method(),
+        http.route = %route,
+        http.response.status_code = field::Empty,
+        url.path = req.path(),
+        client.address = req.connection_info().realip_remote_addr().unwrap_or(""),
+        request_id = %request_id,
+    );
+    span.set_parent(global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(req.headers()))
+    }));
+
+    let started_at = Instant::now();
+    let outcome = request_id::scope(request_id.clone(), async move {
+        next.call(req)
+            .await
+            .map(ServiceResponse::map_into_boxed_body)
+            // rendered here rather than by the server for the id to make it into the body
+            .map_err(|err| {
+                let response = err.error_response();
+                (err, response)
+            })
+    })
+    .instrument(span.clone())
+    .await;
+
+    let status = match &outcome {
+        Ok(response) => response.status(),
+        Err((_, response)) => response.status(),
+    };
+    let elapsed_ms = started_at.elapsed().as_secs_f64() * 1000.0;
+    span.record("http.response.status_code", status.as_u16());
+    span.in_scope(|| {
+        if status.is_server_error() {
+            span.record("otel.status_code", "ERROR");
+            tracing::error!(status = status.as_u16(), elapsed_ms, "Request failed");
+        } else {
+            tracing::debug!(status = status.as_u16(), elapsed_ms, "Request served");
+        }
+    });
+
+    let request_id =
+        HeaderValue::from_str(&request_id).expect("request id is a valid header value");
+    match outcome {
+        Ok(mut response) => {
+            response.headers_mut().insert(REQUEST_ID_HEADER, request_id);
+            Ok(response)
+        }
+        Err((err, mut response)) => {
+            response.headers_mut().insert(REQUEST_ID_HEADER, request_id);
+            Err(InternalError::from_response(err, response).into())
+        }
+    }
+}
+
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= REQUEST_ID_MAX_LENGTH
+        && id.bytes().all(|byte| byte.is_ascii_graphic())
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
     }
 }
@@ -0,0 +1,34 @@
+use std::sync::LazyLock;
+
+use actix_web_prom::{PrometheusMetrics, PrometheusMetricsBuilder};
+use prometheus::{IntCounterVec, Opts};
+
+/// Reservations created through the API, by whether they were paid with plan hours.
+pub static RESERVATIONS_CREATED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    IntCounterVec::new(
+        Opts::new(
+            "reservations_created_total",
+            "Total number of created reservations",
+        )
+        .namespace("gateway"),
+        &["payment"],
+    )
+    .unwrap()
+});
+
+/// Request counts and latencies per route and status, served on `/metrics`
+/// together with the business metrics.
+pub fn prometheus() -> PrometheusMetrics {
+    let metrics = PrometheusMetricsBuilder::new("gateway")
+        .endpoint("/metrics")
+        .mask_unmatched_patterns("UNKNOWN")
+        .build()
+        .expect("Failed to build the metrics middleware");
+
+    metrics
+        .registry
+        .register(Box::new(RESERVATIONS_CREATED.clone()))
+        .expect("Failed to register business metrics");
+
+    metrics
+}
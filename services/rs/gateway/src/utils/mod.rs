@@ -1,6 +1,15 @@
+pub mod calendar;
+pub mod checkin;
 pub mod cors;
+pub mod export;
+pub mod health;
+pub mod images;
 pub mod logger;
+pub mod metrics;
 pub mod openapi;
+pub mod plans;
+pub mod pricing;
+pub mod seat_lock;
 pub mod services;
 pub mod validation;
 
@@ -0,0 +1,71 @@
+use convertions::datetime_into_timestamp;
+use protos::billing::{
+    billing_client::BillingClient, AttachUsageRequest, DrawDownRequest, RestoreUsageRequest,
+};
+use service_helper::channel::ServiceChannel;
+use uuid::Uuid;
+
+use crate::models::dto::CreateReservation;
+
+use super::services::ServiceError;
+
+/// Takes the reservation hours off the client's plan.
+/// Returns `None` when no plan covers the reservation, so it's paid per booking.
+/// Fails with [`ServiceError::AlreadyExists`] when there aren't enough hours left.
+pub async fn draw_down_hours(
+    client: &BillingClient<ServiceChannel>,
+    client_id: Uuid,
+    reservation: &CreateReservation,
+) -> Result<Option<String>, ServiceError> {
+    let response = client
+        .clone()
+        .draw_down(DrawDownRequest {
+            client_id: client_id.to_string(),
+            seat_id: reservation.seat_id.to_string(),
+            starts_at: datetime_into_timestamp(reservation.starts_at),
+            ends_at: datetime_into_timestamp(reservation.ends_at),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    Ok(response.usage_id)
+}
+
+/// Links the drawn hours to the created reservation, so they return once it's cancelled.
+pub async fn attach_hours(
+    client: &BillingClient<ServiceChannel>,
+    usage_id: String,
+    reservation_id: &str,
+) {
+    if let Err(err) = client
+        .clone()
+        .attach_usage(AttachUsageRequest {
+            usage_id: usage_id.clone(),
+            reservation_id: reservation_id.to_string(),
+        })
+        .await
+    {
+        tracing::error!(
+            "Failed to attach plan usage {usage_id} to reservation {reservation_id}: {err}"
+        );
+    }
+}
+
+/// Gives the hours back to the plan, either by the usage or by the reservation it's attached to.
+pub async fn restore_hours(
+    client: &BillingClient<ServiceChannel>,
+    usage_id: Option<String>,
+    reservation_id: Option<String>,
+) {
+    if let Err(err) = client
+        .clone()
+        .restore_usage(RestoreUsageRequest {
+            usage_id,
+            reservation_id,
+        })
+        .await
+    {
+        tracing::error!("Failed to restore plan hours: {err}");
+    }
+}
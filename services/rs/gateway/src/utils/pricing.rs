@@ -0,0 +1,208 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Datelike, NaiveTime, TimeDelta, Utc, Weekday};
+use protos::coworking::{coworking_client::CoworkingClient, SeatRequest};
+use service_helper::channel::ServiceChannel;
+
+use crate::{
+    config,
+    models::dto::{CreateReservation, ReservationQuote},
+    routes::ApiError,
+};
+
+use super::services::ServiceError;
+
+/// Prices a reservation from the seat's hourly cost: peak and off-peak time are billed
+/// with their multipliers, then the internal client and promo code discounts are applied.
+pub async fn quote_reservation(
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation: &CreateReservation,
+    is_internal: bool,
+) -> Result<ReservationQuote, ApiError> {
+    let promo_discount = reservation
+        .promo_code
+        .as_deref()
+        .map(|code| {
+            promo_discount(&config::PROMO_CODES, code)
+                .ok_or_else(|| ApiError::InvalidInput(format!("promo code `{code}` is not valid")))
+        })
+        .transpose()?;
+
+    let seat = coworking_client
+        .clone()
+        .get_seat(SeatRequest {
+            id: reservation.seat_id.to_string(),
+        })
+        .await
+        .map_err(ServiceError::from)?
+        .into_inner();
+
+    let (peak, off_peak) = split_peak_time(
+        reservation.starts_at,
+        reservation.ends_at,
+        *config::PEAK_HOURS_START..*config::PEAK_HOURS_END,
+    );
+    let (peak_hours, off_peak_hours) = (hours(peak), hours(off_peak));
+
+    let base_price = seat.cost
+        * (peak_hours * *config::PEAK_MULTIPLIER + off_peak_hours * *config::OFF_PEAK_MULTIPLIER);
+
+    let mut price = base_price;
+    if is_internal {
+        price *= 1.0 - *config::INTERNAL_DISCOUNT_PERCENT / 100.0;
+    }
+    if let Some(percent) = promo_discount {
+        price *= 1.0 - percent / 100.0;
+    }
+
+    let (base_price, price) = (round_to_cents(base_price), round_to_cents(price.max(0.0)));
+
+    Ok(ReservationQuote {
+        seat_id: reservation.seat_id,
+        starts_at: reservation.starts_at,
+        ends_at: reservation.ends_at,
+        hourly_cost: seat.cost,
+        peak_hours: round_to_cents(peak_hours),
+        off_peak_hours: round_to_cents(off_peak_hours),
+        base_price,
+        discount: round_to_cents(base_price - price),
+        promo_code: reservation.promo_code.clone(),
+        price,
+    })
+}
+
+/// Looks the code up in the promo codes, a list of `CODE:percent` pairs like `PROMO_CODES`.
+fn promo_discount(promo_codes: &[String], code: &str) -> Option<f64> {
+    promo_codes
+        .iter()
+        .filter_map(|entry| entry.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case(code.trim()))
+        .and_then(|(_, percent)| percent.trim().parse::<f64>().ok())
+        .map(|percent| percent.clamp(0.0, 100.0))
+}
+
+/// Splits the reservation into the time within the peak hours of a day and the rest of it.
+/// Weekends are off-peak all day long.
+fn split_peak_time(
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    peak_hours: Range<u32>,
+) -> (TimeDelta, TimeDelta) {
+    let (mut peak, mut off_peak) = (TimeDelta::zero(), TimeDelta::zero());
+
+    let mut cursor = starts_at;
+    while cursor < ends_at {
+        let day = cursor.date_naive().and_time(NaiveTime::MIN).and_utc();
+        let next_day = day + TimeDelta::days(1);
+        let peak_starts = day + TimeDelta::hours(peak_hours.start as i64);
+        let peak_ends = day + TimeDelta::hours(peak_hours.end as i64);
+
+        let (is_peak, boundary) = match cursor {
+            _ if matches!(cursor.weekday(), Weekday::Sat | Weekday::Sun) => (false, next_day),
+            cursor if cursor < peak_starts => (false, peak_starts),
+            cursor if cursor < peak_ends => (true, peak_ends),
+            _ => (false, next_day),
+        };
+
+        let next = boundary.min(ends_at);
+        if is_peak {
+            peak += next - cursor;
+        } else {
+            off_peak += next - cursor;
+        }
+        cursor = next;
+    }
+
+    (peak, off_peak)
+}
+
+fn hours(time: TimeDelta) -> f64 {
+    time.num_seconds() as f64 / 3600.0
+}
+
+pub fn round_to_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    const PEAK_HOURS: Range<u32> = 10..18;
+
+    /// 2025-03-03 is a Monday.
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn split(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> (i64, i64) {
+        let (peak, off_peak) = split_peak_time(starts_at, ends_at, PEAK_HOURS);
+        (peak.num_minutes(), off_peak.num_minutes())
+    }
+
+    #[test]
+    fn splits_weekday_time_at_peak_boundaries() {
+        assert_eq!(split(at(3, 8, 0), at(3, 10, 0)), (0, 120));
+        assert_eq!(split(at(3, 11, 0), at(3, 13, 30)), (150, 0));
+        assert_eq!(split(at(3, 9, 30), at(3, 10, 45)), (45, 30));
+        assert_eq!(split(at(3, 17, 0), at(3, 20, 0)), (60, 120));
+        assert_eq!(split(at(3, 8, 0), at(3, 20, 0)), (480, 240));
+    }
+
+    #[test]
+    fn keeps_weekends_off_peak() {
+        assert_eq!(split(at(8, 10, 0), at(8, 18, 0)), (0, 480));
+        assert_eq!(split(at(9, 12, 0), at(9, 13, 0)), (0, 60));
+    }
+
+    #[test]
+    fn splits_time_across_days() {
+        // Friday 17:00 to Saturday 11:00
+        assert_eq!(split(at(7, 17, 0), at(8, 11, 0)), (60, 1020));
+        // Sunday 20:00 to Monday 11:00
+        assert_eq!(split(at(9, 20, 0), at(10, 11, 0)), (60, 840));
+    }
+
+    #[test]
+    fn splits_nothing_for_empty_ranges() {
+        assert_eq!(split(at(3, 12, 0), at(3, 12, 0)), (0, 0));
+        assert_eq!(split(at(3, 13, 0), at(3, 12, 0)), (0, 0));
+    }
+
+    #[test]
+    fn finds_promo_discounts() {
+        let promo_codes = ["WELCOME10:10".to_string(), "HALF: 50 ".to_string()];
+
+        assert_eq!(promo_discount(&promo_codes, "WELCOME10"), Some(10.0));
+        assert_eq!(promo_discount(&promo_codes, " welcome10 "), Some(10.0));
+        assert_eq!(promo_discount(&promo_codes, "half"), Some(50.0));
+        assert_eq!(promo_discount(&promo_codes, "WELCOME"), None);
+        assert_eq!(promo_discount(&[], "WELCOME10"), None);
+    }
+
+    #[test]
+    fn clamps_promo_discounts_and_skips_malformed_entries() {
+        let promo_codes = [
+            "FREE:150".to_string(),
+            "NEGATIVE:-5".to_string(),
+            "BROKEN".to_string(),
+            "NAN:abc".to_string(),
+        ];
+
+        assert_eq!(promo_discount(&promo_codes, "FREE"), Some(100.0));
+        assert_eq!(promo_discount(&promo_codes, "NEGATIVE"), Some(0.0));
+        assert_eq!(promo_discount(&promo_codes, "BROKEN"), None);
+        assert_eq!(promo_discount(&promo_codes, "NAN"), None);
+    }
+
+    #[test]
+    fn rounds_to_cents() {
+        assert_eq!(round_to_cents(614.254), 614.25);
+        assert_eq!(round_to_cents(614.256), 614.26);
+        assert_eq!(round_to_cents(68.125), 68.13);
+        assert_eq!(round_to_cents(-1.005), -1.0);
+        assert_eq!(round_to_cents(300.0), 300.0);
+    }
+}
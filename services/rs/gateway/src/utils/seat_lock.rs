@@ -0,0 +1,71 @@
+use std::future::Future;
+
+use protos::seat_lock::{
+    seat_lock_client::SeatLockClient, BookingLockRequest, ReleaseBookingLockRequest,
+};
+use service_helper::channel::ServiceChannel;
+use uuid::Uuid;
+
+use crate::config;
+
+use super::services::ServiceError;
+
+/// Takes a short-lived lock on the seat for the duration of a reservation change.
+/// The lock is kept apart from the seat holds, identified by `lock_id` alone.
+/// Fails with [`ServiceError::AlreadyExists`] when the seat is held or being booked by someone else.
+async fn lock_seat(
+    client: &SeatLockClient<ServiceChannel>,
+    seat_id: Uuid,
+    holder_id: Uuid,
+    lock_id: Uuid,
+) -> Result<(), ServiceError> {
+    client
+        .clone()
+        .lock_for_booking(BookingLockRequest {
+            user_id: holder_id.to_string(),
+            seat_index: seat_id.to_string(),
+            lock_id: lock_id.to_string(),
+            ttl_seconds: Some(*config::SEAT_LOCK_TTL_SECONDS),
+        })
+        .await
+        .map_err(ServiceError::from)?;
+
+    Ok(())
+}
+
+/// Releases the lock once the reservation change is over, leaving the holds of the holder as they are.
+/// The lock expires by itself anyway, so a failure here is only logged.
+async fn release_seat(client: &SeatLockClient<ServiceChannel>, seat_id: Uuid, lock_id: Uuid) {
+    if let Err(err) = client
+        .clone()
+        .release_booking_lock(ReleaseBookingLockRequest {
+            seat_index: seat_id.to_string(),
+            lock_id: lock_id.to_string(),
+        })
+        .await
+    {
+        tracing::error!("Failed to release the lock on seat {seat_id}: {err}");
+    }
+}
+
+/// Runs the reservation change with the seat locked. The lock is released however the change ends,
+/// so a failed attempt doesn't keep the seat from others until the lock expires.
+/// Only the lock taken here is released, a hold of the seat by the holder outlives the change.
+pub async fn with_seat_lock<T, E, F>(
+    client: &SeatLockClient<ServiceChannel>,
+    seat_id: Uuid,
+    holder_id: Uuid,
+    change: F,
+) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+    E: From<ServiceError>,
+{
+    let lock_id = Uuid::now_v7();
+
+    lock_seat(client, seat_id, holder_id, lock_id).await?;
+    let result = change.await;
+    release_seat(client, seat_id, lock_id).await;
+
+    result
+}
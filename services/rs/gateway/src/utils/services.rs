@@ -31,6 +31,12 @@ pub enum ServiceError {
     #[error("Error while validating input: {0}")]
     Validation(String),
 
+    #[error("{0}")]
+    Unavailable(String),
+
+    #[error("{0}")]
+    Timeout(String),
+
     #[error("Something vent wrong...")]
     Unknown,
 }
@@ -41,10 +47,8 @@ impl ResponseError for ServiceError {
     }
 
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code()).json(ApiError {
-            error: self.error_name(),
-            description: self.to_string(),
-        })
+        HttpResponse::build(self.status_code())
+            .json(ApiError::new(self.error_name(), self.to_string()))
     }
 }
 
@@ -59,6 +63,8 @@ impl ServiceError {
             Self::Unauthorized(..) => StatusCode::UNAUTHORIZED,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
             Self::Validation(..) => StatusCode::BAD_REQUEST,
+            Self::Unavailable(..) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Timeout(..) => StatusCode::GATEWAY_TIMEOUT,
             Self::Unknown => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -73,6 +79,8 @@ impl ServiceError {
             Self::Unauthorized(..) => "unauthorized",
             Self::InvalidInput(..) => "invalid_input",
             Self::Validation(..) => "invalid_input",
+            Self::Unavailable(..) => "service_unavailable",
+            Self::Timeout(..) => "timeout",
             Self::Unknown => "unknown_error",
         }
     }
@@ -89,18 +97,20 @@ impl From<Status> for ServiceError {
         ) {
             (Code::PermissionDenied, message, ..) => Self::Forbidden(message.to_string()),
             (Code::AlreadyExists, message, ..) => Self::AlreadyExists(message.to_string()),
+            (Code::ResourceExhausted, message, ..) => Self::AlreadyExists(message.to_string()),
             (Code::NotFound, message, ..) => Self::NotFound(message.to_string()),
             (Code::Unauthenticated, message, ..) => Self::Unauthorized(message.to_string()),
             (Code::InvalidArgument, message, ..) => Self::InvalidInput(message.to_string()),
             (Code::Unimplemented, ..) => Self::ServiceNotImplemented,
             (Code::Internal, ..) => Self::Unknown,
-            (Code::DeadlineExceeded, ..) => Self::Unknown,
+            (Code::Unavailable, message, ..) => Self::Unavailable(message.to_string()),
+            (Code::DeadlineExceeded, message, ..) => Self::Timeout(message.to_string()),
             (Code::Unknown, message, ..) => {
-                log::debug!("{message:#?}");
+                tracing::debug!("{message:#?}");
                 Self::Unknown
             }
             _ => {
-                log::error!("{:?}", status);
+                tracing::error!("{:?}", status);
                 unreachable!()
             }
         }
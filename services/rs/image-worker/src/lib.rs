@@ -0,0 +1,14 @@
+use env_config::environment_variables;
+
+pub mod pipeline;
+pub mod service;
+
+environment_variables! {
+    SERVICE_ADDRESS: String =  "[::1]:50059",
+    IMAGE_SERVICE_ADDRESS: String = "localhost:50058",
+    IMAGE_MAX_SIZE: u64 = 10u64 * 1024 * 1024,
+    IMAGE_MAX_DIMENSION: u32 = 8192u32,
+    MEDIUM_SIZE: u32 = 1280u32,
+    THUMBNAIL_SIZE: u32 = 320u32,
+    WEBP_QUALITY: f32 = 80f32,
+}
@@ -0,0 +1,57 @@
+use image_worker::{config, service::ImageWorkerService};
+use protos::{
+    image::image_client::ImageClient, image_worker::image_worker_server::ImageWorkerServer,
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::PrincipalLayer,
+    shutdown::Shutdown,
+    telemetry::{self, TraceLayer},
+    tls::{self, CallerAllowlist},
+};
+use tonic::codec::CompressionEncoding;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("image-worker");
+    config::init();
+
+    let shutdown = Shutdown::on_signal();
+
+    let image_client = ImageClient::new(
+        ServiceChannel::lazy("image", &config::IMAGE_SERVICE_ADDRESS)
+            .retryable(&["/image.Image/ListImages"])
+            .streaming(&["/image.Image/UploadImage", "/image.Image/GetImage"]),
+    )
+    .send_compressed(CompressionEncoding::Zstd)
+    .accept_compressed(CompressionEncoding::Zstd);
+
+    let service = ImageWorkerServer::new(ImageWorkerService::new(image_client))
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
+
+    tracing::info!(
+        "Started image worker on {}",
+        config::SERVICE_ADDRESS.as_str()
+    );
+
+    const GATEWAY: &[&str] = &["gateway"];
+    let callers = CallerAllowlist::new().allow("/image_worker.ImageWorker/ProcessImage", GATEWAY);
+
+    let server = tls::server()?
+        .layer(TraceLayer)
+        .layer(callers)
+        .layer(PrincipalLayer)
+        .add_service(service)
+        .serve_with_shutdown(config::SERVICE_ADDRESS.parse()?, shutdown.requested());
+    shutdown.drain(server).await?;
+
+    tracing::info!("Stopped image worker");
+
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use std::io::Cursor;
+
+use image::{
+    imageops::FilterType, DynamicImage, ImageDecoder, ImageError, ImageFormat, ImageReader, Limits,
+};
+use protos::image::ImageVariant;
+use webp::Encoder;
+
+use crate::config;
+
+const SUPPORTED_FORMATS: [ImageFormat; 3] =
+    [ImageFormat::Jpeg, ImageFormat::Png, ImageFormat::WebP];
+
+#[derive(thiserror::Error, Debug)]
+pub enum PipelineError {
+    #[error("Unsupported image format, expected JPEG, PNG or WebP")]
+    UnsupportedFormat,
+
+    #[error("Image must not be larger than {0} bytes")]
+    TooLarge(u64),
+
+    #[error("Failed to decode the image: {0}")]
+    Decode(#[from] ImageError),
+
+    #[error("Failed to encode the image: {0}")]
+    Encode(String),
+}
+
+pub struct ProcessedImage {
+    pub width: u32,
+    pub height: u32,
+    pub variants: Vec<(ImageVariant, Vec<u8>)>,
+}
+
+/// Decodes the image, applies its EXIF orientation and encodes every variant as WebP.
+/// The variants are encoded from raw pixels, so EXIF and other metadata never make it to the storage.
+pub fn process(content: &[u8]) -> Result<ProcessedImage, PipelineError> {
+    let image = decode(content)?;
+
+    let variants = [
+        (ImageVariant::Original, None),
+        (ImageVariant::Medium, Some(*config::MEDIUM_SIZE)),
+        (ImageVariant::Thumbnail, Some(*config::THUMBNAIL_SIZE)),
+    ]
+    .into_iter()
+    .map(|(variant, size)| {
+        let content = match size {
+            Some(size) => encode(&fit(&image, size)),
+            None => encode(&image),
+        }?;
+        Ok((variant, content))
+    })
+    .collect::<Result<_, PipelineError>>()?;
+
+    Ok(ProcessedImage {
+        width: image.width(),
+        height: image.height(),
+        variants,
+    })
+}
+
+/// Rejects the files over the size limit, called while they are still being received as well.
+pub fn check_size(size: usize) -> Result<(), PipelineError> {
+    if size as u64 > *config::IMAGE_MAX_SIZE {
+        return Err(PipelineError::TooLarge(*config::IMAGE_MAX_SIZE));
+    }
+
+    Ok(())
+}
+
+fn decode(content: &[u8]) -> Result<DynamicImage, PipelineError> {
+    check_size(content.len())?;
+
+    let format = image::guess_format(content)
+        .ok()
+        .filter(|format| SUPPORTED_FORMATS.contains(format))
+        .ok_or(PipelineError::UnsupportedFormat)?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(*config::IMAGE_MAX_DIMENSION);
+    limits.max_image_height = Some(*config::IMAGE_MAX_DIMENSION);
+
+    let mut reader = ImageReader::with_format(Cursor::new(content), format);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder()?;
+    let orientation = decoder.orientation()?;
+
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
+
+/// Scales the image down to fit into a `size`x`size` box, smaller images are left as they are.
+fn fit(image: &DynamicImage, size: u32) -> DynamicImage {
+    if image.width() <= size && image.height() <= size {
+        return image.clone();
+    }
+
+    image.resize(size, size, FilterType::Lanczos3)
+}
+
+fn encode(image: &DynamicImage) -> Result<Vec<u8>, PipelineError> {
+    let (width, height) = (image.width(), image.height());
+
+    let memory = if image.color().has_alpha() {
+        let pixels = image.to_rgba8();
+        Encoder::from_rgba(&pixels, width, height).encode_simple(false, *config::WEBP_QUALITY)
+    } else {
+        let pixels = image.to_rgb8();
+        Encoder::from_rgb(&pixels, width, height).encode_simple(false, *config::WEBP_QUALITY)
+    }
+    .map_err(|err| PipelineError::Encode(format!("{err:?}")))?;
+
+    Ok(memory.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{GenericImageView, ImageError, Rgb, RgbImage, Rgba, RgbaImage};
+
+    use super::*;
+
+    const RED: Rgb<u8> = Rgb([255, 0, 0]);
+    const BLUE: Rgb<u8> = Rgb([0, 0, 255]);
+    const CAMERA: &[u8] = b"Secret Camera 3000\0";
+
+    fn write(image: impl Into<DynamicImage>, format: ImageFormat) -> Vec<u8> {
+        let mut content = Cursor::new(Vec::new());
+        image.into().write_to(&mut content, format).unwrap();
+        content.into_inner()
+    }
+
+    /// Red left half, blue right half.
+    fn halves(width: u32, height: u32) -> RgbImage {
+        RgbImage::from_fn(width, height, |x, _| if x < width / 2 { RED } else { BLUE })
+    }
+
+    /// JPEG with an EXIF segment carrying the camera model and the given orientation.
+    fn jpeg_with_exif(image: RgbImage, orientation: u16) -> Vec<u8> {
+        // big-endian TIFF header and a single IFD: Model (ASCII) and Orientation (SHORT)
+        let mut tiff = b"MM\0\x2a\0\0\0\x08\0\x02".to_vec();
+        let model_offset = 8 + 2 + 2 * 12 + 4;
+        tiff.extend_from_slice(&[0x01, 0x10, 0x00, 0x02]);
+        tiff.extend_from_slice(&(CAMERA.len() as u32).to_be_bytes());
+        tiff.extend_from_slice(&(model_offset as u32).to_be_bytes());
+        tiff.extend_from_slice(&[0x01, 0x12, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01]);
+        tiff.extend_from_slice(&orientation.to_be_bytes());
+        tiff.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+        tiff.extend_from_slice(CAMERA);
+
+        let mut segment = vec![0xff, 0xe1];
+        segment.extend_from_slice(&((2 + 6 + tiff.len()) as u16).to_be_bytes());
+        segment.extend_from_slice(b"Exif\0\0");
+        segment.extend_from_slice(&tiff);
+
+        let mut content = write(image, ImageFormat::Jpeg);
+        // right after the start of image marker
+        content.splice(2..2, segment);
+        content
+    }
+
+    fn variant(processed: &ProcessedImage, variant: ImageVariant) -> DynamicImage {
+        let (_, content) = processed
+            .variants
+            .iter()
+            .find(|(kind, _)| *kind == variant)
+            .unwrap();
+        image::load_from_memory_with_format(content, ImageFormat::WebP).unwrap()
+    }
+
+    fn is_close(pixel: Rgb<u8>, expected: Rgb<u8>) -> bool {
+        pixel
+            .0
+            .iter()
+            .zip(expected.0)
+            .all(|(&actual, expected)| actual.abs_diff(expected) < 64)
+    }
+
+    #[test]
+    fn strips_exif() {
+        let content = jpeg_with_exif(halves(16, 8), 1);
+        assert!(content.windows(CAMERA.len()).any(|window| window == CAMERA));
+
+        let processed = process(&content).unwrap();
+
+        for (_, content) in &processed.variants {
+            assert!(!content.windows(4).any(|window| window == b"EXIF"));
+            assert!(!content.windows(CAMERA.len()).any(|window| window == CAMERA));
+        }
+    }
+
+    #[test]
+    fn applies_orientation() {
+        // rotated 90° clockwise: the left half ends up on top
+        let processed = process(&jpeg_with_exif(halves(16, 8), 6)).unwrap();
+        assert_eq!((processed.width, processed.height), (8, 16));
+
+        let original = variant(&processed, ImageVariant::Original).to_rgb8();
+        assert_eq!(original.dimensions(), (8, 16));
+        assert!(is_close(*original.get_pixel(4, 3), RED));
+        assert!(is_close(*original.get_pixel(4, 12), BLUE));
+    }
+
+    #[test]
+    fn encodes_three_webp_variants() {
+        let (width, height) = (*config::MEDIUM_SIZE * 2, *config::MEDIUM_SIZE);
+
+        let processed = process(&write(halves(width, height), ImageFormat::Png)).unwrap();
+
+        assert_eq!((processed.width, processed.height), (width, height));
+        assert_eq!(processed.variants.len(), 3);
+        for (_, content) in &processed.variants {
+            assert_eq!(image::guess_format(content).unwrap(), ImageFormat::WebP);
+        }
+
+        let sizes = [
+            ImageVariant::Original,
+            ImageVariant::Medium,
+            ImageVariant::Thumbnail,
+        ]
+        .map(|kind| variant(&processed, kind).dimensions());
+        assert_eq!(
+            sizes,
+            [
+                (width, height),
+                (*config::MEDIUM_SIZE, *config::MEDIUM_SIZE / 2),
+                (*config::THUMBNAIL_SIZE, *config::THUMBNAIL_SIZE / 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_small_images_and_transparency() {
+        let image = RgbaImage::from_pixel(10, 20, Rgba([0, 255, 0, 128]));
+
+        let processed = process(&write(image, ImageFormat::Png)).unwrap();
+
+        let thumbnail = variant(&processed, ImageVariant::Thumbnail);
+        assert_eq!(thumbnail.dimensions(), (10, 20));
+        assert!(thumbnail.color().has_alpha());
+    }
+
+    #[test]
+    fn rejects_oversized_dimensions() {
+        let image = RgbImage::new(*config::IMAGE_MAX_DIMENSION + 1, 1);
+
+        let result = process(&write(image, ImageFormat::Png));
+
+        assert!(matches!(
+            result,
+            Err(PipelineError::Decode(ImageError::Limits(..)))
+        ));
+    }
+
+    #[test]
+    fn rejects_oversized_files() {
+        let mut content = write(halves(2, 2), ImageFormat::Png);
+        content.resize(*config::IMAGE_MAX_SIZE as usize + 1, 0);
+
+        assert!(matches!(
+            process(&content),
+            Err(PipelineError::TooLarge(..))
+        ));
+        assert!(check_size(*config::IMAGE_MAX_SIZE as usize).is_ok());
+    }
+
+    #[test]
+    fn rejects_unsupported_formats() {
+        let gif = b"GIF89a\x01\x00\x01\x00\x00\x00\x00;";
+
+        assert!(matches!(
+            process(gif),
+            Err(PipelineError::UnsupportedFormat)
+        ));
+        assert!(matches!(
+            process(b"definitely not an image"),
+            Err(PipelineError::UnsupportedFormat)
+        ));
+    }
+
+    #[test]
+    fn rejects_corrupt_images() {
+        let mut content = write(halves(64, 64), ImageFormat::Png);
+        content.truncate(content.len() / 2);
+
+        assert!(matches!(process(&content), Err(PipelineError::Decode(..))));
+    }
+}
@@ -0,0 +1,45 @@
+use image::ImageError;
+use tokio::task::JoinError;
+use tonic::{Code, Status};
+
+use crate::pipeline::PipelineError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error("{0}")]
+    Service(#[from] Status),
+
+    #[error("{0}")]
+    Pipeline(#[from] PipelineError),
+
+    #[error("Failed to process the image: {0}")]
+    Processing(#[from] JoinError),
+
+    #[error("The first message must contain the image metadata")]
+    MissingMetadata,
+
+    #[error("Image file is empty")]
+    EmptyImage,
+}
+
+impl From<ServiceError> for Status {
+    fn from(error: ServiceError) -> Self {
+        if let ServiceError::Service(status) = error {
+            return status;
+        }
+
+        let code: Code = match error {
+            ServiceError::Service(..) => unreachable!(),
+            ServiceError::Pipeline(PipelineError::Encode(..)) => Code::Internal,
+            ServiceError::Pipeline(PipelineError::Decode(ImageError::IoError(..))) => {
+                Code::Internal
+            }
+            ServiceError::Pipeline(..) => Code::InvalidArgument,
+            ServiceError::Processing(..) => Code::Internal,
+            ServiceError::MissingMetadata => Code::InvalidArgument,
+            ServiceError::EmptyImage => Code::InvalidArgument,
+        };
+
+        Status::new(code, error.to_string())
+    }
+}
@@ -0,0 +1,33 @@
+use error::ServiceError;
+use protos::{
+    image::{image_client::ImageClient, UploadImageRequest},
+    image_worker::{image_worker_server::ImageWorker, ProcessImageResponse},
+};
+use service_helper::channel::ServiceChannel;
+use service_helper::response::ServiceResult;
+use tonic::{async_trait, Request, Response, Streaming};
+
+mod error;
+mod process_image;
+
+pub struct ImageWorkerService {
+    pub image_client: ImageClient<ServiceChannel>,
+}
+impl ImageWorkerService {
+    pub fn new(image_client: ImageClient<ServiceChannel>) -> Self {
+        Self { image_client }
+    }
+}
+
+#[async_trait]
+impl ImageWorker for ImageWorkerService {
+    async fn process_image(
+        &self,
+        request: Request<Streaming<UploadImageRequest>>,
+    ) -> ServiceResult<ProcessImageResponse> {
+        process_image::handle(request.into_inner(), &self.image_client)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+}
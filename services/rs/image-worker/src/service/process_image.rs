@@ -0,0 +1,88 @@
+use futures::stream;
+use protos::{
+    image::{
+        image_client::ImageClient, upload_image_request::Data, ImageVariant, Metadata,
+        UploadImageRequest,
+    },
+    image_worker::ProcessImageResponse,
+};
+use service_helper::channel::ServiceChannel;
+use tonic::Streaming;
+
+use crate::pipeline;
+
+use super::error::ServiceError;
+
+const WEBP_CONTENT_TYPE: &str = "image/webp";
+const CHUNK_SIZE: usize = 32 * 1024;
+
+pub async fn handle(
+    mut stream: Streaming<UploadImageRequest>,
+    image_client: &ImageClient<ServiceChannel>,
+) -> Result<ProcessImageResponse, ServiceError> {
+    let metadata = match stream.message().await? {
+        Some(UploadImageRequest {
+            data: Some(Data::Metadata(metadata)),
+        }) => metadata,
+        _ => return Err(ServiceError::MissingMetadata),
+    };
+
+    // decoding needs the whole file, so it is buffered here instead of in the gateway
+    let mut content = Vec::new();
+    while let Some(message) = stream.message().await? {
+        if let Some(Data::Content(chunk)) = message.data {
+            content.extend_from_slice(&chunk);
+            pipeline::check_size(content.len())?;
+        }
+    }
+
+    if content.is_empty() {
+        return Err(ServiceError::EmptyImage);
+    }
+
+    let processed = tokio::task::spawn_blocking(move || pipeline::process(&content)).await??;
+
+    // the original goes last: listings only look at originals,
+    // so an image shows up once all of its variants are stored
+    let mut variants = Vec::with_capacity(processed.variants.len());
+    for (variant, content) in processed.variants.into_iter().rev() {
+        upload(image_client, &metadata, variant, content).await?;
+        variants.push(variant.into());
+    }
+
+    Ok(ProcessImageResponse {
+        id: metadata.id,
+        width: processed.width,
+        height: processed.height,
+        variants,
+    })
+}
+
+async fn upload(
+    image_client: &ImageClient<ServiceChannel>,
+    metadata: &Metadata,
+    variant: ImageVariant,
+    content: Vec<u8>,
+) -> Result<(), ServiceError> {
+    let metadata = UploadImageRequest {
+        data: Some(Data::Metadata(Metadata {
+            id: metadata.id.clone(),
+            content_type: WEBP_CONTENT_TYPE.to_string(),
+            image_type: metadata.image_type,
+            variant: Some(variant.into()),
+        })),
+    };
+
+    let messages = std::iter::once(metadata).chain(content.chunks(CHUNK_SIZE).map(|chunk| {
+        UploadImageRequest {
+            data: Some(Data::Content(chunk.to_vec())),
+        }
+    }));
+
+    image_client
+        .clone()
+        .upload_image(stream::iter(messages.collect::<Vec<_>>()))
+        .await?;
+
+    Ok(())
+}
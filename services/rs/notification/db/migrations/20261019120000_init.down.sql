@@ -0,0 +1 @@
+DROP TABLE IF EXISTS waitlist;
\ No newline at end of file
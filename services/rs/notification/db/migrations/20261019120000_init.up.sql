@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS waitlist
+(
+    id              uuid        NOT NULL PRIMARY KEY,
+    client_id       uuid        NOT NULL,
+    seat_id         uuid,
+    seat_type       text,
+    seat_capacity   bigint,
+    starts_at       timestamptz NOT NULL,
+    ends_at         timestamptz NOT NULL,
+    status          text        NOT NULL,
+    offered_seat_id uuid,
+    hold_expires_at timestamptz,
+    created_at      timestamptz NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS waitlist_status_created_at_idx ON waitlist (status, created_at);
+CREATE INDEX IF NOT EXISTS waitlist_client_id_idx ON waitlist (client_id);
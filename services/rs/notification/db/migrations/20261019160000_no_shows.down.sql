@@ -0,0 +1 @@
+DROP TABLE IF EXISTS no_show_strikes;
\ No newline at end of file
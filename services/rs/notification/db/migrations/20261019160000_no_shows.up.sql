@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS no_show_strikes
+(
+    id             uuid        NOT NULL PRIMARY KEY,
+    client_id      uuid        NOT NULL,
+    reservation_id uuid        NOT NULL,
+    created_at     timestamptz NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS no_show_strikes_client_id_idx ON no_show_strikes (client_id, created_at);
+-- a reservation is struck at most once, even if the sweep runs into it again
+CREATE UNIQUE INDEX IF NOT EXISTS no_show_strikes_reservation_id_idx ON no_show_strikes (reservation_id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS announcement_recipients;
+DROP TABLE IF EXISTS announcement_attachments;
+DROP TABLE IF EXISTS announcements;
\ No newline at end of file
@@ -0,0 +1,39 @@
+CREATE TABLE IF NOT EXISTS announcements
+(
+    id            uuid        NOT NULL PRIMARY KEY,
+    subject       text        NOT NULL,
+    text          text        NOT NULL,
+    html          text,
+    reply_to      text,
+    audience      text        NOT NULL,
+    audience_date date,
+    status        text        NOT NULL,
+    created_at    timestamptz NOT NULL,
+    completed_at  timestamptz
+);
+
+CREATE INDEX IF NOT EXISTS announcements_status_created_at_idx ON announcements (status, created_at);
+
+CREATE TABLE IF NOT EXISTS announcement_attachments
+(
+    announcement_id uuid   NOT NULL REFERENCES announcements (id) ON DELETE CASCADE,
+    position        int    NOT NULL,
+    filename        text   NOT NULL,
+    content_type    text   NOT NULL,
+    content         bytea  NOT NULL,
+    PRIMARY KEY (announcement_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS announcement_recipients
+(
+    announcement_id uuid        NOT NULL REFERENCES announcements (id) ON DELETE CASCADE,
+    client_id       uuid        NOT NULL,
+    email           text        NOT NULL,
+    status          text        NOT NULL,
+    attempts        int         NOT NULL DEFAULT 0,
+    error           text,
+    sent_at         timestamptz,
+    PRIMARY KEY (announcement_id, client_id)
+);
+
+CREATE INDEX IF NOT EXISTS announcement_recipients_status_idx ON announcement_recipients (announcement_id, status);
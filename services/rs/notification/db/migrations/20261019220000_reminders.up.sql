@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS reminders
+(
+    reservation_id uuid        NOT NULL PRIMARY KEY,
+    client_id      uuid        NOT NULL,
+    send_at        timestamptz NOT NULL,
+    created_at     timestamptz NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS reminders_send_at_idx ON reminders (send_at);
@@ -0,0 +1,3 @@
+ALTER TABLE waitlist
+    DROP COLUMN IF EXISTS offered_starts_at,
+    DROP COLUMN IF EXISTS offered_ends_at;
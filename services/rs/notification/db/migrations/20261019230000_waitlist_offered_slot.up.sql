@@ -0,0 +1,9 @@
+-- the freed slot an offer was made from, passed on whole once the hold lapses
+ALTER TABLE waitlist
+    ADD COLUMN IF NOT EXISTS offered_starts_at timestamptz,
+    ADD COLUMN IF NOT EXISTS offered_ends_at   timestamptz;
+
+UPDATE waitlist
+SET offered_starts_at = starts_at,
+    offered_ends_at   = ends_at
+WHERE offered_seat_id IS NOT NULL;
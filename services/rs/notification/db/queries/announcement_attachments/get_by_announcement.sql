@@ -0,0 +1,4 @@
+SELECT *
+FROM announcement_attachments
+WHERE announcement_id = $1
+ORDER BY position
\ No newline at end of file
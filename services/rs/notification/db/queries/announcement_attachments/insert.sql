@@ -0,0 +1,2 @@
+INSERT INTO announcement_attachments (announcement_id, position, filename, content_type, content)
+VALUES ($1, $2, $3, $4, $5)
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT *
+FROM announcement_recipients
+WHERE announcement_id = $1 AND ($4::text IS NULL OR status = $4)
+ORDER BY client_id
+LIMIT $2 OFFSET $3
\ No newline at end of file
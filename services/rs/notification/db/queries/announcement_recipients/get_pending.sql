@@ -0,0 +1,5 @@
+SELECT *
+FROM announcement_recipients
+WHERE announcement_id = $1 AND status = 'pending'
+ORDER BY attempts, client_id
+LIMIT $2
\ No newline at end of file
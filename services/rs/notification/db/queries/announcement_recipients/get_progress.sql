@@ -0,0 +1,6 @@
+SELECT count(*)                                     AS "total!",
+       count(*) FILTER (WHERE status = 'pending') AS "pending!",
+       count(*) FILTER (WHERE status = 'sent')    AS "sent!",
+       count(*) FILTER (WHERE status = 'failed')  AS "failed!"
+FROM announcement_recipients
+WHERE announcement_id = $1
\ No newline at end of file
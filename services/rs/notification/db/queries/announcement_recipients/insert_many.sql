@@ -0,0 +1,4 @@
+INSERT INTO announcement_recipients (announcement_id, client_id, email, status)
+SELECT $1, client_id, email, 'pending'
+FROM UNNEST($2::uuid[], $3::text[]) AS recipients (client_id, email)
+ON CONFLICT (announcement_id, client_id) DO NOTHING
\ No newline at end of file
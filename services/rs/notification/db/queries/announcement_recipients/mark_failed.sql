@@ -0,0 +1,6 @@
+-- the recipient stays pending until it runs out of attempts
+UPDATE announcement_recipients
+SET status   = CASE WHEN attempts + 1 >= $4 THEN 'failed' ELSE 'pending' END,
+    attempts = attempts + 1,
+    error    = $3
+WHERE announcement_id = $1 AND client_id = $2
\ No newline at end of file
@@ -0,0 +1,6 @@
+UPDATE announcement_recipients
+SET status   = 'sent',
+    attempts = attempts + 1,
+    error    = NULL,
+    sent_at  = current_timestamp
+WHERE announcement_id = $1 AND client_id = $2
\ No newline at end of file
@@ -0,0 +1,4 @@
+SELECT *
+FROM announcements
+ORDER BY created_at DESC
+LIMIT $1 OFFSET $2
\ No newline at end of file
@@ -0,0 +1,3 @@
+SELECT *
+FROM announcements
+WHERE id = $1
\ No newline at end of file
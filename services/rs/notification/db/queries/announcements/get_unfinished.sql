@@ -0,0 +1,4 @@
+SELECT *
+FROM announcements
+WHERE status <> 'completed'
+ORDER BY created_at
\ No newline at end of file
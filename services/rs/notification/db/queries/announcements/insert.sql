@@ -0,0 +1,3 @@
+INSERT INTO announcements (id, subject, text, html, reply_to, audience, audience_date, status, created_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, current_timestamp)
+RETURNING *
\ No newline at end of file
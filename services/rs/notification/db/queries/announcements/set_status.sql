@@ -0,0 +1,4 @@
+UPDATE announcements
+SET status       = $2,
+    completed_at = CASE WHEN $2 = 'completed' THEN current_timestamp END
+WHERE id = $1
\ No newline at end of file
@@ -0,0 +1,4 @@
+SELECT COUNT(*) AS "count!"
+FROM no_show_strikes
+WHERE client_id = $1
+  AND created_at >= $2
@@ -0,0 +1,4 @@
+INSERT INTO no_show_strikes (id, client_id, reservation_id, created_at)
+VALUES ($1, $2, $3, current_timestamp)
+ON CONFLICT (reservation_id) DO NOTHING
+RETURNING *
@@ -0,0 +1,2 @@
+DELETE FROM reminders
+WHERE reservation_id = $1
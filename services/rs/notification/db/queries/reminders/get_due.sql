@@ -0,0 +1,5 @@
+SELECT *
+FROM reminders
+WHERE send_at <= current_timestamp
+ORDER BY send_at
+LIMIT $1
@@ -0,0 +1,6 @@
+INSERT INTO reminders (reservation_id, client_id, send_at, created_at)
+VALUES ($1, $2, $3, current_timestamp)
+ON CONFLICT (reservation_id) DO UPDATE
+SET client_id = excluded.client_id,
+    send_at   = excluded.send_at
+RETURNING *
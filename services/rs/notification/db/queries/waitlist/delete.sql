@@ -0,0 +1,3 @@
+DELETE FROM waitlist
+WHERE id = $1 AND client_id = $2 AND status IN ('waiting', 'offered')
+RETURNING *
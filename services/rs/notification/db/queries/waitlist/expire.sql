@@ -0,0 +1,4 @@
+UPDATE waitlist
+SET status = 'expired'
+WHERE status = 'offered' AND hold_expires_at <= current_timestamp
+RETURNING *
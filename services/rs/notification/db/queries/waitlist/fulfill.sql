@@ -0,0 +1,8 @@
+UPDATE waitlist
+SET status = 'fulfilled'
+WHERE status = 'offered'
+  AND client_id = $1
+  AND offered_seat_id = $2
+  AND offered_starts_at < $4
+  AND offered_ends_at > $3
+RETURNING *
@@ -0,0 +1,5 @@
+SELECT *
+FROM waitlist
+WHERE client_id = $1
+ORDER BY created_at DESC
+LIMIT $2 OFFSET $3
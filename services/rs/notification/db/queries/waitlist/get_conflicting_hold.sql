@@ -0,0 +1,10 @@
+SELECT *
+FROM waitlist
+WHERE status = 'offered'
+  AND hold_expires_at > current_timestamp
+  AND offered_seat_id = $2
+  AND client_id <> $1
+  AND offered_starts_at < $4
+  AND offered_ends_at > $3
+ORDER BY hold_expires_at
+LIMIT 1
@@ -0,0 +1,10 @@
+SELECT *
+FROM waitlist
+WHERE status = 'waiting'
+  AND starts_at > current_timestamp
+  AND (seat_id = $1 OR (seat_id IS NULL AND seat_type = $2 AND (seat_capacity IS NULL OR seat_capacity = $3)))
+  AND starts_at >= $4
+  AND ends_at <= $5
+ORDER BY created_at
+LIMIT 1
+FOR UPDATE SKIP LOCKED
@@ -0,0 +1,3 @@
+INSERT INTO waitlist (id, client_id, seat_id, seat_type, seat_capacity, starts_at, ends_at, status, created_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, current_timestamp)
+RETURNING *
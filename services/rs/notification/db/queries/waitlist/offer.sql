@@ -0,0 +1,8 @@
+UPDATE waitlist
+SET status            = 'offered',
+    offered_seat_id   = $2,
+    offered_starts_at = $3,
+    offered_ends_at   = $4,
+    hold_expires_at   = $5
+WHERE id = $1
+RETURNING *
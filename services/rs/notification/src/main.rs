@@ -1,49 +1,147 @@
-use env_logger::Env;
-use notification::{config, service::NotificationService};
+use notification::{
+    config,
+    service::{announcements, expire_holds, no_shows, reminders, NotificationService},
+};
 use protos::{
     client::client_client::ClientClient, coworking::coworking_client::CoworkingClient,
     mail::mail_client::MailClient, notification::notification_server::NotificationServer,
     reservation::reservation_client::ReservationClient,
 };
-use service_helper::make_url;
-use tonic::{codec::CompressionEncoding, transport::Server};
+use service_helper::{
+    channel::ServiceChannel,
+    health::HealthChecks,
+    metrics::{self, MetricsLayer},
+    principal::{self, Principal, PrincipalLayer},
+    shutdown::Shutdown,
+    telemetry::{self, TraceLayer},
+    tls::{self, CallerAllowlist},
+};
+use tonic::codec::CompressionEncoding;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let default_log_level = if cfg!(debug_assertions) {
-        "debug"
-    } else {
-        "info"
-    };
-    env_logger::init_from_env(Env::default().default_filter_or(default_log_level));
+    env_config::load(&[
+        config::SECTION,
+        service_helper::config::SECTION,
+        logging::config::SECTION,
+    ]);
+    let _telemetry = telemetry::init("notification");
     config::init();
 
-    let client_client = ClientClient::connect(make_url(&config::CLIENT_SERVICE_ADDRESS))
+    let shutdown = Shutdown::on_signal();
+
+    metrics::install_exporter(&config::METRICS_ADDRESS);
+
+    postgres_helper::migrate!(config::DATABASE_URL.as_str(), "./db/migrations");
+
+    let pool = postgres_helper::connect(config::DATABASE_URL.as_str())
         .await
-        .expect("Failed to connect to the client service")
+        .expect("Database connection failed");
+
+    let client_channel =
+        ServiceChannel::lazy("client", &config::CLIENT_SERVICE_ADDRESS).retryable(&[
+            "/client.Client/ValidateToken",
+            "/client.Client/Get",
+            "/client.Client/GetMultiple",
+            "/client.Client/GetAll",
+            "/client.Client/GetByCalendarToken",
+        ]);
+    let client_client = ClientClient::new(client_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    let coworking_client = CoworkingClient::connect(make_url(&config::COWORKING_SERVICE_ADDRESS))
-        .await
-        .expect("Failed to connect to the coworking service")
+    let coworking_channel = ServiceChannel::lazy("coworking", &config::COWORKING_SERVICE_ADDRESS)
+        .retryable(&[
+            "/coworking.Coworking/GetByID",
+            "/coworking.Coworking/GetSeat",
+            "/coworking.Coworking/GetSeats",
+        ]);
+    let coworking_client = CoworkingClient::new(coworking_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
-    let reservation_client =
-        ReservationClient::connect(make_url(&config::RESERVATION_SERVICE_ADDRESS))
-            .await
-            .expect("Failed to connect to the reservation service")
-            .send_compressed(CompressionEncoding::Zstd)
-            .accept_compressed(CompressionEncoding::Zstd);
+    let reservation_channel =
+        ServiceChannel::lazy("reservation", &config::RESERVATION_SERVICE_ADDRESS).retryable(&[
+            "/reservation.Reservation/GetByID",
+            "/reservation.Reservation/GetBySeat",
+            "/reservation.Reservation/GetByClient",
+            "/reservation.Reservation/Get",
+            "/reservation.Reservation/GetVisited",
+            "/reservation.Reservation/GetNoShows",
+        ]);
+    let reservation_client = ReservationClient::new(reservation_channel.clone())
+        .send_compressed(CompressionEncoding::Zstd)
+        .accept_compressed(CompressionEncoding::Zstd);
 
-    let mail_client = MailClient::connect(make_url(&config::MAIL_SERVICE_ADDRESS))
-        .await
-        .expect("Failed to connect to the mail service")
+    let mail_channel = ServiceChannel::lazy("mail", &config::MAIL_SERVICE_ADDRESS);
+    let mail_client = MailClient::new(mail_channel.clone())
         .send_compressed(CompressionEncoding::Zstd)
         .accept_compressed(CompressionEncoding::Zstd);
 
+    // the workers call the other services on their own behalf
+    shutdown.spawn_worker(principal::scope(
+        Principal::service("notification"),
+        expire_holds::run(
+            pool.clone(),
+            client_client.clone(),
+            coworking_client.clone(),
+            mail_client.clone(),
+            shutdown.clone(),
+        ),
+    ));
+
+    shutdown.spawn_worker(principal::scope(
+        Principal::service("notification"),
+        no_shows::run(
+            pool.clone(),
+            client_client.clone(),
+            coworking_client.clone(),
+            reservation_client.clone(),
+            mail_client.clone(),
+            shutdown.clone(),
+        ),
+    ));
+
+    shutdown.spawn_worker(principal::scope(
+        Principal::service("notification"),
+        announcements::run(
+            pool.clone(),
+            client_client.clone(),
+            reservation_client.clone(),
+            mail_client.clone(),
+            shutdown.clone(),
+        ),
+    ));
+
+    shutdown.spawn_worker(principal::scope(
+        Principal::service("notification"),
+        reminders::run(
+            pool.clone(),
+            client_client.clone(),
+            coworking_client.clone(),
+            reservation_client.clone(),
+            mail_client.clone(),
+            shutdown.clone(),
+        ),
+    ));
+
+    let health_checks = HealthChecks::new()
+        .check("postgres", {
+            let pool = pool.clone();
+            move || postgres_helper::ping(pool.clone())
+        })
+        .service("client", client_channel)
+        .service("coworking", coworking_channel)
+        .service("reservation", reservation_channel)
+        .service("mail", mail_channel);
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    shutdown.spawn_worker(
+        health_checks
+            .run::<NotificationServer<NotificationService>>(health_reporter, shutdown.clone()),
+    );
+
     let service = NotificationServer::new(NotificationService::new(
+        pool.clone(),
         client_client,
         coworking_client,
         reservation_client,
@@ -52,15 +150,54 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .send_compressed(CompressionEncoding::Zstd)
     .accept_compressed(CompressionEncoding::Zstd);
 
-    log::info!(
+    // off by default, the schema shouldn't be discoverable in production
+    let reflection_service = config::GRPC_REFLECTION
+        .then(|| {
+            tonic_reflection::server::Builder::configure()
+                .register_encoded_file_descriptor_set(protos::FILE_DESCRIPTOR_SET)
+                .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+                .with_service_name(protos::notification::notification_server::SERVICE_NAME)
+                .with_service_name(tonic_health::pb::health_server::SERVICE_NAME)
+                .build_v1()
+        })
+        .transpose()?;
+
+    tracing::info!(
         "Started notification service on {}",
         config::SERVICE_ADDRESS.as_str()
     );
 
-    Server::builder()
+    const GATEWAY: &[&str] = &["gateway"];
+    let callers = CallerAllowlist::new()
+        .allow("/notification.Notification/Schedule", GATEWAY)
+        .allow("/notification.Notification/JoinWaitlist", GATEWAY)
+        .allow("/notification.Notification/GetWaitlist", GATEWAY)
+        .allow("/notification.Notification/LeaveWaitlist", GATEWAY)
+        .allow("/notification.Notification/CheckHold", GATEWAY)
+        .allow("/notification.Notification/FulfillHold", GATEWAY)
+        .allow("/notification.Notification/ReleaseSlot", GATEWAY)
+        .allow("/notification.Notification/CheckStrikes", GATEWAY)
+        .allow("/notification.Notification/CreateAnnouncement", GATEWAY)
+        .allow("/notification.Notification/GetAnnouncement", GATEWAY)
+        .allow("/notification.Notification/GetAnnouncements", GATEWAY)
+        .allow(
+            "/notification.Notification/GetAnnouncementRecipients",
+            GATEWAY,
+        );
+
+    let server = tls::server()?
+        .layer(MetricsLayer)
+        .layer(TraceLayer)
+        .layer(callers)
+        .layer(PrincipalLayer)
+        .add_service(health_service)
+        .add_optional_service(reflection_service)
         .add_service(service)
-        .serve(config::SERVICE_ADDRESS.parse()?)
-        .await?;
+        .serve_with_shutdown(config::SERVICE_ADDRESS.parse()?, shutdown.requested());
+    shutdown.drain(server).await?;
+
+    pool.close().await;
+    tracing::info!("Stopped notification service");
 
     Ok(())
 }
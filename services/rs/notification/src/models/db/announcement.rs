@@ -0,0 +1,282 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use convertions::datetime_into_timestamp;
+use protos::notification::{AnnouncementRecipientResponse, AnnouncementResponse};
+use sqlx::{query_file, query_file_as, Error, Executor, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DBAnnouncement {
+    pub id: Uuid,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+    pub reply_to: Option<String>,
+    pub audience: String,
+    pub audience_date: Option<NaiveDate>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+impl DBAnnouncement {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/announcements/insert.sql",
+            self.id,
+            self.subject,
+            self.text,
+            self.html,
+            self.reply_to,
+            self.audience,
+            self.audience_date,
+            self.status
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_by_id<'a, E>(id: Uuid, executor: E) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/announcements/get_by_id.sql", id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub async fn get_all<'a, E>(limit: i64, offset: i64, executor: E) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/announcements/get_all.sql", limit, offset)
+            .fetch_all(executor)
+            .await
+    }
+
+    /// Announcements which still have recipients to collect or to send to, oldest first.
+    pub async fn get_unfinished<'a, E>(executor: E) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/announcements/get_unfinished.sql")
+            .fetch_all(executor)
+            .await
+    }
+
+    pub async fn set_status<'a, E>(id: Uuid, status: &str, executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!("db/queries/announcements/set_status.sql", id, status)
+            .execute(executor)
+            .await
+            .map(|_| ())
+    }
+
+    pub fn into_proto(self, progress: DBAnnouncementProgress) -> AnnouncementResponse {
+        AnnouncementResponse {
+            id: self.id.to_string(),
+            subject: self.subject,
+            audience: self.audience,
+            day: self.audience_date.map(|date| date.day()),
+            month: self.audience_date.map(|date| date.month()),
+            year: self.audience_date.map(|date| date.year() as u64),
+            status: self.status,
+            created_at: datetime_into_timestamp(self.created_at),
+            completed_at: self.completed_at.map(datetime_into_timestamp),
+            total: progress.total as u64,
+            pending: progress.pending as u64,
+            sent: progress.sent as u64,
+            failed: progress.failed as u64,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DBAnnouncementAttachment {
+    pub announcement_id: Uuid,
+    pub position: i32,
+    pub filename: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+impl DBAnnouncementAttachment {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!(
+            "db/queries/announcement_attachments/insert.sql",
+            self.announcement_id,
+            self.position,
+            self.filename,
+            self.content_type,
+            self.content
+        )
+        .execute(executor)
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn get_by_announcement<'a, E>(
+        announcement_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/announcement_attachments/get_by_announcement.sql",
+            announcement_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DBAnnouncementRecipient {
+    pub announcement_id: Uuid,
+    pub client_id: Uuid,
+    pub email: String,
+    pub status: String,
+    pub attempts: i32,
+    pub error: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+impl DBAnnouncementRecipient {
+    /// Adds the recipients as pending, the ones already added are left as is.
+    pub async fn insert_many<'a, E>(
+        announcement_id: Uuid,
+        client_ids: &[Uuid],
+        emails: &[String],
+        executor: E,
+    ) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!(
+            "db/queries/announcement_recipients/insert_many.sql",
+            announcement_id,
+            client_ids,
+            emails
+        )
+        .execute(executor)
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn get_pending<'a, E>(
+        announcement_id: Uuid,
+        limit: i64,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/announcement_recipients/get_pending.sql",
+            announcement_id,
+            limit
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_by_announcement<'a, E>(
+        announcement_id: Uuid,
+        limit: i64,
+        offset: i64,
+        status: Option<String>,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/announcement_recipients/get_by_announcement.sql",
+            announcement_id,
+            limit,
+            offset,
+            status
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn mark_sent<'a, E>(&self, executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!(
+            "db/queries/announcement_recipients/mark_sent.sql",
+            self.announcement_id,
+            self.client_id
+        )
+        .execute(executor)
+        .await
+        .map(|_| ())
+    }
+
+    /// Records a failed attempt, the recipient fails for good after `max_attempts`.
+    pub async fn mark_failed<'a, E>(
+        &self,
+        error: &str,
+        max_attempts: i32,
+        executor: E,
+    ) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!(
+            "db/queries/announcement_recipients/mark_failed.sql",
+            self.announcement_id,
+            self.client_id,
+            error,
+            max_attempts
+        )
+        .execute(executor)
+        .await
+        .map(|_| ())
+    }
+}
+impl From<DBAnnouncementRecipient> for AnnouncementRecipientResponse {
+    fn from(value: DBAnnouncementRecipient) -> Self {
+        Self {
+            client_id: value.client_id.to_string(),
+            email: value.email,
+            status: value.status,
+            attempts: value.attempts as u32,
+            error: value.error,
+            sent_at: value.sent_at.map(datetime_into_timestamp),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DBAnnouncementProgress {
+    pub total: i64,
+    pub pending: i64,
+    pub sent: i64,
+    pub failed: i64,
+}
+impl DBAnnouncementProgress {
+    pub async fn get<'a, E>(announcement_id: Uuid, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/announcement_recipients/get_progress.sql",
+            announcement_id
+        )
+        .fetch_one(executor)
+        .await
+    }
+}
@@ -0,0 +1,11 @@
+mod announcement;
+mod no_show_strike;
+mod reminder;
+mod waitlist;
+
+pub use announcement::{
+    DBAnnouncement, DBAnnouncementAttachment, DBAnnouncementProgress, DBAnnouncementRecipient,
+};
+pub use no_show_strike::DBNoShowStrike;
+pub use reminder::DBReminder;
+pub use waitlist::DBWaitlistEntry;
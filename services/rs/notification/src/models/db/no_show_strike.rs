@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use sqlx::{query_file_as, query_file_scalar, Error, Executor, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DBNoShowStrike {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub reservation_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+impl DBNoShowStrike {
+    /// Records a strike, `None` means the reservation was already struck.
+    pub async fn insert<'a, E>(
+        client_id: Uuid,
+        reservation_id: Uuid,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/no_show_strikes/insert.sql",
+            Uuid::now_v7(),
+            client_id,
+            reservation_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn count_since<'a, E>(
+        client_id: Uuid,
+        since: DateTime<Utc>,
+        executor: E,
+    ) -> Result<i64, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_scalar!(
+            "db/queries/no_show_strikes/count_since.sql",
+            client_id,
+            since
+        )
+        .fetch_one(executor)
+        .await
+    }
+}
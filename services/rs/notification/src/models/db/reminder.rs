@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use sqlx::{query_file, query_file_as, query_file_scalar, Error, Executor, Postgres};
+use uuid::Uuid;
+
+/// Reminder about an upcoming reservation, kept until it's sent
+/// so the pending ones survive restarts.
+#[derive(Clone, Debug)]
+pub struct DBReminder {
+    pub reservation_id: Uuid,
+    pub client_id: Uuid,
+    pub send_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+impl DBReminder {
+    /// Rescheduling a reservation replaces its reminder.
+    pub async fn upsert<'a, E>(
+        reservation_id: Uuid,
+        client_id: Uuid,
+        send_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/reminders/upsert.sql",
+            reservation_id,
+            client_id,
+            send_at
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_due<'a, E>(limit: i64, executor: E) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/reminders/get_due.sql", limit)
+            .fetch_all(executor)
+            .await
+    }
+
+    pub async fn count<'a, E>(executor: E) -> Result<i64, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_scalar!("db/queries/reminders/count.sql")
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn delete<'a, E>(&self, executor: E) -> Result<(), Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file!("db/queries/reminders/delete.sql", self.reservation_id)
+            .execute(executor)
+            .await
+            .map(|_| ())
+    }
+}
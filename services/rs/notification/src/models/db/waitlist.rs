@@ -0,0 +1,378 @@
+use chrono::{DateTime, Utc};
+use convertions::datetime_into_timestamp;
+use protos::notification::WaitlistEntryResponse;
+use sqlx::{query_file_as, Error, Executor, Postgres, Transaction};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct DBWaitlistEntry {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub seat_id: Option<Uuid>,
+    pub seat_type: Option<String>,
+    pub seat_capacity: Option<i64>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub status: String,
+    pub offered_seat_id: Option<Uuid>,
+    pub offered_starts_at: Option<DateTime<Utc>>,
+    pub offered_ends_at: Option<DateTime<Utc>>,
+    pub hold_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+impl DBWaitlistEntry {
+    pub async fn insert<'a, E>(self, executor: E) -> Result<Self, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/waitlist/insert.sql",
+            self.id,
+            self.client_id,
+            self.seat_id,
+            self.seat_type,
+            self.seat_capacity,
+            self.starts_at,
+            self.ends_at,
+            self.status
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_by_client<'a, E>(
+        client_id: Uuid,
+        limit: i64,
+        offset: i64,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/waitlist/get_by_client.sql",
+            client_id,
+            limit,
+            offset
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn delete<'a, E>(
+        id: Uuid,
+        client_id: Uuid,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/waitlist/delete.sql", id, client_id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    /// The client first in line for the freed slot, locked until the transaction ends.
+    pub async fn get_next_candidate(
+        seat_id: Uuid,
+        seat_type: &str,
+        seat_capacity: i64,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<Option<Self>, Error> {
+        query_file_as!(
+            Self,
+            "db/queries/waitlist/get_next_candidate.sql",
+            seat_id,
+            seat_type,
+            seat_capacity,
+            starts_at,
+            ends_at
+        )
+        .fetch_optional(&mut **transaction)
+        .await
+    }
+
+    pub async fn offer(
+        id: Uuid,
+        seat_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        hold_expires_at: DateTime<Utc>,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<Self, Error> {
+        query_file_as!(
+            Self,
+            "db/queries/waitlist/offer.sql",
+            id,
+            seat_id,
+            starts_at,
+            ends_at,
+            hold_expires_at
+        )
+        .fetch_one(&mut **transaction)
+        .await
+    }
+
+    pub async fn get_conflicting_hold<'a, E>(
+        client_id: Uuid,
+        seat_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Option<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/waitlist/get_conflicting_hold.sql",
+            client_id,
+            seat_id,
+            starts_at,
+            ends_at
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn fulfill<'a, E>(
+        client_id: Uuid,
+        seat_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        executor: E,
+    ) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(
+            Self,
+            "db/queries/waitlist/fulfill.sql",
+            client_id,
+            seat_id,
+            starts_at,
+            ends_at
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn expire<'a, E>(executor: E) -> Result<Vec<Self>, Error>
+    where
+        E: Executor<'a, Database = Postgres>,
+    {
+        query_file_as!(Self, "db/queries/waitlist/expire.sql")
+            .fetch_all(executor)
+            .await
+    }
+
+    /// The seat and the times of the freed slot the entry was offered, if it was.
+    pub fn offered_slot(&self) -> Option<(Uuid, DateTime<Utc>, DateTime<Utc>)> {
+        Some((
+            self.offered_seat_id?,
+            self.offered_starts_at?,
+            self.offered_ends_at?,
+        ))
+    }
+}
+impl From<DBWaitlistEntry> for WaitlistEntryResponse {
+    fn from(value: DBWaitlistEntry) -> Self {
+        Self {
+            id: value.id.to_string(),
+            client_id: value.client_id.to_string(),
+            seat_id: value.seat_id.map(|id| id.to_string()),
+            seat_type: value.seat_type,
+            seat_capacity: value.seat_capacity.map(|capacity| capacity as u64),
+            starts_at: datetime_into_timestamp(value.starts_at),
+            ends_at: datetime_into_timestamp(value.ends_at),
+            status: value.status,
+            offered_seat_id: value.offered_seat_id.map(|id| id.to_string()),
+            hold_expires_at: value.hold_expires_at.map(datetime_into_timestamp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DurationRound, TimeDelta};
+    use sqlx::PgPool;
+
+    use super::*;
+
+    struct Slot {
+        seat_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    }
+
+    fn slot() -> Slot {
+        let starts_at = (Utc::now() + TimeDelta::days(1))
+            .duration_trunc(TimeDelta::seconds(1))
+            .unwrap();
+        Slot {
+            seat_id: Uuid::now_v7(),
+            starts_at,
+            ends_at: starts_at + TimeDelta::hours(2),
+        }
+    }
+
+    async fn join(slot: &Slot, pool: &PgPool) -> DBWaitlistEntry {
+        DBWaitlistEntry {
+            id: Uuid::now_v7(),
+            client_id: Uuid::now_v7(),
+            seat_id: Some(slot.seat_id),
+            seat_type: None,
+            seat_capacity: None,
+            starts_at: slot.starts_at,
+            ends_at: slot.ends_at,
+            status: "waiting".to_string(),
+            offered_seat_id: None,
+            offered_starts_at: None,
+            offered_ends_at: None,
+            hold_expires_at: None,
+            created_at: Utc::now(),
+        }
+        .insert(pool)
+        .await
+        .unwrap()
+    }
+
+    async fn offer_next(
+        slot: &Slot,
+        hold_expires_at: DateTime<Utc>,
+        pool: &PgPool,
+    ) -> Option<DBWaitlistEntry> {
+        let mut transaction = pool.begin().await.unwrap();
+        let candidate = DBWaitlistEntry::get_next_candidate(
+            slot.seat_id,
+            "desk",
+            1,
+            slot.starts_at,
+            slot.ends_at,
+            &mut transaction,
+        )
+        .await
+        .unwrap()?;
+
+        let offer = DBWaitlistEntry::offer(
+            candidate.id,
+            slot.seat_id,
+            slot.starts_at,
+            slot.ends_at,
+            hold_expires_at,
+            &mut transaction,
+        )
+        .await
+        .unwrap();
+        transaction.commit().await.unwrap();
+
+        Some(offer)
+    }
+
+    async fn status(id: Uuid, pool: &PgPool) -> String {
+        sqlx::query_scalar("SELECT status FROM waitlist WHERE id = $1")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "./db/migrations")]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn offers_freed_slot_to_head_of_queue_only(pool: PgPool) {
+        let slot = slot();
+        let first = join(&slot, &pool).await;
+        let second = join(&slot, &pool).await;
+
+        let offer = offer_next(&slot, Utc::now() + TimeDelta::minutes(15), &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(offer.id, first.id);
+        assert_eq!(offer.status, "offered");
+        assert_eq!(
+            offer.offered_slot(),
+            Some((slot.seat_id, slot.starts_at, slot.ends_at))
+        );
+        assert_eq!(status(second.id, &pool).await, "waiting");
+
+        // the held slot can't be taken by the others, while the client it's held for can book it
+        let hold = DBWaitlistEntry::get_conflicting_hold(
+            second.client_id,
+            slot.seat_id,
+            slot.starts_at,
+            slot.ends_at,
+            &pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(hold.map(|hold| hold.id), Some(first.id));
+
+        let hold = DBWaitlistEntry::get_conflicting_hold(
+            first.client_id,
+            slot.seat_id,
+            slot.starts_at,
+            slot.ends_at,
+            &pool,
+        )
+        .await
+        .unwrap();
+        assert!(hold.is_none());
+    }
+
+    #[sqlx::test(migrations = "./db/migrations")]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn passes_lapsed_hold_to_next_in_line(pool: PgPool) {
+        let slot = slot();
+        let first = join(&slot, &pool).await;
+        let second = join(&slot, &pool).await;
+
+        offer_next(&slot, Utc::now() - TimeDelta::seconds(1), &pool)
+            .await
+            .unwrap();
+
+        let expired = DBWaitlistEntry::expire(&pool).await.unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, first.id);
+        assert_eq!(
+            expired[0].offered_slot(),
+            Some((slot.seat_id, slot.starts_at, slot.ends_at))
+        );
+
+        let offer = offer_next(&slot, Utc::now() + TimeDelta::minutes(15), &pool)
+            .await
+            .unwrap();
+        assert_eq!(offer.id, second.id);
+
+        // nobody is left waiting for the slot
+        assert!(offer_next(&slot, Utc::now(), &pool).await.is_none());
+    }
+
+    #[sqlx::test(migrations = "./db/migrations")]
+    #[ignore = "needs Postgres at DATABASE_URL"]
+    async fn fulfills_hold_once_booked(pool: PgPool) {
+        let slot = slot();
+        let entry = join(&slot, &pool).await;
+        offer_next(&slot, Utc::now() + TimeDelta::minutes(15), &pool)
+            .await
+            .unwrap();
+
+        let fulfilled = DBWaitlistEntry::fulfill(
+            entry.client_id,
+            slot.seat_id,
+            slot.starts_at,
+            slot.ends_at,
+            &pool,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(fulfilled.len(), 1);
+        assert_eq!(fulfilled[0].status, "fulfilled");
+        assert!(DBWaitlistEntry::expire(&pool).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+use std::{collections::HashSet, time::Duration};
+
+use chrono::Datelike;
+use futures::future::join_all;
+use protos::{
+    client::{client_client::ClientClient, ClientResponse, ClientsRequest, ListClientsRequest},
+    mail::{mail_client::MailClient, Attachment, Header, SendRequest},
+    reservation::{reservation_client::ReservationClient, GetRequest},
+};
+use service_helper::{channel::ServiceChannel, shutdown::Shutdown};
+use sqlx::PgPool;
+use tonic::Code;
+use uuid::Uuid;
+
+use crate::{
+    config,
+    models::db::{DBAnnouncement, DBAnnouncementAttachment, DBAnnouncementRecipient},
+};
+
+use super::error::ServiceError;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const CLIENTS_PAGE_SIZE: u32 = 100;
+
+/// Fans the created announcements out: collects the recipients from the audience
+/// and mails them in batches, retrying failed deliveries.
+/// Progress lives in the database, so unfinished announcements resume after a restart,
+/// which lets the shutdown interrupt them between the batches.
+pub async fn run(
+    pool: PgPool,
+    client_client: ClientClient<ServiceChannel>,
+    reservation_client: ReservationClient<ServiceChannel>,
+    mail_client: MailClient<ServiceChannel>,
+    shutdown: Shutdown,
+) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.requested() => break,
+        }
+
+        let announcements = match DBAnnouncement::get_unfinished(&pool).await {
+            Ok(announcements) => announcements,
+            Err(err) => {
+                tracing::error!("Failed to fetch unfinished announcements: {err}");
+                continue;
+            }
+        };
+
+        for announcement in announcements {
+            if shutdown.is_requested() {
+                break;
+            }
+
+            let id = announcement.id;
+            if let Err(err) = send(
+                announcement,
+                &pool,
+                &client_client,
+                &reservation_client,
+                &mail_client,
+                &shutdown,
+            )
+            .await
+            {
+                tracing::error!("Failed to send announcement {id}: {err}");
+            }
+        }
+    }
+}
+
+async fn send(
+    announcement: DBAnnouncement,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+    shutdown: &Shutdown,
+) -> Result<(), ServiceError> {
+    if announcement.status == "collecting" {
+        collect_recipients(&announcement, pool, client_client, reservation_client).await?;
+        DBAnnouncement::set_status(announcement.id, "sending", pool).await?;
+    }
+
+    let attachments: Vec<Attachment> =
+        DBAnnouncementAttachment::get_by_announcement(announcement.id, pool)
+            .await?
+            .into_iter()
+            .map(|attachment| Attachment {
+                filename: attachment.filename,
+                content_type: attachment.content_type,
+                content: attachment.content,
+            })
+            .collect();
+
+    loop {
+        let batch = DBAnnouncementRecipient::get_pending(
+            announcement.id,
+            *config::ANNOUNCEMENT_BATCH_SIZE,
+            pool,
+        )
+        .await?;
+        if batch.is_empty() {
+            break;
+        }
+
+        let results = join_all(batch.iter().map(|recipient| {
+            let mut mail_client = mail_client.clone();
+            let request = SendRequest {
+                to: vec![recipient.email.clone()],
+                subject: Some(announcement.subject.clone()),
+                body: Some(announcement.text.clone()),
+                attachments: attachments.clone(),
+                html: announcement.html.clone(),
+                reply_to: announcement.reply_to.clone(),
+                headers: vec![Header {
+                    name: "Precedence".to_string(),
+                    value: "bulk".to_string(),
+                }],
+                ..Default::default()
+            };
+            async move { mail_client.send(request).await }
+        }))
+        .await;
+
+        for (recipient, result) in batch.iter().zip(results) {
+            metrics::counter!(
+                "notification_emails_total",
+                "kind" => "announcement",
+                "result" => if result.is_ok() { "success" } else { "error" }
+            )
+            .increment(1);
+
+            match result {
+                Ok(..) => recipient.mark_sent(pool).await?,
+                // a rejected message won't be accepted on retry either
+                Err(status) if status.code() == Code::InvalidArgument => {
+                    recipient.mark_failed(status.message(), 0, pool).await?
+                }
+                Err(status) => {
+                    recipient
+                        .mark_failed(status.message(), *config::ANNOUNCEMENT_MAX_ATTEMPTS, pool)
+                        .await?
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_millis(*config::ANNOUNCEMENT_BATCH_DELAY_MS)) => {}
+            _ = shutdown.requested() => return Ok(()),
+        }
+    }
+
+    DBAnnouncement::set_status(announcement.id, "completed", pool).await?;
+    tracing::info!("Announcement {} was sent", announcement.id);
+
+    Ok(())
+}
+
+async fn collect_recipients(
+    announcement: &DBAnnouncement,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    if let ("reserved_on", Some(date)) =
+        (announcement.audience.as_str(), announcement.audience_date)
+    {
+        let client_ids: Vec<String> = reservation_client
+            .clone()
+            .get(GetRequest {
+                limit: 0,
+                offset: 0,
+                day: Some(date.day()),
+                month: Some(date.month()),
+                year: Some(date.year() as u64),
+            })
+            .await?
+            .into_inner()
+            .reservations
+            .into_iter()
+            .filter(|reservation| !reservation.is_canceled)
+            .map(|reservation| reservation.client_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        for ids in client_ids.chunks(CLIENTS_PAGE_SIZE as usize) {
+            let clients = client_client
+                .clone()
+                .get_multiple(ClientsRequest {
+                    limit: CLIENTS_PAGE_SIZE,
+                    offset: 0,
+                    day: None,
+                    month: None,
+                    year: None,
+                    ids: ids.to_vec(),
+                })
+                .await?
+                .into_inner()
+                .clients;
+            add_recipients(announcement.id, clients, pool).await?;
+        }
+
+        return Ok(());
+    }
+
+    let send_notifications = (announcement.audience == "opted_in").then_some(true);
+    let mut offset = 0;
+    loop {
+        let clients = client_client
+            .clone()
+            .get_all(ListClientsRequest {
+                limit: CLIENTS_PAGE_SIZE,
+                offset,
+                send_notifications,
+            })
+            .await?
+            .into_inner()
+            .clients;
+        let fetched = clients.len();
+
+        add_recipients(announcement.id, clients, pool).await?;
+
+        if fetched < CLIENTS_PAGE_SIZE as usize {
+            break;
+        }
+        offset += CLIENTS_PAGE_SIZE as u64;
+    }
+
+    Ok(())
+}
+
+async fn add_recipients(
+    announcement_id: Uuid,
+    clients: Vec<ClientResponse>,
+    pool: &PgPool,
+) -> Result<(), ServiceError> {
+    let (client_ids, emails): (Vec<Uuid>, Vec<String>) = clients
+        .into_iter()
+        .map(|client| (Uuid::parse_str(&client.id).unwrap(), client.meta.email))
+        .unzip();
+
+    DBAnnouncementRecipient::insert_many(announcement_id, &client_ids, &emails, pool).await?;
+
+    Ok(())
+}
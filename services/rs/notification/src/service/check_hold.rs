@@ -0,0 +1,33 @@
+use convertions::timestamp_into_datetime;
+use protos::notification::HoldRequest;
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBWaitlistEntry;
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: HoldRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<(), ServiceError> {
+    if !principal.can_access(&req.client_id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let hold = DBWaitlistEntry::get_conflicting_hold(
+        Uuid::parse_str(&req.client_id).map_err(|_| ServiceError::InvalidId("client_id"))?,
+        Uuid::parse_str(&req.seat_id).map_err(|_| ServiceError::InvalidId("seat_id"))?,
+        timestamp_into_datetime(req.starts_at).ok_or(ServiceError::InvalidTime("starts_at"))?,
+        timestamp_into_datetime(req.ends_at).ok_or(ServiceError::InvalidTime("ends_at"))?,
+        pool,
+    )
+    .await?;
+
+    match hold.and_then(|hold| hold.hold_expires_at) {
+        Some(hold_expires_at) => Err(ServiceError::SeatHeld(hold_expires_at)),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{TimeDelta, Utc};
+use protos::notification::CheckStrikesRequest;
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{config, models::db::DBNoShowStrike};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: CheckStrikesRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<(), ServiceError> {
+    if !principal.can_access(&req.client_id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    if *config::NO_SHOW_STRIKE_THRESHOLD <= 0 {
+        return Ok(());
+    }
+
+    let since = Utc::now() - TimeDelta::days(*config::NO_SHOW_STRIKE_WINDOW_DAYS);
+    let strikes =
+        DBNoShowStrike::count_since(Uuid::parse_str(&req.client_id).unwrap(), since, pool).await?;
+
+    if strikes >= *config::NO_SHOW_STRIKE_THRESHOLD {
+        return Err(ServiceError::BookingBlocked(strikes));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use chrono::{NaiveDate, Utc};
+use protos::notification::{AnnouncementResponse, CreateAnnouncementRequest};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBAnnouncement, DBAnnouncementAttachment, DBAnnouncementProgress};
+
+use super::error::ServiceError;
+
+/// Stores the announcement, the recipients are collected and mailed by the `announcements` task.
+pub async fn handle(
+    req: CreateAnnouncementRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<AnnouncementResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let audience_date = match (req.audience.as_str(), req.day, req.month, req.year) {
+        ("all" | "opted_in", None, None, None) => None,
+        ("reserved_on", Some(day), Some(month), Some(year)) => Some(
+            NaiveDate::from_ymd_opt(year as i32, month, day)
+                .ok_or(ServiceError::InvalidAudience(req.audience.clone()))?,
+        ),
+        _ => return Err(ServiceError::InvalidAudience(req.audience)),
+    };
+
+    let mut transaction = pool.begin().await?;
+
+    let announcement = DBAnnouncement {
+        id: Uuid::now_v7(),
+        subject: req.subject,
+        text: req.text,
+        html: req.html,
+        reply_to: req.reply_to,
+        audience: req.audience,
+        audience_date,
+        status: "collecting".to_string(),
+        created_at: Utc::now(),
+        completed_at: None,
+    }
+    .insert(&mut *transaction)
+    .await?;
+
+    for (position, attachment) in req.attachments.into_iter().enumerate() {
+        DBAnnouncementAttachment {
+            announcement_id: announcement.id,
+            position: position as i32,
+            filename: attachment.filename,
+            content_type: attachment.content_type,
+            content: attachment.content,
+        }
+        .insert(&mut *transaction)
+        .await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(announcement.into_proto(DBAnnouncementProgress::default()))
+}
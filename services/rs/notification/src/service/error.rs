@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use tokio::task::JoinError;
 use tonic::{Code, Status};
 
@@ -6,21 +7,65 @@ pub enum ServiceError {
     #[error("{0}")]
     Service(#[from] Status),
 
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
     #[error("Failed to schedule task: {0}")]
     Schedule(#[from] JoinError),
+
+    #[error("Either `seat_id` or `seat_type` must be specified")]
+    InvalidWaitlistEntry,
+
+    #[error("`{0}` isn't a valid id")]
+    InvalidId(&'static str),
+
+    #[error("`{0}` is out of range")]
+    InvalidTime(&'static str),
+
+    #[error("Waitlist entry was not found")]
+    NotFound,
+
+    #[error("The call isn't made on behalf of anyone")]
+    Unauthenticated,
+
+    #[error("You're not allowed to access this client's data")]
+    NotOwner,
+
+    #[error("Seat is held for a waitlisted client until {0}")]
+    SeatHeld(DateTime<Utc>),
+
+    #[error("Booking is blocked after {0} no-shows")]
+    BookingBlocked(i64),
+
+    #[error("Invalid announcement audience: {0}")]
+    InvalidAudience(String),
+
+    #[error("Announcement was not found")]
+    AnnouncementNotFound,
 }
 
-impl Into<Status> for ServiceError {
-    fn into(self) -> Status {
-        if let Self::Service(status) = self {
+impl From<ServiceError> for Status {
+    fn from(error: ServiceError) -> Self {
+        if let ServiceError::Service(status) = error {
             return status;
         }
 
-        let code: Code = match self {
-            Self::Service(..) => unreachable!(),
-            Self::Schedule(..) => Code::Internal,
+        let code: Code = match error {
+            ServiceError::Service(..) => unreachable!(),
+            ServiceError::Database(..) => Code::Internal,
+            ServiceError::Schedule(..) => Code::Internal,
+            ServiceError::InvalidWaitlistEntry => Code::InvalidArgument,
+            ServiceError::InvalidId(..) => Code::InvalidArgument,
+            ServiceError::InvalidTime(..) => Code::InvalidArgument,
+            ServiceError::NotFound => Code::NotFound,
+            ServiceError::Unauthenticated => Code::Unauthenticated,
+            ServiceError::NotOwner => Code::PermissionDenied,
+            ServiceError::SeatHeld(..) => Code::AlreadyExists,
+            ServiceError::BookingBlocked(..) => Code::PermissionDenied,
+            ServiceError::InvalidAudience(..) => Code::InvalidArgument,
+            ServiceError::AnnouncementNotFound => Code::NotFound,
         };
 
-        Status::new(code, self.to_string())
+        Status::new(code, error.to_string())
     }
 }
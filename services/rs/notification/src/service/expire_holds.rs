@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use protos::{
+    client::client_client::ClientClient, coworking::coworking_client::CoworkingClient,
+    mail::mail_client::MailClient,
+};
+use service_helper::{channel::ServiceChannel, shutdown::Shutdown};
+use sqlx::PgPool;
+
+use crate::models::db::DBWaitlistEntry;
+
+use super::release_slot::offer_slot;
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodically expires the waitlist holds nobody claimed in time
+/// and passes the slots on to the next clients in line, one client per slot at a time.
+pub async fn run(
+    pool: PgPool,
+    client_client: ClientClient<ServiceChannel>,
+    coworking_client: CoworkingClient<ServiceChannel>,
+    mail_client: MailClient<ServiceChannel>,
+    shutdown: Shutdown,
+) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.requested() => break,
+        }
+
+        let expired = match DBWaitlistEntry::expire(&pool).await {
+            Ok(expired) => expired,
+            Err(err) => {
+                tracing::error!("Failed to expire waitlist holds: {err}");
+                continue;
+            }
+        };
+
+        for entry in expired {
+            let Some((seat_id, starts_at, ends_at)) = entry.offered_slot() else {
+                continue;
+            };
+
+            if let Err(err) = offer_slot(
+                seat_id,
+                starts_at,
+                ends_at,
+                &pool,
+                &client_client,
+                &coworking_client,
+                &mail_client,
+            )
+            .await
+            {
+                tracing::error!("Failed to pass expired hold {} on: {err}", entry.id);
+            }
+        }
+    }
+}
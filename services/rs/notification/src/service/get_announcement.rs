@@ -0,0 +1,27 @@
+use protos::notification::{AnnouncementRequest, AnnouncementResponse};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBAnnouncement, DBAnnouncementProgress};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: AnnouncementRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<AnnouncementResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    let announcement = DBAnnouncement::get_by_id(id, pool)
+        .await?
+        .ok_or(ServiceError::AnnouncementNotFound)?;
+    let progress = DBAnnouncementProgress::get(id, pool).await?;
+
+    Ok(announcement.into_proto(progress))
+}
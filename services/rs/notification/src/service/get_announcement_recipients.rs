@@ -0,0 +1,38 @@
+use protos::notification::{AnnouncementRecipientsResponse, GetAnnouncementRecipientsRequest};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::{DBAnnouncement, DBAnnouncementRecipient};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: GetAnnouncementRecipientsRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<AnnouncementRecipientsResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let id = Uuid::parse_str(&req.id).unwrap();
+
+    if DBAnnouncement::get_by_id(id, pool).await?.is_none() {
+        return Err(ServiceError::AnnouncementNotFound);
+    }
+
+    let recipients = DBAnnouncementRecipient::get_by_announcement(
+        id,
+        req.limit as i64,
+        req.offset as i64,
+        req.status,
+        pool,
+    )
+    .await?
+    .into_iter()
+    .map(Into::into)
+    .collect();
+
+    Ok(AnnouncementRecipientsResponse { recipients })
+}
@@ -0,0 +1,25 @@
+use protos::notification::{AnnouncementsResponse, GetAnnouncementsRequest};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+
+use crate::models::db::{DBAnnouncement, DBAnnouncementProgress};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: GetAnnouncementsRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<AnnouncementsResponse, ServiceError> {
+    if !principal.is_privileged() {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let mut announcements = vec![];
+    for announcement in DBAnnouncement::get_all(req.limit as i64, req.offset as i64, pool).await? {
+        let progress = DBAnnouncementProgress::get(announcement.id, pool).await?;
+        announcements.push(announcement.into_proto(progress));
+    }
+
+    Ok(AnnouncementsResponse { announcements })
+}
@@ -0,0 +1,30 @@
+use protos::notification::{GetWaitlistRequest, WaitlistEntriesResponse};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBWaitlistEntry;
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: GetWaitlistRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<WaitlistEntriesResponse, ServiceError> {
+    if !principal.can_access(&req.client_id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let client_id =
+        Uuid::parse_str(&req.client_id).map_err(|_| ServiceError::InvalidId("client_id"))?;
+
+    let entries =
+        DBWaitlistEntry::get_by_client(client_id, req.limit as i64, req.offset as i64, pool)
+            .await?
+            .into_iter()
+            .map(DBWaitlistEntry::into)
+            .collect();
+
+    Ok(WaitlistEntriesResponse { entries })
+}
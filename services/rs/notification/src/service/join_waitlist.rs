@@ -0,0 +1,51 @@
+use chrono::Utc;
+use convertions::timestamp_into_datetime;
+use protos::notification::{JoinWaitlistRequest, WaitlistEntryResponse};
+use service_helper::principal::Principal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBWaitlistEntry;
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: JoinWaitlistRequest,
+    principal: &Principal,
+    pool: &PgPool,
+) -> Result<WaitlistEntryResponse, ServiceError> {
+    if !principal.can_access(&req.client_id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    if req.seat_id.is_none() && req.seat_type.is_none() {
+        return Err(ServiceError::InvalidWaitlistEntry);
+    }
+
+    let entry = DBWaitlistEntry {
+        id: Uuid::now_v7(),
+        client_id: Uuid::parse_str(&req.client_id)
+            .map_err(|_| ServiceError::InvalidId("client_id"))?,
+        seat_id: req
+            .seat_id
+            .map(|id| Uuid::parse_str(&id))
+            .transpose()
+            .map_err(|_| ServiceError::InvalidId("seat_id"))?,
+        seat_type: req.seat_type,
+        seat_capacity: req.seat_capacity.map(|capacity| capacity as i64),
+        starts_at: timestamp_into_datetime(req.starts_at)
+            .ok_or(ServiceError::InvalidTime("starts_at"))?,
+        ends_at: timestamp_into_datetime(req.ends_at)
+            .ok_or(ServiceError::InvalidTime("ends_at"))?,
+        status: "waiting".to_string(),
+        offered_seat_id: None,
+        offered_starts_at: None,
+        offered_ends_at: None,
+        hold_expires_at: None,
+        created_at: Utc::now(),
+    }
+    .insert(pool)
+    .await?;
+
+    Ok(entry.into())
+}
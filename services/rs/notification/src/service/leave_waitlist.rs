@@ -0,0 +1,48 @@
+use protos::{
+    client::client_client::ClientClient, coworking::coworking_client::CoworkingClient,
+    mail::mail_client::MailClient, notification::LeaveWaitlistRequest,
+};
+use service_helper::{channel::ServiceChannel, principal::Principal};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::db::DBWaitlistEntry;
+
+use super::{error::ServiceError, release_slot::offer_slot};
+
+pub async fn handle(
+    req: LeaveWaitlistRequest,
+    principal: &Principal,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    if !principal.can_access(&req.client_id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let id = Uuid::parse_str(&req.id).map_err(|_| ServiceError::InvalidId("id"))?;
+    let client_id =
+        Uuid::parse_str(&req.client_id).map_err(|_| ServiceError::InvalidId("client_id"))?;
+
+    let entry = DBWaitlistEntry::delete(id, client_id, pool)
+        .await?
+        .ok_or(ServiceError::NotFound)?;
+
+    // the slot was held for this client, so it has to be passed on to the next one in line
+    if let Some((seat_id, starts_at, ends_at)) = entry.offered_slot() {
+        offer_slot(
+            seat_id,
+            starts_at,
+            ends_at,
+            pool,
+            client_client,
+            coworking_client,
+            mail_client,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
@@ -3,29 +3,56 @@ use protos::{
     client::client_client::ClientClient,
     coworking::coworking_client::CoworkingClient,
     mail::mail_client::MailClient,
-    notification::{notification_server::Notification, ScheduleRequest},
+    notification::{
+        notification_server::Notification, AnnouncementRecipientsResponse, AnnouncementRequest,
+        AnnouncementResponse, AnnouncementsResponse, CheckStrikesRequest,
+        CreateAnnouncementRequest, GetAnnouncementRecipientsRequest, GetAnnouncementsRequest,
+        GetWaitlistRequest, HoldRequest, JoinWaitlistRequest, LeaveWaitlistRequest,
+        ReleaseSlotRequest, ScheduleRequest, WaitlistEntriesResponse, WaitlistEntryResponse,
+    },
     reservation::reservation_client::ReservationClient,
 };
+use service_helper::channel::ServiceChannel;
+use service_helper::principal;
 use service_helper::response::ServiceResult;
-use tonic::{async_trait, transport::Channel, Request, Response};
+use sqlx::{Pool, Postgres};
+use tonic::{async_trait, Request, Response};
 
+pub mod announcements;
+mod check_hold;
+mod check_strikes;
+mod create_announcement;
 mod error;
+pub mod expire_holds;
+mod fulfill_hold;
+mod get_announcement;
+mod get_announcement_recipients;
+mod get_announcements;
+mod get_waitlist;
+mod join_waitlist;
+mod leave_waitlist;
+pub mod no_shows;
+mod release_slot;
+pub mod reminders;
 mod schedule;
 
 pub struct NotificationService {
-    pub client_client: ClientClient<Channel>,
-    pub coworking_client: CoworkingClient<Channel>,
-    pub reservation_client: ReservationClient<Channel>,
-    pub mail_client: MailClient<Channel>,
+    pub postgres_pool: Pool<Postgres>,
+    pub client_client: ClientClient<ServiceChannel>,
+    pub coworking_client: CoworkingClient<ServiceChannel>,
+    pub reservation_client: ReservationClient<ServiceChannel>,
+    pub mail_client: MailClient<ServiceChannel>,
 }
 impl NotificationService {
     pub fn new(
-        client_client: ClientClient<Channel>,
-        coworking_client: CoworkingClient<Channel>,
-        reservation_client: ReservationClient<Channel>,
-        mail_client: MailClient<Channel>,
+        pool: Pool<Postgres>,
+        client_client: ClientClient<ServiceChannel>,
+        coworking_client: CoworkingClient<ServiceChannel>,
+        reservation_client: ReservationClient<ServiceChannel>,
+        mail_client: MailClient<ServiceChannel>,
     ) -> Self {
         Self {
+            postgres_pool: pool,
             client_client,
             coworking_client,
             reservation_client,
@@ -37,8 +64,11 @@ impl NotificationService {
 #[async_trait]
 impl Notification for NotificationService {
     async fn schedule(&self, request: Request<ScheduleRequest>) -> ServiceResult<()> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
         schedule::handle(
             request.into_inner(),
+            &principal,
+            &self.postgres_pool,
             &self.client_client,
             &self.coworking_client,
             &self.reservation_client,
@@ -48,4 +78,122 @@ impl Notification for NotificationService {
         .map(Response::new)
         .map_err(ServiceError::into)
     }
+
+    async fn join_waitlist(
+        &self,
+        request: Request<JoinWaitlistRequest>,
+    ) -> ServiceResult<WaitlistEntryResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        join_waitlist::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_waitlist(
+        &self,
+        request: Request<GetWaitlistRequest>,
+    ) -> ServiceResult<WaitlistEntriesResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_waitlist::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn leave_waitlist(&self, request: Request<LeaveWaitlistRequest>) -> ServiceResult<()> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        leave_waitlist::handle(
+            request.into_inner(),
+            &principal,
+            &self.postgres_pool,
+            &self.client_client,
+            &self.coworking_client,
+            &self.mail_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn check_hold(&self, request: Request<HoldRequest>) -> ServiceResult<()> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        check_hold::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn fulfill_hold(&self, request: Request<HoldRequest>) -> ServiceResult<()> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        fulfill_hold::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn release_slot(&self, request: Request<ReleaseSlotRequest>) -> ServiceResult<()> {
+        release_slot::handle(
+            request.into_inner(),
+            &self.postgres_pool,
+            &self.client_client,
+            &self.coworking_client,
+            &self.mail_client,
+        )
+        .await
+        .map(Response::new)
+        .map_err(ServiceError::into)
+    }
+
+    async fn check_strikes(&self, request: Request<CheckStrikesRequest>) -> ServiceResult<()> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        check_strikes::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn create_announcement(
+        &self,
+        request: Request<CreateAnnouncementRequest>,
+    ) -> ServiceResult<AnnouncementResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        create_announcement::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_announcement(
+        &self,
+        request: Request<AnnouncementRequest>,
+    ) -> ServiceResult<AnnouncementResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_announcement::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_announcements(
+        &self,
+        request: Request<GetAnnouncementsRequest>,
+    ) -> ServiceResult<AnnouncementsResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_announcements::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
+
+    async fn get_announcement_recipients(
+        &self,
+        request: Request<GetAnnouncementRecipientsRequest>,
+    ) -> ServiceResult<AnnouncementRecipientsResponse> {
+        let principal = principal::of(&request).ok_or(ServiceError::Unauthenticated)?;
+        get_announcement_recipients::handle(request.into_inner(), &principal, &self.postgres_pool)
+            .await
+            .map(Response::new)
+            .map_err(ServiceError::into)
+    }
 }
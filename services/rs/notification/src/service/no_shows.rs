@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use chrono::{FixedOffset, TimeDelta, Utc};
+use convertions::{datetime_into_timestamp, timestamp_into_datetime};
+use protos::{
+    client::{client_client::ClientClient, ClientMeta, ClientRequest, ClientResponse},
+    coworking::coworking_client::CoworkingClient,
+    mail::{mail_client::MailClient, SendRequest},
+    reservation::{
+        reservation_client::ReservationClient, DeleteRequest, GetNoShowsRequest,
+        ReservationResponse,
+    },
+};
+use service_helper::{channel::ServiceChannel, shutdown::Shutdown};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{config, models::db::DBNoShowStrike};
+
+use super::{error::ServiceError, release_slot::offer_slot};
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+const SWEEP_LIMIT: u32 = 100;
+
+/// Periodically records a no-show strike on the clients who didn't check in
+/// within the grace period, cancels their reservations and offers the rest
+/// of the slot to the waitlist.
+pub async fn run(
+    pool: PgPool,
+    client_client: ClientClient<ServiceChannel>,
+    coworking_client: CoworkingClient<ServiceChannel>,
+    reservation_client: ReservationClient<ServiceChannel>,
+    mail_client: MailClient<ServiceChannel>,
+    shutdown: Shutdown,
+) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.requested() => break,
+        }
+
+        let started_before = Utc::now() - TimeDelta::minutes(*config::NO_SHOW_GRACE_MINUTES);
+
+        let no_shows = match reservation_client
+            .clone()
+            .get_no_shows(GetNoShowsRequest {
+                started_before: datetime_into_timestamp(started_before),
+                limit: SWEEP_LIMIT,
+            })
+            .await
+        {
+            Ok(response) => response.into_inner().reservations,
+            Err(err) => {
+                tracing::error!("Failed to fetch no-show reservations: {err}");
+                continue;
+            }
+        };
+
+        for reservation in no_shows {
+            if let Err(err) = handle_no_show(
+                &reservation,
+                &pool,
+                &client_client,
+                &coworking_client,
+                &reservation_client,
+                &mail_client,
+            )
+            .await
+            {
+                tracing::error!("Failed to handle no-show {}: {err}", reservation.id);
+            }
+        }
+    }
+}
+
+async fn handle_no_show(
+    reservation: &ReservationResponse,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    // the strike goes first, so it isn't lost when the cancellation fails,
+    // and the next sweep doesn't record it twice for the same reservation
+    DBNoShowStrike::insert(
+        Uuid::parse_str(&reservation.client_id).unwrap(),
+        Uuid::parse_str(&reservation.id).unwrap(),
+        pool,
+    )
+    .await?;
+
+    reservation_client
+        .clone()
+        .cancel(DeleteRequest {
+            id: reservation.id.clone(),
+        })
+        .await?;
+
+    let starts_at = timestamp_into_datetime(reservation.starts_at).unwrap();
+    let ends_at = timestamp_into_datetime(reservation.ends_at).unwrap();
+
+    if let Err(err) = offer_slot(
+        Uuid::parse_str(&reservation.seat_id).unwrap(),
+        starts_at.max(Utc::now()),
+        ends_at,
+        pool,
+        client_client,
+        coworking_client,
+        mail_client,
+    )
+    .await
+    {
+        tracing::error!(
+            "Failed to offer the slot of no-show {} to the waitlist: {err}",
+            reservation.id
+        );
+    }
+
+    if let Err(err) = send_notice(reservation, client_client, mail_client).await {
+        tracing::error!(
+            "Failed to notify client about no-show {}: {err}",
+            reservation.id
+        );
+    }
+
+    Ok(())
+}
+
+async fn send_notice(
+    reservation: &ReservationResponse,
+    client_client: &ClientClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    let ClientResponse {
+        meta: ClientMeta { name, email, .. },
+        ..
+    } = client_client
+        .clone()
+        .get(ClientRequest {
+            id: reservation.client_id.clone(),
+        })
+        .await?
+        .into_inner();
+
+    let timezone = FixedOffset::east_opt(60 * 60 * 3).unwrap();
+    let start_time = timestamp_into_datetime(reservation.starts_at)
+        .unwrap()
+        .with_timezone(&timezone)
+        .naive_local();
+
+    mail_client
+        .clone()
+        .send(SendRequest {
+            to: vec![email],
+            subject: Some("Бронирование отменено".to_string()),
+            body: Some(format!(
+                r#"Здравствуйте, {name}!
+
+Вы не пришли на бронирование {}, {}, поэтому оно было отменено, а место освобождено для других гостей.
+
+Если пропуски будут повторяться, возможность бронирования может быть временно ограничена."#,
+                start_time.date(),
+                start_time.time(),
+            )),
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use convertions::timestamp_into_datetime;
+use protos::{
+    client::{client_client::ClientClient, ClientMeta, ClientRequest, ClientResponse},
+    coworking::{coworking_client::CoworkingClient, SeatRequest, SeatResponse},
+    mail::{mail_client::MailClient, SendRequest},
+    notification::ReleaseSlotRequest,
+};
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{config, models::db::DBWaitlistEntry};
+
+use super::error::ServiceError;
+
+pub async fn handle(
+    req: ReleaseSlotRequest,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    offer_slot(
+        Uuid::parse_str(&req.seat_id).map_err(|_| ServiceError::InvalidId("seat_id"))?,
+        timestamp_into_datetime(req.starts_at).ok_or(ServiceError::InvalidTime("starts_at"))?,
+        timestamp_into_datetime(req.ends_at).ok_or(ServiceError::InvalidTime("ends_at"))?,
+        pool,
+        client_client,
+        coworking_client,
+        mail_client,
+    )
+    .await
+}
+
+/// Holds the freed slot for the waitlisted client first in line and notifies them by email.
+/// The others wait until the hold lapses or the client leaves, then it's passed on to the next one.
+pub async fn offer_slot(
+    seat_id: Uuid,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    let seat = coworking_client
+        .clone()
+        .get_seat(SeatRequest {
+            id: seat_id.to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let hold_expires_at = Utc::now() + TimeDelta::minutes(*config::WAITLIST_HOLD_MINUTES);
+
+    let mut transaction = pool.begin().await?;
+
+    let Some(candidate) = DBWaitlistEntry::get_next_candidate(
+        seat_id,
+        &seat.r#type,
+        seat.capacity as i64,
+        starts_at,
+        ends_at,
+        &mut transaction,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+
+    let offer = DBWaitlistEntry::offer(
+        candidate.id,
+        seat_id,
+        starts_at,
+        ends_at,
+        hold_expires_at,
+        &mut transaction,
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    if let Err(err) = send_offer(&offer, &seat, client_client, mail_client).await {
+        tracing::error!(
+            "Failed to notify client about waitlist entry {}: {err}",
+            offer.id
+        );
+    }
+
+    Ok(())
+}
+
+async fn send_offer(
+    offer: &DBWaitlistEntry,
+    seat: &SeatResponse,
+    client_client: &ClientClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    let ClientResponse {
+        meta: ClientMeta { name, email, .. },
+        ..
+    } = principal::scope(
+        // the slot may've been freed by another client, who has no access to the waiter
+        Principal::service("notification"),
+        client_client.clone().get(ClientRequest {
+            id: offer.client_id.to_string(),
+        }),
+    )
+    .await?
+    .into_inner();
+
+    let timezone = FixedOffset::east_opt(60 * 60 * 3).unwrap();
+    let start_time = offer.starts_at.with_timezone(&timezone).naive_local();
+    let end_time = offer.ends_at.with_timezone(&timezone).naive_local();
+    let hold_time = offer
+        .hold_expires_at
+        .unwrap_or(offer.starts_at)
+        .with_timezone(&timezone)
+        .naive_local();
+
+    mail_client
+        .clone()
+        .send(SendRequest {
+            to: vec![email],
+            subject: Some("Место, которое вы ждали, освободилось".to_string()),
+            body: Some(format!(
+                r#"Здравствуйте, {name}!
+
+Место из вашего листа ожидания освободилось:
+
+💺 Номер места: {}
+🕒 Время: {}, {} – {}
+
+Мы закрепили его за вами до {}. Успейте его забронировать, иначе место будет предложено следующему в очереди."#,
+                seat.number,
+                start_time.date(),
+                start_time.time(),
+                end_time.time(),
+                hold_time.time(),
+            )),
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use protos::{
+    client::client_client::ClientClient, coworking::coworking_client::CoworkingClient,
+    mail::mail_client::MailClient, reservation::reservation_client::ReservationClient,
+};
+use service_helper::{channel::ServiceChannel, shutdown::Shutdown};
+use sqlx::PgPool;
+
+use crate::models::db::DBReminder;
+
+use super::schedule::send_reminder;
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(15);
+const SWEEP_LIMIT: i64 = 100;
+
+/// Periodically sends the reminders which are due.
+/// A reminder is removed once it's been handled, so the pending ones are picked up after a restart.
+pub async fn run(
+    pool: PgPool,
+    client_client: ClientClient<ServiceChannel>,
+    coworking_client: CoworkingClient<ServiceChannel>,
+    reservation_client: ReservationClient<ServiceChannel>,
+    mail_client: MailClient<ServiceChannel>,
+    shutdown: Shutdown,
+) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    let pending_reminders = metrics::gauge!("notification_pending_reminders");
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.requested() => break,
+        }
+
+        let reminders = match DBReminder::get_due(SWEEP_LIMIT, &pool).await {
+            Ok(reminders) => reminders,
+            Err(err) => {
+                tracing::error!("Failed to fetch due reminders: {err}");
+                continue;
+            }
+        };
+
+        for reminder in reminders {
+            // the rest stay in the database until the next start
+            if shutdown.is_requested() {
+                break;
+            }
+
+            let result = send_reminder(
+                &reminder,
+                client_client.clone(),
+                coworking_client.clone(),
+                reservation_client.clone(),
+                mail_client.clone(),
+            )
+            .await;
+
+            metrics::counter!(
+                "notification_emails_total",
+                "kind" => "reminder",
+                "result" => if result.is_ok() { "success" } else { "error" }
+            )
+            .increment(1);
+
+            if let Err(err) = result {
+                tracing::error!(
+                    "Failed to send reminder of reservation {}: {err}",
+                    reminder.reservation_id
+                );
+            }
+
+            if let Err(err) = reminder.delete(&pool).await {
+                tracing::error!(
+                    "Failed to remove reminder of reservation {}: {err}",
+                    reminder.reservation_id
+                );
+            }
+        }
+
+        match DBReminder::count(&pool).await {
+            Ok(count) => pending_reminders.set(count as f64),
+            Err(err) => tracing::error!("Failed to count pending reminders: {err}"),
+        }
+    }
+}
@@ -1,8 +1,7 @@
-use std::time::{Duration, SystemTime};
-
 use super::error::ServiceError;
-use crate::config;
-use chrono::FixedOffset;
+use crate::{config, models::db::DBReminder};
+use calendar::{Calendar, Event, EventStatus};
+use chrono::{FixedOffset, NaiveDateTime, Utc};
 use convertions::timestamp_into_datetime;
 use protos::{
     client::{client_client::ClientClient, ClientMeta, ClientRequest, ClientResponse},
@@ -10,114 +9,105 @@ use protos::{
         coworking_client::CoworkingClient, CoworkingResponse, GetCoworkingByIdRequest, SeatRequest,
         SeatResponse,
     },
-    mail::{mail_client::MailClient, SendRequest},
+    mail::{mail_client::MailClient, Attachment, SendRequest},
     notification::ScheduleRequest,
     reservation::{reservation_client::ReservationClient, GetByIdRequest, ReservationResponse},
 };
-use tokio::time::Instant;
-use tonic::transport::Channel;
+use service_helper::{
+    channel::ServiceChannel,
+    principal::{self, Principal},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
 
+/// Sends the booking confirmation right away and schedules the reminder for `send_at`.
+/// Both emails carry the reservation as an `.ics` attachment.
 pub async fn handle(
     req: ScheduleRequest,
-    client_client: &ClientClient<Channel>,
-    coworking_client: &CoworkingClient<Channel>,
-    reservation_client: &ReservationClient<Channel>,
-    mail_client: &MailClient<Channel>,
+    principal: &Principal,
+    pool: &PgPool,
+    client_client: &ClientClient<ServiceChannel>,
+    coworking_client: &CoworkingClient<ServiceChannel>,
+    reservation_client: &ReservationClient<ServiceChannel>,
+    mail_client: &MailClient<ServiceChannel>,
 ) -> Result<(), ServiceError> {
-    let client_client = client_client.clone();
-    let coworking_client = coworking_client.clone();
-    let reservation_client = reservation_client.clone();
-    let mail_client = mail_client.clone();
-
-    let target_time = SystemTime::UNIX_EPOCH + Duration::from_secs(req.send_at.seconds as u64);
-    let sleep_duration = target_time
-        .duration_since(SystemTime::now())
-        .unwrap_or(Duration::ZERO);
-
-    tokio::spawn(async move {
-        tokio::time::sleep_until(Instant::now() + sleep_duration).await;
-        task(
-            req,
-            client_client,
-            coworking_client,
-            reservation_client,
-            mail_client,
-        )
-        .await
-    })
-    .await??;
+    if !principal.can_access(&req.id) {
+        return Err(ServiceError::NotOwner);
+    }
+
+    let details = ReservationDetails::fetch(
+        &req.id,
+        &req.reservation_id,
+        client_client.clone(),
+        coworking_client.clone(),
+        reservation_client.clone(),
+    )
+    .await?;
+
+    if let Err(err) = send_confirmation(details, mail_client.clone()).await {
+        tracing::error!(
+            "Failed to send confirmation of reservation {}: {err}",
+            req.reservation_id
+        );
+    }
+
+    DBReminder::upsert(
+        Uuid::parse_str(&req.reservation_id).unwrap(),
+        Uuid::parse_str(&req.id).unwrap(),
+        timestamp_into_datetime(req.send_at).unwrap(),
+        pool,
+    )
+    .await?;
 
     Ok(())
 }
 
-async fn task(
-    req: ScheduleRequest,
-    mut client_client: ClientClient<Channel>,
-    mut coworking_client: CoworkingClient<Channel>,
-    mut reservation_client: ReservationClient<Channel>,
-    mut mail_client: MailClient<Channel>,
+pub async fn send_reminder(
+    reminder: &DBReminder,
+    client_client: ClientClient<ServiceChannel>,
+    coworking_client: CoworkingClient<ServiceChannel>,
+    reservation_client: ReservationClient<ServiceChannel>,
+    mut mail_client: MailClient<ServiceChannel>,
 ) -> Result<(), ServiceError> {
-    let ClientResponse {
-        id: client_id,
-        meta: ClientMeta { name, email, .. },
-        ..
-    } = client_client
-        .get(ClientRequest { id: req.id.clone() })
-        .await?
-        .into_inner();
+    let details = ReservationDetails::fetch(
+        &reminder.client_id.to_string(),
+        &reminder.reservation_id.to_string(),
+        client_client,
+        coworking_client,
+        reservation_client,
+    )
+    .await?;
 
-    let CoworkingResponse {
-        name: coworking_name,
-        address: coworking_address,
-        ..
-    } = coworking_client
-        .get_by_id(GetCoworkingByIdRequest {
-            id: config::COWORKING_ID.to_string(),
-        })
-        .await?
-        .into_inner();
+    if details.reservation.is_canceled {
+        return Ok(());
+    }
 
-    let ReservationResponse {
-        starts_at,
-        ends_at,
-        seat_id,
+    let ReservationDetails {
+        name,
+        email,
+        coworking:
+            CoworkingResponse {
+                name: coworking_name,
+                address: coworking_address,
+                ..
+            },
+        seat:
+            SeatResponse {
+                number,
+                r#type,
+                cost,
+                features,
+                ..
+            },
         ..
-    } = reservation_client
-        .get_by_id(GetByIdRequest {
-            id: req.id,
-            client_id,
-            is_admin: false,
-        })
-        .await?
-        .into_inner();
+    } = &details;
 
-    let timezone = FixedOffset::west_opt(60 * 60 * 3).unwrap();
-    let start_time = timestamp_into_datetime(starts_at)
-        .unwrap()
-        .with_timezone(&timezone)
-        .naive_local();
-    let end_time = timestamp_into_datetime(ends_at)
-        .unwrap()
-        .with_timezone(&timezone)
-        .naive_local();
-
-    let SeatResponse {
-        number,
-        r#type,
-        cost,
-        features,
-        ..
-    } = coworking_client
-        .get_seat(SeatRequest { id: seat_id })
-        .await?
-        .into_inner();
+    let (start_time, end_time) = details.local_times();
 
     mail_client
         .send(SendRequest {
-            to: vec![email],
-            subject: Some(format!(
-                "Напоминание: Ваша бронь места в коворкинге через 3 часа"
-            )),
+            to: vec![email.clone()],
+            subject: Some("Напоминание: Ваша бронь места в коворкинге через 3 часа".to_string()),
             body: Some(format!(
                 r#"Здравствуйте, {name}!
 
@@ -140,7 +130,160 @@ async fn task(
                 end_time.time(),
                 features.join(", ")
             )),
+            attachments: vec![details.ics_attachment()],
+            ..Default::default()
         })
         .await?;
     Ok(())
 }
+
+async fn send_confirmation(
+    details: ReservationDetails,
+    mut mail_client: MailClient<ServiceChannel>,
+) -> Result<(), ServiceError> {
+    let ReservationDetails {
+        name,
+        email,
+        coworking:
+            CoworkingResponse {
+                name: coworking_name,
+                address: coworking_address,
+                ..
+            },
+        seat: SeatResponse { number, r#type, .. },
+        ..
+    } = &details;
+
+    let (start_time, end_time) = details.local_times();
+
+    mail_client
+        .send(SendRequest {
+            to: vec![email.clone()],
+            subject: Some("Бронь места в коворкинге подтверждена".to_string()),
+            body: Some(format!(
+                r#"Здравствуйте, {name}!
+
+Ваша бронь подтверждена:
+
+📍 Локация: {coworking_name}
+📌 Адрес: {coworking_address}
+🕒 Время: {}, {} – {}
+💺 Место: №{number}, {type}
+
+Чтобы добавить бронь в календарь, откройте приложенный файл.
+
+До встречи!
+{coworking_name}"#,
+                start_time.date(),
+                start_time.time(),
+                end_time.time(),
+            )),
+            attachments: vec![details.ics_attachment()],
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+struct ReservationDetails {
+    name: String,
+    email: String,
+    coworking: CoworkingResponse,
+    reservation: ReservationResponse,
+    seat: SeatResponse,
+}
+impl ReservationDetails {
+    async fn fetch(
+        client_id: &str,
+        reservation_id: &str,
+        mut client_client: ClientClient<ServiceChannel>,
+        mut coworking_client: CoworkingClient<ServiceChannel>,
+        mut reservation_client: ReservationClient<ServiceChannel>,
+    ) -> Result<Self, ServiceError> {
+        let ClientResponse {
+            id: client_id,
+            meta: ClientMeta { name, email, .. },
+            ..
+        } = client_client
+            .get(ClientRequest {
+                id: client_id.to_string(),
+            })
+            .await?
+            .into_inner();
+
+        let coworking = coworking_client
+            .get_by_id(GetCoworkingByIdRequest {
+                id: config::COWORKING_ID.to_string(),
+            })
+            .await?
+            .into_inner();
+
+        // fetched on behalf of the client, so it's only found if the reservation is theirs
+        let reservation = principal::scope(
+            Principal::client(&client_id),
+            reservation_client.get_by_id(GetByIdRequest {
+                id: reservation_id.to_string(),
+            }),
+        )
+        .await?
+        .into_inner();
+
+        let seat = coworking_client
+            .get_seat(SeatRequest {
+                id: reservation.seat_id.clone(),
+            })
+            .await?
+            .into_inner();
+
+        Ok(Self {
+            name,
+            email,
+            coworking,
+            reservation,
+            seat,
+        })
+    }
+
+    fn local_times(&self) -> (NaiveDateTime, NaiveDateTime) {
+        let timezone = FixedOffset::east_opt(60 * 60 * 3).unwrap();
+        let local_time = |timestamp| {
+            timestamp_into_datetime(timestamp)
+                .unwrap()
+                .with_timezone(&timezone)
+                .naive_local()
+        };
+
+        (
+            local_time(self.reservation.starts_at),
+            local_time(self.reservation.ends_at),
+        )
+    }
+
+    fn ics_attachment(&self) -> Attachment {
+        let event = Event {
+            uid: format!("{}@{}", self.reservation.id, self.coworking.id),
+            starts_at: timestamp_into_datetime(self.reservation.starts_at).unwrap(),
+            ends_at: timestamp_into_datetime(self.reservation.ends_at).unwrap(),
+            summary: format!(
+                "Коворкинг {}, место №{}",
+                self.coworking.name, self.seat.number
+            ),
+            location: self.coworking.address.clone(),
+            description: format!(
+                "Место №{}, {}\nОсобенности: {}",
+                self.seat.number,
+                self.seat.r#type,
+                self.seat.features.join(", ")
+            ),
+            status: EventStatus::Confirmed,
+        };
+
+        Attachment {
+            filename: "reservation.ics".to_string(),
+            content_type: "text/calendar; method=PUBLISH; charset=utf-8".to_string(),
+            content: Calendar::new(None, vec![event])
+                .to_ics(Utc::now())
+                .into_bytes(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 CREATE DATABASE coworking;
 CREATE DATABASE client;
 CREATE DATABASE admin;
-CREATE DATABASE reservation;
\ No newline at end of file
+CREATE DATABASE reservation;
+CREATE DATABASE notification;
+CREATE DATABASE billing;
\ No newline at end of file